@@ -185,6 +185,7 @@ impl Into<MinifyOptions> for TransformOptions {
         None
       },
       unused_symbols,
+      ..MinifyOptions::default()
     }
   }
 }
@@ -284,6 +285,7 @@ pub extern "C" fn lightningcss_stylesheet_parse(
     error_recovery: options.error_recovery,
     source_index: 0,
     warnings: Some(warnings.clone()),
+    preserve_comments: false,
   };
 
   let stylesheet = unwrap!(StyleSheet::parse(code, opts), error, std::ptr::null_mut());
@@ -335,6 +337,7 @@ pub extern "C" fn lightningcss_stylesheet_to_css(
     } else {
       None
     },
+    ..PrinterOptions::default()
   };
 
   let res = unwrap!(wrapper.stylesheet.to_css(opts), error, ToCssResult::default());
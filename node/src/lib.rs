@@ -588,11 +588,13 @@ fn compile<'i>(code: &'i str, config: &Config) -> Result<TransformResult<'i>, Co
         source_index: 0,
         error_recovery: config.error_recovery.unwrap_or_default(),
         warnings: warnings.clone(),
+        preserve_comments: false,
       },
     )?;
     stylesheet.minify(MinifyOptions {
       targets: config.targets,
       unused_symbols: config.unused_symbols.clone().unwrap_or_default(),
+      ..MinifyOptions::default()
     })?;
 
     stylesheet.to_css(PrinterOptions {
@@ -611,6 +613,7 @@ fn compile<'i>(code: &'i str, config: &Config) -> Result<TransformResult<'i>, Co
         None
       },
       pseudo_classes: config.pseudo_classes.as_ref().map(|p| p.into()),
+      ..PrinterOptions::default()
     })?
   };
 
@@ -689,6 +692,7 @@ fn compile_bundle<'i, P: SourceProvider>(
     stylesheet.minify(MinifyOptions {
       targets: config.targets,
       unused_symbols: config.unused_symbols.clone().unwrap_or_default(),
+      ..MinifyOptions::default()
     })?;
 
     stylesheet.to_css(PrinterOptions {
@@ -707,6 +711,7 @@ fn compile_bundle<'i, P: SourceProvider>(
         None
       },
       pseudo_classes: config.pseudo_classes.as_ref().map(|p| p.into()),
+      ..PrinterOptions::default()
     })?
   };
 
@@ -806,6 +811,7 @@ fn compile_attr<'i>(
         None
       },
       pseudo_classes: None,
+      ..PrinterOptions::default()
     })?
   };
   Ok(AttrResult {
@@ -962,3 +968,38 @@ impl<'i> From<Error<ParserError<'i>>> for Warning<'i> {
     }
   }
 }
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+  use super::*;
+  use js_sys::{Object, Reflect, Uint8Array};
+  use wasm_bindgen_test::*;
+
+  fn config(code: &str) -> Object {
+    let config = Object::new();
+    Reflect::set(&config, &"filename".into(), &"test.css".into()).unwrap();
+    Reflect::set(&config, &"code".into(), &Uint8Array::from(code.as_bytes())).unwrap();
+    Reflect::set(&config, &"minify".into(), &true.into()).unwrap();
+    config
+  }
+
+  #[wasm_bindgen_test]
+  fn test_transform() {
+    let res = transform(config(".foo { color: #ff0000 }").into()).unwrap();
+    let code = Uint8Array::new(&Reflect::get(&res, &"code".into()).unwrap()).to_vec();
+    assert_eq!(code, b".foo{color:red}");
+    let map = Reflect::get(&res, &"map".into()).unwrap();
+    assert!(map.is_null() || map.is_undefined());
+    assert_eq!(
+      js_sys::Array::from(&Reflect::get(&res, &"warnings".into()).unwrap()).length(),
+      0
+    );
+  }
+
+  #[wasm_bindgen_test]
+  fn test_transform_error() {
+    let err = transform(config(".foo { color: red } @import 'foo.css';").into()).unwrap_err();
+    let err: js_sys::Error = err.into();
+    assert!(String::from(err.message()).contains("@import"));
+  }
+}
@@ -169,7 +169,12 @@ let cssFeatures = [
   'css-nesting',
   'css-not-sel-list',
   'css-has',
-  'font-family-system-ui'
+  'css-nth-child-of',
+  'font-family-system-ui',
+  'woff',
+  'woff2',
+  'ttf',
+  'eot'
 ];
 
 let compat = new Map();
@@ -219,6 +224,10 @@ let mdnFeatures = {
   logicalMarginShorthand: mdn.css.properties['margin-inline'].__compat.support,
   logicalPadding: mdn.css.properties['padding-inline-start'].__compat.support,
   logicalPaddingShorthand: mdn.css.properties['padding-inline'].__compat.support,
+  logicalScrollMargin: mdn.css.properties['scroll-margin-inline-start'].__compat.support,
+  logicalScrollMarginShorthand: mdn.css.properties['scroll-margin-inline'].__compat.support,
+  logicalScrollPadding: mdn.css.properties['scroll-padding-inline-start'].__compat.support,
+  logicalScrollPaddingShorthand: mdn.css.properties['scroll-padding-inline'].__compat.support,
   logicalInset: mdn.css.properties['inset-inline-start'].__compat.support,
   logicalSize: mdn.css.properties['inline-size'].__compat.support,
   logicalTextAlign: mdn.css.properties['text-align']['flow_relative_values_start_and_end'].__compat.support,
@@ -247,7 +256,15 @@ let mdnFeatures = {
       })
   ),
   imageSet: mdn.css.types.image['image-set'].__compat.support,
-  xResolutionUnit: mdn.css.types.resolution.x.__compat.support
+  xResolutionUnit: mdn.css.types.resolution.x.__compat.support,
+  viewportPercentageUnitsDynamic: mdn.css.types.length.viewport_percentage_units_dynamic.__compat.support,
+  viewportPercentageUnitsLarge: mdn.css.types.length.viewport_percentage_units_large.__compat.support,
+  viewportPercentageUnitsSmall: mdn.css.types.length.viewport_percentage_units_small.__compat.support,
+  individualTransforms: mdn.css.properties.translate.__compat.support,
+  gap: mdn.css.properties.gap.grid_context.__compat.support,
+  displayGrid: mdn.css.properties.display.grid.__compat.support,
+  displayFlex: mdn.css.properties.display.flex.__compat.support,
+  displayContents: mdn.css.properties.display.contents.__compat.support
 };
 
 for (let feature in mdnFeatures) {
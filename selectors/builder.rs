@@ -315,12 +315,29 @@ where
       | Component::NonTSPseudoClass(..) => {
         specificity.class_like_selectors += 1;
       }
-      Component::Negation(ref list) | Component::Is(ref list) | Component::Any(_, ref list) => {
+      Component::NthChildOf(_, _, ref list) | Component::NthLastChildOf(_, _, ref list) => {
         // https://drafts.csswg.org/selectors/#specificity-rules:
         //
-        //     The specificity of an :is() pseudo-class is replaced by the
-        //     specificity of the most specific complex selector in its
-        //     selector list argument.
+        //     The specificity of an :nth-child() or :nth-last-child() selector
+        //     is the specificity of the pseudo class itself (counting as one
+        //     pseudo-class selector) plus the specificity of the most specific
+        //     complex selector in its selector list argument (if any).
+        specificity.class_like_selectors += 1;
+        let mut max = 0;
+        for selector in &**list {
+          max = std::cmp::max(selector.specificity(), max);
+        }
+        *specificity += Specificity::from(max);
+      }
+      Component::Negation(ref list)
+      | Component::Is(ref list)
+      | Component::Has(ref list)
+      | Component::Any(_, ref list) => {
+        // https://drafts.csswg.org/selectors/#specificity-rules:
+        //
+        //     The specificity of an :is(), :not(), or :has() pseudo-class is
+        //     replaced by the specificity of the most specific complex
+        //     selector in its selector list argument.
         let mut max = 0;
         for selector in &**list {
           max = std::cmp::max(selector.specificity(), max);
@@ -328,7 +345,6 @@ where
         *specificity += Specificity::from(max);
       }
       Component::Where(..)
-      | Component::Has(..)
       | Component::ExplicitUniversalType
       | Component::ExplicitAnyNamespace
       | Component::ExplicitNoNamespace
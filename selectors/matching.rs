@@ -372,6 +372,8 @@ fn matches_hover_and_active_quirk<'i, Impl: SelectorImpl<'i>>(
     | Component::Empty
     | Component::NthChild(_, _)
     | Component::NthLastChild(_, _)
+    | Component::NthChildOf(..)
+    | Component::NthLastChildOf(..)
     | Component::NthCol(_, _)
     | Component::NthLastCol(_, _)
     | Component::NthOfType(_, _)
@@ -789,6 +791,12 @@ where
     },
     Component::NthChild(a, b) => matches_generic_nth_child(element, context, a, b, false, false, flags_setter),
     Component::NthLastChild(a, b) => matches_generic_nth_child(element, context, a, b, false, true, flags_setter),
+    Component::NthChildOf(a, b, ref list) => {
+      matches_nth_child_of(element, context, a, b, list, false, flags_setter)
+    }
+    Component::NthLastChildOf(a, b, ref list) => {
+      matches_nth_child_of(element, context, a, b, list, true, flags_setter)
+    }
     Component::NthCol(a, b) => matches_generic_nth_child(element, context, a, b, false, false, flags_setter),
     Component::NthLastCol(a, b) => matches_generic_nth_child(element, context, a, b, false, true, flags_setter),
     Component::NthOfType(a, b) => matches_generic_nth_child(element, context, a, b, true, false, flags_setter),
@@ -886,6 +894,73 @@ where
   }
 }
 
+/// Matches `:nth-child(An+B of S)` and `:nth-last-child(An+B of S)`. Only siblings that match
+/// one of the selectors are counted, so the nth index cache cannot be used.
+fn matches_nth_child_of<'i, E, F>(
+  element: &E,
+  context: &mut LocalMatchingContext<'_, '_, 'i, E::Impl>,
+  a: i32,
+  b: i32,
+  selectors: &[Selector<'i, E::Impl>],
+  is_from_end: bool,
+  flags_setter: &mut F,
+) -> bool
+where
+  E: Element<'i>,
+  F: FnMut(&E, ElementSelectorFlags),
+{
+  if element.ignores_nth_child_selectors() {
+    return false;
+  }
+
+  flags_setter(
+    element,
+    if is_from_end {
+      ElementSelectorFlags::HAS_SLOW_SELECTOR
+    } else {
+      ElementSelectorFlags::HAS_SLOW_SELECTOR_LATER_SIBLINGS
+    },
+  );
+
+  let mut matches_any = |element: &E, context: &mut MatchingContext<'_, 'i, E::Impl>| {
+    context.nest(|context| {
+      selectors
+        .iter()
+        .any(|selector| matches_complex_selector(selector.iter(), element, context, flags_setter))
+    })
+  };
+
+  if !matches_any(element, &mut *context.shared) {
+    return false;
+  }
+
+  let mut index: i32 = 1;
+  let mut curr = element.clone();
+  loop {
+    let sibling = if is_from_end {
+      curr.next_sibling_element()
+    } else {
+      curr.prev_sibling_element()
+    };
+    curr = match sibling {
+      Some(e) => e,
+      None => break,
+    };
+    if matches_any(&curr, &mut *context.shared) {
+      index += 1;
+    }
+  }
+
+  // Is there a non-negative integer n such that An+B=index?
+  match index.checked_sub(b) {
+    None => false,
+    Some(an) => match an.checked_div(a) {
+      Some(n) => n >= 0 && a * n == an,
+      None /* a == 0 */ => an == 0,
+    },
+  }
+}
+
 #[inline]
 fn nth_child_index<'i, E>(
   element: &E,
@@ -827,6 +827,21 @@ impl<'i, Impl: SelectorImpl<'i>> Selector<'i, Impl> {
     Selector(spec, components)
   }
 
+  /// Creates a Selector from a vec of Components, specified in parse order, keeping the
+  /// pseudo-element, slotted, and part flags of this selector. The specificity is recomputed.
+  pub fn with_components(&self, vec: Vec<Component<'i, Impl>>) -> Self {
+    let mut builder = SelectorBuilder::default();
+    for component in vec.into_iter() {
+      if let Some(combinator) = component.as_combinator() {
+        builder.push_combinator(combinator);
+      } else {
+        builder.push_simple_selector(component);
+      }
+    }
+    let (spec, components) = builder.build(self.has_pseudo_element(), self.is_slotted(), self.is_part());
+    Selector(spec, components)
+  }
+
   /// Returns count of simple selectors and combinators in the Selector.
   #[inline]
   pub fn len(&self) -> usize {
@@ -1107,6 +1122,14 @@ pub enum Component<'i, Impl: SelectorImpl<'i>> {
   Scope,
   NthChild(i32, i32),
   NthLastChild(i32, i32),
+  /// The `:nth-child(An+B of S)` pseudo-class.
+  ///
+  /// https://drafts.csswg.org/selectors/#the-nth-child-pseudo
+  ///
+  /// Only siblings matching one of the selectors in the list are counted.
+  NthChildOf(i32, i32, Box<[Selector<'i, Impl>]>),
+  /// The `:nth-last-child(An+B of S)` pseudo-class.
+  NthLastChildOf(i32, i32, Box<[Selector<'i, Impl>]>),
   NthCol(i32, i32), // https://www.w3.org/TR/selectors-4/#the-nth-col-pseudo
   NthLastCol(i32, i32), // https://www.w3.org/TR/selectors-4/#the-nth-last-col-pseudo
   NthOfType(i32, i32),
@@ -1292,7 +1315,11 @@ impl<'i, Impl: SelectorImpl<'i>> Component<'i, Impl> {
         }
       }
 
-      Negation(ref list) | Is(ref list) | Where(ref list) => {
+      Negation(ref list)
+      | Is(ref list)
+      | Where(ref list)
+      | NthChildOf(_, _, ref list)
+      | NthLastChildOf(_, _, ref list) => {
         if !visitor.visit_selector_list(&list) {
           return false;
         }
@@ -1495,6 +1522,26 @@ impl ToCss for Combinator {
   }
 }
 
+/// Serialize <an+b> values (part of the CSS Syntax spec, but currently only used for selectors).
+/// <https://drafts.csswg.org/css-syntax-3/#serialize-an-anb-value>
+pub fn write_affine<W>(dest: &mut W, a: i32, b: i32) -> fmt::Result
+where
+  W: fmt::Write,
+{
+  match (a, b) {
+    (0, 0) => dest.write_char('0'),
+
+    (1, 0) => dest.write_char('n'),
+    (-1, 0) => dest.write_str("-n"),
+    (_, 0) => write!(dest, "{}n", a),
+
+    (0, _) => write!(dest, "{}", b),
+    (1, _) => write!(dest, "n{:+}", b),
+    (-1, _) => write!(dest, "-n{:+}", b),
+    (_, _) => write!(dest, "{}n{:+}", a, b),
+  }
+}
+
 impl<'i, Impl: SelectorImpl<'i>> ToCss for Component<'i, Impl> {
   fn to_css<W>(&self, dest: &mut W) -> fmt::Result
   where
@@ -1502,26 +1549,6 @@ impl<'i, Impl: SelectorImpl<'i>> ToCss for Component<'i, Impl> {
   {
     use self::Component::*;
 
-    /// Serialize <an+b> values (part of the CSS Syntax spec, but currently only used here).
-    /// <https://drafts.csswg.org/css-syntax-3/#serialize-an-anb-value>
-    fn write_affine<W>(dest: &mut W, a: i32, b: i32) -> fmt::Result
-    where
-      W: fmt::Write,
-    {
-      match (a, b) {
-        (0, 0) => dest.write_char('0'),
-
-        (1, 0) => dest.write_char('n'),
-        (-1, 0) => dest.write_str("-n"),
-        (_, 0) => write!(dest, "{}n", a),
-
-        (0, _) => write!(dest, "{}", b),
-        (1, _) => write!(dest, "n{:+}", b),
-        (-1, _) => write!(dest, "-n{:+}", b),
-        (_, _) => write!(dest, "{}n{:+}", a, b),
-      }
-    }
-
     match *self {
       Combinator(ref c) => c.to_css(dest),
       Slotted(ref selector) => {
@@ -1619,6 +1646,17 @@ impl<'i, Impl: SelectorImpl<'i>> ToCss for Component<'i, Impl> {
         write_affine(dest, a, b)?;
         dest.write_char(')')
       }
+      NthChildOf(a, b, ref list) | NthLastChildOf(a, b, ref list) => {
+        match *self {
+          NthChildOf(..) => dest.write_str(":nth-child(")?,
+          NthLastChildOf(..) => dest.write_str(":nth-last-child(")?,
+          _ => unreachable!(),
+        }
+        write_affine(dest, a, b)?;
+        dest.write_str(" of ")?;
+        serialize_selector_list(list.iter(), dest)?;
+        dest.write_char(')')
+      }
       Is(ref list) | Where(ref list) | Negation(ref list) | Has(ref list) | Any(_, ref list) => {
         match *self {
           Where(..) => dest.write_str(":where(")?,
@@ -1702,16 +1740,33 @@ where
   P: Parser<'i, Impl = Impl>,
   Impl: SelectorImpl<'i>,
 {
+  let mut builder = SelectorBuilder::default();
+
   if nesting_requirement == NestingRequirement::Prefixed {
-    let state = input.state();
-    if !input.expect_delim('&').is_ok() {
-      return Err(input.new_custom_error(SelectorParseErrorKind::MissingNestingPrefix));
+    let parser_state = input.state();
+    // A nested selector that starts with a combinator or a type selector is relative to the
+    // parent rule, i.e. it has an implicit nesting selector, e.g. `> .foo` is `& > .foo`.
+    let combinator = match input.next() {
+      Ok(Token::Delim('&')) => None,
+      Ok(Token::Delim('>')) => Some(Combinator::Child),
+      Ok(Token::Delim('+')) => Some(Combinator::NextSibling),
+      Ok(Token::Delim('~')) => Some(Combinator::LaterSibling),
+      Ok(Token::Ident(_)) | Ok(Token::Delim('*')) => {
+        input.reset(&parser_state);
+        Some(Combinator::Descendant)
+      }
+      _ => return Err(input.new_custom_error(SelectorParseErrorKind::MissingNestingPrefix)),
+    };
+
+    if let Some(combinator) = combinator {
+      state.insert(SelectorParsingState::AFTER_NESTING);
+      builder.push_simple_selector(Component::Nesting);
+      builder.push_combinator(combinator);
+    } else {
+      input.reset(&parser_state);
     }
-    input.reset(&state);
   }
 
-  let mut builder = SelectorBuilder::default();
-
   let mut has_pseudo_element = false;
   let mut slotted = false;
   let mut part = false;
@@ -2384,8 +2439,8 @@ where
   Impl: SelectorImpl<'i>,
 {
   match_ignore_ascii_case! { &name,
-      "nth-child" => return parse_nth_pseudo_class(parser, input, *state, Component::NthChild),
-      "nth-last-child" => return parse_nth_pseudo_class(parser, input, *state, Component::NthLastChild),
+      "nth-child" => return parse_nth_child(parser, input, state, Component::NthChild, Component::NthChildOf),
+      "nth-last-child" => return parse_nth_child(parser, input, state, Component::NthLastChild, Component::NthLastChildOf),
       "nth-col" => return parse_nth_pseudo_class(parser, input, *state, Component::NthCol),
       "nth-last-col" => return parse_nth_pseudo_class(parser, input, *state, Component::NthLastCol),
       "nth-of-type" => return parse_nth_pseudo_class(parser, input, *state, Component::NthOfType),
@@ -2434,6 +2489,43 @@ where
   Ok(selector(a, b))
 }
 
+/// Parses `:nth-child()` or `:nth-last-child()`, including the optional `of <selector-list>` clause.
+fn parse_nth_child<'i, 't, P, Impl, F, G>(
+  parser: &P,
+  input: &mut CssParser<'i, 't>,
+  state: &mut SelectorParsingState,
+  selector: F,
+  selector_of: G,
+) -> Result<Component<'i, Impl>, ParseError<'i, P::Error>>
+where
+  P: Parser<'i, Impl = Impl>,
+  Impl: SelectorImpl<'i>,
+  F: FnOnce(i32, i32) -> Component<'i, Impl>,
+  G: FnOnce(i32, i32, Box<[Selector<'i, Impl>]>) -> Component<'i, Impl>,
+{
+  if !state.allows_tree_structural_pseudo_classes() {
+    return Err(input.new_custom_error(SelectorParseErrorKind::InvalidState));
+  }
+  let (a, b) = parse_nth(input)?;
+  if input.try_parse(|input| input.expect_ident_matching("of")).is_err() {
+    return Ok(selector(a, b));
+  }
+
+  let mut child_state =
+    *state | SelectorParsingState::SKIP_DEFAULT_NAMESPACE | SelectorParsingState::DISALLOW_PSEUDOS;
+  let list = SelectorList::parse_with_state(
+    parser,
+    input,
+    &mut child_state,
+    ParseErrorRecovery::DiscardList,
+    NestingRequirement::None,
+  )?;
+  if child_state.contains(SelectorParsingState::AFTER_NESTING) {
+    state.insert(SelectorParsingState::AFTER_NESTING)
+  }
+  Ok(selector_of(a, b, list.0.into_vec().into_boxed_slice()))
+}
+
 /// Returns whether the name corresponds to a CSS2 pseudo-element that
 /// can be specified with the single colon syntax (in addition to the
 /// double-colon syntax, which can be used for all pseudo-elements).
@@ -3379,6 +3471,18 @@ pub mod tests {
     assert!(parse("foo:where()").is_err());
     assert!(parse("foo:where(div, foo, .bar baz)").is_ok());
     assert!(parse("foo:where(::before)").is_err());
+
+    assert!(parse("a:has(> img)").is_ok());
+    assert!(parse("h1:has(+ p, .x)").is_ok());
+    assert_eq!(
+      parse("a:has(> #b, .c)").unwrap().0[0].specificity(),
+      specificity(1, 0, 1)
+    );
+    assert_eq!(parse(":has(.a .b)").unwrap().0[0].specificity(), specificity(0, 2, 0));
+    assert_eq!(
+      parse(":where(#a):has(p)").unwrap().0[0].specificity(),
+      specificity(0, 0, 1)
+    );
   }
 
   #[test]
@@ -3418,6 +3522,23 @@ pub mod tests {
     assert_eq!(iter.next_sequence(), None);
   }
 
+  #[test]
+  fn test_nth_child_of() {
+    let selector = &parse(":nth-child(2n+1 of .x)").unwrap().0[0];
+    assert_eq!(selector.specificity(), specificity(0, 2, 0));
+    let selector = &parse(":nth-child(2n+1)").unwrap().0[0];
+    assert_eq!(selector.specificity(), specificity(0, 1, 0));
+    let selector = &parse_expected(
+      "li:nth-last-child(odd of #a, .b.c)",
+      Some("li:nth-last-child(2n+1 of #a, .b.c)"),
+    )
+    .unwrap()
+    .0[0];
+    assert_eq!(selector.specificity(), specificity(1, 1, 1));
+    assert!(parse(":nth-child(2n+1 of)").is_err());
+    assert!(parse(":nth-child(2n+1 of ::before)").is_err());
+  }
+
   struct TestVisitor {
     seen: Vec<String>,
   }
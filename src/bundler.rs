@@ -142,6 +142,78 @@ impl Drop for FileProvider {
   }
 }
 
+/// Provides an implementation of [SourceProvider](SourceProvider) that
+/// resolves import specifiers to source text using a function.
+///
+/// Specifiers are passed to the function as written, and the function returns
+/// `None` if the specifier could not be resolved.
+///
+/// # Example
+///
+/// ```
+/// use std::path::Path;
+/// use lightningcss::{
+///   bundler::{Bundler, FnProvider},
+///   stylesheet::{ParserOptions, PrinterOptions}
+/// };
+///
+/// let provider = FnProvider::new(|specifier| match specifier {
+///   "style.css" => Some("@import 'a.css' print; .b { color: red }".into()),
+///   "a.css" => Some(".a { color: blue }".into()),
+///   _ => None,
+/// });
+/// let mut bundler = Bundler::new(&provider, None, ParserOptions::default());
+/// let stylesheet = bundler.bundle(Path::new("style.css")).unwrap();
+/// let res = stylesheet.to_css(PrinterOptions { minify: true, ..PrinterOptions::default() }).unwrap();
+/// assert_eq!(res.code, "@media print{.a{color:#00f}}.b{color:red}");
+/// ```
+pub struct FnProvider<F> {
+  resolve: F,
+  inputs: Mutex<Vec<*mut String>>,
+}
+
+impl<F: Fn(&str) -> Option<String> + Send + Sync> FnProvider<F> {
+  /// Creates a new FnProvider with the given resolver function.
+  pub fn new(resolve: F) -> FnProvider<F> {
+    FnProvider {
+      resolve,
+      inputs: Mutex::new(Vec::new()),
+    }
+  }
+}
+
+unsafe impl<F: Sync> Sync for FnProvider<F> {}
+unsafe impl<F: Send> Send for FnProvider<F> {}
+
+impl<F: Fn(&str) -> Option<String> + Send + Sync> SourceProvider for FnProvider<F> {
+  type Error = std::io::Error;
+
+  fn read<'a>(&'a self, file: &Path) -> Result<&'a str, Self::Error> {
+    let specifier = file.to_string_lossy();
+    let source = (self.resolve)(&specifier).ok_or_else(|| {
+      std::io::Error::new(std::io::ErrorKind::NotFound, format!("Could not resolve {}", specifier))
+    })?;
+    let ptr = Box::into_raw(Box::new(source));
+    self.inputs.lock().unwrap().push(ptr);
+    // SAFETY: this is safe because the pointer is not dropped
+    // until the FnProvider is, and we never remove from the
+    // list of pointers stored in the vector.
+    Ok(unsafe { &*ptr })
+  }
+
+  fn resolve(&self, specifier: &str, _originating_file: &Path) -> Result<PathBuf, Self::Error> {
+    Ok(PathBuf::from(specifier))
+  }
+}
+
+impl<F> Drop for FnProvider<F> {
+  fn drop(&mut self) {
+    for ptr in self.inputs.lock().unwrap().iter() {
+      std::mem::drop(unsafe { Box::from_raw(*ptr) })
+    }
+  }
+}
+
 /// An error that could occur during bundling.
 #[derive(Debug, Serialize)]
 pub enum BundleErrorKind<'i, T: std::error::Error> {
@@ -627,6 +699,10 @@ impl<'a, 'o, 's, P: SourceProvider> Bundler<'a, 'o, 's, P> {
             let layer = std::mem::replace(rule, CssRule::Ignored);
             dest.push(layer);
           }
+          CssRule::Comment(_) => {
+            let comment = std::mem::replace(rule, CssRule::Ignored);
+            dest.push(comment);
+          }
           CssRule::Ignored => {}
           _ => break,
         }
@@ -692,6 +768,11 @@ fn visit_vars<'a, 'b>(
           }
           return Some(&mut var.name);
         }
+        Some(TokenOrValue::Env(env)) => {
+          if let Some(fallback) = &mut env.fallback {
+            stack.push(fallback.0.iter_mut());
+          }
+        }
         Some(TokenOrValue::UnresolvedColor(color)) => match color {
           UnresolvedColor::RGB { alpha, .. } | UnresolvedColor::HSL { alpha, .. } => {
             stack.push(alpha.0.iter_mut());
@@ -1662,6 +1743,83 @@ mod tests {
     // }, "/a.css");
   }
 
+  #[test]
+  fn test_fn_provider() {
+    let provider = |map: HashMap<&'static str, &'static str>| {
+      FnProvider::new(move |specifier| map.get(specifier).map(|s| s.to_string()))
+    };
+
+    let res = bundle(
+      provider(HashMap::from([
+        ("a.css", "@import \"b.css\"; .a { color: red }"),
+        ("b.css", ".b { color: green }"),
+      ])),
+      "a.css",
+    );
+    assert_eq!(
+      res,
+      indoc! { r#"
+      .b {
+        color: green;
+      }
+
+      .a {
+        color: red;
+      }
+    "#}
+    );
+
+    let res = bundle(
+      provider(HashMap::from([
+        ("a.css", "@import \"b.css\" print; .a { color: red }"),
+        ("b.css", ".b { color: green }"),
+      ])),
+      "a.css",
+    );
+    assert_eq!(
+      res,
+      indoc! { r#"
+      @media print {
+        .b {
+          color: green;
+        }
+      }
+
+      .a {
+        color: red;
+      }
+    "#}
+    );
+
+    let res = bundle(
+      provider(HashMap::from([
+        ("a.css", "@import \"b.css\"; .a { color: red }"),
+        ("b.css", "@import \"a.css\"; .b { color: green }"),
+      ])),
+      "a.css",
+    );
+    assert_eq!(
+      res,
+      indoc! { r#"
+      .b {
+        color: green;
+      }
+
+      .a {
+        color: red;
+      }
+    "#}
+    );
+
+    error_test(
+      provider(HashMap::from([("a.css", "@import \"missing.css\";")])),
+      "a.css",
+      Some(Box::new(|err| {
+        assert!(matches!(err, BundleErrorKind::ResolverError(e) if e.kind() == std::io::ErrorKind::NotFound));
+      })),
+    );
+  }
+
   #[test]
   fn test_css_module() {
     macro_rules! map {
@@ -1806,7 +1964,7 @@ mod tests {
       ._8Cs9ZG_b {
         --_8Cs9ZG_bg: red;
         --_8Cs9ZG_fallback: yellow;
-        --_8Cs9ZG_opacity: .5;
+        --_8Cs9ZG_opacity: 0.5;
       }
 
       ._6lixEq_a {
@@ -25,6 +25,7 @@ pub enum Feature {
   CssNamespaces,
   CssNesting,
   CssNotSelList,
+  CssNthChildOf,
   CssOptionalPseudo,
   CssPlaceholder,
   CssPlaceholderShown,
@@ -37,11 +38,17 @@ pub enum Feature {
   CueFunction,
   CustomMediaQueries,
   Dialog,
+  DisplayContents,
+  DisplayFlex,
+  DisplayGrid,
   DoublePositionGradients,
+  Eot,
   FontFamilySystemUi,
   FormValidation,
   Fullscreen,
+  Gap,
   ImageSet,
+  IndividualTransforms,
   LabColors,
   LangList,
   LogicalBorderRadius,
@@ -52,6 +59,10 @@ pub enum Feature {
   LogicalMarginShorthand,
   LogicalPadding,
   LogicalPaddingShorthand,
+  LogicalScrollMargin,
+  LogicalScrollMarginShorthand,
+  LogicalScrollPadding,
+  LogicalScrollPaddingShorthand,
   LogicalSize,
   LogicalTextAlign,
   MediaIntervalSyntax,
@@ -66,6 +77,12 @@ pub enum Feature {
   SpaceSeparatedColorFunction,
   TextDecorationThicknessPercent,
   TextDecorationThicknessShorthand,
+  Ttf,
+  ViewportPercentageUnitsDynamic,
+  ViewportPercentageUnitsLarge,
+  ViewportPercentageUnitsSmall,
+  Woff,
+  Woff2,
   XResolutionUnit,
 }
 
@@ -1256,6 +1273,51 @@ impl Feature {
           return false;
         }
       }
+      Feature::CssNthChildOf => {
+        if let Some(version) = browsers.edge {
+          if version < 7274496 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 7405568 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.chrome {
+          if version < 7274496 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 589824 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 6356992 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 589824 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 7274496 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1441792 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
       Feature::CssHas => {
         if let Some(version) = browsers.chrome {
           if version < 6881280 {
@@ -1327,6 +1389,161 @@ impl Feature {
           return false;
         }
       }
+      Feature::Woff => {
+        if let Some(version) = browsers.ie {
+          if version < 589824 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 786432 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 198144 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.chrome {
+          if version < 327680 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 327936 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 721152 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 327680 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 263168 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 262144 {
+            return false;
+          }
+        }
+      }
+      Feature::Woff2 => {
+        if let Some(version) = browsers.edge {
+          if version < 917504 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 2555904 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.chrome {
+          if version < 2359296 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 786432 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 1507328 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 655360 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 6750208 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 262144 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::Ttf => {
+        if let Some(version) = browsers.edge {
+          if version < 786432 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 197888 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.chrome {
+          if version < 262144 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 196864 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 655616 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 262656 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 131584 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 262144 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::Eot => {
+        if let Some(version) = browsers.ie {
+          if version < 393216 {
+            return false;
+          }
+        }
+        if browsers.android.is_some()
+          || browsers.chrome.is_some()
+          || browsers.edge.is_some()
+          || browsers.firefox.is_some()
+          || browsers.ios_saf.is_some()
+          || browsers.opera.is_some()
+          || browsers.safari.is_some()
+          || browsers.samsung.is_some()
+        {
+          return false;
+        }
+      }
       Feature::DoublePositionGradients => {
         if let Some(version) = browsers.chrome {
           if version < 4653056 {
@@ -2213,6 +2430,328 @@ impl Feature {
           return false;
         }
       }
+      Feature::ViewportPercentageUnitsDynamic
+      | Feature::ViewportPercentageUnitsLarge
+      | Feature::ViewportPercentageUnitsSmall => {
+        if let Some(version) = browsers.chrome {
+          if version < 7077888 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 7077888 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 6619136 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 6160384 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 984064 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 984064 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1376256 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 7077888 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::IndividualTransforms => {
+        if let Some(version) = browsers.chrome {
+          if version < 6815744 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 6815744 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 4718592 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 5898240 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 917760 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 918784 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1310720 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 6815744 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::Gap => {
+        if let Some(version) = browsers.chrome {
+          if version < 4325376 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 1048576 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 3997696 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 3473408 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 786432 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 786432 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 589824 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 4325376 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::DisplayGrid => {
+        if let Some(version) = browsers.chrome {
+          if version < 3735552 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 1048576 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 3407872 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 2883584 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 655616 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 656128 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 393216 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 3735552 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::DisplayFlex => {
+        if let Some(version) = browsers.chrome {
+          if version < 1900544 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 786432 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 1310720 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ie {
+          if version < 720896 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 786688 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 589824 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 589824 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 131072 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 263168 {
+            return false;
+          }
+        }
+      }
+      Feature::DisplayContents => {
+        if let Some(version) = browsers.chrome {
+          if version < 4259840 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 2424832 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 3407872 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 721152 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 721664 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 589824 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 4259840 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::LogicalScrollMargin
+      | Feature::LogicalScrollMarginShorthand
+      | Feature::LogicalScrollPadding
+      | Feature::LogicalScrollPaddingShorthand => {
+        if let Some(version) = browsers.chrome {
+          if version < 4521984 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 4456448 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 3670016 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 983040 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 983040 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 655360 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 4521984 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
       Feature::P3Colors | Feature::LangList => {
         if let Some(version) = browsers.safari {
           if version < 655616 {
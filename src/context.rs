@@ -2,11 +2,12 @@ use std::collections::HashSet;
 
 use crate::compat::Feature;
 use crate::declaration::DeclarationBlock;
-use crate::properties::custom::UnparsedProperty;
+use crate::properties::custom::{Token, TokenList, TokenOrValue, UnparsedProperty};
 use crate::properties::Property;
 use crate::rules::supports::{SupportsCondition, SupportsRule};
 use crate::rules::{style::StyleRule, CssRule, CssRuleList};
-use crate::selector::{Direction, PseudoClass};
+use crate::selector::{scope_to_rtl, Direction, PseudoClass};
+use crate::stylesheet::LogicalFallback;
 use crate::targets::Browsers;
 use crate::vendor_prefix::VendorPrefix;
 use parcel_selectors::parser::Component;
@@ -24,15 +25,17 @@ pub(crate) enum DeclarationContext {
   StyleRule,
   Keyframes,
   StyleAttribute,
+  Page,
 }
 
 #[derive(Debug)]
 pub(crate) struct PropertyHandlerContext<'i, 'o> {
   pub targets: Option<Browsers>,
   pub is_important: bool,
+  pub logical_fallback: LogicalFallback,
   supports: Vec<SupportsEntry<'i>>,
-  ltr: Vec<Property<'i>>,
-  rtl: Vec<Property<'i>>,
+  ltr: DeclarationBlock<'i>,
+  rtl: DeclarationBlock<'i>,
   pub context: DeclarationContext,
   pub unused_symbols: &'o HashSet<String>,
 }
@@ -42,18 +45,19 @@ impl<'i, 'o> PropertyHandlerContext<'i, 'o> {
     PropertyHandlerContext {
       targets,
       is_important: false,
+      logical_fallback: LogicalFallback::default(),
       supports: Vec::new(),
-      ltr: Vec::new(),
-      rtl: Vec::new(),
+      ltr: DeclarationBlock::default(),
+      rtl: DeclarationBlock::default(),
       context: DeclarationContext::None,
       unused_symbols,
     }
   }
 
   pub fn is_supported(&self, feature: Feature) -> bool {
-    // Don't convert logical properties in style attributes because
+    // Don't convert logical properties in style attributes or @page rules because
     // our fallbacks rely on extra rules to define --ltr and --rtl.
-    if self.context == DeclarationContext::StyleAttribute {
+    if self.context == DeclarationContext::StyleAttribute || self.context == DeclarationContext::Page {
       return true;
     }
 
@@ -65,8 +69,13 @@ impl<'i, 'o> PropertyHandlerContext<'i, 'o> {
   }
 
   pub fn add_logical_rule(&mut self, ltr: Property<'i>, rtl: Property<'i>) {
-    self.ltr.push(ltr);
-    self.rtl.push(rtl);
+    if self.is_important {
+      self.ltr.important_declarations.push(ltr);
+      self.rtl.important_declarations.push(rtl);
+    } else {
+      self.ltr.declarations.push(ltr);
+      self.rtl.declarations.push(rtl);
+    }
   }
 
   pub fn get_logical_rules(&mut self, style_rule: &StyleRule<'i>) -> Vec<CssRule<'i>> {
@@ -74,19 +83,11 @@ impl<'i, 'o> PropertyHandlerContext<'i, 'o> {
     let mut dest = Vec::new();
 
     macro_rules! rule {
-      ($dir: ident, $decls: ident) => {
-        let mut selectors = style_rule.selectors.clone();
-        for selector in &mut selectors.0 {
-          selector.append(Component::NonTSPseudoClass(PseudoClass::Dir(Direction::$dir)));
-        }
-
+      ($selectors: expr, $decls: ident) => {
         let rule = StyleRule {
-          selectors,
+          selectors: $selectors,
           vendor_prefix: VendorPrefix::None,
-          declarations: DeclarationBlock {
-            declarations: std::mem::take(&mut self.$decls),
-            important_declarations: vec![],
-          },
+          declarations: std::mem::take(&mut self.$decls),
           rules: CssRuleList(vec![]),
           loc: style_rule.loc.clone(),
         };
@@ -95,12 +96,49 @@ impl<'i, 'o> PropertyHandlerContext<'i, 'o> {
       };
     }
 
-    if !self.ltr.is_empty() {
-      rule!(Ltr, ltr);
+    macro_rules! dir_selectors {
+      ($dir: ident) => {{
+        let mut selectors = style_rule.selectors.clone();
+        for selector in &mut selectors.0 {
+          selector.append(Component::NonTSPseudoClass(PseudoClass::Dir(Direction::$dir)));
+        }
+        selectors
+      }};
     }
 
-    if !self.rtl.is_empty() {
-      rule!(Rtl, rtl);
+    match self.logical_fallback {
+      LogicalFallback::Dir => {
+        if !self.ltr.is_empty() {
+          rule!(dir_selectors!(Ltr), ltr);
+        }
+
+        if !self.rtl.is_empty() {
+          rule!(dir_selectors!(Rtl), rtl);
+        }
+      }
+      LogicalFallback::Ltr => {
+        if !self.ltr.is_empty() {
+          rule!(style_rule.selectors.clone(), ltr);
+        }
+
+        self.rtl = DeclarationBlock::default();
+      }
+      LogicalFallback::LtrWithRtlAttribute => {
+        // Reset the left-to-right properties that are not overridden by the right-to-left ones,
+        // e.g. margin-left when margin-inline-start maps to margin-right.
+        if !self.rtl.is_empty() {
+          reset_properties(&self.ltr.declarations, &mut self.rtl.declarations);
+          reset_properties(&self.ltr.important_declarations, &mut self.rtl.important_declarations);
+        }
+
+        if !self.ltr.is_empty() {
+          rule!(style_rule.selectors.clone(), ltr);
+        }
+
+        if !self.rtl.is_empty() {
+          rule!(scope_to_rtl(&style_rule.selectors), rtl);
+        }
+      }
     }
 
     dest
@@ -168,6 +206,7 @@ impl<'i, 'o> PropertyHandlerContext<'i, 'o> {
           declarations: DeclarationBlock {
             declarations: entry.declarations,
             important_declarations: entry.important_declarations,
+            comments: vec![],
           },
           rules: CssRuleList(vec![]),
           loc: style_rule.loc.clone(),
@@ -179,3 +218,16 @@ impl<'i, 'o> PropertyHandlerContext<'i, 'o> {
     dest
   }
 }
+
+/// Adds an `initial` declaration to `rtl` for each property in `ltr` that it does not already set.
+fn reset_properties<'i>(ltr: &Vec<Property<'i>>, rtl: &mut Vec<Property<'i>>) {
+  for property in ltr {
+    let property_id = property.property_id();
+    if !rtl.iter().any(|p| p.property_id() == property_id) {
+      rtl.push(Property::Unparsed(UnparsedProperty {
+        property_id,
+        value: TokenList(vec![TokenOrValue::Token(Token::Ident("initial".into()))]),
+      }));
+    }
+  }
+}
@@ -0,0 +1,216 @@
+//! A tree view of a style sheet, for diagnostics and bug reports.
+//!
+//! Unlike the derived `Debug` implementations, which print every field of every value,
+//! a [DebugTree](DebugTree) prints one line per rule and declaration, indented by nesting
+//! depth. Each rule is followed by the source location it was parsed from. Preludes and
+//! declaration values are serialized as CSS, so the output closely reproduces the input.
+//!
+//! This module requires the `debug_tree` feature.
+//!
+//! # Example
+//!
+//! ```
+//! use lightningcss::stylesheet::{StyleSheet, ParserOptions};
+//!
+//! let stylesheet = StyleSheet::parse(
+//!   "@media print { .foo { color: red } }",
+//!   ParserOptions::default()
+//! ).unwrap();
+//!
+//! // StyleSheet
+//! //   Media print @ :0:1
+//! //     Style .foo @ :0:16
+//! //       color: red
+//! println!("{}", stylesheet.debug_tree());
+//! ```
+
+use crate::declaration::DeclarationBlock;
+use crate::error::ErrorLocation;
+use crate::printer::{Printer, PrinterOptions};
+use crate::rules::{CssRule, CssRuleList, Location, ToCssWithContext};
+use crate::selector::Selectors;
+use crate::traits::ToCss;
+use parcel_selectors::SelectorList;
+use std::fmt;
+
+/// A [Display](std::fmt::Display) implementation that prints a style sheet as a tree.
+///
+/// Returned by [StyleSheet::debug_tree](crate::stylesheet::StyleSheet::debug_tree). See the
+/// [module documentation](self) for details about the format.
+pub struct DebugTree<'a, 'i> {
+  rules: &'a CssRuleList<'i>,
+  sources: &'a [String],
+}
+
+impl<'a, 'i> DebugTree<'a, 'i> {
+  pub(crate) fn new(rules: &'a CssRuleList<'i>, sources: &'a [String]) -> Self {
+    DebugTree { rules, sources }
+  }
+
+  fn write_rules(&self, f: &mut fmt::Formatter<'_>, rules: &CssRuleList, depth: usize) -> fmt::Result {
+    for rule in &rules.0 {
+      match rule {
+        CssRule::Style(style) => {
+          self.write_rule(f, depth, "Style", &selectors_to_string(&style.selectors), style.loc)?;
+          write_declarations(f, &style.declarations, depth + 1)?;
+          self.write_rules(f, &style.rules, depth + 1)?;
+        }
+        CssRule::Nesting(nesting) => {
+          let style = &nesting.style;
+          self.write_rule(f, depth, "Nesting", &selectors_to_string(&style.selectors), nesting.loc)?;
+          write_declarations(f, &style.declarations, depth + 1)?;
+          self.write_rules(f, &style.rules, depth + 1)?;
+        }
+        CssRule::Media(media) => {
+          self.write_rule(f, depth, "Media", &to_string(&media.query), media.loc)?;
+          self.write_rules(f, &media.rules, depth + 1)?;
+        }
+        CssRule::Supports(supports) => {
+          self.write_rule(f, depth, "Supports", &to_string(&supports.condition), supports.loc)?;
+          self.write_rules(f, &supports.rules, depth + 1)?;
+        }
+        CssRule::Container(container) => {
+          let mut prelude = container.name.as_ref().map(to_string).unwrap_or_default();
+          if !prelude.is_empty() {
+            prelude.push(' ');
+          }
+          prelude.push_str(&to_string(&container.condition));
+          self.write_rule(f, depth, "Container", &prelude, container.loc)?;
+          self.write_rules(f, &container.rules, depth + 1)?;
+        }
+        CssRule::LayerBlock(layer) => {
+          let name = layer.name.as_ref().map(to_string).unwrap_or_default();
+          self.write_rule(f, depth, "LayerBlock", &name, layer.loc)?;
+          self.write_rules(f, &layer.rules, depth + 1)?;
+        }
+        CssRule::LayerStatement(layer) => {
+          let names: Vec<String> = layer.names.iter().map(to_string).collect();
+          self.write_rule(f, depth, "LayerStatement", &names.join(", "), layer.loc)?;
+        }
+        CssRule::MozDocument(document) => {
+          self.write_rule(f, depth, "MozDocument", "", document.loc)?;
+          self.write_rules(f, &document.rules, depth + 1)?;
+        }
+        CssRule::Import(import) => {
+          self.write_rule(f, depth, "Import", &format!("{:?}", import.url.as_ref()), import.loc)?;
+        }
+        CssRule::Namespace(namespace) => {
+          let prelude = match &namespace.prefix {
+            Some(prefix) => format!("{} {:?}", prefix, namespace.url.as_ref()),
+            None => format!("{:?}", namespace.url.as_ref()),
+          };
+          self.write_rule(f, depth, "Namespace", &prelude, namespace.loc)?;
+        }
+        CssRule::Keyframes(keyframes) => {
+          self.write_rule(f, depth, "Keyframes", &to_string(&keyframes.name), keyframes.loc)?;
+          for keyframe in &keyframes.keyframes {
+            let selectors: Vec<String> = keyframe.selectors.iter().map(to_string).collect();
+            writeln!(f, "{}Keyframe {}", indent(depth + 1), selectors.join(", "))?;
+            write_declarations(f, &keyframe.declarations, depth + 2)?;
+          }
+        }
+        CssRule::FontFace(font_face) => {
+          self.write_rule(f, depth, "FontFace", "", font_face.loc)?;
+          for property in &font_face.properties {
+            writeln!(f, "{}{}", indent(depth + 1), to_string(property))?;
+          }
+        }
+        CssRule::FontPaletteValues(font_palette_values) => {
+          let name = to_string(&font_palette_values.name);
+          self.write_rule(f, depth, "FontPaletteValues", &name, font_palette_values.loc)?;
+          for property in &font_palette_values.properties {
+            writeln!(f, "{}{}", indent(depth + 1), to_string(property))?;
+          }
+        }
+        CssRule::Page(page) => {
+          let selectors: Vec<String> = page.selectors.iter().map(to_string).collect();
+          self.write_rule(f, depth, "Page", &selectors.join(", "), page.loc)?;
+          write_declarations(f, &page.declarations, depth + 1)?;
+          for margin_rule in &page.rules {
+            let margin_box = to_string(&margin_rule.margin_box);
+            self.write_rule(f, depth + 1, "PageMargin", &margin_box, margin_rule.loc)?;
+            write_declarations(f, &margin_rule.declarations, depth + 2)?;
+          }
+        }
+        CssRule::CounterStyle(counter_style) => {
+          self.write_rule(
+            f,
+            depth,
+            "CounterStyle",
+            &to_string(&counter_style.name),
+            counter_style.loc,
+          )?;
+          write_declarations(f, &counter_style.declarations, depth + 1)?;
+        }
+        CssRule::Viewport(viewport) => {
+          self.write_rule(f, depth, "Viewport", "", viewport.loc)?;
+          write_declarations(f, &viewport.declarations, depth + 1)?;
+        }
+        CssRule::CustomMedia(custom_media) => {
+          let prelude = format!("{} {}", to_string(&custom_media.name), to_string(&custom_media.query));
+          self.write_rule(f, depth, "CustomMedia", &prelude, custom_media.loc)?;
+        }
+        CssRule::Property(property) => {
+          self.write_rule(f, depth, "Property", &to_string(&property.name), property.loc)?;
+        }
+        CssRule::Unknown(unknown) => {
+          self.write_rule(f, depth, "Unknown", &format!("@{}", unknown.name), unknown.loc)?;
+        }
+        CssRule::Comment(comment) => writeln!(f, "{}Comment /*{}*/", indent(depth), comment)?,
+        CssRule::Ignored => {}
+      }
+    }
+    Ok(())
+  }
+
+  fn write_rule(
+    &self,
+    f: &mut fmt::Formatter<'_>,
+    depth: usize,
+    kind: &str,
+    prelude: &str,
+    loc: Location,
+  ) -> fmt::Result {
+    let filename = self.sources.get(loc.source_index as usize).cloned().unwrap_or_default();
+    write!(f, "{}{}", indent(depth), kind)?;
+    if !prelude.is_empty() {
+      write!(f, " {}", prelude)?;
+    }
+    writeln!(f, " @ {}", ErrorLocation::new(loc, filename))
+  }
+}
+
+impl<'a, 'i> fmt::Display for DebugTree<'a, 'i> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    writeln!(f, "StyleSheet")?;
+    self.write_rules(f, self.rules, 1)
+  }
+}
+
+fn write_declarations(f: &mut fmt::Formatter<'_>, declarations: &DeclarationBlock, depth: usize) -> fmt::Result {
+  for (property, important) in declarations
+    .declarations
+    .iter()
+    .map(|property| (property, false))
+    .chain(declarations.important_declarations.iter().map(|property| (property, true)))
+  {
+    let css = property.to_css_string(important, PrinterOptions::default()).unwrap_or_default();
+    writeln!(f, "{}{}", indent(depth), css)?;
+  }
+  Ok(())
+}
+
+fn indent(depth: usize) -> String {
+  "  ".repeat(depth)
+}
+
+fn to_string<T: ToCss>(value: &T) -> String {
+  value.to_css_string(PrinterOptions::default()).unwrap_or_default()
+}
+
+fn selectors_to_string(selectors: &SelectorList<Selectors>) -> String {
+  let mut s = String::new();
+  let mut printer = Printer::new(&mut s, PrinterOptions::default());
+  let _ = selectors.to_css_with_context(&mut printer, None);
+  s
+}
@@ -5,8 +5,8 @@ use std::ops::Range;
 
 use crate::context::PropertyHandlerContext;
 use crate::error::{ParserError, PrinterError};
-use crate::parser::ParserOptions;
-use crate::printer::Printer;
+use crate::parser::{leading_comments, ParserOptions};
+use crate::printer::{Printer, PrinterOptions};
 use crate::properties::box_shadow::BoxShadowHandler;
 use crate::properties::masking::MaskHandler;
 use crate::properties::{
@@ -41,7 +41,7 @@ use cssparser::*;
 /// Properties are separated into a list of `!important` declararations,
 /// and a list of normal declarations. This reduces memory usage compared
 /// with storing a boolean along with each property.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeclarationBlock<'i> {
   /// A list of `!important` declarations in the block.
@@ -49,6 +49,10 @@ pub struct DeclarationBlock<'i> {
   pub important_declarations: Vec<Property<'i>>,
   /// A list of normal declarations in the block.
   pub declarations: Vec<Property<'i>>,
+  /// Comments within the block, e.g. `/*! license */` comments. These are written at the
+  /// start of the block, because declarations may be reordered during minification.
+  #[cfg_attr(feature = "serde", serde(borrow, default))]
+  pub comments: Vec<CowArcStr<'i>>,
 }
 
 impl<'i> DeclarationBlock<'i> {
@@ -67,19 +71,31 @@ impl<'i> DeclarationBlock<'i> {
         options,
       },
     );
-    while let Some(res) = parser.next() {
-      if let Err((err, _)) = res {
-        if options.error_recovery {
-          options.warn(err);
-          continue;
+    let mut comments = Vec::new();
+    loop {
+      let start = parser.input.position();
+      let res = parser.next();
+      comments.extend(leading_comments(
+        parser.input.slice_from(start),
+        options.preserve_comments,
+      ));
+      match res {
+        Some(Err((err, _))) => {
+          if options.error_recovery {
+            options.warn(err);
+            continue;
+          }
+          return Err(err);
         }
-        return Err(err);
+        Some(Ok(_)) => {}
+        None => break,
       }
     }
 
     Ok(DeclarationBlock {
       important_declarations,
       declarations,
+      comments,
     })
   }
 
@@ -101,6 +117,11 @@ impl<'i> ToCss for DeclarationBlock<'i> {
   where
     W: std::fmt::Write,
   {
+    for comment in self.printed_comments(dest.minify) {
+      write_comment(comment, dest)?;
+      dest.whitespace()?;
+    }
+
     let len = self.declarations.len() + self.important_declarations.len();
     let mut i = 0;
 
@@ -131,6 +152,7 @@ impl<'i> DeclarationBlock<'i> {
     dest.whitespace()?;
     dest.write_char('{')?;
     dest.indent();
+    self.comments_to_css(dest)?;
 
     let mut i = 0;
     let len = self.declarations.len() + self.important_declarations.len();
@@ -180,15 +202,37 @@ impl<'i> DeclarationBlock<'i> {
     handle!(self.important_declarations, important_handler, true);
     handle!(self.declarations, handler, false);
 
+    context.is_important = false;
     handler.finalize(context);
+    context.is_important = true;
     important_handler.finalize(context);
+    context.is_important = false;
     self.important_declarations = std::mem::take(&mut important_handler.decls);
     self.declarations = std::mem::take(&mut handler.decls);
+    remove_duplicates(&mut self.important_declarations);
+    remove_duplicates(&mut self.declarations);
   }
 
-  /// Returns whether the declaration block is empty.
+  /// Returns whether the declaration block is empty. Blocks that only contain comments are not empty.
   pub fn is_empty(&self) -> bool {
-    return self.declarations.is_empty() && self.important_declarations.is_empty();
+    return self.declarations.is_empty() && self.important_declarations.is_empty() && self.comments.is_empty();
+  }
+
+  /// Returns the comments to write. Only license comments are preserved when minifying.
+  fn printed_comments(&self, minify: bool) -> impl Iterator<Item = &CowArcStr<'i>> {
+    self.comments.iter().filter(move |comment| !minify || comment.starts_with('!'))
+  }
+
+  /// Writes the comments in the block, each on its own line.
+  pub(crate) fn comments_to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    for comment in self.printed_comments(dest.minify) {
+      dest.newline()?;
+      write_comment(comment, dest)?;
+    }
+    Ok(())
   }
 
   pub(crate) fn property_location<'t>(
@@ -379,6 +423,46 @@ impl<'i> DeclarationBlock<'i> {
   }
 }
 
+/// A single CSS declaration, e.g. `color: red !important`.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Declaration<'i> {
+  /// The property and its value.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub property: Property<'i>,
+  /// Whether the declaration is marked `!important`.
+  pub important: bool,
+}
+
+impl<'i> Declaration<'i> {
+  /// Parses a single declaration, without a trailing semicolon.
+  pub fn parse<'t>(
+    input: &mut Parser<'i, 't>,
+    options: &ParserOptions<'_, 'i>,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let name = input.expect_ident_cloned()?;
+    input.expect_colon()?;
+    let (property, important) = parse_property_value(name, input, options)?;
+    input.expect_exhausted()?;
+    Ok(Declaration { property, important })
+  }
+
+  /// Parses a single declaration from a string.
+  pub fn parse_string(
+    input: &'i str,
+    options: ParserOptions<'_, 'i>,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut input = ParserInput::new(input);
+    let mut parser = Parser::new(&mut input);
+    Self::parse(&mut parser, &options)
+  }
+
+  /// Serializes the declaration to a string.
+  pub fn to_css_string(&self, options: PrinterOptions) -> Result<String, PrinterError> {
+    self.property.to_css_string(self.important, options)
+  }
+}
+
 struct PropertyDeclarationParser<'a, 'o, 'i> {
   important_declarations: &'a mut Vec<Property<'i>>,
   declarations: &'a mut Vec<Property<'i>>,
@@ -412,6 +496,15 @@ impl<'a, 'o, 'i> AtRuleParser<'i> for PropertyDeclarationParser<'a, 'o, 'i> {
   type Error = ParserError<'i>;
 }
 
+fn write_comment<W>(comment: &str, dest: &mut Printer<W>) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+{
+  dest.write_str("/*")?;
+  dest.write_str(comment)?;
+  dest.write_str("*/")
+}
+
 pub(crate) fn parse_declaration<'i, 't>(
   name: CowRcStr<'i>,
   input: &mut cssparser::Parser<'i, 't>,
@@ -419,6 +512,20 @@ pub(crate) fn parse_declaration<'i, 't>(
   important_declarations: &mut DeclarationList<'i>,
   options: &ParserOptions,
 ) -> Result<(), cssparser::ParseError<'i, ParserError<'i>>> {
+  let (property, important) = parse_property_value(name, input, options)?;
+  if important {
+    important_declarations.push(property);
+  } else {
+    declarations.push(property);
+  }
+  Ok(())
+}
+
+fn parse_property_value<'i, 't>(
+  name: CowRcStr<'i>,
+  input: &mut cssparser::Parser<'i, 't>,
+  options: &ParserOptions,
+) -> Result<(Property<'i>, bool), cssparser::ParseError<'i, ParserError<'i>>> {
   let property = input.parse_until_before(Delimiter::Bang, |input| {
     Property::parse(PropertyId::from(CowArcStr::from(name)), input, options)
   })?;
@@ -428,16 +535,24 @@ pub(crate) fn parse_declaration<'i, 't>(
       input.expect_ident_matching("important")
     })
     .is_ok();
-  if important {
-    important_declarations.push(property);
-  } else {
-    declarations.push(property);
-  }
-  Ok(())
+  Ok((property, important))
 }
 
 pub(crate) type DeclarationList<'i> = Vec<Property<'i>>;
 
+/// Removes declarations that are repeated later in the list. The later declaration is
+/// valid wherever the earlier one is, and always wins the cascade, so it is safe to drop.
+fn remove_duplicates(declarations: &mut DeclarationList) {
+  let mut i = 0;
+  while i < declarations.len() {
+    if declarations[i + 1..].contains(&declarations[i]) {
+      declarations.remove(i);
+    } else {
+      i += 1;
+    }
+  }
+}
+
 pub(crate) struct DeclarationHandler<'i> {
   background: BackgroundHandler<'i>,
   border: BorderHandler<'i>,
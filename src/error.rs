@@ -79,6 +79,8 @@ pub enum ParserError<'i> {
   InvalidPageSelector,
   /// An invalid value was encountered.
   InvalidValue,
+  /// A required descriptor was missing from an at rule.
+  MissingDescriptor(&'static str),
   /// Invalid qualified rule.
   QualifiedRuleInvalid,
   /// A selector was invalid.
@@ -89,6 +91,8 @@ pub enum ParserError<'i> {
   UnexpectedNamespaceRule,
   /// An unexpected token was encountered.
   UnexpectedToken(#[serde(skip)] Token<'i>),
+  /// An `@charset` rule declared an encoding other than UTF-8.
+  UnsupportedCharset(CowArcStr<'i>),
   /// Maximum nesting depth was reached.
   MaximumNestingDepth,
 }
@@ -105,6 +109,7 @@ impl<'i> fmt::Display for ParserError<'i> {
       InvalidNesting => write!(f, "Invalid nesting"),
       InvalidPageSelector => write!(f, "Invalid page selector"),
       InvalidValue => write!(f, "Invalid value"),
+      MissingDescriptor(name) => write!(f, "Missing required `{}` descriptor", name),
       QualifiedRuleInvalid => write!(f, "Invalid qualified rule"),
       SelectorError(s) => s.fmt(f),
       UnexpectedImportRule => write!(
@@ -116,6 +121,7 @@ impl<'i> fmt::Display for ParserError<'i> {
         "@namespaces rules must precede all rules aside from @charset, @import, and @layer statements"
       ),
       UnexpectedToken(token) => write!(f, "Unexpected token {:?}", token),
+      UnsupportedCharset(charset) => write!(f, "Unsupported @charset \"{}\", only UTF-8 is supported", charset),
       MaximumNestingDepth => write!(f, "Overflowed the maximum nesting depth"),
     }
   }
@@ -322,6 +328,48 @@ impl fmt::Display for MinifyErrorKind {
   }
 }
 
+pub(crate) type MinifyWarning = ErrorWithLocation<MinifyWarningKind>;
+
+/// A warning emitted during transformation. Warnings do not affect the output.
+#[derive(Debug, PartialEq, Clone, Serialize)]
+#[serde(tag = "type")]
+pub enum MinifyWarningKind {
+  /// A value was removed because none of the browser targets support it, and no fallback exists.
+  UnsupportedValueRemoved {
+    /// The name of the property.
+    property: String,
+    /// The value that was removed.
+    value: String,
+    /// Why the value was removed.
+    reason: String,
+  },
+  /// A selector is not supported by the browser targets, and cannot be downleveled.
+  UnsupportedSelector {
+    /// The selector that is unsupported.
+    selector: String,
+    /// Why the selector is unsupported.
+    reason: String,
+  },
+}
+
+impl fmt::Display for MinifyWarningKind {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    use MinifyWarningKind::*;
+    match self {
+      UnsupportedValueRemoved {
+        property,
+        value,
+        reason,
+      } => {
+        write!(f, "Removed unsupported value `{}: {}`: {}", property, value, reason)
+      }
+      UnsupportedSelector { selector, reason } => {
+        write!(f, "Unsupported selector `{}`: {}", selector, reason)
+      }
+    }
+  }
+}
+
 impl MinifyErrorKind {
   #[deprecated(note = "use `MinifyErrorKind::to_string()` or `fmt::Display` instead")]
   #[allow(missing_docs)]
@@ -20,6 +20,8 @@ pub mod bundler;
 mod compat;
 mod context;
 pub mod css_modules;
+#[cfg(feature = "debug_tree")]
+pub mod debug;
 pub mod declaration;
 pub mod dependencies;
 pub mod error;
@@ -37,12 +39,16 @@ pub mod targets;
 pub mod traits;
 pub mod values;
 pub mod vendor_prefix;
+pub mod visitor;
 
 #[cfg(test)]
 mod tests {
   use crate::css_modules::{CssModuleExport, CssModuleExports, CssModuleReference, CssModuleReferences};
-  use crate::dependencies::Dependency;
-  use crate::error::{Error, ErrorLocation, MinifyErrorKind, ParserError, PrinterErrorKind, SelectorError};
+  use crate::declaration::Declaration;
+  use crate::dependencies::{Dependency, DependencyOptions, Location as DependencyLocation};
+  use crate::error::{
+    Error, ErrorLocation, MinifyErrorKind, MinifyWarningKind, ParserError, PrinterErrorKind, SelectorError,
+  };
   use crate::properties::custom::Token;
   use crate::properties::Property;
   use crate::rules::CssRule;
@@ -78,6 +84,33 @@ mod tests {
     assert_eq!(res.code, expected);
   }
 
+  fn printer_test(source: &str, expected: &str, options: PrinterOptions) {
+    printer_test_with_options(source, expected, ParserOptions::default(), options)
+  }
+
+  fn printer_test_with_options<'i, 'o>(
+    source: &'i str,
+    expected: &str,
+    parser_options: ParserOptions<'o, 'i>,
+    printer_options: PrinterOptions,
+  ) {
+    let stylesheet = StyleSheet::parse(&source, parser_options).unwrap();
+    let res = stylesheet.to_css(printer_options).unwrap();
+    assert_eq!(res.code, expected);
+  }
+
+  fn minify_printer_test(
+    source: &str,
+    expected: &str,
+    minify_options: MinifyOptions,
+    printer_options: PrinterOptions,
+  ) {
+    let mut stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
+    stylesheet.minify(minify_options).unwrap();
+    let res = stylesheet.to_css(printer_options).unwrap();
+    assert_eq!(res.code, expected);
+  }
+
   fn prefix_test(source: &str, expected: &str, targets: Browsers) {
     let mut stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
     stylesheet
@@ -95,6 +128,24 @@ mod tests {
     assert_eq!(res.code, expected);
   }
 
+  fn logical_fallback_test(source: &str, expected: &str, targets: Browsers, logical_fallback: LogicalFallback) {
+    let mut stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
+    stylesheet
+      .minify(MinifyOptions {
+        targets: Some(targets),
+        logical_fallback,
+        ..MinifyOptions::default()
+      })
+      .unwrap();
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        targets: Some(targets),
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(res.code, expected);
+  }
+
   fn attr_test(source: &str, expected: &str, minify: bool, targets: Option<Browsers>) {
     let mut attr = StyleAttribute::parse(source, ParserOptions::default()).unwrap();
     attr.minify(MinifyOptions {
@@ -112,10 +163,18 @@ mod tests {
   }
 
   fn nesting_test(source: &str, expected: &str) {
-    let targets = Some(Browsers {
-      chrome: Some(95 << 16),
-      ..Browsers::default()
-    });
+    nesting_test_with_targets(
+      source,
+      expected,
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  fn nesting_test_with_targets(source: &str, expected: &str, targets: Browsers) {
+    let targets = Some(targets);
     let mut stylesheet = StyleSheet::parse(
       &source,
       ParserOptions {
@@ -206,6 +265,30 @@ mod tests {
     }
   }
 
+  fn minify_warnings(source: &str, targets: Browsers) -> Vec<Error<MinifyWarningKind>> {
+    use std::sync::{Arc, RwLock};
+
+    let warnings = Some(Arc::new(RwLock::new(Vec::new())));
+    let mut stylesheet = StyleSheet::parse(
+      source,
+      ParserOptions {
+        filename: "test.css".into(),
+        ..ParserOptions::default()
+      },
+    )
+    .unwrap();
+    stylesheet
+      .minify(MinifyOptions {
+        targets: Some(targets),
+        warnings: warnings.clone(),
+        ..MinifyOptions::default()
+      })
+      .unwrap();
+    let w = warnings.unwrap();
+    let warnings = w.read().unwrap();
+    warnings.clone()
+  }
+
   macro_rules! map(
     { $($key:expr => $name:literal $(referenced: $referenced: literal)? $($value:literal $(global: $global: literal)? $(from $from:literal)?)*),* } => {
       {
@@ -1499,19 +1582,19 @@ mod tests {
       indoc! {r#"
       .foo:not(:lang(ae)):not(:lang(ar)):not(:lang(arc)):not(:lang(bcc)):not(:lang(bqi)):not(:lang(ckb)):not(:lang(dv)):not(:lang(fa)):not(:lang(glk)):not(:lang(he)):not(:lang(ku)):not(:lang(mzn)):not(:lang(nqo)):not(:lang(pnb)):not(:lang(ps)):not(:lang(sd)):not(:lang(ug)):not(:lang(ur)):not(:lang(yi)) {
         border-left-color: #b32323;
-        border-left-color: color(display-p3 .643308 .192455 .167712);
+        border-left-color: color(display-p3 0.643308 0.192455 0.167712);
         border-left-color: lab(40% 56.6 39);
         border-right-color: #ee00be;
-        border-right-color: color(display-p3 .972962 -.362078 .804206);
+        border-right-color: color(display-p3 0.972962 -0.362078 0.804206);
         border-right-color: lch(50.998% 135.363 338);
       }
 
-      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+      .foo:lang(ae), .foo:lang(ar), .foo:lang(arc), .foo:lang(bcc), .foo:lang(bqi), .foo:lang(ckb), .foo:lang(dv), .foo:lang(fa), .foo:lang(glk), .foo:lang(he), .foo:lang(ku), .foo:lang(mzn), .foo:lang(nqo), .foo:lang(pnb), .foo:lang(ps), .foo:lang(sd), .foo:lang(ug), .foo:lang(ur), .foo:lang(yi) {
         border-left-color: #ee00be;
-        border-left-color: color(display-p3 .972962 -.362078 .804206);
+        border-left-color: color(display-p3 0.972962 -0.362078 0.804206);
         border-left-color: lch(50.998% 135.363 338);
         border-right-color: #b32323;
-        border-right-color: color(display-p3 .643308 .192455 .167712);
+        border-right-color: color(display-p3 0.643308 0.192455 0.167712);
         border-right-color: lab(40% 56.6 39);
       }
     "#},
@@ -2015,6 +2098,19 @@ mod tests {
 
   #[test]
   pub fn test_border_radius() {
+    minify_test(
+      ".foo { border-top-left-radius: 10px 20px; border-top-right-radius: 10px 20px; border-bottom-right-radius: 10px 20px; border-bottom-left-radius: 10px 20px }",
+      ".foo{border-radius:10px/20px}",
+    );
+    minify_test(
+      ".foo { border-radius: 1px 2px 3px 2px / 4px 4px }",
+      ".foo{border-radius:1px 2px 3px/4px}",
+    );
+    minify_test(
+      ".foo { border-radius: 1px 2px 3px 4px / 5px 6px 5px 6px }",
+      ".foo{border-radius:1px 2px 3px 4px/5px 6px}",
+    );
+
     test(
       r#"
       .foo {
@@ -2616,6 +2712,36 @@ mod tests {
 
   #[test]
   pub fn test_margin() {
+    minify_test(
+      ".foo { margin-top: 1px; margin-right: 1px; margin-bottom: 1px; margin-left: 1px }",
+      ".foo{margin:1px}",
+    );
+    minify_test(
+      ".foo { margin-top: 1px; margin-right: 2px; margin-bottom: 1px; margin-left: 2px }",
+      ".foo{margin:1px 2px}",
+    );
+    minify_test(
+      ".foo { margin-top: 1px; margin-right: 2px; margin-bottom: 3px; margin-left: 2px }",
+      ".foo{margin:1px 2px 3px}",
+    );
+    minify_test(
+      ".foo { margin-top: 1px; margin-right: 2px; margin-bottom: 3px; margin-left: 4px }",
+      ".foo{margin:1px 2px 3px 4px}",
+    );
+    minify_test(".foo { margin: 1px 2px 1px 2px }", ".foo{margin:1px 2px}");
+    minify_test(
+      ".foo { margin-top: 1px !important; margin-right: 2px !important; margin-bottom: 3px !important; margin-left: 4px !important }",
+      ".foo{margin:1px 2px 3px 4px!important}",
+    );
+    minify_test(
+      ".foo { margin-top: 1px !important; margin-right: 2px; margin-bottom: 3px; margin-left: 4px }",
+      ".foo{margin-bottom:3px;margin-left:4px;margin-right:2px;margin-top:1px!important}",
+    );
+    minify_test(
+      ".foo { margin: 10px !important; margin-top: 5px }",
+      ".foo{margin-top:5px;margin:10px!important}",
+    );
+
     test(
       r#"
       .foo {
@@ -2893,6 +3019,24 @@ mod tests {
 
   #[test]
   pub fn test_padding() {
+    minify_test(
+      ".foo { padding-top: 1px; padding-right: 1px; padding-bottom: 1px; padding-left: 1px }",
+      ".foo{padding:1px}",
+    );
+    minify_test(
+      ".foo { padding-top: 1px; padding-right: 2px; padding-bottom: 1px; padding-left: 2px }",
+      ".foo{padding:1px 2px}",
+    );
+    minify_test(
+      ".foo { padding-top: 1px; padding-right: 2px; padding-bottom: 3px; padding-left: 2px }",
+      ".foo{padding:1px 2px 3px}",
+    );
+    minify_test(
+      ".foo { padding-top: 1px; padding-right: 2px; padding-bottom: 3px; padding-left: 4px }",
+      ".foo{padding:1px 2px 3px 4px}",
+    );
+    minify_test(".foo { padding: 1px 2px 1px 2px }", ".foo{padding:1px 2px}");
+
     test(
       r#"
       .foo {
@@ -3146,6 +3290,99 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_scroll_margin() {
+    minify_test(".foo { scroll-margin: 10px }", ".foo{scroll-margin:10px}");
+    minify_test(".foo { scroll-margin: 10px 20px }", ".foo{scroll-margin:10px 20px}");
+    minify_test(
+      ".foo { scroll-margin: 10px 20px 10px }",
+      ".foo{scroll-margin:10px 20px}",
+    );
+    minify_test(
+      ".foo { scroll-margin: 10px 20px 30px 20px }",
+      ".foo{scroll-margin:10px 20px 30px}",
+    );
+    minify_test(
+      ".foo { scroll-margin: 10px 20px 30px 40px }",
+      ".foo{scroll-margin:10px 20px 30px 40px}",
+    );
+    minify_test(
+      ".foo { scroll-margin-top: 1px; scroll-margin-right: 2px; scroll-margin-bottom: 3px; scroll-margin-left: 2px }",
+      ".foo{scroll-margin:1px 2px 3px}",
+    );
+    minify_test(
+      ".foo { scroll-margin: 1px; scroll-margin-left: 2px }",
+      ".foo{scroll-margin:1px 1px 1px 2px}",
+    );
+    minify_test(
+      ".foo { scroll-margin-inline-start: 1px; scroll-margin-inline-end: 2px }",
+      ".foo{scroll-margin-inline:1px 2px}",
+    );
+    minify_test(
+      ".foo { scroll-margin-block-start: 1px; scroll-margin-block-end: 1px }",
+      ".foo{scroll-margin-block:1px}",
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        scroll-margin-inline: 2px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        scroll-margin-left: 2px;
+        scroll-margin-right: 2px;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        scroll-margin-inline-start: 2px;
+      }
+    "#,
+      indoc! {r#"
+      .foo:not(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
+        scroll-margin-left: 2px;
+      }
+
+      .foo:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) {
+        scroll-margin-right: 2px;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        scroll-margin-inline: 2px 4px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        scroll-margin-inline: 2px 4px;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
   #[test]
   fn test_scroll_padding() {
     prefix_test(
@@ -3156,7 +3393,8 @@ mod tests {
     "#,
       indoc! {r#"
       .foo {
-        scroll-padding-inline: 2px;
+        scroll-padding-left: 2px;
+        scroll-padding-right: 2px;
       }
     "#
       },
@@ -3404,6 +3642,127 @@ mod tests {
         },
       );
     }
+
+    for unit in &[
+      "svw", "svh", "svi", "svb", "svmin", "svmax", "lvw", "lvh", "lvi", "lvb", "lvmin", "lvmax", "dvw", "dvh",
+      "dvi", "dvb", "dvmin", "dvmax",
+    ] {
+      minify_test(
+        &format!(".foo {{ width: 100{} }}", unit),
+        &format!(".foo{{width:100{}}}", unit),
+      );
+    }
+
+    prefix_test(
+      r#"
+      .foo {
+        height: 100dvh;
+        min-height: 100svh;
+        max-width: 50lvw;
+        width: 20dvmin;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        height: 100vh;
+        height: 100dvh;
+        min-height: 100vh;
+        min-height: 100svh;
+        max-width: 50vw;
+        max-width: 50lvw;
+        width: 20vmin;
+        width: 20dvmin;
+      }
+    "#},
+      Browsers {
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        height: 100vh;
+        height: 100dvh;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        height: 100vh;
+        height: 100dvh;
+      }
+    "#},
+      Browsers {
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        block-size: 100dvh;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        height: 100vh;
+        height: 100dvh;
+      }
+    "#},
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        height: 100dvh;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        height: 100dvh;
+      }
+    "#},
+      Browsers {
+        safari: Some(16 << 16),
+        chrome: Some(108 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_aspect_ratio() {
+    minify_test(".foo { aspect-ratio: auto }", ".foo{aspect-ratio:auto}");
+    minify_test(".foo { aspect-ratio: 16 / 9 }", ".foo{aspect-ratio:16/9}");
+    minify_test(".foo { aspect-ratio: 2 }", ".foo{aspect-ratio:2}");
+    minify_test(".foo { aspect-ratio: 2 / 1 }", ".foo{aspect-ratio:2}");
+    minify_test(".foo { aspect-ratio: auto 4/3 }", ".foo{aspect-ratio:auto 4/3}");
+    minify_test(".foo { aspect-ratio: 4/3 auto }", ".foo{aspect-ratio:auto 4/3}");
+    minify_test(".foo { aspect-ratio: 1920/1080 }", ".foo{aspect-ratio:16/9}");
+    minify_test(".foo { aspect-ratio: auto 4 / 2 }", ".foo{aspect-ratio:auto 2}");
+    minify_test(".foo { aspect-ratio: 0.5 / 0.25 }", ".foo{aspect-ratio:.5/.25}");
+    test(
+      ".foo { aspect-ratio: 16/9 }",
+      indoc! {r#"
+      .foo {
+        aspect-ratio: 16 / 9;
+      }
+    "#},
+    );
+    test(
+      ".foo { aspect-ratio: 1920/1080 auto }",
+      indoc! {r#"
+      .foo {
+        aspect-ratio: auto 1920 / 1080;
+      }
+    "#},
+    );
   }
 
   #[test]
@@ -3773,25 +4132,69 @@ mod tests {
       ".foo { background: calc(var(--v) / 0.3)",
       indoc! {r#"
       .foo {
-        background: calc(var(--v) / .3);
+        background: calc(var(--v) / 0.3);
       }
     "#},
     );
-  }
 
-  #[test]
-  pub fn test_flex() {
-    test(
-      r#"
-      .foo {
-        flex-direction: column;
-        flex-wrap: wrap;
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        flex-flow: column wrap;
-      }
+    minify_test(
+      ".foo { background: url(a.png) center / cover no-repeat, linear-gradient(red, blue) fixed, yellow }",
+      ".foo{background:url(a.png) 50%/cover no-repeat,linear-gradient(red,#00f) fixed,#ff0}",
+    );
+    minify_test(
+      ".foo { background: url(a.png) 0 0 / 50% auto }",
+      ".foo{background:url(a.png) 0 0/50%}",
+    );
+    minify_test(
+      ".foo { background: url(a.png) 0 0 / contain content-box border-box }",
+      ".foo{background:url(a.png) 0 0/contain content-box border-box}",
+    );
+    minify_test(
+      ".foo { background: red url(a.png), url(b.png) }",
+      ".foo{background:red url(a.png),url(b.png)}",
+    );
+    minify_test(
+      ".foo { background: red url(a.png), url(b.png); background-color: blue }",
+      ".foo{background:red url(a.png),url(b.png);background-color:#00f}",
+    );
+    minify_test(
+      ".foo { background: url(a.png), url(b.png) red; background-position: 10px 20px, center; background-color: blue }",
+      ".foo{background:url(a.png) 10px 20px,#00f url(b.png) 50%}",
+    );
+    test(
+      r#"
+      .foo {
+        background-image: url(a.png), url(b.png);
+        background-position: 0 0, 100% 100%;
+        background-size: 10px 20px, cover;
+        background-repeat: no-repeat, repeat-y;
+        background-attachment: scroll, fixed;
+        background-origin: padding-box, content-box;
+        background-clip: border-box, padding-box;
+        background-color: red;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        background: url("a.png") 0 0 / 10px 20px no-repeat, red url("b.png") 100% 100% / cover repeat-y fixed content-box padding-box;
+      }
+    "#},
+    );
+  }
+
+  #[test]
+  pub fn test_flex() {
+    test(
+      r#"
+      .foo {
+        flex-direction: column;
+        flex-wrap: wrap;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        flex-flow: column wrap;
+      }
     "#
       },
     );
@@ -4257,6 +4660,82 @@ mod tests {
       },
     );
 
+    minify_test(".foo { gap: 10px }", ".foo{gap:10px}");
+    minify_test(".foo { gap: 10px 10px }", ".foo{gap:10px}");
+    minify_test(".foo { gap: 10px 5% }", ".foo{gap:10px 5%}");
+    minify_test(".foo { grid-gap: 10px 20px }", ".foo{grid-gap:10px 20px}");
+    minify_test(".foo { grid-row-gap: 10px }", ".foo{grid-row-gap:10px}");
+    minify_test(".foo { gap: 1px; grid-gap: 2px }", ".foo{gap:1px;grid-gap:2px}");
+    minify_test(".foo { grid-gap: 2px; gap: 1px }", ".foo{grid-gap:2px;gap:1px}");
+    minify_test(
+      ".foo { row-gap: 1px; grid-column-gap: 2px }",
+      ".foo{row-gap:1px;grid-column-gap:2px}",
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        gap: 10px 20px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        grid-gap: 10px 20px;
+        gap: 10px 20px;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(11 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        row-gap: 10px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        grid-row-gap: 10px;
+        row-gap: 10px;
+      }
+    "#
+      },
+      Browsers {
+        chrome: Some(60 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        grid-gap: 10px;
+        grid-column-gap: 20px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        gap: 10px 20px;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    use crate::properties::PropertyId;
+    assert!(PropertyId::GridGap.is_shorthand());
+    assert_eq!(
+      PropertyId::GridGap.longhands(),
+      Some(vec![PropertyId::RowGap, PropertyId::ColumnGap])
+    );
+
     test(
       r#"
       .foo {
@@ -4978,6 +5457,32 @@ mod tests {
         ..Browsers::default()
       },
     );
+    prefix_test(
+      r#"
+      .foo {
+        place-items: center;
+        place-content: space-between;
+        place-self: end;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        place-content: space-between;
+        align-self: end;
+        justify-self: end;
+        align-items: center;
+        justify-items: center;
+      }
+    "#},
+      Browsers {
+        safari: Some(10 << 16),
+        ..Browsers::default()
+      },
+    );
+    minify_test(".foo { place-items: center center }", ".foo{place-items:center}");
+    minify_test(".foo { place-items: start end }", ".foo{place-items:start end}");
+    minify_test(".foo { place-content: stretch }", ".foo{place-content:stretch}");
+    minify_test(".foo { place-self: auto center }", ".foo{place-self:auto center}");
     prefix_test(
       r#"
       .foo {
@@ -5158,6 +5663,37 @@ mod tests {
       ".foo { font: normal normal 500 medium/10px Charcoal; }",
       ".foo{font:500 medium/10px Charcoal}",
     );
+    minify_test(
+      ".foo { font: italic small-caps 300 16px/2 Georgia, serif; }",
+      ".foo{font:italic small-caps 300 16px/2 Georgia,serif}",
+    );
+    minify_test(
+      ".foo { font: condensed 12px Arial; }",
+      ".foo{font:condensed 12px Arial}",
+    );
+    minify_test(
+      ".foo { font: 12px/1.5 Arial; font-weight: bold; }",
+      ".foo{font:700 12px/1.5 Arial}",
+    );
+    minify_test(
+      ".foo { font: 12px Arial; font-stretch: 87.5%; }",
+      ".foo{font:semi-condensed 12px Arial}",
+    );
+    minify_test(
+      ".foo { font: 12px Arial; font-stretch: 90%; }",
+      ".foo{font:12px Arial;font-stretch:90%}",
+    );
+    minify_test(".foo { font: 12px; }", ".foo{font:12px}");
+    minify_test(".foo { font: caption; }", ".foo{font:caption}");
+    minify_test(".foo { font: Small-Caption; }", ".foo{font:Small-Caption}");
+    minify_test(
+      ".foo { font-size: 12px; font-weight: bold; font: menu; }",
+      ".foo{font:menu}",
+    );
+    minify_test(
+      ".foo { font: status-bar; font-weight: bold; }",
+      ".foo{font:status-bar;font-weight:700}",
+    );
     minify_test(
       ".foo { font-family: 'sans-serif'; }",
       ".foo{font-family:\"sans-serif\"}",
@@ -5272,9 +5808,64 @@ mod tests {
     minify_test(":nth-last-col(-n+2) {width: 20px}", ":nth-last-col(-n+2){width:20px}");
     minify_test(":nth-last-col(even) {width: 20px}", ":nth-last-col(2n){width:20px}");
     minify_test(":nth-last-col(odd) {width: 20px}", ":nth-last-col(2n+1){width:20px}");
-
+    minify_test(":nth-child(2n+1) {width: 20px}", ":nth-child(2n+1){width:20px}");
+    minify_test(
+      ":nth-child(2n+1 of .x) {width: 20px}",
+      ":nth-child(2n+1 of .x){width:20px}",
+    );
+    minify_test(
+      ":nth-child(even of li.important) {width: 20px}",
+      ":nth-child(2n of li.important){width:20px}",
+    );
+    minify_test(
+      ":nth-last-child(odd of li.a, .b) {width: 20px}",
+      ":nth-last-child(2n+1 of li.a,.b){width:20px}",
+    );
+    minify_test(
+      ":nth-child(-n+3 OF :not(.hidden)) {width: 20px}",
+      ":nth-child(-n+3 of :not(.hidden)){width:20px}",
+    );
+    test(
+      "li:nth-child(2n+1 of .x,.y) { width: 20px }",
+      indoc! {r#"
+      li:nth-child(2n+1 of .x, .y) {
+        width: 20px;
+      }
+    "#},
+    );
+    error_test(
+      ":nth-child(2n+1 of) {width: 20px}",
+      ParserError::SelectorError(SelectorError::EmptySelector),
+    );
+
+    minify_test("*.foo {color:red}", ".foo{color:red}");
+    minify_test("*#foo {color:red}", "#foo{color:red}");
+    minify_test("*[href] {color:red}", "[href]{color:red}");
+    minify_test("* > *.bar {color:red}", "*>.bar{color:red}");
+    minify_test("ul *.foo {color:red}", "ul .foo{color:red}");
+    minify_test("ul li * {color:red}", "ul li *{color:red}");
+    minify_test("* + * {margin-top:1em}", "*+*{margin-top:1em}");
+    minify_test("* {color:red}", "*{color:red}");
+    minify_test("*::before {color:red}", "*:before{color:red}");
+    minify_test("*::selection {color:red}", "*::selection{color:red}");
+    test("*::before {color:red}", "*:before {\n  color: red;\n}\n");
     minify_test("[foo=\"baz\"] {color:red}", "[foo=baz]{color:red}");
-    minify_test("[foo=\"foo bar\"] {color:red}", "[foo=foo\\ bar]{color:red}");
+    minify_test("[foo=\"foo bar\"] {color:red}", "[foo=\"foo bar\"]{color:red}");
+    minify_test("[data-x=\"foo\"] {color:red}", "[data-x=foo]{color:red}");
+    minify_test("[data-x=\"foo-bar_1\"] {color:red}", "[data-x=foo-bar_1]{color:red}");
+    minify_test("[data-x=\"-foo\"] {color:red}", "[data-x=-foo]{color:red}");
+    minify_test("[data-x=\"1foo\"] {color:red}", "[data-x=\"1foo\"]{color:red}");
+    minify_test("[data-x=\"-1\"] {color:red}", "[data-x=\"-1\"]{color:red}");
+    minify_test("[data-x=\"a.b\"] {color:red}", "[data-x=\"a.b\"]{color:red}");
+    minify_test("[data-x=\"foo\" i] {color:red}", "[data-x=foo i]{color:red}");
+    minify_test("[data-x=\"foo\" s] {color:red}", "[data-x=foo s]{color:red}");
+    minify_test(
+      "[data-x=\"foo bar\" i] {color:red}",
+      "[data-x=\"foo bar\" i]{color:red}",
+    );
+    minify_test("[data-x=\"\" i] {color:red}", "[data-x=\"\" i]{color:red}");
+    minify_test("[data-x^=foo] {color:red}", "[data-x^=foo]{color:red}");
+    test("[data-x=foo i] {color:red}", "[data-x=\"foo\" i] {\n  color: red;\n}\n");
     minify_test("[foo=\"foo bar baz\"] {color:red}", "[foo=\"foo bar baz\"]{color:red}");
     minify_test("[foo=\"\"] {color:red}", "[foo=\"\"]{color:red}");
     minify_test(
@@ -5320,6 +5911,21 @@ mod tests {
     minify_test(".x:has(.bar, #foo) {color:red}", ".x:has(.bar,#foo){color:red}");
     minify_test(".x:has(span + span) {color:red}", ".x:has(span+span){color:red}");
     minify_test("a:has(:visited) {color:red}", "a:has(:visited){color:red}");
+    minify_test("h1:has(+ p, .x) {color:red}", "h1:has(+p,.x){color:red}");
+    test("a:has(> img) {color:red}", "a:has(> img) {\n  color: red;\n}\n");
+    test("h1:has(+ p, .x) {color:red}", "h1:has(+ p, .x) {\n  color: red;\n}\n");
+    prefix_test(
+      "a:has(> img) {color:red}",
+      indoc! {r#"
+        a:has(> img) {
+          color: red;
+        }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
     for element in [
       "-webkit-scrollbar",
       "-webkit-scrollbar-button",
@@ -5465,161 +6071,401 @@ mod tests {
     );
 
     prefix_test(
-      "a:lang(en, fr) {color:red}",
+      ":is(h1, h2) .title {color:red}",
       indoc! {r#"
-      a:-webkit-any(:lang(en), :lang(fr)) {
+      h1 .title, h2 .title {
         color: red;
       }
+      "#},
+      Browsers {
+        ie: Some(11 << 16),
+        ..Browsers::default()
+      },
+    );
 
-      a:-moz-any(:lang(en), :lang(fr)) {
+    prefix_test(
+      ".foo:is(h1, h2) {color:red}",
+      indoc! {r#"
+      h1.foo, h2.foo {
         color: red;
       }
+      "#},
+      Browsers {
+        ie: Some(11 << 16),
+        ..Browsers::default()
+      },
+    );
 
-      a:is(:lang(en), :lang(fr)) {
+    prefix_test(
+      ":is(.a, .b):hover {color:red}",
+      indoc! {r#"
+      .a:hover, .b:hover {
         color: red;
       }
       "#},
       Browsers {
-        safari: Some(11 << 16),
-        firefox: Some(50 << 16),
+        ie: Some(11 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      "a:lang(en, fr) {color:red}",
+      ":is(.a, .b) > :is(.c, .d) {color:red}",
       indoc! {r#"
-      a:is(:lang(en), :lang(fr)) {
+      .a > .c, .a > .d, .b > .c, .b > .d {
         color: red;
       }
       "#},
       Browsers {
-        safari: Some(14 << 16),
-        firefox: Some(88 << 16),
+        ie: Some(11 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      "a:lang(en, fr) {color:red}",
+      ".foo:is(.a, :is(.b, .c)) {color:red}",
       indoc! {r#"
-      a:lang(en, fr) {
+      .foo.a, .foo.b, .foo.c {
         color: red;
       }
       "#},
       Browsers {
-        safari: Some(14 << 16),
+        ie: Some(11 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      "a:dir(rtl) {color:red}",
+      ":is(.foo, #bar) .title {color:red}",
       indoc! {r#"
-      a:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+      :is(.foo, #bar) .title {
         color: red;
       }
+      "#},
+      Browsers {
+        ie: Some(11 << 16),
+        ..Browsers::default()
+      },
+    );
 
-      a:-moz-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+    prefix_test(
+      ":where(.foo, .bar) .title {color:red}",
+      indoc! {r#"
+      :where(.foo, .bar) .title {
         color: red;
       }
+      "#},
+      Browsers {
+        ie: Some(11 << 16),
+        ..Browsers::default()
+      },
+    );
 
-      a:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+    prefix_test(
+      ":where(*) > .title {color:red}",
+      indoc! {r#"
+      * > .title {
         color: red;
       }
       "#},
       Browsers {
-        safari: Some(11 << 16),
-        firefox: Some(50 << 16),
+        ie: Some(11 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      "a:dir(ltr) {color:red}",
+      ":is(.a .b, .c > .d) .title {color:red}",
       indoc! {r#"
-      a:not(:lang(ae)):not(:lang(ar)):not(:lang(arc)):not(:lang(bcc)):not(:lang(bqi)):not(:lang(ckb)):not(:lang(dv)):not(:lang(fa)):not(:lang(glk)):not(:lang(he)):not(:lang(ku)):not(:lang(mzn)):not(:lang(nqo)):not(:lang(pnb)):not(:lang(ps)):not(:lang(sd)):not(:lang(ug)):not(:lang(ur)):not(:lang(yi)) {
+      .a .b .title, .c > .d .title {
         color: red;
       }
       "#},
       Browsers {
-        safari: Some(11 << 16),
-        firefox: Some(50 << 16),
+        chrome: Some(80 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      "a:dir(rtl) {color:red}",
+      ".x:is(.a .b, .c .d) :is(.e, .f) {color:red}",
       indoc! {r#"
-      a:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+      .x:is(.a .b, .c .d) .e, .x:is(.a .b, .c .d) .f {
         color: red;
       }
       "#},
       Browsers {
-        safari: Some(14 << 16),
-        firefox: Some(88 << 16),
+        ie: Some(11 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      "a:dir(ltr) {color:red}",
+      ":is(.a .b, .c) .title {color:red}",
       indoc! {r#"
-      a:not(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+      :is(.a .b, .c) .title {
         color: red;
       }
       "#},
       Browsers {
-        safari: Some(14 << 16),
-        firefox: Some(88 << 16),
+        chrome: Some(80 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      "a:dir(rtl) {color:red}",
+      ":is(.foo, .bar) .title {color:red}",
       indoc! {r#"
-      a:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) {
+      :-webkit-any(.foo, .bar) .title {
+        color: red;
+      }
+
+      :is(.foo, .bar) .title {
         color: red;
       }
       "#},
       Browsers {
-        safari: Some(14 << 16),
+        chrome: Some(80 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      "a:dir(ltr) {color:red}",
+      ":is(.foo, .bar) .title {color:red}",
       indoc! {r#"
-      a:not(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
+      :is(.foo, .bar) .title {
         color: red;
       }
       "#},
       Browsers {
-        safari: Some(14 << 16),
+        chrome: Some(95 << 16),
         ..Browsers::default()
       },
     );
 
-    minify_test(".foo::cue {color: red}", ".foo::cue{color:red}");
-    minify_test(".foo::cue-region {color: red}", ".foo::cue-region{color:red}");
-    minify_test(".foo::cue(b) {color: red}", ".foo::cue(b){color:red}");
-    minify_test(".foo::cue-region(b) {color: red}", ".foo::cue-region(b){color:red}");
-    minify_test(
-      "::cue(v[voice='active']) {color: yellow;}",
-      "::cue(v[voice=active]){color:#ff0}",
-    );
-    minify_test(":foo(bar) { color: yellow }", ":foo(bar){color:#ff0}");
-    minify_test("::foo(bar) { color: yellow }", "::foo(bar){color:#ff0}");
-    minify_test("::foo(*) { color: yellow }", "::foo(*){color:#ff0}");
-  }
+    prefix_test(
+      "a:lang(en, fr) {color:red}",
+      indoc! {r#"
+      a:-webkit-any(:lang(en), :lang(fr)) {
+        color: red;
+      }
 
-  #[test]
-  fn test_keyframes() {
-    minify_test(
-      r#"
+      a:-moz-any(:lang(en), :lang(fr)) {
+        color: red;
+      }
+
+      a:is(:lang(en), :lang(fr)) {
+        color: red;
+      }
+      "#},
+      Browsers {
+        safari: Some(11 << 16),
+        firefox: Some(50 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      "a:lang(en, fr) {color:red}",
+      indoc! {r#"
+      a:is(:lang(en), :lang(fr)) {
+        color: red;
+      }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        firefox: Some(88 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      "a:lang(en, fr) {color:red}",
+      indoc! {r#"
+      a:lang(en, fr) {
+        color: red;
+      }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      "a:dir(rtl) {color:red}",
+      indoc! {r#"
+      a:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        color: red;
+      }
+
+      a:-moz-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        color: red;
+      }
+
+      a:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        color: red;
+      }
+      "#},
+      Browsers {
+        safari: Some(11 << 16),
+        firefox: Some(50 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      "a:dir(ltr) {color:red}",
+      indoc! {r#"
+      a:not(:lang(ae)):not(:lang(ar)):not(:lang(arc)):not(:lang(bcc)):not(:lang(bqi)):not(:lang(ckb)):not(:lang(dv)):not(:lang(fa)):not(:lang(glk)):not(:lang(he)):not(:lang(ku)):not(:lang(mzn)):not(:lang(nqo)):not(:lang(pnb)):not(:lang(ps)):not(:lang(sd)):not(:lang(ug)):not(:lang(ur)):not(:lang(yi)) {
+        color: red;
+      }
+      "#},
+      Browsers {
+        safari: Some(11 << 16),
+        firefox: Some(50 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      "a:dir(rtl) {color:red}",
+      indoc! {r#"
+      a:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        color: red;
+      }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        firefox: Some(88 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      "a:dir(ltr) {color:red}",
+      indoc! {r#"
+      a:not(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        color: red;
+      }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        firefox: Some(88 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      "a:dir(rtl) {color:red}",
+      indoc! {r#"
+      a:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) {
+        color: red;
+      }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      "a:dir(ltr) {color:red}",
+      indoc! {r#"
+      a:not(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
+        color: red;
+      }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    minify_test(".foo::cue {color: red}", ".foo::cue{color:red}");
+    minify_test(".foo::cue-region {color: red}", ".foo::cue-region{color:red}");
+    minify_test(".foo::cue(b) {color: red}", ".foo::cue(b){color:red}");
+    minify_test(".foo::cue-region(b) {color: red}", ".foo::cue-region(b){color:red}");
+    minify_test(
+      "::cue(v[voice='active']) {color: yellow;}",
+      "::cue(v[voice=active]){color:#ff0}",
+    );
+    minify_test(":foo(bar) { color: yellow }", ":foo(bar){color:#ff0}");
+    minify_test("::foo(bar) { color: yellow }", "::foo(bar){color:#ff0}");
+    minify_test("::foo(*) { color: yellow }", "::foo(*){color:#ff0}");
+  }
+
+  #[test]
+  fn test_legacy_pseudo_elements() {
+    fn minify_prefix_test(source: &str, expected: &str, targets: Browsers) {
+      minify_printer_test(
+        source,
+        expected,
+        MinifyOptions {
+          targets: Some(targets),
+          ..MinifyOptions::default()
+        },
+        PrinterOptions {
+          minify: true,
+          targets: Some(targets),
+          ..PrinterOptions::default()
+        },
+      );
+    }
+
+    let modern = Browsers {
+      chrome: Some(90 << 16),
+      safari: Some(14 << 16),
+      ..Browsers::default()
+    };
+    minify_prefix_test(".foo:before {color:red}", ".foo::before{color:red}", modern);
+    minify_prefix_test(".foo:after {color:red}", ".foo::after{color:red}", modern);
+    minify_prefix_test(".foo:first-line {color:red}", ".foo::first-line{color:red}", modern);
+    minify_prefix_test(".foo:first-letter {color:red}", ".foo::first-letter{color:red}", modern);
+    minify_prefix_test(".foo::before {color:red}", ".foo::before{color:red}", modern);
+    minify_prefix_test(".foo:hover:after {color:red}", ".foo:hover::after{color:red}", modern);
+    minify_prefix_test(".foo:hover {color:red}", ".foo:hover{color:red}", modern);
+    minify_prefix_test(".foo:first-child {color:red}", ".foo:first-child{color:red}", modern);
+    prefix_test(
+      ".foo:before, .bar:first-letter {color:red}",
+      indoc! {r#"
+      .foo::before, .bar::first-letter {
+        color: red;
+      }
+    "#},
+      modern,
+    );
+
+    // IE always gets the single colon syntax, even when it supports the double colon.
+    let ie = Browsers {
+      chrome: Some(90 << 16),
+      ie: Some(11 << 16),
+      ..Browsers::default()
+    };
+    minify_prefix_test(".foo:before {color:red}", ".foo:before{color:red}", ie);
+    minify_prefix_test(".foo::after {color:red}", ".foo:after{color:red}", ie);
+    minify_prefix_test(".foo::first-line {color:red}", ".foo:first-line{color:red}", ie);
+    minify_prefix_test(".foo::first-letter {color:red}", ".foo:first-letter{color:red}", ie);
+    minify_prefix_test(".foo:hover {color:red}", ".foo:hover{color:red}", ie);
+
+    // Browsers that only support the single colon syntax.
+    minify_prefix_test(
+      ".foo::before {color:red}",
+      ".foo:before{color:red}",
+      Browsers {
+        safari: Some(3 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    // Without targets, the shorter single colon syntax is used.
+    minify_test(".foo::before {color:red}", ".foo:before{color:red}");
+  }
+
+  #[test]
+  fn test_keyframes() {
+    minify_test(
+      r#"
       @keyframes "test" {
         100% {
           background: blue
@@ -5972,74 +6818,251 @@ mod tests {
         ..Browsers::default()
       },
     );
-  }
 
-  #[test]
-  fn test_important() {
-    test(
+    minify_test(
       r#"
-      .foo {
-        align-items: center;
-        justify-items: center !important;
+      @keyframes test {
+        0% {
+          opacity: 0;
+        }
+        50% {
+          opacity: 1;
+        }
+        100% {
+          opacity: 0;
+        }
       }
     "#,
-      indoc! {r#"
-      .foo {
-        align-items: center;
-        justify-items: center !important;
-      }
-    "#},
+      "@keyframes test{0%,to{opacity:0}50%{opacity:1}}",
     );
-
-    test(
+    minify_test(
       r#"
-      .foo {
-        justify-items: center !important;
-        align-items: center;
+      @keyframes test {
+        from {
+          color: red;
+        }
+        to {
+          color: red;
+        }
       }
     "#,
-      indoc! {r#"
-      .foo {
-        align-items: center;
-        justify-items: center !important;
-      }
-    "#},
+      "@keyframes test{0%,to{color:red}}",
     );
-
     minify_test(
       r#"
-      .foo {
-        font-family: SFMono-Regular, Menlo, Monaco, Consolas, "Liberation Mono", "Courier New", monospace !important;
+      @keyframes test {
+        from {
+          color: red;
+        }
+        0% {
+          color: red;
+        }
+        to {
+          color: blue;
+        }
       }
     "#,
-      ".foo{font-family:SFMono-Regular,Menlo,Monaco,Consolas,Liberation Mono,Courier New,monospace!important}",
-    );
-  }
-
-  #[test]
-  fn test_calc() {
-    minify_test(".foo { width: calc(20px * 2) }", ".foo{width:40px}");
-    minify_test(".foo { font-size: calc(100vw / 35) }", ".foo{font-size:2.85714vw}");
-    minify_test(".foo { width: calc(20px * 2 * 3) }", ".foo{width:120px}");
-    minify_test(".foo { width: calc(20px + 30px) }", ".foo{width:50px}");
-    minify_test(".foo { width: calc(20px + 30px + 40px) }", ".foo{width:90px}");
-    minify_test(".foo { width: calc(100% - 30px) }", ".foo{width:calc(100% - 30px)}");
-    minify_test(
-      ".foo { width: calc(100% - 30px + 20px) }",
-      ".foo{width:calc(100% - 10px)}",
+      "@keyframes test{0%{color:red}to{color:#00f}}",
     );
     minify_test(
-      ".foo { width: calc(20px + 100% - 30px) }",
-      ".foo{width:calc(100% - 10px)}",
+      r#"
+      @keyframes test {
+        from {
+          color: red;
+        }
+        50% {
+        }
+        to {
+          color: blue;
+        }
+      }
+    "#,
+      "@keyframes test{0%{color:red}to{color:#00f}}",
     );
     minify_test(
-      ".foo { width: calc(20px + 100% + 10vw - 30px) }",
+      r#"
+      @keyframes test {
+        from {
+          color: red;
+        }
+        to {
+          color: blue;
+        }
+      }
+    "#,
+      "@keyframes test{0%{color:red}to{color:#00f}}",
+    );
+    // Keyframes with the same offset cannot be reordered.
+    minify_test(
+      r#"
+      @keyframes test {
+        0% {
+          color: red;
+        }
+        100% {
+          color: blue;
+        }
+        100% {
+          color: red;
+        }
+      }
+    "#,
+      "@keyframes test{0%{color:red}to{color:#00f}to{color:red}}",
+    );
+    test(
+      r#"
+      @keyframes test {
+        from {
+          opacity: 0;
+        }
+        50% {
+          opacity: 1;
+        }
+        to {
+          opacity: 0;
+        }
+      }
+    "#,
+      indoc! {r#"
+      @keyframes test {
+        from, to {
+          opacity: 0;
+        }
+
+        50% {
+          opacity: 1;
+        }
+      }
+    "#},
+    );
+  }
+
+  #[test]
+  fn test_important() {
+    test(
+      r#"
+      .foo {
+        align-items: center;
+        justify-items: center !important;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        align-items: center;
+        justify-items: center !important;
+      }
+    "#},
+    );
+
+    test(
+      r#"
+      .foo {
+        justify-items: center !important;
+        align-items: center;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        align-items: center;
+        justify-items: center !important;
+      }
+    "#},
+    );
+
+    minify_test(
+      r#"
+      .foo {
+        font-family: SFMono-Regular, Menlo, Monaco, Consolas, "Liberation Mono", "Courier New", monospace !important;
+      }
+    "#,
+      ".foo{font-family:SFMono-Regular,Menlo,Monaco,Consolas,Liberation Mono,Courier New,monospace!important}",
+    );
+  }
+
+  #[test]
+  fn test_length_to_px() {
+    use crate::values::length::Length;
+
+    fn to_px(source: &str) -> Option<f32> {
+      Length::parse_string(source).unwrap().to_px()
+    }
+
+    fn assert_px(source: &str, expected: f32) {
+      let px = to_px(source).unwrap();
+      assert!(
+        (px - expected).abs() < 0.001,
+        "{} resolved to {}px, expected {}px",
+        source,
+        px,
+        expected
+      );
+    }
+
+    assert_px("10px", 10.0);
+    assert_px("1in", 96.0);
+    assert_px("2.54cm", 96.0);
+    assert_px("25.4mm", 96.0);
+    assert_px("101.6q", 96.0);
+    assert_px("72pt", 96.0);
+    assert_px("6pc", 96.0);
+    assert_px("-1in", -96.0);
+    assert_px("calc(1in + 1px)", 97.0);
+
+    assert_eq!(to_px("1em"), None);
+    assert_eq!(to_px("1rem"), None);
+    assert_eq!(to_px("1ch"), None);
+    assert_eq!(to_px("1vw"), None);
+    assert_eq!(to_px("1dvh"), None);
+    assert_eq!(to_px("1cqw"), None);
+    assert_eq!(to_px("calc(1px + 1em)"), None);
+  }
+
+  #[test]
+  fn test_calc() {
+    minify_test(".foo { width: calc(20px * 2) }", ".foo{width:40px}");
+    minify_test(".foo { font-size: calc(100vw / 35) }", ".foo{font-size:2.85714vw}");
+    minify_test(".foo { width: calc(20px * 2 * 3) }", ".foo{width:120px}");
+    minify_test(".foo { width: calc(20px + 30px) }", ".foo{width:50px}");
+    minify_test(".foo { width: calc(20px + 30px + 40px) }", ".foo{width:90px}");
+    minify_test(".foo { width: calc(100% - 30px) }", ".foo{width:calc(100% - 30px)}");
+    minify_test(
+      ".foo { width: calc(100% - 30px + 20px) }",
+      ".foo{width:calc(100% - 10px)}",
+    );
+    minify_test(
+      ".foo { width: calc(20px + 100% - 30px) }",
+      ".foo{width:calc(100% - 10px)}",
+    );
+    minify_test(
+      ".foo { width: calc(20px + 100% + 10vw - 30px) }",
       ".foo{width:calc(100% - 10px + 10vw)}",
     );
     minify_test(
       ".foo { width: calc(20px + 100% - 30px) }",
       ".foo{width:calc(100% - 10px)}",
     );
+    minify_test(
+      ".foo { width: calc(30% + 10px + 20% + 10px) }",
+      ".foo{width:calc(50% + 20px)}",
+    );
+    minify_test(
+      ".foo { width: calc(10px + 30% - 5px + 20%) }",
+      ".foo{width:calc(5px + 50%)}",
+    );
+    minify_test(
+      ".foo { width: calc(50% - 10px - 20% - 5px) }",
+      ".foo{width:calc(30% - 15px)}",
+    );
+    minify_test(
+      ".foo { width: calc(100% + 10px - 30px) }",
+      ".foo{width:calc(100% - 20px)}",
+    );
+    minify_test(
+      ".foo { width: calc(10px - 30px + 100%) }",
+      ".foo{width:calc(100% - 20px)}",
+    );
+    minify_test(".foo { width: calc(20% - 50% + 10px) }", ".foo{width:calc(10px - 30%)}");
+    minify_test(".foo { width: calc(10px - 30px) }", ".foo{width:-20px}");
     minify_test(
       ".foo { width: calc(2 * (100% - 20px)) }",
       ".foo{width:calc(200% - 40px)}",
@@ -6113,12 +7136,48 @@ mod tests {
     );
     minify_test(".foo { width: calc(500px/0) }", ".foo{width:calc(500px/0)}");
     minify_test(".foo { width: calc(500px/2px) }", ".foo{width:calc(500px/2px)}");
+    minify_test(".foo { width: calc(10px / 0) }", ".foo{width:calc(10px/0)}");
+    minify_test(".foo { width: calc(10px / (1 + 1)) }", ".foo{width:5px}");
+    minify_test(".foo { width: calc(100% / var(--n)) }", ".foo{width:calc(100%/var(--n))}");
+    minify_test(
+      ".foo { width: calc(100% / var(--cols) - 10px) }",
+      ".foo{width:calc(100%/var(--cols) - 10px)}",
+    );
+    test(
+      ".foo { width: calc(100% / var(--n)) }",
+      indoc! {r#"
+      .foo {
+        width: calc(100% / var(--n));
+      }
+      "#},
+    );
     minify_test(".foo { width: calc(100% / 3 * 3) }", ".foo{width:100%}");
     minify_test(".foo { width: calc(+100px + +100px) }", ".foo{width:200px}");
     minify_test(".foo { width: calc(+100px - +100px) }", ".foo{width:0}");
     minify_test(".foo { width: calc(200px * +1) }", ".foo{width:200px}");
     minify_test(".foo { width: calc(200px / +1) }", ".foo{width:200px}");
     minify_test(".foo { width: calc(1.1e+1px + 1.1e+1px) }", ".foo{width:22px}");
+    minify_test(".foo { width: calc(0px + 10%) }", ".foo{width:10%}");
+    minify_test(".foo { width: calc(10% + 0px) }", ".foo{width:10%}");
+    minify_test(".foo { width: calc(10px + 0%) }", ".foo{width:10px}");
+    minify_test(".foo { width: calc(1em + 0px) }", ".foo{width:1em}");
+    minify_test(".foo { width: calc(10px - 10px + 5%) }", ".foo{width:5%}");
+    minify_test(".foo { width: calc(0px + 0%) }", ".foo{width:0}");
+    minify_test(".foo { width: calc(0% + 0px) }", ".foo{width:0}");
+    minify_test(".foo { width: calc(1 * 10px) }", ".foo{width:10px}");
+    minify_test(".foo { width: calc(10px / 1) }", ".foo{width:10px}");
+    minify_test(".foo { width: calc(1 * (10px + 1em)) }", ".foo{width:calc(10px + 1em)}");
+    minify_test(".foo { width: calc(0px + 1 * 10%) }", ".foo{width:10%}");
+    minify_test(".foo { width: calc(10px + (5px + 3px)) }", ".foo{width:18px}");
+    minify_test(".foo { width: calc(10px + (5px + (3px + 2px))) }", ".foo{width:20px}");
+    minify_test(".foo { width: calc((10px - (5px - 3px)) + 2px) }", ".foo{width:10px}");
+    minify_test(".foo { width: calc(2 * (10px + 5px)) }", ".foo{width:30px}");
+    minify_test(".foo { width: calc(10px + 2em) }", ".foo{width:calc(10px + 2em)}");
+    minify_test(".foo { width: calc(10px + 2em + 5px) }", ".foo{width:calc(15px + 2em)}");
+    minify_test(
+      ".foo { width: calc(10px + (2em + (5px - 1em))) }",
+      ".foo{width:calc(15px + 1em)}",
+    );
     minify_test(".foo { border-width: calc(1px + 2px) }", ".foo{border-width:3px}");
     minify_test(
       ".foo { border-width: calc(1em + 2px + 2em + 3px) }",
@@ -6204,6 +7263,53 @@ mod tests {
       ".foo{border-width:clamp(1px,1px + 2em,4px)}",
     );
     minify_test(".foo { border-width: clamp(1px, 2pt, 1in) }", ".foo{border-width:2pt}");
+    minify_test(".foo { width: max(10px, 20px) }", ".foo{width:20px}");
+    minify_test(".foo { width: clamp(10px, 5px, 30px) }", ".foo{width:10px}");
+    minify_test(".foo { width: clamp(1rem, 2vw, 3rem) }", ".foo{width:clamp(1rem,2vw,3rem)}");
+    minify_test(
+      ".foo { font-size: clamp(1rem, 2.5vw, 2rem) }",
+      ".foo{font-size:clamp(1rem,2.5vw,2rem)}",
+    );
+    minify_test(
+      ".foo { font-size: clamp(1rem, 1rem + 2.5vw, 2rem) }",
+      ".foo{font-size:clamp(1rem,1rem + 2.5vw,2rem)}",
+    );
+    minify_test(
+      ".foo { font-size: clamp(16px, 4vh, 1in) }",
+      ".foo{font-size:clamp(16px,4vh,1in)}",
+    );
+    minify_test(
+      ".foo { font-size: calc(1rem + 2vw) }",
+      ".foo{font-size:calc(1rem + 2vw)}",
+    );
+    minify_test(
+      ".foo { line-height: calc(1rem + 2vmax) }",
+      ".foo{line-height:calc(1rem + 2vmax)}",
+    );
+    prefix_test(
+      ".foo { font-size: clamp(1rem, 2.5vw, 2rem) }",
+      indoc! {r#"
+        .foo {
+          font-size: max(1rem, min(2.5vw, 2rem));
+        }
+      "#},
+      Browsers {
+        safari: Some(12 << 16),
+        ..Browsers::default()
+      },
+    );
+    minify_test(
+      ".foo { width: clamp(min(10px, 20px), 15px, max(30px, 40px)) }",
+      ".foo{width:15px}",
+    );
+    minify_test(
+      ".foo { width: clamp(min(10px, 20px), 50px, max(30px, 40px)) }",
+      ".foo{width:40px}",
+    );
+    minify_test(
+      ".foo { width: clamp(min(1rem, 10px), 2vw, max(3rem, 30px)) }",
+      ".foo{width:clamp(min(1rem,10px),2vw,max(3rem,30px))}",
+    );
 
     minify_test(
       ".foo { top: calc(-1 * clamp(1.75rem, 8vw, 4rem)) }",
@@ -6336,6 +7442,15 @@ mod tests {
       ".foo{transform:rotateX(-40deg)rotateY(50deg)}",
     );
     minify_test(".foo { width: calc(10px * mod(18, 5)) }", ".foo{width:30px}");
+    minify_test(".foo { width: round(nearest, 101px, 10px) }", ".foo{width:100px}");
+    minify_test(".foo { width: round(up, 101px, 10px) }", ".foo{width:110px}");
+    minify_test(".foo { width: round(down, 109px, 10px) }", ".foo{width:100px}");
+    minify_test(".foo { width: round(to-zero, -109px, 10px) }", ".foo{width:-100px}");
+    minify_test(".foo { width: round(up, 22px, 5vw) }", ".foo{width:round(up,22px,5vw)}");
+    minify_test(".foo { width: round(22px, 0px) }", ".foo{width:round(22px,0px)}");
+    minify_test(".foo { width: round(up, 22px, 0px) }", ".foo{width:round(up,22px,0px)}");
+    minify_test(".foo { width: rem(18px, 0px) }", ".foo{width:rem(18px,0px)}");
+    minify_test(".foo { width: mod(18px, 0px) }", ".foo{width:mod(18px,0px)}");
   }
 
   #[test]
@@ -6384,6 +7499,9 @@ mod tests {
     minify_test(".foo { rotate: atan(1)", ".foo{rotate:45deg}");
     minify_test(".foo { rotate: atan(0)", ".foo{rotate:none}");
     minify_test(".foo { rotate: atan(45deg)", ".foo{rotate:atan(45deg)}"); // invalid
+    minify_test(".foo { rotate: calc(atan(1) * 2)", ".foo{rotate:90deg}");
+    minify_test(".foo { rotate: calc(asin(1) + acos(0))", ".foo{rotate:180deg}");
+    minify_test(".foo { transform: rotate(atan(1)) }", ".foo{transform:rotate(45deg)}");
 
     minify_test(".foo { rotate: atan2(1px, -1px)", ".foo{rotate:135deg}");
     minify_test(".foo { rotate: atan2(1vw, -1vw)", ".foo{rotate:135deg}");
@@ -6430,6 +7548,133 @@ mod tests {
     minify_test(".foo { width: calc(10px * sign(1%)", ".foo{width:calc(10px*sign(1%))}");
   }
 
+  #[test]
+  fn test_angle() {
+    use crate::values::angle::Angle;
+
+    let angle = Angle::Turn(0.5) * 2.0;
+    assert_eq!(angle.to_css_string(PrinterOptions::default()).unwrap(), "1turn");
+    assert_eq!(angle.to_degrees(), 360.0);
+
+    let angle = Angle::Grad(50.0) * 2.0;
+    assert_eq!(angle.to_css_string(PrinterOptions::default()).unwrap(), "100grad");
+    assert_eq!(angle.to_degrees(), 90.0);
+
+    let angle = Angle::Rad(1.5) * 2.0;
+    assert!(matches!(angle, Angle::Rad(v) if v == 3.0));
+
+    assert_eq!(Angle::Deg(90.0) - Angle::Deg(30.0), Angle::Deg(60.0));
+    assert!(matches!(Angle::Turn(0.5) - Angle::Turn(0.25), Angle::Turn(v) if v == 0.25));
+    assert_eq!(Angle::Deg(180.0) - Angle::Turn(0.25), Angle::Deg(90.0));
+    assert_eq!(
+      (-Angle::Turn(0.25)).to_css_string(PrinterOptions::default()).unwrap(),
+      "-0.25turn"
+    );
+    assert_eq!(-Angle::Deg(45.0), Angle::Deg(-45.0));
+    minify_test(".foo { rotate: calc(1turn - 90deg) }", ".foo{rotate:270deg}");
+
+    let serialize = |angle: Angle| angle.to_css_string(PrinterOptions::default()).unwrap();
+    assert_eq!(serialize(Angle::Rad(std::f32::consts::PI / 6.0)), "30deg");
+    assert_eq!(serialize(Angle::Rad(std::f32::consts::PI / 4.0)), "45deg");
+    assert_eq!(serialize(Angle::Rad(std::f32::consts::PI / 3.0)), "60deg");
+    assert_eq!(serialize(Angle::Rad(-std::f32::consts::PI / 2.0)), "-90deg");
+    assert_eq!(serialize(Angle::Rad(0.0)), "0deg");
+    assert_eq!(serialize(Angle::Rad(1.0)), "1rad");
+    assert_eq!(serialize(Angle::Rad(1.0472)), "1.0472rad");
+    assert_eq!(serialize(Angle::Rad(-1.0472)), "-1.0472rad");
+    assert_eq!(serialize(Angle::Rad(3.14159)), "180deg");
+    assert_eq!(serialize(Angle::Rad(1.47113)), "84.2895deg");
+    assert_eq!(
+      Angle::Rad(1.0472)
+        .to_css_string(PrinterOptions {
+          precision: Some(3),
+          ..PrinterOptions::default()
+        })
+        .unwrap(),
+      "60deg"
+    );
+
+    assert_eq!(Angle::Deg(360.0).to_turns(), 1.0);
+    assert_eq!(Angle::Deg(90.0).to_grads(), 100.0);
+    assert_eq!(Angle::Grad(100.0).to_turns(), 0.25);
+    assert_eq!(Angle::Turn(0.5).to_grads(), 200.0);
+    for angle in [
+      Angle::Deg(45.0),
+      Angle::Grad(50.0),
+      Angle::Turn(0.125),
+      Angle::Rad(std::f32::consts::PI / 4.0),
+    ] {
+      assert!((Angle::Turn(angle.to_turns()).to_degrees() - angle.to_degrees()).abs() < 1e-4);
+      assert!((Angle::Grad(angle.to_grads()).to_degrees() - angle.to_degrees()).abs() < 1e-4);
+      assert!((Angle::Rad(angle.to_radians()).to_degrees() - angle.to_degrees()).abs() < 1e-4);
+      assert!((Angle::Turn(angle.to_turns()).to_grads() - angle.to_grads()).abs() < 1e-4);
+    }
+
+    let parse_with_unitless_zero = |s: &str| {
+      let mut input = cssparser::ParserInput::new(s);
+      let mut parser = cssparser::Parser::new(&mut input);
+      Angle::parse_with_unitless_zero(&mut parser).map_err(|_| ())
+    };
+    assert_eq!(parse_with_unitless_zero("0"), Ok(Angle::Deg(0.0)));
+    assert_eq!(parse_with_unitless_zero("5"), Err(()));
+    assert_eq!(Angle::parse_string("0").map_err(|_| ()), Ok(Angle::Deg(0.0)));
+    assert!(Angle::parse_string("5").is_err());
+    assert!(Angle::parse_string("0px").is_err());
+    let mut s = String::new();
+    {
+      let mut printer = crate::printer::Printer::new(&mut s, PrinterOptions::default());
+      Angle::Deg(0.0).to_css_with_unitless_zero(&mut printer).unwrap();
+    }
+    assert_eq!(s, "0");
+    assert_eq!(serialize(Angle::Deg(0.0)), "0deg");
+
+    assert_eq!(Angle::Grad(50.0).minify(), Angle::Deg(45.0));
+    assert!(matches!(Angle::Deg(90.0).minify(), Angle::Deg(v) if v == 90.0));
+    assert!(matches!(Angle::Deg(360.0).minify(), Angle::Deg(v) if v == 360.0));
+    assert!(matches!(Angle::Turn(0.25).minify(), Angle::Turn(v) if v == 0.25));
+    assert!(matches!(Angle::Rad(std::f32::consts::PI / 4.0).minify(), Angle::Deg(v) if v == 45.0));
+    assert!(matches!(Angle::Deg(120.0).minify(), Angle::Deg(v) if v == 120.0));
+    assert!(matches!(Angle::Deg(180.0).minify(), Angle::Deg(v) if v == 180.0));
+    assert!(matches!(Angle::Rad(1.0).minify(), Angle::Rad(v) if v == 1.0));
+    minify_test(".foo { rotate: 90deg }", ".foo{rotate:90deg}");
+    minify_test(".foo { rotate: 100grad }", ".foo{rotate:90deg}");
+    minify_test(".foo { rotate: 50grad }", ".foo{rotate:45deg}");
+    minify_test(".foo { rotate: .125turn }", ".foo{rotate:.125turn}");
+    minify_test(".foo { rotate: 0.25turn }", ".foo{rotate:.25turn}");
+    minify_test(".foo { rotate: 0.7853982rad }", ".foo{rotate:45deg}");
+    minify_test(".foo { rotate: 400grad }", ".foo{rotate:360deg}");
+    minify_test(".foo { rotate: 360deg }", ".foo{rotate:360deg}");
+    minify_test(".foo { rotate: 1.5turn }", ".foo{rotate:1.5turn}");
+    minify_test(".foo { rotate: 33grad }", ".foo{rotate:33grad}");
+    assert_eq!(
+      Angle::Grad(33.0)
+        .to_css_string(PrinterOptions {
+          minify: true,
+          precision: Some(1),
+          ..PrinterOptions::default()
+        })
+        .unwrap(),
+      "30grad"
+    );
+    minify_test(".foo { rotate: 1rad }", ".foo{rotate:1rad}");
+    test(
+      ".foo { rotate: 100grad }",
+      indoc! {r#"
+      .foo {
+        rotate: 100grad;
+      }
+      "#},
+    );
+    test(
+      ".foo { rotate: .25turn }",
+      indoc! {r#"
+      .foo {
+        rotate: 0.25turn;
+      }
+      "#},
+    );
+  }
+
   #[test]
   fn test_box_shadow() {
     minify_test(
@@ -6501,11 +7746,26 @@ mod tests {
       },
     );
 
+    prefix_test(
+      ".foo { box-shadow: 12px 12px lab(40% 56.6 39), 12px 12px Canvas }",
+      indoc! { r#"
+        .foo {
+          -webkit-box-shadow: 12px 12px #b32323, 12px 12px Canvas;
+          box-shadow: 12px 12px #b32323, 12px 12px Canvas;
+          box-shadow: 12px 12px lab(40% 56.6 39), 12px 12px Canvas;
+        }
+      "#},
+      Browsers {
+        chrome: Some(4 << 16),
+        ..Browsers::default()
+      },
+    );
+
     prefix_test(
       ".foo { -webkit-box-shadow: 12px 12px #0006 }",
       indoc! { r#"
         .foo {
-          -webkit-box-shadow: 12px 12px rgba(0, 0, 0, .4);
+          -webkit-box-shadow: 12px 12px rgba(0, 0, 0, 0.4);
         }
       "#},
       Browsers {
@@ -6521,8 +7781,8 @@ mod tests {
       }",
       indoc! { r#"
         .foo {
-          -webkit-box-shadow: 12px 12px rgba(0, 0, 0, .4);
-          -moz-box-shadow: 12px 12px rgba(0, 0, 0, .6);
+          -webkit-box-shadow: 12px 12px rgba(0, 0, 0, 0.4);
+          -moz-box-shadow: 12px 12px rgba(0, 0, 0, 0.6);
         }
       "#},
       Browsers {
@@ -6874,56 +8134,203 @@ mod tests {
       },
     );
 
-    test(
+    prefix_test(
       r#"
-      @media not all {
-        .a {
-          color: green;
+        @media (400px <= width <= 900px) {
+          .foo {
+            color: chartreuse;
+          }
         }
-      }
       "#,
-      "\n",
+      indoc! { r#"
+        @media (min-width: 400px) and (max-width: 900px) {
+          .foo {
+            color: #7fff00;
+          }
+        }
+      "#},
+      Browsers {
+        firefox: Some(60 << 16),
+        ..Browsers::default()
+      },
     );
 
     prefix_test(
       r#"
-      @media (width > calc(1px + 1rem)) {
-        .foo { color: yellow; }
-      }
+        @media (400px < width <= 900px) {
+          .foo {
+            color: chartreuse;
+          }
+        }
       "#,
       indoc! { r#"
-        @media (min-width: calc(1.001px + 1rem)) {
+        @media (min-width: 400.001px) and (max-width: 900px) {
           .foo {
-            color: #ff0;
+            color: #7fff00;
           }
         }
       "#},
       Browsers {
-        chrome: Some(85 << 16),
+        firefox: Some(60 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
       r#"
-      @media (width > max(10px, 1rem)) {
-        .foo { color: yellow; }
-      }
+        @media (width > 20em) {
+          .foo {
+            color: chartreuse;
+          }
+        }
       "#,
       indoc! { r#"
-        @media (min-width: calc(max(10px, 1rem) + .001px)) {
+        @media (min-width: 20.001em) {
           .foo {
-            color: #ff0;
+            color: #7fff00;
           }
         }
       "#},
       Browsers {
-        chrome: Some(85 << 16),
+        firefox: Some(60 << 16),
         ..Browsers::default()
       },
     );
-  }
 
-  #[test]
+    prefix_test(
+      r#"
+        @media (height < 50vh) {
+          .foo {
+            color: chartreuse;
+          }
+        }
+      "#,
+      indoc! { r#"
+        @media (max-height: 49.999vh) {
+          .foo {
+            color: #7fff00;
+          }
+        }
+      "#},
+      Browsers {
+        firefox: Some(60 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+        @media (color > 2) {
+          .foo {
+            color: chartreuse;
+          }
+        }
+      "#,
+      indoc! { r#"
+        @media (min-color: 3) {
+          .foo {
+            color: #7fff00;
+          }
+        }
+      "#},
+      Browsers {
+        firefox: Some(60 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+        @media (color-index < 256) {
+          .foo {
+            color: chartreuse;
+          }
+        }
+      "#,
+      indoc! { r#"
+        @media (max-color-index: 255) {
+          .foo {
+            color: #7fff00;
+          }
+        }
+      "#},
+      Browsers {
+        firefox: Some(60 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+        @media (resolution > 192dpi) {
+          .foo {
+            color: chartreuse;
+          }
+        }
+      "#,
+      indoc! { r#"
+        @media (min-resolution: 192.001dpi) {
+          .foo {
+            color: #7fff00;
+          }
+        }
+      "#},
+      Browsers {
+        firefox: Some(60 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    test(
+      r#"
+      @media not all {
+        .a {
+          color: green;
+        }
+      }
+      "#,
+      "\n",
+    );
+
+    prefix_test(
+      r#"
+      @media (width > calc(1px + 1rem)) {
+        .foo { color: yellow; }
+      }
+      "#,
+      indoc! { r#"
+        @media (min-width: calc(1.001px + 1rem)) {
+          .foo {
+            color: #ff0;
+          }
+        }
+      "#},
+      Browsers {
+        chrome: Some(85 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      @media (width > max(10px, 1rem)) {
+        .foo { color: yellow; }
+      }
+      "#,
+      indoc! { r#"
+        @media (min-width: calc(max(10px, 1rem) + 0.001px)) {
+          .foo {
+            color: #ff0;
+          }
+        }
+      "#},
+      Browsers {
+        chrome: Some(85 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
   fn test_merge_layers() {
     test(
       r#"
@@ -7134,151 +8541,328 @@ mod tests {
     );
     test(
       r#"
-      [foo="bar"] {
+      .foo {
         color: red;
       }
       .bar {
-        color: red;
+        background: green;
+      }
+      .foo {
+        width: 10px;
       }
     "#,
       indoc! {r#"
-      [foo="bar"] {
+      .foo {
         color: red;
+        width: 10px;
       }
 
       .bar {
-        color: red;
+        background: green;
       }
     "#},
     );
-
-    prefix_test(
+    test(
       r#"
-      [foo="bar"] {
+      .foo {
         color: red;
       }
       .bar {
+        background: green;
+      }
+      .baz {
         color: red;
       }
     "#,
       indoc! {r#"
-      [foo="bar"] {
+      .foo, .baz {
         color: red;
       }
 
       .bar {
-        color: red;
+        background: green;
       }
     "#},
-      Browsers {
-        ie: Some(6 << 16),
-        ..Browsers::default()
-      },
     );
-
-    prefix_test(
+    test(
       r#"
-      [foo="bar"] {
+      .foo {
         color: red;
       }
       .bar {
-        color: red;
+        width: 20px;
+      }
+      .foo {
+        width: 10px;
       }
     "#,
       indoc! {r#"
-      [foo="bar"], .bar {
+      .foo {
         color: red;
       }
+
+      .bar {
+        width: 20px;
+      }
+
+      .foo {
+        width: 10px;
+      }
     "#},
-      Browsers {
-        ie: Some(10 << 16),
-        ..Browsers::default()
-      },
     );
-
     test(
       r#"
-      .foo:-moz-read-only {
+      .foo {
         color: red;
       }
+      .bar {
+        margin: 0;
+      }
+      .foo {
+        margin-top: 10px;
+      }
     "#,
       indoc! {r#"
-      .foo:-moz-read-only {
+      .foo {
         color: red;
       }
+
+      .bar {
+        margin: 0;
+      }
+
+      .foo {
+        margin-top: 10px;
+      }
     "#},
     );
-
     test(
       r#"
-      .foo:-moz-read-only {
+      .foo {
         color: red;
       }
-
-      .foo:read-only {
-        color: red;
+      .bar {
+        font: 12px/1.5 serif;
+      }
+      .foo {
+        line-height: 2;
       }
     "#,
       indoc! {r#"
-      .foo:-moz-read-only {
+      .foo {
         color: red;
       }
 
-      .foo:read-only {
-        color: red;
+      .bar {
+        font: 12px / 1.5 serif;
+      }
+
+      .foo {
+        line-height: 2;
       }
     "#},
     );
-
-    prefix_test(
+    test(
       r#"
-      .foo:-moz-read-only {
+      .foo {
         color: red;
       }
-
-      .foo:read-only {
-        color: red;
+      @media (hover) {
+        .bar {
+          background: green;
+        }
+      }
+      .foo {
+        width: 10px;
       }
     "#,
       indoc! {r#"
-      .foo:read-only {
+      .foo {
         color: red;
       }
+
+      @media (hover) {
+        .bar {
+          background: green;
+        }
+      }
+
+      .foo {
+        width: 10px;
+      }
     "#},
-      Browsers {
-        firefox: Some(85 << 16),
-        ..Browsers::default()
-      },
+    );
+    minify_test(
+      ".a { color: red } .b { word-wrap: break-word } .a { overflow-wrap: normal }",
+      ".a{color:red}.b{word-wrap:break-word}.a{overflow-wrap:normal}",
+    );
+    minify_test(
+      ".a { color: red } .b { grid-gap: 10px } .a { row-gap: 20px }",
+      ".a{color:red}.b{grid-gap:10px}.a{row-gap:20px}",
     );
 
-    prefix_test(
+    // Only a limited number of earlier rules are checked.
+    let between = |count| {
+      (1..=count)
+        .map(|i| format!(".b{} {{ width: {}px }} ", i, i))
+        .collect::<String>()
+    };
+    let between_minified = |count| (1..=count).map(|i| format!(".b{}{{width:{}px}}", i, i)).collect::<String>();
+    minify_test(
+      &format!(".a {{ color: red }} {}.a {{ height: 10px }}", between(10)),
+      &format!(".a{{color:red;height:10px}}{}", between_minified(10)),
+    );
+    minify_test(
+      &format!(".a {{ color: red }} {}.a {{ height: 10px }}", between(20)),
+      &format!(".a{{color:red}}{}.a{{height:10px}}", between_minified(20)),
+    );
+    test(
       r#"
-      .foo:-moz-read-only {
+      [foo="bar"] {
         color: red;
       }
-
       .bar {
-        color: yellow;
-      }
-
-      .foo:read-only {
         color: red;
       }
     "#,
       indoc! {r#"
-      .foo:-moz-read-only {
+      [foo="bar"] {
         color: red;
       }
 
       .bar {
-        color: #ff0;
-      }
-
-      .foo:read-only {
         color: red;
       }
     "#},
-      Browsers {
+    );
+
+    prefix_test(
+      r#"
+      [foo="bar"] {
+        color: red;
+      }
+      .bar {
+        color: red;
+      }
+    "#,
+      indoc! {r#"
+      [foo="bar"] {
+        color: red;
+      }
+
+      .bar {
+        color: red;
+      }
+    "#},
+      Browsers {
+        ie: Some(6 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      [foo="bar"] {
+        color: red;
+      }
+      .bar {
+        color: red;
+      }
+    "#,
+      indoc! {r#"
+      [foo="bar"], .bar {
+        color: red;
+      }
+    "#},
+      Browsers {
+        ie: Some(10 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    test(
+      r#"
+      .foo:-moz-read-only {
+        color: red;
+      }
+    "#,
+      indoc! {r#"
+      .foo:-moz-read-only {
+        color: red;
+      }
+    "#},
+    );
+
+    test(
+      r#"
+      .foo:-moz-read-only {
+        color: red;
+      }
+
+      .foo:read-only {
+        color: red;
+      }
+    "#,
+      indoc! {r#"
+      .foo:-moz-read-only {
+        color: red;
+      }
+
+      .foo:read-only {
+        color: red;
+      }
+    "#},
+    );
+
+    prefix_test(
+      r#"
+      .foo:-moz-read-only {
+        color: red;
+      }
+
+      .foo:read-only {
+        color: red;
+      }
+    "#,
+      indoc! {r#"
+      .foo:read-only {
+        color: red;
+      }
+    "#},
+      Browsers {
+        firefox: Some(85 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo:-moz-read-only {
+        color: red;
+      }
+
+      .bar {
+        color: yellow;
+      }
+
+      .foo:read-only {
+        color: red;
+      }
+    "#,
+      indoc! {r#"
+      .foo:-moz-read-only {
+        color: red;
+      }
+
+      .bar {
+        color: #ff0;
+      }
+
+      .foo:read-only {
+        color: red;
+      }
+    "#},
+      Browsers {
         firefox: Some(85 << 16),
         ..Browsers::default()
       },
@@ -7774,6 +9358,22 @@ mod tests {
       ".foo{transition:width 1s ease-in 4s}",
     );
     minify_test(".foo { transition: opacity 0s .6s }", ".foo{transition:opacity 0s .6s}");
+    minify_test(".foo { transition: all 2s }", ".foo{transition:2s}");
+    minify_test(".foo { transition: all 0s ease 0s }", ".foo{transition:all}");
+    minify_test(".foo { transition: all ease-in }", ".foo{transition:ease-in}");
+    minify_test(".foo { transition: all 0s 1s }", ".foo{transition:0s 1s}");
+    minify_test(
+      ".foo { transition: opacity 2s, all 1s ease-in }",
+      ".foo{transition:opacity 2s,1s ease-in}",
+    );
+    minify_test(
+      ".foo { transition-property: opacity; transition-duration: 2s; transition-timing-function: ease-in; transition-delay: 1s }",
+      ".foo{transition:opacity 2s ease-in 1s}",
+    );
+    minify_test(
+      ".foo { transition-property: all; transition-duration: 0s; transition-timing-function: ease; transition-delay: 0s }",
+      ".foo{transition:all}",
+    );
     test(
       r#"
       .foo {
@@ -7785,7 +9385,7 @@ mod tests {
     "#,
       indoc! {r#"
       .foo {
-        transition: opacity 90ms ease-in-out .5s;
+        transition: opacity 90ms ease-in-out 0.5s;
       }
     "#},
     );
@@ -7799,7 +9399,7 @@ mod tests {
     "#,
       indoc! {r#"
       .foo {
-        transition: opacity 2s .5s;
+        transition: opacity 2s 0.5s;
       }
     "#},
     );
@@ -7812,7 +9412,7 @@ mod tests {
     "#,
       indoc! {r#"
       .foo {
-        transition: opacity .5s;
+        transition: opacity 0.5s;
         transition-timing-function: var(--ease);
       }
     "#},
@@ -7844,7 +9444,7 @@ mod tests {
     "#,
       indoc! {r#"
       .foo {
-        transition: opacity 2s ease-in-out .5s, color 4s ease-in;
+        transition: opacity 2s ease-in-out 0.5s, color 4s ease-in;
       }
     "#},
     );
@@ -7859,7 +9459,7 @@ mod tests {
     "#,
       indoc! {r#"
       .foo {
-        transition: opacity 2s ease-in-out .5s, color 2s ease-in-out .5s;
+        transition: opacity 2s ease-in-out 0.5s, color 2s ease-in-out 0.5s;
       }
     "#},
     );
@@ -7878,6 +9478,24 @@ mod tests {
       }
     "#},
     );
+    test(
+      r#"
+      .foo {
+        transition-property: opacity;
+        transition-duration: 2s, 4s;
+        transition-timing-function: ease;
+        transition-delay: 0s;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        transition-property: opacity;
+        transition-duration: 2s, 4s;
+        transition-delay: 0s;
+        transition-timing-function: ease;
+      }
+    "#},
+    );
 
     test(
       r#"
@@ -7890,7 +9508,7 @@ mod tests {
     "#,
       indoc! {r#"
       .foo {
-        -webkit-transition: opacity 2s ease-in-out .5s, color 4s ease-in;
+        -webkit-transition: opacity 2s ease-in-out 0.5s, color 4s ease-in;
       }
     "#},
     );
@@ -7914,9 +9532,9 @@ mod tests {
     "#,
       indoc! {r#"
       .foo {
-        -webkit-transition: opacity 2s ease-in-out .5s, color 4s ease-in;
-        -moz-transition: opacity 2s ease-in-out .5s, color 4s ease-in;
-        transition: opacity 2s ease-in-out .5s, color 4s ease-in;
+        -webkit-transition: opacity 2s ease-in-out 0.5s, color 4s ease-in;
+        -moz-transition: opacity 2s ease-in-out 0.5s, color 4s ease-in;
+        transition: opacity 2s ease-in-out 0.5s, color 4s ease-in;
       }
     "#},
     );
@@ -7940,9 +9558,9 @@ mod tests {
     "#,
       indoc! {r#"
       .foo {
-        -webkit-transition: opacity 2s ease-in-out .5s, color 4s ease-in;
-        -moz-transition: opacity 2s ease-in-out .5s, color 4s ease-in;
-        transition: opacity 2s ease-in-out .5s, color 4s ease-in;
+        -webkit-transition: opacity 2s ease-in-out 0.5s, color 4s ease-in;
+        -moz-transition: opacity 2s ease-in-out 0.5s, color 4s ease-in;
+        transition: opacity 2s ease-in-out 0.5s, color 4s ease-in;
       }
     "#},
     );
@@ -7974,10 +9592,10 @@ mod tests {
         transition-duration: 2s, 4s;
         -webkit-transition-timing-function: ease-in-out;
         -moz-transition-timing-function: ease-in-out;
-        -webkit-transition-delay: .5s;
+        -webkit-transition-delay: 0.5s;
         transition-timing-function: ease-in-out, ease-in;
         -moz-transition-delay: 0s;
-        transition-delay: .5s, 0s;
+        transition-delay: 0.5s, 0s;
       }
     "#},
     );
@@ -8009,9 +9627,9 @@ mod tests {
         -moz-transition-duration: 4s;
         -webkit-transition-timing-function: ease-in-out;
         transition-timing-function: ease-in-out, ease-in;
-        -webkit-transition-delay: .5s;
+        -webkit-transition-delay: 0.5s;
         -moz-transition-timing-function: ease-in-out;
-        transition-delay: .5s, 0s;
+        transition-delay: 0.5s, 0s;
         -moz-transition-delay: 0s;
       }
     "#},
@@ -8229,7 +9847,7 @@ mod tests {
         transition: -webkit-border-top-left-radius, border-top-left-radius;
       }
 
-      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+      .foo:lang(ae), .foo:lang(ar), .foo:lang(arc), .foo:lang(bcc), .foo:lang(bqi), .foo:lang(ckb), .foo:lang(dv), .foo:lang(fa), .foo:lang(glk), .foo:lang(he), .foo:lang(ku), .foo:lang(mzn), .foo:lang(nqo), .foo:lang(pnb), .foo:lang(ps), .foo:lang(sd), .foo:lang(ug), .foo:lang(ur), .foo:lang(yi) {
         -webkit-transition: -webkit-border-top-right-radius, border-top-right-radius;
         transition: -webkit-border-top-right-radius, border-top-right-radius;
       }
@@ -8273,9 +9891,9 @@ mod tests {
     "#,
       indoc! {r#"
       .foo {
-        -webkit-transition: background .2s;
-        -moz-transition: background .2s;
-        transition: background .23s;
+        -webkit-transition: background 0.2s;
+        -moz-transition: background 0.2s;
+        transition: background 0.23s;
       }
     "#},
     );
@@ -8290,9 +9908,9 @@ mod tests {
     "#,
       indoc! {r#"
       .foo {
-        -webkit-transition: background .2s;
-        -moz-transition: background .2s;
-        transition: background .23s;
+        -webkit-transition: background 0.2s;
+        -moz-transition: background 0.2s;
+        transition: background 0.23s;
       }
     "#},
       Browsers {
@@ -8491,7 +10109,7 @@ mod tests {
     "#,
       indoc! {r#"
       .foo {
-        animation: 90ms ease-in-out .1s 2 alternate forwards foo;
+        animation: 90ms ease-in-out 0.1s 2 alternate forwards foo;
       }
     "#},
     );
@@ -8510,7 +10128,7 @@ mod tests {
     "#,
       indoc! {r#"
       .foo {
-        animation: 90ms ease-in-out .1s 2 alternate forwards foo, .2s paused bar;
+        animation: 90ms ease-in-out 0.1s 2 alternate forwards foo, 0.2s paused bar;
       }
     "#},
     );
@@ -8523,7 +10141,7 @@ mod tests {
     "#,
       indoc! {r#"
       .foo {
-        animation: .2s ease-in-out bar;
+        animation: 0.2s ease-in-out bar;
       }
     "#},
     );
@@ -8536,7 +10154,7 @@ mod tests {
     "#,
       indoc! {r#"
       .foo {
-        animation: .2s bar;
+        animation: 0.2s bar;
         animation-timing-function: var(--ease);
       }
     "#},
@@ -8556,14 +10174,65 @@ mod tests {
     "#,
       indoc! {r#"
       .foo {
-        animation-name: foo, bar;
-        animation-duration: 90ms;
-        animation-timing-function: ease-in-out;
-        animation-iteration-count: 2;
-        animation-direction: alternate;
+        animation: 90ms ease-in-out 0.1s 2 alternate forwards foo, 90ms ease-in-out 0.1s 2 alternate forwards bar;
+      }
+    "#},
+    );
+    test(
+      r#"
+      .foo {
+        animation-name: foo, bar, baz;
+        animation-duration: 1s, 2s;
+        animation-timing-function: ease;
+        animation-iteration-count: 1;
+        animation-direction: normal;
         animation-play-state: running;
-        animation-delay: .1s;
-        animation-fill-mode: forwards;
+        animation-delay: 0s;
+        animation-fill-mode: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        animation: 1s foo, 2s bar, 1s baz;
+      }
+    "#},
+    );
+    test(
+      r#"
+      .foo {
+        animation-name: foo;
+        animation-duration: 1s, 2s;
+        animation-timing-function: ease;
+        animation-iteration-count: 1;
+        animation-direction: normal;
+        animation-play-state: running;
+        animation-delay: 0s;
+        animation-fill-mode: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        animation-name: foo;
+        animation-duration: 1s, 2s;
+        animation-timing-function: ease;
+        animation-iteration-count: 1;
+        animation-direction: normal;
+        animation-play-state: running;
+        animation-delay: 0s;
+        animation-fill-mode: none;
+      }
+    "#},
+    );
+    test(
+      r#"
+      .foo {
+        animation: foo 1s, bar 2s 500ms;
+        animation-delay: 1s;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        animation: 1s 1s foo, 2s 1s bar;
       }
     "#},
     );
@@ -8582,7 +10251,7 @@ mod tests {
     "#,
       indoc! {r#"
       .foo {
-        -webkit-animation: 90ms ease-in-out .1s 2 alternate forwards foo;
+        -webkit-animation: 90ms ease-in-out 0.1s 2 alternate forwards foo;
       }
     "#},
     );
@@ -8595,7 +10264,7 @@ mod tests {
     "#,
       indoc! {r#"
       .foo {
-        -moz-animation: .2s ease-in-out bar;
+        -moz-animation: 0.2s ease-in-out bar;
       }
     "#},
     );
@@ -8610,8 +10279,8 @@ mod tests {
     "#,
       indoc! {r#"
       .foo {
-        -webkit-animation: .2s ease-in-out bar;
-        -moz-animation: .2s ease-in-out bar;
+        -webkit-animation: 0.2s ease-in-out bar;
+        -moz-animation: 0.2s ease-in-out bar;
       }
     "#},
     );
@@ -8624,9 +10293,9 @@ mod tests {
     "#,
       indoc! {r#"
       .foo {
-        -webkit-animation: .2s ease-in-out bar;
-        -moz-animation: .2s ease-in-out bar;
-        animation: .2s ease-in-out bar;
+        -webkit-animation: 0.2s ease-in-out bar;
+        -moz-animation: 0.2s ease-in-out bar;
+        animation: 0.2s ease-in-out bar;
       }
     "#},
       Browsers {
@@ -8646,7 +10315,7 @@ mod tests {
     "#,
       indoc! {r#"
       .foo {
-        animation: .2s ease-in-out bar;
+        animation: 0.2s ease-in-out bar;
       }
     "#},
       Browsers {
@@ -8863,6 +10532,38 @@ mod tests {
       ".foo{transform:rotate(calc(10deg + var(--test)))}",
       ".foo{transform:rotate(calc(10deg + var(--test)))}",
     );
+    minify_test(
+      ".foo { transform: translateX(0) translateY(20%) scale(1) }",
+      ".foo{transform:translateY(20%)scale(1)}",
+    );
+    minify_test(
+      ".foo { transform: translateX(10%) translateY(20%) }",
+      ".foo{transform:translate(10%,20%)}",
+    );
+    minify_test(
+      ".foo { transform: translateY(20%) translateX(10%) }",
+      ".foo{transform:translate(10%,20%)}",
+    );
+    minify_test(
+      ".foo { transform: translateX(10%) scale(1) translateY(20%) }",
+      ".foo{transform:translate(10%)scale(1)translateY(20%)}",
+    );
+    minify_test(
+      ".foo { transform: translateX(10%) rotate(45deg) translateY(20%) }",
+      ".foo{transform:translate(10%)rotate(45deg)translateY(20%)}",
+    );
+    minify_test(
+      ".foo { transform: scaleX(2) scaleY(3) translateX(10%) }",
+      ".foo{transform:scale(2,3)translate(10%)}",
+    );
+    minify_test(
+      ".foo { transform: scaleX(2) translateX(10%) scaleY(3) }",
+      ".foo{transform:scaleX(2)translate(10%)scaleY(3)}",
+    );
+    minify_test(
+      ".foo { transform: rotate(0) rotate(45deg) translateX(10%) }",
+      ".foo{transform:rotate(0)rotate(45deg)translate(10%)}",
+    );
     minify_test(".foo { transform: scale(calc(10% + 20%))", ".foo{transform:scale(.3)}");
     minify_test(".foo { transform: scale(calc(.1 + .2))", ".foo{transform:scale(.3)}");
 
@@ -8885,6 +10586,8 @@ mod tests {
     minify_test(".foo { rotate: 0 1 0 10deg }", ".foo{rotate:y 10deg}");
     minify_test(".foo { rotate: 1 1 1 10deg }", ".foo{rotate:1 1 1 10deg}");
     minify_test(".foo { rotate: 0 0 1 0deg }", ".foo{rotate:none}");
+    minify_test(".foo { rotate: 0 }", ".foo{rotate:none}");
+    minify_test(".foo { rotate: 0 1 0 0 }", ".foo{rotate:y 0deg}");
     minify_test(".foo { rotate: none }", ".foo{rotate:none}");
     minify_test(".foo { scale: 1 }", ".foo{scale:1}");
     minify_test(".foo { scale: 1 1 }", ".foo{scale:1}");
@@ -8905,9 +10608,9 @@ mod tests {
     "#,
       indoc! {r#"
       .foo {
-        -webkit-transform: scale(.5);
-        -moz-transform: scale(.5);
-        transform: scale(.5);
+        -webkit-transform: scale(0.5);
+        -moz-transform: scale(0.5);
+        transform: scale(0.5);
       }
     "#},
       Browsers {
@@ -8936,21 +10639,191 @@ mod tests {
         ..Browsers::default()
       },
     );
-  }
 
-  #[test]
-  pub fn test_gradients() {
-    minify_test(
-      ".foo { background: linear-gradient(yellow, blue) }",
-      ".foo{background:linear-gradient(#ff0,#00f)}",
+    prefix_test(
+      r#"
+      .foo {
+        translate: 10px 20px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        transform: translate(10px, 20px);
+      }
+    "#},
+      Browsers {
+        safari: Some(13 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { background: linear-gradient(to bottom, yellow, blue); }",
-      ".foo{background:linear-gradient(#ff0,#00f)}",
+    prefix_test(
+      r#"
+      .foo {
+        translate: 10px 20px 30px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        transform: translate3d(10px, 20px, 30px);
+      }
+    "#},
+      Browsers {
+        safari: Some(13 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { background: linear-gradient(180deg, yellow, blue); }",
-      ".foo{background:linear-gradient(#ff0,#00f)}",
+    prefix_test(
+      r#"
+      .foo {
+        rotate: 45deg;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        transform: rotate(45deg);
+      }
+    "#},
+      Browsers {
+        safari: Some(13 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        scale: 2;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        transform: scale(2);
+      }
+    "#},
+      Browsers {
+        safari: Some(13 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        scale: 1 2 3;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        transform: scale3d(1, 2, 3);
+      }
+    "#},
+      Browsers {
+        safari: Some(13 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        scale: 2;
+        rotate: 45deg;
+        translate: 10px 20px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        transform: translate(10px, 20px)rotate(45deg)scale(2);
+      }
+    "#},
+      Browsers {
+        safari: Some(13 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        translate: 10px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-transform: translate(10px);
+        transform: translate(10px);
+      }
+    "#},
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        scale: 2;
+        rotate: 45deg;
+        translate: 10px 20px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        translate: 10px 20px;
+        rotate: 45deg;
+        scale: 2;
+      }
+    "#},
+      Browsers {
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        transform: rotate(45deg);
+        translate: 10px 20px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        transform: translate(10px, 20px)rotate(45deg);
+      }
+    "#},
+      Browsers {
+        safari: Some(13 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        scale: 2;
+        transform: rotate(45deg);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        transform: scale(2)rotate(45deg);
+      }
+    "#},
+      Browsers {
+        safari: Some(13 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  pub fn test_gradients() {
+    minify_test(
+      ".foo { background: linear-gradient(yellow, blue) }",
+      ".foo{background:linear-gradient(#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(to bottom, yellow, blue); }",
+      ".foo{background:linear-gradient(#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(180deg, yellow, blue); }",
+      ".foo{background:linear-gradient(#ff0,#00f)}",
     );
     minify_test(
       ".foo { background: linear-gradient(0.5turn, yellow, blue); }",
@@ -9020,25 +10893,81 @@ mod tests {
       ".foo { background: linear-gradient(0, yellow, blue); }",
       ".foo{background:linear-gradient(#00f,#ff0)}",
     );
+    minify_test(
+      ".foo { background: linear-gradient(red 0%, red 0%, blue 100%); }",
+      ".foo{background:linear-gradient(red,#00f)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(red 0%, blue 100%); }",
+      ".foo{background:linear-gradient(red,#00f)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(red 0%, yellow, blue 100%); }",
+      ".foo{background:linear-gradient(red,#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(red 0%, 30%, blue 100%); }",
+      ".foo{background:linear-gradient(red,30%,#00f)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(red 50%, blue 50%); }",
+      ".foo{background:linear-gradient(red 50%,#00f 50%)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(red 0%, red 50%, blue 50%, blue 100%); }",
+      ".foo{background:linear-gradient(red 0% 50%,#00f 50% 100%)}",
+    );
+    minify_test(
+      ".foo { background: radial-gradient(red 0px, blue 100%); }",
+      ".foo{background:radial-gradient(red,#00f)}",
+    );
+    minify_test(
+      ".foo { background: conic-gradient(red 0deg, blue 100%); }",
+      ".foo{background:conic-gradient(red,#00f)}",
+    );
+    minify_test(
+      ".foo { background: repeating-linear-gradient(red 0px, blue 10px); }",
+      ".foo{background:repeating-linear-gradient(red,#00f 10px)}",
+    );
+    test(
+      ".foo { background: linear-gradient(red 0%, red 0%, blue 100%); }",
+      indoc! {r#"
+      .foo {
+        background: linear-gradient(red 0%, red 0%, #00f 100%);
+      }
+    "#},
+    );
     minify_test(
       ".foo { background: -webkit-linear-gradient(yellow, blue) }",
       ".foo{background:-webkit-linear-gradient(#ff0,#00f)}",
     );
     minify_test(
       ".foo { background: -webkit-linear-gradient(bottom, yellow, blue); }",
-      ".foo{background:-webkit-linear-gradient(#ff0,#00f)}",
+      ".foo{background:-webkit-linear-gradient(#00f,#ff0)}",
     );
     minify_test(
       ".foo { background: -webkit-linear-gradient(top right, red, white, blue) }",
       ".foo{background:-webkit-linear-gradient(top right,red,#fff,#00f)}",
     );
+    minify_test(
+      ".foo { background: -webkit-linear-gradient(left, yellow, blue) }",
+      ".foo{background:-webkit-linear-gradient(left,#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: -webkit-linear-gradient(0deg, yellow, blue) }",
+      ".foo{background:-webkit-linear-gradient(0deg,#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: -webkit-linear-gradient(-90deg, yellow, blue) }",
+      ".foo{background:-webkit-linear-gradient(#ff0,#00f)}",
+    );
     minify_test(
       ".foo { background: -moz-linear-gradient(yellow, blue) }",
       ".foo{background:-moz-linear-gradient(#ff0,#00f)}",
     );
     minify_test(
       ".foo { background: -moz-linear-gradient(bottom, yellow, blue); }",
-      ".foo{background:-moz-linear-gradient(#ff0,#00f)}",
+      ".foo{background:-moz-linear-gradient(#00f,#ff0)}",
     );
     minify_test(
       ".foo { background: -moz-linear-gradient(top right, red, white, blue) }",
@@ -9050,7 +10979,7 @@ mod tests {
     );
     minify_test(
       ".foo { background: -o-linear-gradient(bottom, yellow, blue); }",
-      ".foo{background:-o-linear-gradient(#ff0,#00f)}",
+      ".foo{background:-o-linear-gradient(#00f,#ff0)}",
     );
     minify_test(
       ".foo { background: -o-linear-gradient(top right, red, white, blue) }",
@@ -9311,7 +11240,7 @@ mod tests {
       indoc! {r#"
       .foo {
         background-image: -webkit-gradient(linear, 0 0, 100% 0, from(red), to(#00f));
-        background-image: -webkit-linear-gradient(right, red, #00f);
+        background-image: -webkit-linear-gradient(left, red, #00f);
         background-image: linear-gradient(to right, red, #00f);
       }
       "#},
@@ -9329,7 +11258,7 @@ mod tests {
       indoc! {r#"
       .foo {
         background-image: -webkit-gradient(linear, 0 100%, 0 0, from(red), to(#00f));
-        background-image: -webkit-linear-gradient(top, red, #00f);
+        background-image: -webkit-linear-gradient(bottom, red, #00f);
         background-image: linear-gradient(to top, red, #00f);
       }
       "#},
@@ -9347,7 +11276,7 @@ mod tests {
       indoc! {r#"
       .foo {
         background-image: -webkit-gradient(linear, 100% 0, 0 0, from(red), to(#00f));
-        background-image: -webkit-linear-gradient(left, red, #00f);
+        background-image: -webkit-linear-gradient(right, red, #00f);
         background-image: linear-gradient(to left, red, #00f);
       }
       "#},
@@ -9365,7 +11294,7 @@ mod tests {
       indoc! {r#"
       .foo {
         background-image: -webkit-gradient(linear, 100% 0, 0 100%, from(red), to(#00f));
-        background-image: -webkit-linear-gradient(bottom left, red, #00f);
+        background-image: -webkit-linear-gradient(top right, red, #00f);
         background-image: linear-gradient(to bottom left, red, #00f);
       }
       "#},
@@ -9383,7 +11312,7 @@ mod tests {
       indoc! {r#"
       .foo {
         background-image: -webkit-gradient(linear, 0 100%, 100% 0, from(red), to(#00f));
-        background-image: -webkit-linear-gradient(top right, red, #00f);
+        background-image: -webkit-linear-gradient(bottom left, red, #00f);
         background-image: linear-gradient(to top right, red, #00f);
       }
       "#},
@@ -9401,7 +11330,7 @@ mod tests {
       indoc! {r#"
       .foo {
         background-image: -webkit-gradient(linear, 0 0, 100% 0, from(red), to(#00f));
-        background-image: -webkit-linear-gradient(90deg, red, #00f);
+        background-image: -webkit-linear-gradient(0deg, red, #00f);
         background-image: linear-gradient(90deg, red, #00f);
       }
       "#},
@@ -9427,6 +11356,23 @@ mod tests {
         ..Browsers::default()
       },
     );
+    prefix_test(
+      r#"
+      .foo {
+        background-image: linear-gradient(135deg, red, blue);
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        background-image: -webkit-linear-gradient(-45deg, red, #00f);
+        background-image: linear-gradient(135deg, red, #00f);
+      }
+      "#},
+      Browsers {
+        chrome: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
     prefix_test(
       r#"
       .foo {
@@ -9626,7 +11572,7 @@ mod tests {
       indoc! { r#"
         .foo {
           background: linear-gradient(#ff0f0e, #7773ff);
-          background: linear-gradient(color(display-p3 1 .0000153435 -.00000303562), color(display-p3 .440289 .28452 1.23485));
+          background: linear-gradient(color(display-p3 1 0.0000153435 -0.00000303562), color(display-p3 0.440289 0.28452 1.23485));
           background: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
         }
       "#},
@@ -9713,7 +11659,7 @@ mod tests {
       indoc! { r#"
         .foo {
           background-image: linear-gradient(#ff0f0e, #7773ff);
-          background-image: linear-gradient(color(display-p3 1 .0000153435 -.00000303562), color(display-p3 .440289 .28452 1.23485));
+          background-image: linear-gradient(color(display-p3 1 0.0000153435 -0.00000303562), color(display-p3 0.440289 0.28452 1.23485));
           background-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
         }
       "#},
@@ -9926,65 +11872,190 @@ mod tests {
     );
     minify_test(
       "@font-face {unicode-range: u+????, U+1????, U+10????;}",
-      "@font-face{unicode-range:U+????,U+1????,U+10????}",
+      "@font-face{unicode-range:U+0-1FFFF,U+10????}",
     );
-  }
-
-  #[test]
-  fn test_font_palette_values() {
     minify_test(
-      r#"@font-palette-values --Cooler {
-      font-family: Bixa;
-      base-palette: 1;
-      override-colors: 1 #7EB7E4;
-    }"#,
-      "@font-palette-values --Cooler{font-family:Bixa;base-palette:1;override-colors:1 #7eb7e4}",
+      "@font-face {unicode-range: u+????, U+2????, U+10????;}",
+      "@font-face{unicode-range:U+????,U+2????,U+10????}",
     );
     minify_test(
-      r#"@font-palette-values --Cooler {
-      font-family: Handover Sans;
-      base-palette: 3;
-      override-colors: 1 rgb(43, 12, 9), 3 lime;
-    }"#,
-      "@font-palette-values --Cooler{font-family:Handover Sans;base-palette:3;override-colors:1 #2b0c09,3 #0f0}",
+      "@font-face {unicode-range: U+0-7F, U+80-FF;}",
+      "@font-face{unicode-range:U+??}",
     );
-    minify_test(r#"@font-palette-values --Cooler {
-      font-family: Handover Sans;
-      base-palette: 3;
-      override-colors: 1 rgb(43, 12, 9), 3 var(--highlight);
-    }"#, "@font-palette-values --Cooler{font-family:Handover Sans;base-palette:3;override-colors:1 #2b0c09,3 var(--highlight)}");
+    minify_test(
+      "@font-face {unicode-range: U+0-7F, U+50-FF;}",
+      "@font-face{unicode-range:U+??}",
+    );
+    minify_test(
+      "@font-face {unicode-range: U+4??, U+500-5FF;}",
+      "@font-face{unicode-range:U+400-5FF}",
+    );
+    minify_test(
+      "@font-face {unicode-range: U+4??, U+450-460;}",
+      "@font-face{unicode-range:U+4??}",
+    );
+    minify_test(
+      "@font-face {unicode-range: U+100-1FF, U+A5, U+300-3FF, U+200-2FF;}",
+      "@font-face{unicode-range:U+100-3FF,U+A5}",
+    );
+    minify_test(
+      "@font-face {unicode-range: U+26, U+26;}",
+      "@font-face{unicode-range:U+26}",
+    );
+    minify_test(
+      "@font-face {unicode-range: U+26, U+27;}",
+      "@font-face{unicode-range:U+26-27}",
+    );
+    minify_test(
+      "@font-face {unicode-range: U+26, U+28;}",
+      "@font-face{unicode-range:U+26,U+28}",
+    );
+    minify_test("@font-face {font-display: swap}", "@font-face{font-display:swap}");
+    minify_test(
+      "@font-face {font-display: OPTIONAL}",
+      "@font-face{font-display:optional}",
+    );
+    minify_test("@font-face {font-display: foo}", "@font-face{font-display:foo}");
+    minify_test(
+      "@font-face {font-family: Test; font-weight: 100 900; font-display: fallback; unicode-range: U+0-7F, U+4??}",
+      "@font-face{font-family:Test;font-weight:100 900;font-display:fallback;unicode-range:U+0-7F,U+4??}",
+    );
+
+    let source = r#"
+      @font-face {
+        font-family: Test;
+        src: local(Test), url(test.eot) format("embedded-opentype"), url(test.ttf) format("truetype"), url(test.woff) format("woff"), url(test.woff2) format("woff2"), url(test.svg) format("svg");
+      }
+    "#;
     prefix_test(
-      r#"@font-palette-values --Cooler {
-      font-family: Handover Sans;
-      base-palette: 3;
-      override-colors: 1 rgb(43, 12, 9), 3 lch(50.998% 135.363 338);
-    }"#,
-      indoc! {r#"@font-palette-values --Cooler {
-      font-family: Handover Sans;
-      base-palette: 3;
-      override-colors: 1 #2b0c09, 3 #ee00be;
-      override-colors: 1 #2b0c09, 3 lch(50.998% 135.363 338);
-    }
-    "#},
+      source,
+      indoc! {r#"
+        @font-face {
+          font-family: Test;
+          src: local(Test), url("test.woff2") format("woff2"), url("test.woff") format("woff"), url("test.ttf") format("truetype"), url("test.svg") format("svg");
+        }
+      "#},
       Browsers {
         chrome: Some(90 << 16),
+        safari: Some(12 << 16),
         ..Browsers::default()
       },
     );
     prefix_test(
-      r#"@font-palette-values --Cooler {
-      font-family: Handover Sans;
-      base-palette: 3;
-      override-colors: 1 var(--foo), 3 lch(50.998% 135.363 338);
-    }"#,
-      indoc! {r#"@font-palette-values --Cooler {
-      font-family: Handover Sans;
-      base-palette: 3;
-      override-colors: 1 var(--foo), 3 #ee00be;
-    }
-
-    @supports (color: lab(0% 0 0)) {
-      @font-palette-values --Cooler {
+      source,
+      indoc! {r#"
+        @font-face {
+          font-family: Test;
+          src: local(Test), url("test.woff2") format("woff2"), url("test.woff") format("woff"), url("test.ttf") format("truetype"), url("test.eot") format("embedded-opentype"), url("test.svg") format("svg");
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ie: Some(11 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      source,
+      indoc! {r#"
+        @font-face {
+          font-family: Test;
+          src: local(Test), url("test.woff") format("woff"), url("test.eot") format("embedded-opentype"), url("test.svg") format("svg");
+        }
+      "#},
+      Browsers {
+        ie: Some(9 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      @font-face {
+        font-family: Test;
+        src: url(test.ttf), url(test.woff2) format("woff2"), local(Test), url(test.otf) format("opentype");
+      }
+    "#,
+      indoc! {r#"
+        @font-face {
+          font-family: Test;
+          src: url("test.woff2") format("woff2"), url("test.otf") format("opentype"), local(Test), url("test.ttf");
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+    // Keep unsupported formats if no other url remains.
+    prefix_test(
+      "@font-face { src: local(Test), url(test.woff2) format(\"woff2\") }",
+      indoc! {r#"
+        @font-face {
+          src: local(Test), url("test.woff2") format("woff2");
+        }
+      "#},
+      Browsers {
+        ie: Some(11 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_font_palette_values() {
+    minify_test(
+      r#"@font-palette-values --Cooler {
+      font-family: Bixa;
+      base-palette: 1;
+      override-colors: 1 #7EB7E4;
+    }"#,
+      "@font-palette-values --Cooler{font-family:Bixa;base-palette:1;override-colors:1 #7eb7e4}",
+    );
+    minify_test(
+      r#"@font-palette-values --Cooler {
+      font-family: Handover Sans;
+      base-palette: 3;
+      override-colors: 1 rgb(43, 12, 9), 3 lime;
+    }"#,
+      "@font-palette-values --Cooler{font-family:Handover Sans;base-palette:3;override-colors:1 #2b0c09,3 #0f0}",
+    );
+    minify_test(r#"@font-palette-values --Cooler {
+      font-family: Handover Sans;
+      base-palette: 3;
+      override-colors: 1 rgb(43, 12, 9), 3 var(--highlight);
+    }"#, "@font-palette-values --Cooler{font-family:Handover Sans;base-palette:3;override-colors:1 #2b0c09,3 var(--highlight)}");
+    prefix_test(
+      r#"@font-palette-values --Cooler {
+      font-family: Handover Sans;
+      base-palette: 3;
+      override-colors: 1 rgb(43, 12, 9), 3 lch(50.998% 135.363 338);
+    }"#,
+      indoc! {r#"@font-palette-values --Cooler {
+      font-family: Handover Sans;
+      base-palette: 3;
+      override-colors: 1 #2b0c09, 3 #ee00be;
+      override-colors: 1 #2b0c09, 3 lch(50.998% 135.363 338);
+    }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"@font-palette-values --Cooler {
+      font-family: Handover Sans;
+      base-palette: 3;
+      override-colors: 1 var(--foo), 3 lch(50.998% 135.363 338);
+    }"#,
+      indoc! {r#"@font-palette-values --Cooler {
+      font-family: Handover Sans;
+      base-palette: 3;
+      override-colors: 1 var(--foo), 3 #ee00be;
+    }
+
+    @supports (color: lab(0% 0 0)) {
+      @font-palette-values --Cooler {
         font-family: Handover Sans;
         base-palette: 3;
         override-colors: 1 var(--foo), 3 lab(50.998% 125.506 -50.7078);
@@ -10015,6 +12086,85 @@ mod tests {
     minify_test("@page:first {margin: 0.5cm}", "@page:first{margin:.5cm}");
     minify_test("@page :blank:first {margin: 0.5cm}", "@page:blank:first{margin:.5cm}");
     minify_test("@page toc, index {margin: 0.5cm}", "@page toc,index{margin:.5cm}");
+    minify_test(
+      "@page :first { margin: 1in; @top-center { content: \"Title\" } }",
+      "@page:first{margin:1in;@top-center{content:\"Title\"}}",
+    );
+    minify_test(
+      "@page toc:left { @bottom-right-corner { content: counter(page) } @top-left { content: none } }",
+      "@page toc:left{@bottom-right-corner{content:counter(page)}@top-left{content:none}}",
+    );
+    minify_test(
+      "@page { margin-top: 1in; margin-right: 1in; margin-bottom: 1in; margin-left: 1in; @bottom-center { padding-top: 0; padding-right: 0; padding-bottom: 0; padding-left: 0 } }",
+      "@page{margin:1in;@bottom-center{padding:0}}",
+    );
+    minify_test(
+      "@page { margin: 1in !important; @top-center { color: red } }",
+      "@page{margin:1in!important;@top-center{color:red}}",
+    );
+    test(
+      r#"
+      @page :first {
+        margin: 1in;
+        @top-center {
+          content: "Title";
+        }
+        @bottom-right {
+          content: counter(page);
+        }
+      }
+    "#,
+      indoc! {r#"
+      @page :first {
+        margin: 1in;
+
+        @top-center {
+          content: "Title";
+        }
+
+        @bottom-right {
+          content: counter(page);
+        }
+      }
+    "#},
+    );
+    test(
+      "@page :blank { @top-left-corner { content: none } }",
+      indoc! {r#"
+      @page :blank {
+        @top-left-corner {
+          content: none;
+        }
+      }
+    "#},
+    );
+    error_test(
+      "@page { @foo { color: red } }",
+      ParserError::AtRuleInvalid("foo".into()),
+    );
+    prefix_test(
+      r#"
+      @page {
+        margin-inline-start: 1in;
+      }
+      .foo {
+        color: red;
+      }
+    "#,
+      indoc! {r#"
+      @page {
+        margin-inline-start: 1in;
+      }
+
+      .foo {
+        color: red;
+      }
+    "#},
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
   }
 
   #[test]
@@ -10150,1067 +12300,1020 @@ mod tests {
   }
 
   #[test]
-  fn test_counter_style() {
-    test(
+  fn test_supports_evaluation() {
+    prefix_test(
       r#"
-      @counter-style circled-alpha {
-        system: fixed;
-        symbols: Ⓐ Ⓑ Ⓒ;
-        suffix: " ";
+      @supports (place-content: center) {
+        .foo {
+          place-content: center;
+        }
       }
     "#,
-      indoc! { r#"
-      @counter-style circled-alpha {
-        system: fixed;
-        symbols: Ⓐ Ⓑ Ⓒ;
-        suffix: " ";
+      indoc! {r#"
+      .foo {
+        place-content: center;
       }
     "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
     );
-  }
-
-  #[test]
-  fn test_namespace() {
-    minify_test(
-      "@namespace url(http://toto.example.org);",
-      "@namespace \"http://toto.example.org\";",
-    );
-    minify_test(
-      "@namespace \"http://toto.example.org\";",
-      "@namespace \"http://toto.example.org\";",
-    );
-    minify_test(
-      "@namespace toto \"http://toto.example.org\";",
-      "@namespace toto \"http://toto.example.org\";",
-    );
-    minify_test(
-      "@namespace toto url(http://toto.example.org);",
-      "@namespace toto \"http://toto.example.org\";",
-    );
-
-    test(
+    prefix_test(
       r#"
-      @namespace "http://example.com/foo";
-
-      x {
-        color: red;
+      @supports selector(:focus-visible) {
+        .foo:focus-visible {
+          color: red;
+        }
       }
     "#,
       indoc! {r#"
-      @namespace "http://example.com/foo";
-
-      x {
+      .foo:focus-visible {
         color: red;
       }
     "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
     );
-
-    test(
+    prefix_test(
       r#"
-      @namespace toto "http://toto.example.org";
-
-      toto|x {
-        color: red;
-      }
-
-      [toto|att=val] {
-        color: blue
+      @supports (display: grid) {
+        .foo {
+          color: red;
+        }
       }
     "#,
       indoc! {r#"
-      @namespace toto "http://toto.example.org";
-
-      toto|x {
+      .foo {
         color: red;
       }
-
-      [toto|att="val"] {
-        color: #00f;
-      }
     "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
     );
-
-    test(
+    prefix_test(
       r#"
-      @namespace "http://example.com/foo";
-
-      |x {
-        color: red;
-      }
-
-      [|att=val] {
-        color: blue
+      @supports (display: grid) {
+        .foo {
+          color: red;
+        }
       }
     "#,
       indoc! {r#"
-      @namespace "http://example.com/foo";
-
-      |x {
-        color: red;
-      }
-
-      [att="val"] {
-        color: #00f;
+      @supports (display: grid) {
+        .foo {
+          color: red;
+        }
       }
     "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ie: Some(11 << 16),
+        ..Browsers::default()
+      },
     );
-
-    test(
+    prefix_test(
       r#"
-      @namespace "http://example.com/foo";
-
-      *|x {
-        color: red;
-      }
-
-      [*|att=val] {
-        color: blue
+      @supports (place-content: center) or (foo: bar) {
+        .foo {
+          color: red;
+        }
       }
     "#,
       indoc! {r#"
-      @namespace "http://example.com/foo";
-
-      *|x {
+      .foo {
         color: red;
       }
-
-      [*|att="val"] {
-        color: #00f;
-      }
     "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
     );
-
-    error_test(
-      ".foo { color: red } @namespace \"http://example.com/foo\";",
-      ParserError::UnexpectedNamespaceRule,
-    );
-  }
-
-  #[test]
-  fn test_import() {
-    minify_test("@import url(foo.css);", "@import \"foo.css\";");
-    minify_test("@import \"foo.css\";", "@import \"foo.css\";");
-    minify_test("@import url(foo.css) print;", "@import \"foo.css\" print;");
-    minify_test("@import \"foo.css\" print;", "@import \"foo.css\" print;");
-    minify_test(
-      "@import \"foo.css\" screen and (orientation: landscape);",
-      "@import \"foo.css\" screen and (orientation:landscape);",
-    );
-    minify_test(
-      "@import url(foo.css) supports(display: flex);",
-      "@import \"foo.css\" supports(display: flex);",
-    );
-    minify_test(
-      "@import url(foo.css) supports(display: flex) print;",
-      "@import \"foo.css\" supports(display: flex) print;",
-    );
-    minify_test(
-      "@import url(foo.css) supports(not (display: flex));",
-      "@import \"foo.css\" supports(not (display: flex));",
-    );
-    minify_test(
-      "@import url(foo.css) supports((display: flex));",
-      "@import \"foo.css\" supports(display: flex);",
-    );
-    minify_test("@charset \"UTF-8\"; @import url(foo.css);", "@import \"foo.css\";");
-    minify_test("@layer foo; @import url(foo.css);", "@layer foo;@import \"foo.css\";");
-    error_test(
-      ".foo { color: red } @import url(bar.css);",
-      ParserError::UnexpectedImportRule,
-    );
-    error_test(
-      "@namespace \"http://example.com/foo\"; @import url(bar.css);",
-      ParserError::UnexpectedImportRule,
-    );
-    error_test(
-      "@media print { .foo { color: red }} @import url(bar.css);",
-      ParserError::UnexpectedImportRule,
-    );
-    error_test(
-      "@layer foo; @import url(foo.css); @layer bar; @import url(bar.css)",
-      ParserError::UnexpectedImportRule,
-    );
-  }
-
-  #[test]
-  fn test_prefixes() {
     prefix_test(
       r#"
       .foo {
-        -webkit-transition: opacity 200ms;
-        -moz-transition: opacity 200ms;
-        transition: opacity 200ms;
+        color: red;
       }
-      "#,
+
+      @supports not (place-content: center) {
+        .foo {
+          color: green;
+        }
+      }
+    "#,
       indoc! {r#"
       .foo {
-        transition: opacity .2s;
+        color: red;
       }
-      "#},
+    "#},
       Browsers {
         chrome: Some(95 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
-      ".foo{transition:opacity 200ms}",
-      indoc! {r#"
+      r#"
       .foo {
-        -webkit-transition: opacity .2s;
-        -moz-transition: opacity .2s;
-        transition: opacity .2s;
+        color: red;
       }
-      "#},
-      Browsers {
-        safari: Some(5 << 16),
-        firefox: Some(14 << 16),
-        ..Browsers::default()
-      },
-    );
-  }
 
-  #[test]
-  fn test_display() {
-    minify_test(".foo { display: block }", ".foo{display:block}");
-    minify_test(".foo { display: block flow }", ".foo{display:block}");
-    minify_test(".foo { display: flow-root }", ".foo{display:flow-root}");
-    minify_test(".foo { display: block flow-root }", ".foo{display:flow-root}");
-    minify_test(".foo { display: inline }", ".foo{display:inline}");
-    minify_test(".foo { display: inline flow }", ".foo{display:inline}");
-    minify_test(".foo { display: inline-block }", ".foo{display:inline-block}");
-    minify_test(".foo { display: inline flow-root }", ".foo{display:inline-block}");
-    minify_test(".foo { display: run-in }", ".foo{display:run-in}");
-    minify_test(".foo { display: run-in flow }", ".foo{display:run-in}");
-    minify_test(".foo { display: list-item }", ".foo{display:list-item}");
-    minify_test(".foo { display: block flow list-item }", ".foo{display:list-item}");
-    minify_test(".foo { display: inline list-item }", ".foo{display:inline list-item}");
-    minify_test(
-      ".foo { display: inline flow list-item }",
-      ".foo{display:inline list-item}",
-    );
-    minify_test(".foo { display: flex }", ".foo{display:flex}");
-    minify_test(".foo { display: block flex }", ".foo{display:flex}");
-    minify_test(".foo { display: inline-flex }", ".foo{display:inline-flex}");
-    minify_test(".foo { display: inline flex }", ".foo{display:inline-flex}");
-    minify_test(".foo { display: grid }", ".foo{display:grid}");
-    minify_test(".foo { display: block grid }", ".foo{display:grid}");
-    minify_test(".foo { display: inline-grid }", ".foo{display:inline-grid}");
-    minify_test(".foo { display: inline grid }", ".foo{display:inline-grid}");
-    minify_test(".foo { display: ruby }", ".foo{display:ruby}");
-    minify_test(".foo { display: inline ruby }", ".foo{display:ruby}");
-    minify_test(".foo { display: block ruby }", ".foo{display:block ruby}");
-    minify_test(".foo { display: table }", ".foo{display:table}");
-    minify_test(".foo { display: block table }", ".foo{display:table}");
-    minify_test(".foo { display: inline-table }", ".foo{display:inline-table}");
-    minify_test(".foo { display: inline table }", ".foo{display:inline-table}");
-    minify_test(".foo { display: table-row-group }", ".foo{display:table-row-group}");
-    minify_test(".foo { display: contents }", ".foo{display:contents}");
-    minify_test(".foo { display: none }", ".foo{display:none}");
-    minify_test(".foo { display: -webkit-flex }", ".foo{display:-webkit-flex}");
-    minify_test(".foo { display: -ms-flexbox }", ".foo{display:-ms-flexbox}");
-    minify_test(".foo { display: -webkit-box }", ".foo{display:-webkit-box}");
-    minify_test(".foo { display: -moz-box }", ".foo{display:-moz-box}");
-    minify_test(
-      ".foo { display: -webkit-flex; display: -moz-box; display: flex }",
-      ".foo{display:-webkit-flex;display:-moz-box;display:flex}",
-    );
-    minify_test(
-      ".foo { display: -webkit-flex; display: flex; display: -moz-box }",
-      ".foo{display:-webkit-flex;display:flex;display:-moz-box}",
-    );
-    minify_test(".foo { display: flex; display: grid }", ".foo{display:grid}");
-    minify_test(
-      ".foo { display: -webkit-inline-flex; display: -moz-inline-box; display: inline-flex }",
-      ".foo{display:-webkit-inline-flex;display:-moz-inline-box;display:inline-flex}",
-    );
-    minify_test(
-      ".foo { display: flex; display: var(--grid); }",
-      ".foo{display:flex;display:var(--grid)}",
-    );
-    prefix_test(
-      ".foo{ display: flex }",
-      indoc! {r#"
-      .foo {
-        display: -webkit-box;
-        display: -moz-box;
-        display: -webkit-flex;
-        display: -ms-flexbox;
-        display: flex;
+      @supports (color: lab(0% 0 0)) and (foo: bar) {
+        .foo {
+          color: green;
+        }
       }
-      "#},
-      Browsers {
-        safari: Some(4 << 16),
-        firefox: Some(14 << 16),
-        ie: Some(10 << 16),
-        ..Browsers::default()
-      },
-    );
-    prefix_test(
-      ".foo{ display: flex; display: -webkit-box; }",
+    "#,
       indoc! {r#"
       .foo {
-        display: -webkit-box;
+        color: red;
       }
-      "#},
+    "#},
       Browsers {
-        safari: Some(4 << 16),
-        firefox: Some(14 << 16),
-        ie: Some(10 << 16),
+        chrome: Some(90 << 16),
         ..Browsers::default()
       },
     );
     prefix_test(
-      ".foo{ display: -webkit-box; display: flex; }",
+      r#"
+      @supports (place-content: center) and (foo: bar) {
+        .foo {
+          color: red;
+        }
+      }
+    "#,
       indoc! {r#"
-      .foo {
-        display: -webkit-box;
-        display: -moz-box;
-        display: -webkit-flex;
-        display: -ms-flexbox;
-        display: flex;
+      @supports (place-content: center) and (foo: bar) {
+        .foo {
+          color: red;
+        }
       }
-      "#},
+    "#},
       Browsers {
-        safari: Some(4 << 16),
-        firefox: Some(14 << 16),
-        ie: Some(10 << 16),
+        chrome: Some(95 << 16),
         ..Browsers::default()
       },
     );
     prefix_test(
       r#"
-      .foo {
-        display: -webkit-box;
-        display: -moz-box;
-        display: -webkit-flex;
-        display: -ms-flexbox;
-        display: flex;
+      @supports (color: lab(0% 0 0)) {
+        .foo {
+          color: red;
+        }
       }
-      "#,
+    "#,
       indoc! {r#"
-      .foo {
-        display: flex;
+      @supports (color: lab(0% 0 0)) {
+        .foo {
+          color: red;
+        }
       }
-      "#},
+    "#},
       Browsers {
-        safari: Some(14 << 16),
+        chrome: Some(90 << 16),
+        safari: Some(15 << 16),
         ..Browsers::default()
       },
     );
-    prefix_test(
+    test(
       r#"
-      .foo {
-        display: -webkit-box;
-        display: flex;
-        display: -moz-box;
-        display: -webkit-flex;
-        display: -ms-flexbox;
-      }
-      "#,
-      indoc! {r#"
-      .foo {
-        display: -moz-box;
-        display: -webkit-flex;
-        display: -ms-flexbox;
+      @supports (place-content: center) {
+        .foo {
+          color: red;
+        }
       }
-      "#},
-      Browsers {
-        safari: Some(14 << 16),
-        ..Browsers::default()
-      },
-    );
-    prefix_test(
-      ".foo{ display: inline-flex }",
+    "#,
       indoc! {r#"
-      .foo {
-        display: -webkit-inline-box;
-        display: -moz-inline-box;
-        display: -webkit-inline-flex;
-        display: -ms-inline-flexbox;
-        display: inline-flex;
+      @supports (place-content: center) {
+        .foo {
+          color: red;
+        }
       }
-      "#},
-      Browsers {
-        safari: Some(4 << 16),
-        firefox: Some(14 << 16),
-        ie: Some(10 << 16),
-        ..Browsers::default()
-      },
+    "#},
     );
-    prefix_test(
-      r#"
+  }
+
+  #[test]
+  fn test_minify_warnings() {
+    use std::sync::{Arc, RwLock};
+    let source = r#"
       .foo {
-        display: -webkit-inline-box;
-        display: -moz-inline-box;
-        display: -webkit-inline-flex;
-        display: -ms-inline-flexbox;
-        display: inline-flex;
+        color: red;
       }
-      "#,
-      indoc! {r#"
+
+      @supports (color: lab(40% 56.6 39)) {
+        .foo {
+          color: lab(40% 56.6 39);
+        }
+      }
+    "#;
+    let expected = indoc! {r#"
       .foo {
-        display: inline-flex;
+        color: red;
       }
-      "#},
-      Browsers {
-        safari: Some(14 << 16),
-        ..Browsers::default()
+    "#};
+    let targets = Browsers {
+      safari: Some(12 << 16),
+      ..Browsers::default()
+    };
+
+    let warnings = Some(Arc::new(RwLock::new(Vec::new())));
+    let mut stylesheet = StyleSheet::parse(
+      source,
+      ParserOptions {
+        filename: "test.css".into(),
+        ..ParserOptions::default()
       },
+    )
+    .unwrap();
+    stylesheet
+      .minify(MinifyOptions {
+        targets: Some(targets),
+        warnings: warnings.clone(),
+        ..MinifyOptions::default()
+      })
+      .unwrap();
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        targets: Some(targets),
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(res.code, expected);
+
+    // Collecting warnings does not change the output.
+    prefix_test(source, expected, targets);
+
+    let w = warnings.unwrap();
+    let warnings = w.read().unwrap();
+    assert_eq!(
+      *warnings,
+      vec![Error {
+        kind: MinifyWarningKind::UnsupportedValueRemoved {
+          property: "color".into(),
+          value: "lab(40% 56.6 39)".into(),
+          reason: "the @supports condition never matches the browser targets".into(),
+        },
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 5,
+          column: 7
+        })
+      }]
     );
   }
 
   #[test]
-  fn test_visibility() {
-    minify_test(".foo { visibility: visible }", ".foo{visibility:visible}");
-    minify_test(".foo { visibility: hidden }", ".foo{visibility:hidden}");
-    minify_test(".foo { visibility: collapse }", ".foo{visibility:collapse}");
-    minify_test(".foo { visibility: Visible }", ".foo{visibility:visible}");
+  fn test_has_warnings() {
+    let source = ".foo { color: red }\na:has(> img), h1:has(+ p, .x) { color: red }";
+    assert_eq!(
+      minify_warnings(
+        source,
+        Browsers {
+          safari: Some(14 << 16),
+          ..Browsers::default()
+        }
+      ),
+      vec![Error {
+        kind: MinifyWarningKind::UnsupportedSelector {
+          selector: "a:has(> img), h1:has(+ p, .x)".into(),
+          reason: "the :has() pseudo-class is not supported by the browser targets".into(),
+        },
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 1,
+          column: 1
+        })
+      }]
+    );
+    assert_eq!(
+      minify_warnings(
+        ".foo:not(:has(.bar)) { color: red }",
+        Browsers {
+          chrome: Some(90 << 16),
+          ..Browsers::default()
+        }
+      )
+      .len(),
+      1
+    );
+    assert_eq!(
+      minify_warnings(
+        "li:nth-child(2 of :has(.bar)) { color: red }",
+        Browsers {
+          safari: Some(14 << 16),
+          ..Browsers::default()
+        }
+      )
+      .len(),
+      1
+    );
+    assert_eq!(
+      minify_warnings(
+        source,
+        Browsers {
+          safari: Some(16 << 16),
+          ..Browsers::default()
+        }
+      ),
+      vec![]
+    );
   }
 
   #[test]
-  fn test_text_transform() {
-    minify_test(".foo { text-transform: uppercase }", ".foo{text-transform:uppercase}");
-    minify_test(".foo { text-transform: lowercase }", ".foo{text-transform:lowercase}");
-    minify_test(".foo { text-transform: capitalize }", ".foo{text-transform:capitalize}");
-    minify_test(".foo { text-transform: none }", ".foo{text-transform:none}");
-    minify_test(".foo { text-transform: full-width }", ".foo{text-transform:full-width}");
-    minify_test(
-      ".foo { text-transform: full-size-kana }",
-      ".foo{text-transform:full-size-kana}",
+  fn test_nth_child_of_warnings() {
+    let source = "li:nth-child(2n+1) { color: red }\nli:nth-child(2n+1 of .x) { color: red }";
+    assert_eq!(
+      minify_warnings(
+        source,
+        Browsers {
+          chrome: Some(100 << 16),
+          ..Browsers::default()
+        }
+      ),
+      vec![Error {
+        kind: MinifyWarningKind::UnsupportedSelector {
+          selector: "li:nth-child(2n+1 of .x)".into(),
+          reason: "the `of` clause of :nth-child() is not supported by the browser targets".into(),
+        },
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 1,
+          column: 1
+        })
+      }]
+    );
+    assert_eq!(
+      minify_warnings(
+        ".foo:is(:nth-last-child(odd of .bar)) { color: red }",
+        Browsers {
+          firefox: Some(100 << 16),
+          ..Browsers::default()
+        }
+      )
+      .len(),
+      1
+    );
+    assert_eq!(
+      minify_warnings(
+        source,
+        Browsers {
+          safari: Some(15 << 16),
+          chrome: Some(111 << 16),
+          ..Browsers::default()
+        }
+      ),
+      vec![]
+    );
+  }
+
+  #[test]
+  fn test_counter_style() {
+    test(
+      r#"
+      @counter-style circled-alpha {
+        system: fixed;
+        symbols: Ⓐ Ⓑ Ⓒ;
+        suffix: " ";
+      }
+    "#,
+      indoc! { r#"
+      @counter-style circled-alpha {
+        system: fixed;
+        symbols: Ⓐ Ⓑ Ⓒ;
+        suffix: " ";
+      }
+    "#},
     );
+  }
+
+  #[test]
+  fn test_namespace() {
     minify_test(
-      ".foo { text-transform: uppercase full-width }",
-      ".foo{text-transform:uppercase full-width}",
+      "@namespace url(http://toto.example.org);",
+      "@namespace \"http://toto.example.org\";",
     );
     minify_test(
-      ".foo { text-transform: full-width uppercase }",
-      ".foo{text-transform:uppercase full-width}",
+      "@namespace \"http://toto.example.org\";",
+      "@namespace \"http://toto.example.org\";",
     );
     minify_test(
-      ".foo { text-transform: uppercase full-width full-size-kana }",
-      ".foo{text-transform:uppercase full-width full-size-kana}",
+      "@namespace toto \"http://toto.example.org\";",
+      "@namespace toto \"http://toto.example.org\";",
     );
     minify_test(
-      ".foo { text-transform: full-width uppercase full-size-kana }",
-      ".foo{text-transform:uppercase full-width full-size-kana}",
+      "@namespace toto url(http://toto.example.org);",
+      "@namespace toto \"http://toto.example.org\";",
     );
-  }
 
-  #[test]
-  fn test_whitespace() {
-    minify_test(".foo { white-space: normal }", ".foo{white-space:normal}");
-    minify_test(".foo { white-space: pre }", ".foo{white-space:pre}");
-    minify_test(".foo { white-space: nowrap }", ".foo{white-space:nowrap}");
-    minify_test(".foo { white-space: pre-wrap }", ".foo{white-space:pre-wrap}");
-    minify_test(".foo { white-space: break-spaces }", ".foo{white-space:break-spaces}");
-    minify_test(".foo { white-space: pre-line }", ".foo{white-space:pre-line}");
-    minify_test(".foo { white-space: NoWrAp }", ".foo{white-space:nowrap}");
-  }
+    test(
+      r#"
+      @namespace "http://example.com/foo";
 
-  #[test]
-  fn test_tab_size() {
-    minify_test(".foo { tab-size: 8 }", ".foo{tab-size:8}");
-    minify_test(".foo { tab-size: 4px }", ".foo{tab-size:4px}");
-    minify_test(".foo { -moz-tab-size: 4px }", ".foo{-moz-tab-size:4px}");
-    minify_test(".foo { -o-tab-size: 4px }", ".foo{-o-tab-size:4px}");
-    prefix_test(
-      ".foo{ tab-size: 4 }",
+      x {
+        color: red;
+      }
+    "#,
       indoc! {r#"
-      .foo {
-        -moz-tab-size: 4;
-        -o-tab-size: 4;
-        tab-size: 4;
+      @namespace "http://example.com/foo";
+
+      x {
+        color: red;
       }
-      "#},
-      Browsers {
-        safari: Some(8 << 16),
-        firefox: Some(50 << 16),
-        opera: Some(12 << 16),
-        ..Browsers::default()
-      },
+    "#},
     );
-    prefix_test(
+
+    test(
       r#"
-      .foo {
-        -moz-tab-size: 4;
-        -o-tab-size: 4;
-        tab-size: 4;
+      @namespace toto "http://toto.example.org";
+
+      toto|x {
+        color: red;
       }
-      "#,
-      indoc! {r#"
-      .foo {
-        tab-size: 4;
-      }
-      "#},
-      Browsers {
-        safari: Some(8 << 16),
-        firefox: Some(94 << 16),
-        opera: Some(30 << 16),
-        ..Browsers::default()
-      },
-    );
-  }
-
-  #[test]
-  fn test_word_break() {
-    minify_test(".foo { word-break: normal }", ".foo{word-break:normal}");
-    minify_test(".foo { word-break: keep-all }", ".foo{word-break:keep-all}");
-    minify_test(".foo { word-break: break-all }", ".foo{word-break:break-all}");
-    minify_test(".foo { word-break: break-word }", ".foo{word-break:break-word}");
-  }
 
-  #[test]
-  fn test_line_break() {
-    minify_test(".foo { line-break: auto }", ".foo{line-break:auto}");
-    minify_test(".foo { line-break: Loose }", ".foo{line-break:loose}");
-    minify_test(".foo { line-break: anywhere }", ".foo{line-break:anywhere}");
-  }
+      [toto|att=val] {
+        color: blue
+      }
+    "#,
+      indoc! {r#"
+      @namespace toto "http://toto.example.org";
 
-  #[test]
-  fn test_wrap() {
-    minify_test(".foo { overflow-wrap: nOrmal }", ".foo{overflow-wrap:normal}");
-    minify_test(".foo { overflow-wrap: break-Word }", ".foo{overflow-wrap:break-word}");
-    minify_test(".foo { overflow-wrap: Anywhere }", ".foo{overflow-wrap:anywhere}");
-    minify_test(".foo { word-wrap: Normal }", ".foo{word-wrap:normal}");
-    minify_test(".foo { word-wrap: Break-wOrd }", ".foo{word-wrap:break-word}");
-    minify_test(".foo { word-wrap: Anywhere }", ".foo{word-wrap:anywhere}");
-  }
+      toto|x {
+        color: red;
+      }
 
-  #[test]
-  fn test_hyphens() {
-    minify_test(".foo { hyphens: manual }", ".foo{hyphens:manual}");
-    minify_test(".foo { hyphens: auto }", ".foo{hyphens:auto}");
-    minify_test(".foo { hyphens: none }", ".foo{hyphens:none}");
-    minify_test(".foo { -webkit-hyphens: manual }", ".foo{-webkit-hyphens:manual}");
-    minify_test(".foo { -moz-hyphens: manual }", ".foo{-moz-hyphens:manual}");
-    minify_test(".foo { -ms-hyphens: manual }", ".foo{-ms-hyphens:manual}");
-    prefix_test(
-      ".foo{ hyphens: manual }",
-      indoc! {r#"
-      .foo {
-        -webkit-hyphens: manual;
-        -moz-hyphens: manual;
-        -ms-hyphens: manual;
-        hyphens: manual;
+      [toto|att="val"] {
+        color: #00f;
       }
-      "#},
-      Browsers {
-        safari: Some(14 << 16),
-        firefox: Some(40 << 16),
-        ie: Some(10 << 16),
-        ..Browsers::default()
-      },
+    "#},
     );
-    prefix_test(
+
+    test(
       r#"
-      .foo {
-        -webkit-hyphens: manual;
-        -moz-hyphens: manual;
-        -ms-hyphens: manual;
-        hyphens: manual;
-      }
-      "#,
-      indoc! {r#"
-      .foo {
-        -webkit-hyphens: manual;
-        hyphens: manual;
+      @namespace "http://example.com/foo";
+
+      |x {
+        color: red;
       }
-      "#},
-      Browsers {
-        safari: Some(14 << 16),
-        chrome: Some(88 << 16),
-        firefox: Some(88 << 16),
-        edge: Some(79 << 16),
-        ..Browsers::default()
-      },
-    );
-    prefix_test(
-      r#"
-      .foo {
-        -webkit-hyphens: manual;
-        -moz-hyphens: manual;
-        -ms-hyphens: manual;
-        hyphens: manual;
+
+      [|att=val] {
+        color: blue
       }
-      "#,
+    "#,
       indoc! {r#"
-      .foo {
-        hyphens: manual;
+      @namespace "http://example.com/foo";
+
+      |x {
+        color: red;
       }
-      "#},
-      Browsers {
-        chrome: Some(88 << 16),
-        firefox: Some(88 << 16),
-        edge: Some(79 << 16),
-        ..Browsers::default()
-      },
-    );
-  }
 
-  #[test]
-  fn test_text_align() {
-    minify_test(".foo { text-align: left }", ".foo{text-align:left}");
-    minify_test(".foo { text-align: Left }", ".foo{text-align:left}");
-    minify_test(".foo { text-align: END }", ".foo{text-align:end}");
-    minify_test(".foo { text-align: left }", ".foo{text-align:left}");
+      [att="val"] {
+        color: #00f;
+      }
+    "#},
+    );
 
-    prefix_test(
+    test(
       r#"
-      .foo {
-        text-align: start;
+      @namespace "http://example.com/foo";
+
+      *|x {
+        color: red;
+      }
+
+      [*|att=val] {
+        color: blue
       }
     "#,
       indoc! {r#"
-      .foo:not(:lang(ae)):not(:lang(ar)):not(:lang(arc)):not(:lang(bcc)):not(:lang(bqi)):not(:lang(ckb)):not(:lang(dv)):not(:lang(fa)):not(:lang(glk)):not(:lang(he)):not(:lang(ku)):not(:lang(mzn)):not(:lang(nqo)):not(:lang(pnb)):not(:lang(ps)):not(:lang(sd)):not(:lang(ug)):not(:lang(ur)):not(:lang(yi)) {
-        text-align: left;
+      @namespace "http://example.com/foo";
+
+      *|x {
+        color: red;
       }
 
-      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        text-align: right;
+      [*|att="val"] {
+        color: #00f;
       }
-    "#
-      },
-      Browsers {
-        safari: Some(2 << 16),
-        ..Browsers::default()
-      },
+    "#},
+    );
+
+    minify_test(
+      "@namespace svg url(http://www.w3.org/2000/svg); svg|rect, svg|* > svg|circle.foo { color: red }",
+      "@namespace svg \"http://www.w3.org/2000/svg\";svg|rect,svg|*>svg|circle.foo{color:red}",
+    );
+    minify_test(
+      "@namespace \"http://www.w3.org/2000/svg\"; *|* { color: red }",
+      "@namespace \"http://www.w3.org/2000/svg\";*|*{color:red}",
+    );
+    minify_test("*|* { color: red }", "*|*{color:red}");
+    minify_test("*|x, [*|att] { color: red }", "*|x,[*|att]{color:red}");
+
+    error_test(
+      ".foo { color: red } @namespace \"http://example.com/foo\";",
+      ParserError::UnexpectedNamespaceRule,
+    );
+    error_test(
+      "svg|rect { color: red }",
+      ParserError::SelectorError(SelectorError::ExpectedNamespace("svg".into())),
+    );
+    error_test(
+      "@namespace toto \"http://toto.example.org\"; [svg|att=val] { color: red }",
+      ParserError::SelectorError(SelectorError::ExpectedNamespace("svg".into())),
+    );
+  }
+
+  #[test]
+  fn test_import() {
+    minify_test("@import url(foo.css);", "@import \"foo.css\";");
+    minify_test("@import \"foo.css\";", "@import \"foo.css\";");
+    minify_test("@import url(foo.css) print;", "@import \"foo.css\" print;");
+    minify_test("@import \"foo.css\" print;", "@import \"foo.css\" print;");
+    minify_test(
+      "@import \"foo.css\" screen and (orientation: landscape);",
+      "@import \"foo.css\" screen and (orientation:landscape);",
+    );
+    minify_test(
+      "@import url(foo.css) supports(display: flex);",
+      "@import \"foo.css\" supports(display: flex);",
     );
+    minify_test(
+      "@import url(foo.css) supports(display: flex) print;",
+      "@import \"foo.css\" supports(display: flex) print;",
+    );
+    minify_test(
+      "@import url(foo.css) supports(not (display: flex));",
+      "@import \"foo.css\" supports(not (display: flex));",
+    );
+    minify_test(
+      "@import url(foo.css) supports((display: flex));",
+      "@import \"foo.css\" supports(display: flex);",
+    );
+    minify_test("@charset \"UTF-8\"; @import url(foo.css);", "@import \"foo.css\";");
+    minify_test("@layer foo; @import url(foo.css);", "@layer foo;@import \"foo.css\";");
+    error_test(
+      ".foo { color: red } @import url(bar.css);",
+      ParserError::UnexpectedImportRule,
+    );
+    error_test(
+      "@namespace \"http://example.com/foo\"; @import url(bar.css);",
+      ParserError::UnexpectedImportRule,
+    );
+    error_test(
+      "@media print { .foo { color: red }} @import url(bar.css);",
+      ParserError::UnexpectedImportRule,
+    );
+    error_test(
+      "@layer foo; @import url(foo.css); @layer bar; @import url(bar.css)",
+      ParserError::UnexpectedImportRule,
+    );
+  }
 
+  #[test]
+  fn test_prefixes() {
     prefix_test(
       r#"
       .foo {
-        text-align: end;
+        -webkit-transition: opacity 200ms;
+        -moz-transition: opacity 200ms;
+        transition: opacity 200ms;
       }
-    "#,
+      "#,
       indoc! {r#"
-      .foo:not(:lang(ae)):not(:lang(ar)):not(:lang(arc)):not(:lang(bcc)):not(:lang(bqi)):not(:lang(ckb)):not(:lang(dv)):not(:lang(fa)):not(:lang(glk)):not(:lang(he)):not(:lang(ku)):not(:lang(mzn)):not(:lang(nqo)):not(:lang(pnb)):not(:lang(ps)):not(:lang(sd)):not(:lang(ug)):not(:lang(ur)):not(:lang(yi)) {
-        text-align: right;
-      }
-
-      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        text-align: left;
+      .foo {
+        transition: opacity 0.2s;
       }
-    "#
-      },
+      "#},
       Browsers {
-        safari: Some(2 << 16),
+        chrome: Some(95 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      r#"
-      .foo {
-        text-align: start;
-      }
-    "#,
+      ".foo{transition:opacity 200ms}",
       indoc! {r#"
       .foo {
-        text-align: start;
+        -webkit-transition: opacity 0.2s;
+        -moz-transition: opacity 0.2s;
+        transition: opacity 0.2s;
       }
-    "#
-      },
+      "#},
       Browsers {
-        safari: Some(14 << 16),
+        safari: Some(5 << 16),
+        firefox: Some(14 << 16),
         ..Browsers::default()
       },
     );
+  }
 
+  #[test]
+  fn test_display() {
+    minify_test(".foo { display: block }", ".foo{display:block}");
+    minify_test(".foo { display: block flow }", ".foo{display:block}");
+    minify_test(".foo { display: flow-root }", ".foo{display:flow-root}");
+    minify_test(".foo { display: block flow-root }", ".foo{display:flow-root}");
+    minify_test(".foo { display: inline }", ".foo{display:inline}");
+    minify_test(".foo { display: inline flow }", ".foo{display:inline}");
+    minify_test(".foo { display: inline-block }", ".foo{display:inline-block}");
+    minify_test(".foo { display: inline flow-root }", ".foo{display:inline-block}");
+    minify_test(".foo { display: run-in }", ".foo{display:run-in}");
+    minify_test(".foo { display: run-in flow }", ".foo{display:run-in}");
+    minify_test(".foo { display: list-item }", ".foo{display:list-item}");
+    minify_test(".foo { display: block flow list-item }", ".foo{display:list-item}");
+    minify_test(".foo { display: inline list-item }", ".foo{display:inline list-item}");
+    minify_test(
+      ".foo { display: inline flow list-item }",
+      ".foo{display:inline list-item}",
+    );
+    minify_test(".foo { display: flex }", ".foo{display:flex}");
+    minify_test(".foo { display: block flex }", ".foo{display:flex}");
+    minify_test(".foo { display: inline-flex }", ".foo{display:inline-flex}");
+    minify_test(".foo { display: inline flex }", ".foo{display:inline-flex}");
+    minify_test(".foo { display: grid }", ".foo{display:grid}");
+    minify_test(".foo { display: block grid }", ".foo{display:grid}");
+    minify_test(".foo { display: inline-grid }", ".foo{display:inline-grid}");
+    minify_test(".foo { display: inline grid }", ".foo{display:inline-grid}");
+    minify_test(".foo { display: ruby }", ".foo{display:ruby}");
+    minify_test(".foo { display: inline ruby }", ".foo{display:ruby}");
+    minify_test(".foo { display: block ruby }", ".foo{display:block ruby}");
+    minify_test(".foo { display: table }", ".foo{display:table}");
+    minify_test(".foo { display: block table }", ".foo{display:table}");
+    minify_test(".foo { display: inline-table }", ".foo{display:inline-table}");
+    minify_test(".foo { display: inline table }", ".foo{display:inline-table}");
+    minify_test(".foo { display: table-row-group }", ".foo{display:table-row-group}");
+    minify_test(".foo { display: contents }", ".foo{display:contents}");
+    minify_test(".foo { display: none }", ".foo{display:none}");
+    minify_test(".foo { display: -webkit-flex }", ".foo{display:-webkit-flex}");
+    minify_test(".foo { display: -ms-flexbox }", ".foo{display:-ms-flexbox}");
+    minify_test(".foo { display: -webkit-box }", ".foo{display:-webkit-box}");
+    minify_test(".foo { display: -moz-box }", ".foo{display:-moz-box}");
+    minify_test(
+      ".foo { display: -webkit-flex; display: -moz-box; display: flex }",
+      ".foo{display:-webkit-flex;display:-moz-box;display:flex}",
+    );
+    minify_test(
+      ".foo { display: -webkit-flex; display: flex; display: -moz-box }",
+      ".foo{display:-webkit-flex;display:flex;display:-moz-box}",
+    );
+    minify_test(".foo { display: flex; display: grid }", ".foo{display:grid}");
+    minify_test(
+      ".foo { display: -webkit-inline-flex; display: -moz-inline-box; display: inline-flex }",
+      ".foo{display:-webkit-inline-flex;display:-moz-inline-box;display:inline-flex}",
+    );
+    minify_test(
+      ".foo { display: flex; display: var(--grid); }",
+      ".foo{display:flex;display:var(--grid)}",
+    );
     prefix_test(
-      r#"
-      .foo > .bar {
-        text-align: start;
-      }
-    "#,
+      ".foo{ display: flex }",
       indoc! {r#"
-      .foo > .bar:not(:lang(ae)):not(:lang(ar)):not(:lang(arc)):not(:lang(bcc)):not(:lang(bqi)):not(:lang(ckb)):not(:lang(dv)):not(:lang(fa)):not(:lang(glk)):not(:lang(he)):not(:lang(ku)):not(:lang(mzn)):not(:lang(nqo)):not(:lang(pnb)):not(:lang(ps)):not(:lang(sd)):not(:lang(ug)):not(:lang(ur)):not(:lang(yi)) {
-        text-align: left;
+      .foo {
+        display: -webkit-box;
+        display: -moz-box;
+        display: -webkit-flex;
+        display: -ms-flexbox;
+        display: flex;
       }
-
-      .foo > .bar:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        text-align: right;
+      "#},
+      Browsers {
+        safari: Some(4 << 16),
+        firefox: Some(14 << 16),
+        ie: Some(10 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo{ display: flex; display: -webkit-box; }",
+      indoc! {r#"
+      .foo {
+        display: -webkit-box;
       }
-    "#
+      "#},
+      Browsers {
+        safari: Some(4 << 16),
+        firefox: Some(14 << 16),
+        ie: Some(10 << 16),
+        ..Browsers::default()
       },
+    );
+    prefix_test(
+      ".foo{ display: -webkit-box; display: flex; }",
+      indoc! {r#"
+      .foo {
+        display: -webkit-box;
+        display: -moz-box;
+        display: -webkit-flex;
+        display: -ms-flexbox;
+        display: flex;
+      }
+      "#},
       Browsers {
-        safari: Some(2 << 16),
+        safari: Some(4 << 16),
+        firefox: Some(14 << 16),
+        ie: Some(10 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
       r#"
-      .foo:after {
-        text-align: start;
+      .foo {
+        display: -webkit-box;
+        display: -moz-box;
+        display: -webkit-flex;
+        display: -ms-flexbox;
+        display: flex;
       }
-    "#,
+      "#,
       indoc! {r#"
-      .foo:not(:lang(ae)):not(:lang(ar)):not(:lang(arc)):not(:lang(bcc)):not(:lang(bqi)):not(:lang(ckb)):not(:lang(dv)):not(:lang(fa)):not(:lang(glk)):not(:lang(he)):not(:lang(ku)):not(:lang(mzn)):not(:lang(nqo)):not(:lang(pnb)):not(:lang(ps)):not(:lang(sd)):not(:lang(ug)):not(:lang(ur)):not(:lang(yi)):after {
-        text-align: left;
-      }
-
-      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)):after {
-        text-align: right;
+      .foo {
+        display: flex;
       }
-    "#
-      },
+      "#},
       Browsers {
-        safari: Some(2 << 16),
+        safari: Some(14 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
       r#"
-      .foo:hover {
-        text-align: start;
+      .foo {
+        display: -webkit-box;
+        display: flex;
+        display: -moz-box;
+        display: -webkit-flex;
+        display: -ms-flexbox;
       }
-    "#,
+      "#,
       indoc! {r#"
-      .foo:hover:not(:lang(ae)):not(:lang(ar)):not(:lang(arc)):not(:lang(bcc)):not(:lang(bqi)):not(:lang(ckb)):not(:lang(dv)):not(:lang(fa)):not(:lang(glk)):not(:lang(he)):not(:lang(ku)):not(:lang(mzn)):not(:lang(nqo)):not(:lang(pnb)):not(:lang(ps)):not(:lang(sd)):not(:lang(ug)):not(:lang(ur)):not(:lang(yi)) {
-        text-align: left;
-      }
-
-      .foo:hover:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        text-align: right;
+      .foo {
+        display: -moz-box;
+        display: -webkit-flex;
+        display: -ms-flexbox;
       }
-    "#
-      },
+      "#},
       Browsers {
-        safari: Some(2 << 16),
+        safari: Some(14 << 16),
         ..Browsers::default()
       },
     );
-  }
-
-  #[test]
-  fn test_text_align_last() {
-    minify_test(".foo { text-align-last: left }", ".foo{text-align-last:left}");
-    minify_test(".foo { text-align-last: justify }", ".foo{text-align-last:justify}");
     prefix_test(
-      ".foo{ text-align-last: left }",
+      ".foo{ display: inline-flex }",
       indoc! {r#"
       .foo {
-        -moz-text-align-last: left;
-        text-align-last: left;
+        display: -webkit-inline-box;
+        display: -moz-inline-box;
+        display: -webkit-inline-flex;
+        display: -ms-inline-flexbox;
+        display: inline-flex;
       }
       "#},
       Browsers {
-        firefox: Some(40 << 16),
+        safari: Some(4 << 16),
+        firefox: Some(14 << 16),
+        ie: Some(10 << 16),
         ..Browsers::default()
       },
     );
     prefix_test(
       r#"
       .foo {
-        -moz-text-align-last: left;
-        text-align-last: left;
+        display: -webkit-inline-box;
+        display: -moz-inline-box;
+        display: -webkit-inline-flex;
+        display: -ms-inline-flexbox;
+        display: inline-flex;
       }
       "#,
       indoc! {r#"
       .foo {
-        text-align-last: left;
+        display: inline-flex;
       }
       "#},
       Browsers {
-        firefox: Some(88 << 16),
+        safari: Some(14 << 16),
         ..Browsers::default()
       },
     );
   }
 
   #[test]
-  fn test_text_justify() {
-    minify_test(".foo { text-justify: auto }", ".foo{text-justify:auto}");
-    minify_test(".foo { text-justify: inter-word }", ".foo{text-justify:inter-word}");
-  }
-
-  #[test]
-  fn test_word_spacing() {
-    minify_test(".foo { word-spacing: normal }", ".foo{word-spacing:normal}");
-    minify_test(".foo { word-spacing: 3px }", ".foo{word-spacing:3px}");
-  }
-
-  #[test]
-  fn test_letter_spacing() {
-    minify_test(".foo { letter-spacing: normal }", ".foo{letter-spacing:normal}");
-    minify_test(".foo { letter-spacing: 3px }", ".foo{letter-spacing:3px}");
+  fn test_visibility() {
+    minify_test(".foo { visibility: visible }", ".foo{visibility:visible}");
+    minify_test(".foo { visibility: hidden }", ".foo{visibility:hidden}");
+    minify_test(".foo { visibility: collapse }", ".foo{visibility:collapse}");
+    minify_test(".foo { visibility: Visible }", ".foo{visibility:visible}");
   }
 
   #[test]
-  fn test_text_indent() {
-    minify_test(".foo { text-indent: 20px }", ".foo{text-indent:20px}");
-    minify_test(".foo { text-indent: 10% }", ".foo{text-indent:10%}");
-    minify_test(".foo { text-indent: 3em hanging }", ".foo{text-indent:3em hanging}");
-    minify_test(".foo { text-indent: 3em each-line }", ".foo{text-indent:3em each-line}");
+  fn test_text_transform() {
+    minify_test(".foo { text-transform: uppercase }", ".foo{text-transform:uppercase}");
+    minify_test(".foo { text-transform: lowercase }", ".foo{text-transform:lowercase}");
+    minify_test(".foo { text-transform: capitalize }", ".foo{text-transform:capitalize}");
+    minify_test(".foo { text-transform: none }", ".foo{text-transform:none}");
+    minify_test(".foo { text-transform: full-width }", ".foo{text-transform:full-width}");
     minify_test(
-      ".foo { text-indent: 3em hanging each-line }",
-      ".foo{text-indent:3em hanging each-line}",
+      ".foo { text-transform: full-size-kana }",
+      ".foo{text-transform:full-size-kana}",
     );
     minify_test(
-      ".foo { text-indent: 3em each-line hanging }",
-      ".foo{text-indent:3em hanging each-line}",
+      ".foo { text-transform: uppercase full-width }",
+      ".foo{text-transform:uppercase full-width}",
     );
     minify_test(
-      ".foo { text-indent: each-line 3em hanging }",
-      ".foo{text-indent:3em hanging each-line}",
+      ".foo { text-transform: full-width uppercase }",
+      ".foo{text-transform:uppercase full-width}",
     );
     minify_test(
-      ".foo { text-indent: each-line hanging 3em }",
-      ".foo{text-indent:3em hanging each-line}",
+      ".foo { text-transform: uppercase full-width full-size-kana }",
+      ".foo{text-transform:uppercase full-width full-size-kana}",
+    );
+    minify_test(
+      ".foo { text-transform: full-width uppercase full-size-kana }",
+      ".foo{text-transform:uppercase full-width full-size-kana}",
     );
   }
 
   #[test]
-  fn test_text_decoration() {
-    minify_test(".foo { text-decoration-line: none }", ".foo{text-decoration-line:none}");
-    minify_test(
-      ".foo { text-decoration-line: underline }",
-      ".foo{text-decoration-line:underline}",
-    );
-    minify_test(
-      ".foo { text-decoration-line: overline }",
-      ".foo{text-decoration-line:overline}",
-    );
-    minify_test(
-      ".foo { text-decoration-line: line-through }",
-      ".foo{text-decoration-line:line-through}",
-    );
-    minify_test(
-      ".foo { text-decoration-line: blink }",
-      ".foo{text-decoration-line:blink}",
-    );
-    minify_test(
-      ".foo { text-decoration-line: underline overline }",
-      ".foo{text-decoration-line:underline overline}",
-    );
-    minify_test(
-      ".foo { text-decoration-line: overline underline }",
-      ".foo{text-decoration-line:underline overline}",
-    );
-    minify_test(
-      ".foo { text-decoration-line: overline line-through underline }",
-      ".foo{text-decoration-line:underline overline line-through}",
-    );
-    minify_test(
-      ".foo { text-decoration-line: spelling-error }",
-      ".foo{text-decoration-line:spelling-error}",
-    );
-    minify_test(
-      ".foo { text-decoration-line: grammar-error }",
-      ".foo{text-decoration-line:grammar-error}",
-    );
-    minify_test(
-      ".foo { -webkit-text-decoration-line: overline underline }",
-      ".foo{-webkit-text-decoration-line:underline overline}",
-    );
-    minify_test(
-      ".foo { -moz-text-decoration-line: overline underline }",
-      ".foo{-moz-text-decoration-line:underline overline}",
-    );
-
-    minify_test(
-      ".foo { text-decoration-style: solid }",
-      ".foo{text-decoration-style:solid}",
-    );
-    minify_test(
-      ".foo { text-decoration-style: dotted }",
-      ".foo{text-decoration-style:dotted}",
-    );
-    minify_test(
-      ".foo { -webkit-text-decoration-style: solid }",
-      ".foo{-webkit-text-decoration-style:solid}",
-    );
-
-    minify_test(
-      ".foo { text-decoration-color: yellow }",
-      ".foo{text-decoration-color:#ff0}",
-    );
-    minify_test(
-      ".foo { -webkit-text-decoration-color: yellow }",
-      ".foo{-webkit-text-decoration-color:#ff0}",
-    );
-
-    minify_test(".foo { text-decoration: none }", ".foo{text-decoration:none}");
-    minify_test(
-      ".foo { text-decoration: underline dotted }",
-      ".foo{text-decoration:underline dotted}",
-    );
-    minify_test(
-      ".foo { text-decoration: underline dotted yellow }",
-      ".foo{text-decoration:underline dotted #ff0}",
-    );
-    minify_test(
-      ".foo { text-decoration: yellow dotted underline }",
-      ".foo{text-decoration:underline dotted #ff0}",
-    );
-    minify_test(
-      ".foo { text-decoration: underline overline dotted yellow }",
-      ".foo{text-decoration:underline overline dotted #ff0}",
-    );
-    minify_test(
-      ".foo { -webkit-text-decoration: yellow dotted underline }",
-      ".foo{-webkit-text-decoration:underline dotted #ff0}",
-    );
-    minify_test(
-      ".foo { -moz-text-decoration: yellow dotted underline }",
-      ".foo{-moz-text-decoration:underline dotted #ff0}",
-    );
+  fn test_whitespace() {
+    minify_test(".foo { white-space: normal }", ".foo{white-space:normal}");
+    minify_test(".foo { white-space: pre }", ".foo{white-space:pre}");
+    minify_test(".foo { white-space: nowrap }", ".foo{white-space:nowrap}");
+    minify_test(".foo { white-space: pre-wrap }", ".foo{white-space:pre-wrap}");
+    minify_test(".foo { white-space: break-spaces }", ".foo{white-space:break-spaces}");
+    minify_test(".foo { white-space: pre-line }", ".foo{white-space:pre-line}");
+    minify_test(".foo { white-space: NoWrAp }", ".foo{white-space:nowrap}");
+  }
 
-    test(
-      r#"
-      .foo {
-        text-decoration-line: underline;
-        text-decoration-style: dotted;
-        text-decoration-color: yellow;
-        text-decoration-thickness: 2px;
-      }
-    "#,
+  #[test]
+  fn test_tab_size() {
+    minify_test(".foo { tab-size: 8 }", ".foo{tab-size:8}");
+    minify_test(".foo { tab-size: 4px }", ".foo{tab-size:4px}");
+    minify_test(".foo { -moz-tab-size: 4px }", ".foo{-moz-tab-size:4px}");
+    minify_test(".foo { -o-tab-size: 4px }", ".foo{-o-tab-size:4px}");
+    prefix_test(
+      ".foo{ tab-size: 4 }",
       indoc! {r#"
       .foo {
-        text-decoration: underline 2px dotted #ff0;
+        -moz-tab-size: 4;
+        -o-tab-size: 4;
+        tab-size: 4;
       }
-    "#},
+      "#},
+      Browsers {
+        safari: Some(8 << 16),
+        firefox: Some(50 << 16),
+        opera: Some(12 << 16),
+        ..Browsers::default()
+      },
     );
-
-    test(
+    prefix_test(
       r#"
       .foo {
-        text-decoration: underline;
-        text-decoration-style: dotted;
+        -moz-tab-size: 4;
+        -o-tab-size: 4;
+        tab-size: 4;
       }
-    "#,
+      "#,
       indoc! {r#"
       .foo {
-        text-decoration: underline dotted;
+        tab-size: 4;
       }
-    "#},
+      "#},
+      Browsers {
+        safari: Some(8 << 16),
+        firefox: Some(94 << 16),
+        opera: Some(30 << 16),
+        ..Browsers::default()
+      },
     );
+  }
 
-    test(
-      r#"
-      .foo {
-        text-decoration: underline;
-        text-decoration-style: var(--style);
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        text-decoration: underline;
-        text-decoration-style: var(--style);
-      }
-    "#},
-    );
+  #[test]
+  fn test_word_break() {
+    minify_test(".foo { word-break: normal }", ".foo{word-break:normal}");
+    minify_test(".foo { word-break: keep-all }", ".foo{word-break:keep-all}");
+    minify_test(".foo { word-break: break-all }", ".foo{word-break:break-all}");
+    minify_test(".foo { word-break: break-word }", ".foo{word-break:break-word}");
+  }
 
-    test(
-      r#"
-      .foo {
-        -webkit-text-decoration: underline;
-        -webkit-text-decoration-style: dotted;
-      }
-    "#,
+  #[test]
+  fn test_line_break() {
+    minify_test(".foo { line-break: auto }", ".foo{line-break:auto}");
+    minify_test(".foo { line-break: Loose }", ".foo{line-break:loose}");
+    minify_test(".foo { line-break: anywhere }", ".foo{line-break:anywhere}");
+  }
+
+  #[test]
+  fn test_wrap() {
+    minify_test(".foo { overflow-wrap: nOrmal }", ".foo{overflow-wrap:normal}");
+    minify_test(".foo { overflow-wrap: break-Word }", ".foo{overflow-wrap:break-word}");
+    minify_test(".foo { overflow-wrap: Anywhere }", ".foo{overflow-wrap:anywhere}");
+    minify_test(".foo { word-wrap: Normal }", ".foo{word-wrap:normal}");
+    minify_test(".foo { word-wrap: Break-wOrd }", ".foo{word-wrap:break-word}");
+    minify_test(".foo { word-wrap: Anywhere }", ".foo{word-wrap:anywhere}");
+  }
+
+  #[test]
+  fn test_hyphens() {
+    minify_test(".foo { hyphens: manual }", ".foo{hyphens:manual}");
+    minify_test(".foo { hyphens: auto }", ".foo{hyphens:auto}");
+    minify_test(".foo { hyphens: none }", ".foo{hyphens:none}");
+    minify_test(".foo { -webkit-hyphens: manual }", ".foo{-webkit-hyphens:manual}");
+    minify_test(".foo { -moz-hyphens: manual }", ".foo{-moz-hyphens:manual}");
+    minify_test(".foo { -ms-hyphens: manual }", ".foo{-ms-hyphens:manual}");
+    prefix_test(
+      ".foo{ hyphens: manual }",
       indoc! {r#"
       .foo {
-        -webkit-text-decoration: underline dotted;
+        -webkit-hyphens: manual;
+        -moz-hyphens: manual;
+        -ms-hyphens: manual;
+        hyphens: manual;
       }
-    "#},
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        firefox: Some(40 << 16),
+        ie: Some(10 << 16),
+        ..Browsers::default()
+      },
     );
-
     prefix_test(
       r#"
       .foo {
-        text-decoration: underline dotted;
+        -webkit-hyphens: manual;
+        -moz-hyphens: manual;
+        -ms-hyphens: manual;
+        hyphens: manual;
       }
-    "#,
+      "#,
       indoc! {r#"
       .foo {
-        -webkit-text-decoration: underline dotted;
-        -moz-text-decoration: underline dotted;
-        text-decoration: underline dotted;
+        -webkit-hyphens: manual;
+        hyphens: manual;
       }
-    "#},
+      "#},
       Browsers {
-        safari: Some(8 << 16),
-        firefox: Some(30 << 16),
+        safari: Some(14 << 16),
+        chrome: Some(88 << 16),
+        firefox: Some(88 << 16),
+        edge: Some(79 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
       r#"
       .foo {
-        text-decoration-line: underline;
+        -webkit-hyphens: manual;
+        -moz-hyphens: manual;
+        -ms-hyphens: manual;
+        hyphens: manual;
       }
-    "#,
+      "#,
       indoc! {r#"
       .foo {
-        -webkit-text-decoration-line: underline;
-        -moz-text-decoration-line: underline;
-        text-decoration-line: underline;
+        hyphens: manual;
       }
-    "#},
+      "#},
       Browsers {
-        safari: Some(8 << 16),
-        firefox: Some(30 << 16),
+        chrome: Some(88 << 16),
+        firefox: Some(88 << 16),
+        edge: Some(79 << 16),
         ..Browsers::default()
       },
     );
+  }
+
+  #[test]
+  fn test_text_align() {
+    minify_test(".foo { text-align: left }", ".foo{text-align:left}");
+    minify_test(".foo { text-align: Left }", ".foo{text-align:left}");
+    minify_test(".foo { text-align: END }", ".foo{text-align:end}");
+    minify_test(".foo { text-align: left }", ".foo{text-align:left}");
 
     prefix_test(
       r#"
       .foo {
-        text-decoration-style: dotted;
+        text-align: start;
       }
     "#,
       indoc! {r#"
-      .foo {
-        -webkit-text-decoration-style: dotted;
-        -moz-text-decoration-style: dotted;
-        text-decoration-style: dotted;
+      .foo:not(:lang(ae)):not(:lang(ar)):not(:lang(arc)):not(:lang(bcc)):not(:lang(bqi)):not(:lang(ckb)):not(:lang(dv)):not(:lang(fa)):not(:lang(glk)):not(:lang(he)):not(:lang(ku)):not(:lang(mzn)):not(:lang(nqo)):not(:lang(pnb)):not(:lang(ps)):not(:lang(sd)):not(:lang(ug)):not(:lang(ur)):not(:lang(yi)) {
+        text-align: left;
       }
-    "#},
-      Browsers {
-        safari: Some(8 << 16),
-        firefox: Some(30 << 16),
-        ..Browsers::default()
-      },
-    );
 
-    prefix_test(
-      r#"
-      .foo {
-        text-decoration-color: yellow;
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        -webkit-text-decoration-color: #ff0;
-        -moz-text-decoration-color: #ff0;
-        text-decoration-color: #ff0;
+      .foo:lang(ae), .foo:lang(ar), .foo:lang(arc), .foo:lang(bcc), .foo:lang(bqi), .foo:lang(ckb), .foo:lang(dv), .foo:lang(fa), .foo:lang(glk), .foo:lang(he), .foo:lang(ku), .foo:lang(mzn), .foo:lang(nqo), .foo:lang(pnb), .foo:lang(ps), .foo:lang(sd), .foo:lang(ug), .foo:lang(ur), .foo:lang(yi) {
+        text-align: right;
       }
-    "#},
-      Browsers {
-        safari: Some(8 << 16),
-        firefox: Some(30 << 16),
-        ..Browsers::default()
+    "#
       },
-    );
-
-    prefix_test(
-      r#"
-      .foo {
-        text-decoration: underline;
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        text-decoration: underline;
-      }
-    "#},
       Browsers {
-        safari: Some(8 << 16),
-        firefox: Some(30 << 16),
+        safari: Some(2 << 16),
         ..Browsers::default()
       },
     );
@@ -11218,71 +13321,21 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        -webkit-text-decoration: underline dotted;
-        -moz-text-decoration: underline dotted;
-        text-decoration: underline dotted;
+        text-align: end;
       }
     "#,
       indoc! {r#"
-      .foo {
-        text-decoration: underline dotted;
+      .foo:not(:lang(ae)):not(:lang(ar)):not(:lang(arc)):not(:lang(bcc)):not(:lang(bqi)):not(:lang(ckb)):not(:lang(dv)):not(:lang(fa)):not(:lang(glk)):not(:lang(he)):not(:lang(ku)):not(:lang(mzn)):not(:lang(nqo)):not(:lang(pnb)):not(:lang(ps)):not(:lang(sd)):not(:lang(ug)):not(:lang(ur)):not(:lang(yi)) {
+        text-align: right;
       }
-    "#},
-      Browsers {
-        safari: Some(14 << 16),
-        firefox: Some(45 << 16),
-        ..Browsers::default()
-      },
-    );
 
-    prefix_test(
-      r#"
-      .foo {
-        text-decoration: var(--test);
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        -webkit-text-decoration: var(--test);
-        -moz-text-decoration: var(--test);
-        text-decoration: var(--test);
+      .foo:lang(ae), .foo:lang(ar), .foo:lang(arc), .foo:lang(bcc), .foo:lang(bqi), .foo:lang(ckb), .foo:lang(dv), .foo:lang(fa), .foo:lang(glk), .foo:lang(he), .foo:lang(ku), .foo:lang(mzn), .foo:lang(nqo), .foo:lang(pnb), .foo:lang(ps), .foo:lang(sd), .foo:lang(ug), .foo:lang(ur), .foo:lang(yi) {
+        text-align: left;
       }
-    "#},
-      Browsers {
-        safari: Some(8 << 16),
-        firefox: Some(30 << 16),
-        ..Browsers::default()
+    "#
       },
-    );
-
-    minify_test(
-      ".foo { text-decoration-skip-ink: all }",
-      ".foo{text-decoration-skip-ink:all}",
-    );
-    minify_test(
-      ".foo { -webkit-text-decoration-skip-ink: all }",
-      ".foo{-webkit-text-decoration-skip-ink:all}",
-    );
-
-    prefix_test(
-      r#"
-      .foo {
-        text-decoration: lch(50.998% 135.363 338) underline;
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        -webkit-text-decoration: underline #ee00be;
-        -moz-text-decoration: underline #ee00be;
-        text-decoration: underline #ee00be;
-        -webkit-text-decoration: underline lch(50.998% 135.363 338);
-        -moz-text-decoration: underline lch(50.998% 135.363 338);
-        text-decoration: underline lch(50.998% 135.363 338);
-      }
-    "#},
       Browsers {
-        safari: Some(8 << 16),
-        firefox: Some(30 << 16),
+        safari: Some(2 << 16),
         ..Browsers::default()
       },
     );
@@ -11290,204 +13343,274 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        text-decoration-color: lch(50.998% 135.363 338);
+        text-align: start;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-decoration-color: #ee00be;
-        -moz-text-decoration-color: #ee00be;
-        text-decoration-color: #ee00be;
-        -webkit-text-decoration-color: lch(50.998% 135.363 338);
-        -moz-text-decoration-color: lch(50.998% 135.363 338);
-        text-decoration-color: lch(50.998% 135.363 338);
+        text-align: start;
       }
-    "#},
+    "#
+      },
       Browsers {
-        safari: Some(8 << 16),
-        firefox: Some(30 << 16),
+        safari: Some(14 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      .foo {
-        text-decoration: lch(50.998% 135.363 338) var(--style);
+      .foo > .bar {
+        text-align: start;
       }
     "#,
       indoc! {r#"
-      .foo {
-        text-decoration: #ee00be var(--style);
+      .foo > .bar:not(:lang(ae)):not(:lang(ar)):not(:lang(arc)):not(:lang(bcc)):not(:lang(bqi)):not(:lang(ckb)):not(:lang(dv)):not(:lang(fa)):not(:lang(glk)):not(:lang(he)):not(:lang(ku)):not(:lang(mzn)):not(:lang(nqo)):not(:lang(pnb)):not(:lang(ps)):not(:lang(sd)):not(:lang(ug)):not(:lang(ur)):not(:lang(yi)) {
+        text-align: left;
       }
 
-      @supports (color: lab(0% 0 0)) {
-        .foo {
-          text-decoration: lab(50.998% 125.506 -50.7078) var(--style);
-        }
+      .foo > .bar:lang(ae), .foo > .bar:lang(ar), .foo > .bar:lang(arc), .foo > .bar:lang(bcc), .foo > .bar:lang(bqi), .foo > .bar:lang(ckb), .foo > .bar:lang(dv), .foo > .bar:lang(fa), .foo > .bar:lang(glk), .foo > .bar:lang(he), .foo > .bar:lang(ku), .foo > .bar:lang(mzn), .foo > .bar:lang(nqo), .foo > .bar:lang(pnb), .foo > .bar:lang(ps), .foo > .bar:lang(sd), .foo > .bar:lang(ug), .foo > .bar:lang(ur), .foo > .bar:lang(yi) {
+        text-align: right;
       }
-    "#},
+    "#
+      },
       Browsers {
-        chrome: Some(90 << 16),
+        safari: Some(2 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      .foo {
-        text-decoration: underline 10px;
+      .foo:after {
+        text-align: start;
       }
     "#,
       indoc! {r#"
-      .foo {
-        text-decoration: underline;
-        text-decoration-thickness: 10px;
+      .foo:not(:lang(ae)):not(:lang(ar)):not(:lang(arc)):not(:lang(bcc)):not(:lang(bqi)):not(:lang(ckb)):not(:lang(dv)):not(:lang(fa)):not(:lang(glk)):not(:lang(he)):not(:lang(ku)):not(:lang(mzn)):not(:lang(nqo)):not(:lang(pnb)):not(:lang(ps)):not(:lang(sd)):not(:lang(ug)):not(:lang(ur)):not(:lang(yi)):after {
+        text-align: left;
       }
-    "#},
-      Browsers {
-        safari: Some(15 << 16),
-        ..Browsers::default()
-      },
-    );
 
-    prefix_test(
-      r#"
-      .foo {
-        text-decoration: underline 10px;
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        text-decoration: underline 10px;
+      .foo:lang(ae):after, .foo:lang(ar):after, .foo:lang(arc):after, .foo:lang(bcc):after, .foo:lang(bqi):after, .foo:lang(ckb):after, .foo:lang(dv):after, .foo:lang(fa):after, .foo:lang(glk):after, .foo:lang(he):after, .foo:lang(ku):after, .foo:lang(mzn):after, .foo:lang(nqo):after, .foo:lang(pnb):after, .foo:lang(ps):after, .foo:lang(sd):after, .foo:lang(ug):after, .foo:lang(ur):after, .foo:lang(yi):after {
+        text-align: right;
       }
-    "#},
+    "#
+      },
       Browsers {
-        chrome: Some(90 << 16),
+        safari: Some(2 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      .foo {
-        text-decoration: underline 10%;
+      .foo:hover {
+        text-align: start;
       }
     "#,
       indoc! {r#"
-      .foo {
-        text-decoration: underline;
-        text-decoration-thickness: calc(1em / 10);
+      .foo:hover:not(:lang(ae)):not(:lang(ar)):not(:lang(arc)):not(:lang(bcc)):not(:lang(bqi)):not(:lang(ckb)):not(:lang(dv)):not(:lang(fa)):not(:lang(glk)):not(:lang(he)):not(:lang(ku)):not(:lang(mzn)):not(:lang(nqo)):not(:lang(pnb)):not(:lang(ps)):not(:lang(sd)):not(:lang(ug)):not(:lang(ur)):not(:lang(yi)) {
+        text-align: left;
       }
-    "#},
-      Browsers {
-        safari: Some(12 << 16),
-        ..Browsers::default()
-      },
-    );
 
-    prefix_test(
-      r#"
-      .foo {
-        text-decoration: underline 10%;
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        text-decoration: underline 10%;
+      .foo:hover:lang(ae), .foo:hover:lang(ar), .foo:hover:lang(arc), .foo:hover:lang(bcc), .foo:hover:lang(bqi), .foo:hover:lang(ckb), .foo:hover:lang(dv), .foo:hover:lang(fa), .foo:hover:lang(glk), .foo:hover:lang(he), .foo:hover:lang(ku), .foo:hover:lang(mzn), .foo:hover:lang(nqo), .foo:hover:lang(pnb), .foo:hover:lang(ps), .foo:hover:lang(sd), .foo:hover:lang(ug), .foo:hover:lang(ur), .foo:hover:lang(yi) {
+        text-align: right;
       }
-    "#},
+    "#
+      },
       Browsers {
-        firefox: Some(89 << 16),
+        safari: Some(2 << 16),
         ..Browsers::default()
       },
     );
+  }
 
+  #[test]
+  fn test_text_align_last() {
+    minify_test(".foo { text-align-last: left }", ".foo{text-align-last:left}");
+    minify_test(".foo { text-align-last: justify }", ".foo{text-align-last:justify}");
     prefix_test(
-      r#"
-      .foo {
-        text-decoration-thickness: 10%;
-      }
-    "#,
+      ".foo{ text-align-last: left }",
       indoc! {r#"
       .foo {
-        text-decoration-thickness: calc(1em / 10);
+        -moz-text-align-last: left;
+        text-align-last: left;
       }
-    "#},
+      "#},
       Browsers {
-        safari: Some(12 << 16),
+        firefox: Some(40 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
       r#"
       .foo {
-        text-decoration-thickness: 10%;
+        -moz-text-align-last: left;
+        text-align-last: left;
       }
-    "#,
+      "#,
       indoc! {r#"
       .foo {
-        text-decoration-thickness: 10%;
+        text-align-last: left;
       }
-    "#},
+      "#},
       Browsers {
-        firefox: Some(89 << 16),
+        firefox: Some(88 << 16),
         ..Browsers::default()
       },
     );
   }
 
   #[test]
-  fn test_text_emphasis() {
-    minify_test(".foo { text-emphasis-style: none }", ".foo{text-emphasis-style:none}");
-    minify_test(
-      ".foo { text-emphasis-style: filled }",
-      ".foo{text-emphasis-style:filled}",
-    );
-    minify_test(".foo { text-emphasis-style: open }", ".foo{text-emphasis-style:open}");
-    minify_test(".foo { text-emphasis-style: dot }", ".foo{text-emphasis-style:dot}");
-    minify_test(
-      ".foo { text-emphasis-style: filled dot }",
-      ".foo{text-emphasis-style:dot}",
-    );
-    minify_test(
-      ".foo { text-emphasis-style: dot filled }",
-      ".foo{text-emphasis-style:dot}",
-    );
-    minify_test(
-      ".foo { text-emphasis-style: open dot }",
-      ".foo{text-emphasis-style:open dot}",
-    );
-    minify_test(
-      ".foo { text-emphasis-style: dot open }",
-      ".foo{text-emphasis-style:open dot}",
-    );
-    minify_test(".foo { text-emphasis-style: \"x\" }", ".foo{text-emphasis-style:\"x\"}");
+  fn test_text_justify() {
+    minify_test(".foo { text-justify: auto }", ".foo{text-justify:auto}");
+    minify_test(".foo { text-justify: inter-word }", ".foo{text-justify:inter-word}");
+  }
 
-    minify_test(".foo { text-emphasis-color: yellow }", ".foo{text-emphasis-color:#ff0}");
+  #[test]
+  fn test_word_spacing() {
+    minify_test(".foo { word-spacing: normal }", ".foo{word-spacing:normal}");
+    minify_test(".foo { word-spacing: 3px }", ".foo{word-spacing:3px}");
+  }
 
-    minify_test(".foo { text-emphasis: none }", ".foo{text-emphasis:none}");
-    minify_test(".foo { text-emphasis: filled }", ".foo{text-emphasis:filled}");
+  #[test]
+  fn test_letter_spacing() {
+    minify_test(".foo { letter-spacing: normal }", ".foo{letter-spacing:normal}");
+    minify_test(".foo { letter-spacing: 3px }", ".foo{letter-spacing:3px}");
+  }
+
+  #[test]
+  fn test_text_indent() {
+    minify_test(".foo { text-indent: 20px }", ".foo{text-indent:20px}");
+    minify_test(".foo { text-indent: 10% }", ".foo{text-indent:10%}");
+    minify_test(".foo { text-indent: 3em hanging }", ".foo{text-indent:3em hanging}");
+    minify_test(".foo { text-indent: 3em each-line }", ".foo{text-indent:3em each-line}");
     minify_test(
-      ".foo { text-emphasis: filled yellow }",
-      ".foo{text-emphasis:filled #ff0}",
+      ".foo { text-indent: 3em hanging each-line }",
+      ".foo{text-indent:3em hanging each-line}",
     );
     minify_test(
-      ".foo { text-emphasis: dot filled yellow }",
-      ".foo{text-emphasis:dot #ff0}",
+      ".foo { text-indent: 3em each-line hanging }",
+      ".foo{text-indent:3em hanging each-line}",
+    );
+    minify_test(
+      ".foo { text-indent: each-line 3em hanging }",
+      ".foo{text-indent:3em hanging each-line}",
+    );
+    minify_test(
+      ".foo { text-indent: each-line hanging 3em }",
+      ".foo{text-indent:3em hanging each-line}",
+    );
+  }
+
+  #[test]
+  fn test_text_decoration() {
+    minify_test(".foo { text-decoration-line: none }", ".foo{text-decoration-line:none}");
+    minify_test(
+      ".foo { text-decoration-line: underline }",
+      ".foo{text-decoration-line:underline}",
+    );
+    minify_test(
+      ".foo { text-decoration-line: overline }",
+      ".foo{text-decoration-line:overline}",
+    );
+    minify_test(
+      ".foo { text-decoration-line: line-through }",
+      ".foo{text-decoration-line:line-through}",
+    );
+    minify_test(
+      ".foo { text-decoration-line: blink }",
+      ".foo{text-decoration-line:blink}",
+    );
+    minify_test(
+      ".foo { text-decoration-line: underline overline }",
+      ".foo{text-decoration-line:underline overline}",
+    );
+    minify_test(
+      ".foo { text-decoration-line: overline underline }",
+      ".foo{text-decoration-line:underline overline}",
+    );
+    minify_test(
+      ".foo { text-decoration-line: overline line-through underline }",
+      ".foo{text-decoration-line:underline overline line-through}",
+    );
+    minify_test(
+      ".foo { text-decoration-line: spelling-error }",
+      ".foo{text-decoration-line:spelling-error}",
+    );
+    minify_test(
+      ".foo { text-decoration-line: grammar-error }",
+      ".foo{text-decoration-line:grammar-error}",
+    );
+    minify_test(
+      ".foo { -webkit-text-decoration-line: overline underline }",
+      ".foo{-webkit-text-decoration-line:underline overline}",
+    );
+    minify_test(
+      ".foo { -moz-text-decoration-line: overline underline }",
+      ".foo{-moz-text-decoration-line:underline overline}",
+    );
+
+    minify_test(
+      ".foo { text-decoration-style: solid }",
+      ".foo{text-decoration-style:solid}",
+    );
+    minify_test(
+      ".foo { text-decoration-style: dotted }",
+      ".foo{text-decoration-style:dotted}",
+    );
+    minify_test(
+      ".foo { -webkit-text-decoration-style: solid }",
+      ".foo{-webkit-text-decoration-style:solid}",
+    );
+
+    minify_test(
+      ".foo { text-decoration-color: yellow }",
+      ".foo{text-decoration-color:#ff0}",
+    );
+    minify_test(
+      ".foo { -webkit-text-decoration-color: yellow }",
+      ".foo{-webkit-text-decoration-color:#ff0}",
+    );
+
+    minify_test(".foo { text-decoration: none }", ".foo{text-decoration:none}");
+    minify_test(
+      ".foo { text-decoration: underline dotted }",
+      ".foo{text-decoration:underline dotted}",
+    );
+    minify_test(
+      ".foo { text-decoration: underline dotted yellow }",
+      ".foo{text-decoration:underline dotted #ff0}",
+    );
+    minify_test(
+      ".foo { text-decoration: yellow dotted underline }",
+      ".foo{text-decoration:underline dotted #ff0}",
+    );
+    minify_test(
+      ".foo { text-decoration: underline overline dotted yellow }",
+      ".foo{text-decoration:underline overline dotted #ff0}",
+    );
+    minify_test(
+      ".foo { -webkit-text-decoration: yellow dotted underline }",
+      ".foo{-webkit-text-decoration:underline dotted #ff0}",
+    );
+    minify_test(
+      ".foo { -moz-text-decoration: yellow dotted underline }",
+      ".foo{-moz-text-decoration:underline dotted #ff0}",
     );
 
     test(
       r#"
       .foo {
-        text-emphasis-style: filled;
-        text-emphasis-color: yellow;
+        text-decoration-line: underline;
+        text-decoration-style: dotted;
+        text-decoration-color: yellow;
+        text-decoration-thickness: 2px;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-emphasis: filled #ff0;
+        text-decoration: underline 2px dotted #ff0;
       }
     "#},
     );
@@ -11495,13 +13618,13 @@ mod tests {
     test(
       r#"
       .foo {
-        text-emphasis: filled red;
-        text-emphasis-color: yellow;
+        text-decoration: underline;
+        text-decoration-style: dotted;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-emphasis: filled #ff0;
+        text-decoration: underline dotted;
       }
     "#},
     );
@@ -11509,90 +13632,68 @@ mod tests {
     test(
       r#"
       .foo {
-        text-emphasis: filled yellow;
-        text-emphasis-color: var(--color);
+        text-decoration: underline;
+        text-decoration-style: var(--style);
       }
     "#,
       indoc! {r#"
       .foo {
-        text-emphasis: filled #ff0;
-        text-emphasis-color: var(--color);
+        text-decoration: underline;
+        text-decoration-style: var(--style);
       }
     "#},
     );
 
-    prefix_test(
+    test(
       r#"
       .foo {
-        text-emphasis-style: filled;
+        -webkit-text-decoration: underline;
+        -webkit-text-decoration-style: dotted;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-emphasis-style: filled;
-        text-emphasis-style: filled;
+        -webkit-text-decoration: underline dotted;
       }
     "#},
-      Browsers {
-        safari: Some(10 << 16),
-        chrome: Some(30 << 16),
-        firefox: Some(45 << 16),
-        ..Browsers::default()
-      },
     );
 
     prefix_test(
       r#"
       .foo {
-        -webkit-text-emphasis-style: filled;
-        text-emphasis-style: filled;
+        text-decoration: underline dotted;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-emphasis-style: filled;
+        -webkit-text-decoration: underline dotted;
+        -moz-text-decoration: underline dotted;
+        text-decoration: underline dotted;
       }
     "#},
       Browsers {
-        safari: Some(10 << 16),
-        firefox: Some(45 << 16),
+        safari: Some(8 << 16),
+        firefox: Some(30 << 16),
         ..Browsers::default()
       },
     );
 
-    minify_test(
-      ".foo { text-emphasis-position: over }",
-      ".foo{text-emphasis-position:over}",
-    );
-    minify_test(
-      ".foo { text-emphasis-position: under }",
-      ".foo{text-emphasis-position:under}",
-    );
-    minify_test(
-      ".foo { text-emphasis-position: over right }",
-      ".foo{text-emphasis-position:over}",
-    );
-    minify_test(
-      ".foo { text-emphasis-position: over left }",
-      ".foo{text-emphasis-position:over left}",
-    );
-
     prefix_test(
       r#"
       .foo {
-        text-emphasis-position: over;
+        text-decoration-line: underline;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-emphasis-position: over;
-        text-emphasis-position: over;
+        -webkit-text-decoration-line: underline;
+        -moz-text-decoration-line: underline;
+        text-decoration-line: underline;
       }
     "#},
       Browsers {
-        safari: Some(10 << 16),
-        chrome: Some(30 << 16),
-        firefox: Some(45 << 16),
+        safari: Some(8 << 16),
+        firefox: Some(30 << 16),
         ..Browsers::default()
       },
     );
@@ -11600,18 +13701,19 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        text-emphasis-position: over left;
+        text-decoration-style: dotted;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-emphasis-position: over left;
+        -webkit-text-decoration-style: dotted;
+        -moz-text-decoration-style: dotted;
+        text-decoration-style: dotted;
       }
     "#},
       Browsers {
-        safari: Some(10 << 16),
-        chrome: Some(30 << 16),
-        firefox: Some(45 << 16),
+        safari: Some(8 << 16),
+        firefox: Some(30 << 16),
         ..Browsers::default()
       },
     );
@@ -11619,19 +13721,19 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        text-emphasis-position: var(--test);
+        text-decoration-color: yellow;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-emphasis-position: var(--test);
-        text-emphasis-position: var(--test);
+        -webkit-text-decoration-color: #ff0;
+        -moz-text-decoration-color: #ff0;
+        text-decoration-color: #ff0;
       }
     "#},
       Browsers {
-        safari: Some(10 << 16),
-        chrome: Some(30 << 16),
-        firefox: Some(45 << 16),
+        safari: Some(8 << 16),
+        firefox: Some(30 << 16),
         ..Browsers::default()
       },
     );
@@ -11639,20 +13741,17 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        text-emphasis: filled lch(50.998% 135.363 338);
+        text-decoration: underline;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-emphasis: filled #ee00be;
-        text-emphasis: filled #ee00be;
-        -webkit-text-emphasis: filled lch(50.998% 135.363 338);
-        text-emphasis: filled lch(50.998% 135.363 338);
+        text-decoration: underline;
       }
     "#},
       Browsers {
-        chrome: Some(25 << 16),
-        firefox: Some(48 << 16),
+        safari: Some(8 << 16),
+        firefox: Some(30 << 16),
         ..Browsers::default()
       },
     );
@@ -11660,20 +13759,19 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        text-emphasis-color: lch(50.998% 135.363 338);
+        -webkit-text-decoration: underline dotted;
+        -moz-text-decoration: underline dotted;
+        text-decoration: underline dotted;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-emphasis-color: #ee00be;
-        text-emphasis-color: #ee00be;
-        -webkit-text-emphasis-color: lch(50.998% 135.363 338);
-        text-emphasis-color: lch(50.998% 135.363 338);
+        text-decoration: underline dotted;
       }
     "#},
       Browsers {
-        chrome: Some(25 << 16),
-        firefox: Some(48 << 16),
+        safari: Some(14 << 16),
+        firefox: Some(45 << 16),
         ..Browsers::default()
       },
     );
@@ -11681,126 +13779,146 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        text-emphasis: lch(50.998% 135.363 338) var(--style);
+        text-decoration: var(--test);
       }
     "#,
       indoc! {r#"
       .foo {
-        text-emphasis: #ee00be var(--style);
-      }
-
-      @supports (color: lab(0% 0 0)) {
-        .foo {
-          text-emphasis: lab(50.998% 125.506 -50.7078) var(--style);
-        }
+        -webkit-text-decoration: var(--test);
+        -moz-text-decoration: var(--test);
+        text-decoration: var(--test);
       }
     "#},
       Browsers {
         safari: Some(8 << 16),
+        firefox: Some(30 << 16),
         ..Browsers::default()
       },
     );
-  }
 
-  #[test]
-  fn test_text_shadow() {
-    minify_test(
-      ".foo { text-shadow: 1px 1px 2px yellow; }",
-      ".foo{text-shadow:1px 1px 2px #ff0}",
-    );
-    minify_test(
-      ".foo { text-shadow: 1px 1px 2px 3px yellow; }",
-      ".foo{text-shadow:1px 1px 2px 3px #ff0}",
-    );
     minify_test(
-      ".foo { text-shadow: 1px 1px 0 yellow; }",
-      ".foo{text-shadow:1px 1px #ff0}",
+      ".foo { text-decoration: underline wavy red 2px }",
+      ".foo{text-decoration:underline 2px wavy red}",
     );
     minify_test(
-      ".foo { text-shadow: 1px 1px yellow; }",
-      ".foo{text-shadow:1px 1px #ff0}",
+      ".foo { text-decoration: from-font underline }",
+      ".foo{text-decoration:underline from-font}",
     );
+    minify_test(".foo { text-decoration: none }", ".foo{text-decoration:none}");
+    minify_test(".foo { text-decoration: wavy }", ".foo{text-decoration:wavy}");
+    minify_test(".foo { text-decoration: none red }", ".foo{text-decoration:red}");
     minify_test(
-      ".foo { text-shadow: 1px 1px yellow, 2px 3px red; }",
-      ".foo{text-shadow:1px 1px #ff0,2px 3px red}",
+      ".foo { text-decoration: solid currentColor auto underline }",
+      ".foo{text-decoration:underline}",
     );
 
     prefix_test(
-      ".foo { text-shadow: 12px 12px lab(40% 56.6 39) }",
-      indoc! { r#"
-        .foo {
-          text-shadow: 12px 12px #b32323;
-          text-shadow: 12px 12px lab(40% 56.6 39);
-        }
-      "#},
+      r#"
+      .foo {
+        text-decoration: underline wavy red 2px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-text-decoration: underline wavy red;
+        text-decoration: underline wavy red;
+        text-decoration-thickness: 2px;
+      }
+    "#},
       Browsers {
-        chrome: Some(4 << 16),
+        safari: Some(8 << 16),
         ..Browsers::default()
       },
     );
 
+    minify_test(
+      ".foo { text-decoration-skip-ink: all }",
+      ".foo{text-decoration-skip-ink:all}",
+    );
+    minify_test(
+      ".foo { -webkit-text-decoration-skip-ink: all }",
+      ".foo{-webkit-text-decoration-skip-ink:all}",
+    );
+
     prefix_test(
-      ".foo { text-shadow: 12px 12px lab(40% 56.6 39) }",
-      indoc! { r#"
-        .foo {
-          text-shadow: 12px 12px #b32323;
-          text-shadow: 12px 12px color(display-p3 .643308 .192455 .167712);
-          text-shadow: 12px 12px lab(40% 56.6 39);
-        }
-      "#},
+      r#"
+      .foo {
+        text-decoration: lch(50.998% 135.363 338) underline;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-text-decoration: underline #ee00be;
+        -moz-text-decoration: underline #ee00be;
+        text-decoration: underline #ee00be;
+        -webkit-text-decoration: underline lch(50.998% 135.363 338);
+        -moz-text-decoration: underline lch(50.998% 135.363 338);
+        text-decoration: underline lch(50.998% 135.363 338);
+      }
+    "#},
       Browsers {
-        chrome: Some(90 << 16),
-        safari: Some(14 << 16),
+        safari: Some(8 << 16),
+        firefox: Some(30 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { text-shadow: 12px 12px lab(40% 56.6 39), 12px 12px yellow }",
-      indoc! { r#"
-        .foo {
-          text-shadow: 12px 12px #b32323, 12px 12px #ff0;
-          text-shadow: 12px 12px lab(40% 56.6 39), 12px 12px #ff0;
-        }
-      "#},
+      r#"
+      .foo {
+        text-decoration-color: lch(50.998% 135.363 338);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-text-decoration-color: #ee00be;
+        -moz-text-decoration-color: #ee00be;
+        text-decoration-color: #ee00be;
+        -webkit-text-decoration-color: lch(50.998% 135.363 338);
+        -moz-text-decoration-color: lch(50.998% 135.363 338);
+        text-decoration-color: lch(50.998% 135.363 338);
+      }
+    "#},
       Browsers {
-        chrome: Some(4 << 16),
+        safari: Some(8 << 16),
+        firefox: Some(30 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { text-shadow: var(--foo) 12px lab(40% 56.6 39) }",
-      indoc! { r#"
-        .foo {
-          text-shadow: var(--foo) 12px #b32323;
-        }
+      r#"
+      .foo {
+        text-decoration: lch(50.998% 135.363 338) var(--style);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        text-decoration: #ee00be var(--style);
+      }
 
-        @supports (color: lab(0% 0 0)) {
-          .foo {
-            text-shadow: var(--foo) 12px lab(40% 56.6 39);
-          }
+      @supports (color: lab(0% 0 0)) {
+        .foo {
+          text-decoration: lab(50.998% 125.506 -50.7078) var(--style);
         }
-      "#},
+      }
+    "#},
       Browsers {
-        chrome: Some(4 << 16),
+        chrome: Some(90 << 16),
         ..Browsers::default()
       },
     );
-  }
 
-  #[test]
-  fn test_break() {
     prefix_test(
       r#"
       .foo {
-        box-decoration-break: clone;
+        text-decoration: underline 10px;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-box-decoration-break: clone;
-        box-decoration-break: clone;
+        text-decoration: underline;
+        text-decoration-thickness: 10px;
       }
     "#},
       Browsers {
@@ -11812,66 +13930,68 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        box-decoration-break: clone;
+        text-decoration: underline 10px;
       }
     "#,
       indoc! {r#"
       .foo {
-        box-decoration-break: clone;
+        text-decoration: underline 10px;
       }
     "#},
       Browsers {
-        firefox: Some(95 << 16),
+        chrome: Some(90 << 16),
         ..Browsers::default()
       },
     );
-  }
 
-  #[test]
-  fn test_position() {
-    test(
+    prefix_test(
       r#"
       .foo {
-        position: relative;
-        position: absolute;
+        text-decoration: underline 10%;
       }
     "#,
       indoc! {r#"
       .foo {
-        position: absolute;
+        text-decoration: underline;
+        text-decoration-thickness: calc(1em / 10);
       }
     "#},
+      Browsers {
+        safari: Some(12 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    prefix_test(
       r#"
       .foo {
-        position: -webkit-sticky;
-        position: sticky;
+        text-decoration: underline 10%;
       }
     "#,
       indoc! {r#"
       .foo {
-        position: -webkit-sticky;
-        position: sticky;
+        text-decoration: underline 10%;
       }
     "#},
+      Browsers {
+        firefox: Some(89 << 16),
+        ..Browsers::default()
+      },
     );
 
     prefix_test(
       r#"
       .foo {
-        position: sticky;
+        text-decoration-thickness: 10%;
       }
     "#,
       indoc! {r#"
       .foo {
-        position: -webkit-sticky;
-        position: sticky;
+        text-decoration-thickness: calc(1em / 10);
       }
     "#},
       Browsers {
-        safari: Some(8 << 16),
+        safari: Some(12 << 16),
         ..Browsers::default()
       },
     );
@@ -11879,33 +13999,71 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        position: -webkit-sticky;
-        position: sticky;
+        text-decoration-thickness: 10%;
       }
     "#,
       indoc! {r#"
       .foo {
-        position: sticky;
+        text-decoration-thickness: 10%;
       }
     "#},
       Browsers {
-        safari: Some(13 << 16),
+        firefox: Some(89 << 16),
         ..Browsers::default()
       },
     );
+  }
+
+  #[test]
+  fn test_text_emphasis() {
+    minify_test(".foo { text-emphasis-style: none }", ".foo{text-emphasis-style:none}");
+    minify_test(
+      ".foo { text-emphasis-style: filled }",
+      ".foo{text-emphasis-style:filled}",
+    );
+    minify_test(".foo { text-emphasis-style: open }", ".foo{text-emphasis-style:open}");
+    minify_test(".foo { text-emphasis-style: dot }", ".foo{text-emphasis-style:dot}");
+    minify_test(
+      ".foo { text-emphasis-style: filled dot }",
+      ".foo{text-emphasis-style:dot}",
+    );
+    minify_test(
+      ".foo { text-emphasis-style: dot filled }",
+      ".foo{text-emphasis-style:dot}",
+    );
+    minify_test(
+      ".foo { text-emphasis-style: open dot }",
+      ".foo{text-emphasis-style:open dot}",
+    );
+    minify_test(
+      ".foo { text-emphasis-style: dot open }",
+      ".foo{text-emphasis-style:open dot}",
+    );
+    minify_test(".foo { text-emphasis-style: \"x\" }", ".foo{text-emphasis-style:\"x\"}");
+
+    minify_test(".foo { text-emphasis-color: yellow }", ".foo{text-emphasis-color:#ff0}");
+
+    minify_test(".foo { text-emphasis: none }", ".foo{text-emphasis:none}");
+    minify_test(".foo { text-emphasis: filled }", ".foo{text-emphasis:filled}");
+    minify_test(
+      ".foo { text-emphasis: filled yellow }",
+      ".foo{text-emphasis:filled #ff0}",
+    );
+    minify_test(
+      ".foo { text-emphasis: dot filled yellow }",
+      ".foo{text-emphasis:dot #ff0}",
+    );
 
     test(
       r#"
       .foo {
-        top: 0;
-        left: 0;
-        bottom: 0;
-        right: 0;
+        text-emphasis-style: filled;
+        text-emphasis-color: yellow;
       }
     "#,
       indoc! {r#"
       .foo {
-        inset: 0;
+        text-emphasis: filled #ff0;
       }
     "#},
     );
@@ -11913,15 +14071,13 @@ mod tests {
     test(
       r#"
       .foo {
-        top: 2px;
-        left: 4px;
-        bottom: 2px;
-        right: 4px;
+        text-emphasis: filled red;
+        text-emphasis-color: yellow;
       }
     "#,
       indoc! {r#"
       .foo {
-        inset: 2px 4px;
+        text-emphasis: filled #ff0;
       }
     "#},
     );
@@ -11929,93 +14085,90 @@ mod tests {
     test(
       r#"
       .foo {
-        top: 1px;
-        left: 2px;
-        bottom: 3px;
-        right: 4px;
+        text-emphasis: filled yellow;
+        text-emphasis-color: var(--color);
       }
     "#,
       indoc! {r#"
       .foo {
-        inset: 1px 4px 3px 2px;
+        text-emphasis: filled #ff0;
+        text-emphasis-color: var(--color);
       }
     "#},
     );
 
-    test(
+    prefix_test(
       r#"
       .foo {
-        inset-block-start: 2px;
-        inset-block-end: 2px;
-        inset-inline-start: 4px;
-        inset-inline-end: 4px;
+        text-emphasis-style: filled;
       }
     "#,
       indoc! {r#"
       .foo {
-        inset-block: 2px;
-        inset-inline: 4px;
+        -webkit-text-emphasis-style: filled;
+        text-emphasis-style: filled;
       }
     "#},
+      Browsers {
+        safari: Some(10 << 16),
+        chrome: Some(30 << 16),
+        firefox: Some(45 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    prefix_test(
       r#"
       .foo {
-        inset-block-start: 2px;
-        inset-block-end: 3px;
-        inset-inline-start: 4px;
-        inset-inline-end: 5px;
+        -webkit-text-emphasis-style: filled;
+        text-emphasis-style: filled;
       }
     "#,
       indoc! {r#"
       .foo {
-        inset-block: 2px 3px;
-        inset-inline: 4px 5px;
+        text-emphasis-style: filled;
       }
     "#},
+      Browsers {
+        safari: Some(10 << 16),
+        firefox: Some(45 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
-      r#"
-      .foo {
-        inset-block-start: 2px;
-        inset-block-end: 3px;
-        inset: 4px;
-        inset-inline-start: 4px;
-        inset-inline-end: 5px;
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        inset: 4px;
-        inset-inline: 4px 5px;
-      }
-    "#},
+    minify_test(
+      ".foo { text-emphasis-position: over }",
+      ".foo{text-emphasis-position:over}",
+    );
+    minify_test(
+      ".foo { text-emphasis-position: under }",
+      ".foo{text-emphasis-position:under}",
+    );
+    minify_test(
+      ".foo { text-emphasis-position: over right }",
+      ".foo{text-emphasis-position:over}",
+    );
+    minify_test(
+      ".foo { text-emphasis-position: over left }",
+      ".foo{text-emphasis-position:over left}",
     );
 
     prefix_test(
       r#"
       .foo {
-        inset-inline-start: 2px;
+        text-emphasis-position: over;
       }
     "#,
       indoc! {r#"
-      .foo:not(:lang(ae)):not(:lang(ar)):not(:lang(arc)):not(:lang(bcc)):not(:lang(bqi)):not(:lang(ckb)):not(:lang(dv)):not(:lang(fa)):not(:lang(glk)):not(:lang(he)):not(:lang(ku)):not(:lang(mzn)):not(:lang(nqo)):not(:lang(pnb)):not(:lang(ps)):not(:lang(sd)):not(:lang(ug)):not(:lang(ur)):not(:lang(yi)) {
-        left: 2px;
-      }
-
-      .foo:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        right: 2px;
-      }
-
-      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        right: 2px;
+      .foo {
+        -webkit-text-emphasis-position: over;
+        text-emphasis-position: over;
       }
-    "#
-      },
+    "#},
       Browsers {
-        safari: Some(8 << 16),
+        safari: Some(10 << 16),
+        chrome: Some(30 << 16),
+        firefox: Some(45 << 16),
         ..Browsers::default()
       },
     );
@@ -12023,29 +14176,18 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        inset-inline-start: 2px;
-        inset-inline-end: 4px;
+        text-emphasis-position: over left;
       }
     "#,
       indoc! {r#"
-      .foo:not(:lang(ae)):not(:lang(ar)):not(:lang(arc)):not(:lang(bcc)):not(:lang(bqi)):not(:lang(ckb)):not(:lang(dv)):not(:lang(fa)):not(:lang(glk)):not(:lang(he)):not(:lang(ku)):not(:lang(mzn)):not(:lang(nqo)):not(:lang(pnb)):not(:lang(ps)):not(:lang(sd)):not(:lang(ug)):not(:lang(ur)):not(:lang(yi)) {
-        left: 2px;
-        right: 4px;
-      }
-
-      .foo:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        left: 4px;
-        right: 2px;
-      }
-
-      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        left: 4px;
-        right: 2px;
+      .foo {
+        text-emphasis-position: over left;
       }
-    "#
-      },
+    "#},
       Browsers {
-        safari: Some(8 << 16),
+        safari: Some(10 << 16),
+        chrome: Some(30 << 16),
+        firefox: Some(45 << 16),
         ..Browsers::default()
       },
     );
@@ -12053,18 +14195,19 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        inset-inline: 2px;
+        text-emphasis-position: var(--test);
       }
     "#,
       indoc! {r#"
       .foo {
-        left: 2px;
-        right: 2px;
+        -webkit-text-emphasis-position: var(--test);
+        text-emphasis-position: var(--test);
       }
-    "#
-      },
+    "#},
       Browsers {
-        safari: Some(8 << 16),
+        safari: Some(10 << 16),
+        chrome: Some(30 << 16),
+        firefox: Some(45 << 16),
         ..Browsers::default()
       },
     );
@@ -12072,17 +14215,20 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        inset-block-start: 2px;
+        text-emphasis: filled lch(50.998% 135.363 338);
       }
     "#,
       indoc! {r#"
       .foo {
-        top: 2px;
+        -webkit-text-emphasis: filled #ee00be;
+        text-emphasis: filled #ee00be;
+        -webkit-text-emphasis: filled lch(50.998% 135.363 338);
+        text-emphasis: filled lch(50.998% 135.363 338);
       }
-    "#
-      },
+    "#},
       Browsers {
-        safari: Some(8 << 16),
+        chrome: Some(25 << 16),
+        firefox: Some(48 << 16),
         ..Browsers::default()
       },
     );
@@ -12090,17 +14236,20 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        inset-block-end: 2px;
+        text-emphasis-color: lch(50.998% 135.363 338);
       }
     "#,
       indoc! {r#"
       .foo {
-        bottom: 2px;
+        -webkit-text-emphasis-color: #ee00be;
+        text-emphasis-color: #ee00be;
+        -webkit-text-emphasis-color: lch(50.998% 135.363 338);
+        text-emphasis-color: lch(50.998% 135.363 338);
       }
-    "#
-      },
+    "#},
       Browsers {
-        safari: Some(8 << 16),
+        chrome: Some(25 << 16),
+        firefox: Some(48 << 16),
         ..Browsers::default()
       },
     );
@@ -12108,18 +14257,18 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        top: 1px;
-        left: 2px;
-        bottom: 3px;
-        right: 4px;
+        text-emphasis: lch(50.998% 135.363 338) var(--style);
       }
     "#,
       indoc! {r#"
       .foo {
-        top: 1px;
-        bottom: 3px;
-        left: 2px;
-        right: 4px;
+        text-emphasis: #ee00be var(--style);
+      }
+
+      @supports (color: lab(0% 0 0)) {
+        .foo {
+          text-emphasis: lab(50.998% 125.506 -50.7078) var(--style);
+        }
       }
     "#},
       Browsers {
@@ -12130,195 +14279,203 @@ mod tests {
   }
 
   #[test]
-  fn test_overflow() {
-    minify_test(".foo { overflow: hidden }", ".foo{overflow:hidden}");
-    minify_test(".foo { overflow: hidden hidden }", ".foo{overflow:hidden}");
-    minify_test(".foo { overflow: hidden auto }", ".foo{overflow:hidden auto}");
-
-    test(
-      r#"
-      .foo {
-        overflow-x: hidden;
-        overflow-y: auto;
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        overflow: hidden auto;
-      }
-    "#},
+  fn test_text_shadow() {
+    minify_test(
+      ".foo { text-shadow: 1px 1px 2px yellow; }",
+      ".foo{text-shadow:1px 1px 2px #ff0}",
     );
-
-    test(
-      r#"
-      .foo {
-        overflow: hidden;
-        overflow-y: auto;
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        overflow: hidden auto;
-      }
-    "#},
+    minify_test(
+      ".foo { text-shadow: 1px 1px 2px 3px yellow; }",
+      ".foo{text-shadow:1px 1px 2px 3px #ff0}",
     );
-    test(
-      r#"
-      .foo {
-        overflow: hidden;
-        overflow-y: var(--y);
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        overflow: hidden;
-        overflow-y: var(--y);
-      }
-    "#},
+    minify_test(
+      ".foo { text-shadow: 1px 1px 0 yellow; }",
+      ".foo{text-shadow:1px 1px #ff0}",
+    );
+    minify_test(
+      ".foo { text-shadow: 1px 1px yellow; }",
+      ".foo{text-shadow:1px 1px #ff0}",
+    );
+    minify_test(
+      ".foo { text-shadow: 1px 1px yellow, 2px 3px red; }",
+      ".foo{text-shadow:1px 1px #ff0,2px 3px red}",
     );
+
     prefix_test(
-      r#"
-      .foo {
-        overflow: hidden auto;
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        overflow-x: hidden;
-        overflow-y: auto;
-      }
-    "#},
+      ".foo { text-shadow: 12px 12px lab(40% 56.6 39) }",
+      indoc! { r#"
+        .foo {
+          text-shadow: 12px 12px #b32323;
+          text-shadow: 12px 12px lab(40% 56.6 39);
+        }
+      "#},
       Browsers {
-        chrome: Some(67 << 16),
+        chrome: Some(4 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
-      r#"
+      ".foo { text-shadow: 12px 12px lab(40% 56.6 39) }",
+      indoc! { r#"
+        .foo {
+          text-shadow: 12px 12px #b32323;
+          text-shadow: 12px 12px color(display-p3 0.643308 0.192455 0.167712);
+          text-shadow: 12px 12px lab(40% 56.6 39);
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { text-shadow: 12px 12px lab(40% 56.6 39), 12px 12px currentColor }",
+      indoc! { r#"
+        .foo {
+          text-shadow: 12px 12px #b32323, 12px 12px currentColor;
+          text-shadow: 12px 12px lab(40% 56.6 39), 12px 12px currentColor;
+        }
+      "#},
+      Browsers {
+        chrome: Some(4 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { text-shadow: 12px 12px lab(40% 56.6 39), 12px 12px Canvas }",
+      indoc! { r#"
+        .foo {
+          text-shadow: 12px 12px #b32323, 12px 12px Canvas;
+          text-shadow: 12px 12px lab(40% 56.6 39), 12px 12px Canvas;
+        }
+      "#},
+      Browsers {
+        chrome: Some(4 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { text-shadow: 12px 12px lab(40% 56.6 39), 12px 12px yellow }",
+      indoc! { r#"
+        .foo {
+          text-shadow: 12px 12px #b32323, 12px 12px #ff0;
+          text-shadow: 12px 12px lab(40% 56.6 39), 12px 12px #ff0;
+        }
+      "#},
+      Browsers {
+        chrome: Some(4 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { text-shadow: var(--foo) 12px lab(40% 56.6 39) }",
+      indoc! { r#"
+        .foo {
+          text-shadow: var(--foo) 12px #b32323;
+        }
+
+        @supports (color: lab(0% 0 0)) {
+          .foo {
+            text-shadow: var(--foo) 12px lab(40% 56.6 39);
+          }
+        }
+      "#},
+      Browsers {
+        chrome: Some(4 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_break() {
+    prefix_test(
+      r#"
       .foo {
-        overflow: hidden hidden;
+        box-decoration-break: clone;
       }
     "#,
       indoc! {r#"
       .foo {
-        overflow: hidden;
+        -webkit-box-decoration-break: clone;
+        box-decoration-break: clone;
       }
     "#},
       Browsers {
-        chrome: Some(67 << 16),
+        safari: Some(15 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
       r#"
       .foo {
-        overflow: hidden auto;
+        box-decoration-break: clone;
       }
     "#,
       indoc! {r#"
       .foo {
-        overflow: hidden auto;
+        box-decoration-break: clone;
       }
     "#},
       Browsers {
-        chrome: Some(68 << 16),
+        firefox: Some(95 << 16),
         ..Browsers::default()
       },
     );
+  }
 
-    minify_test(".foo { text-overflow: ellipsis }", ".foo{text-overflow:ellipsis}");
-    prefix_test(
+  #[test]
+  fn test_position() {
+    test(
       r#"
       .foo {
-        text-overflow: ellipsis;
+        position: relative;
+        position: absolute;
       }
     "#,
       indoc! {r#"
       .foo {
-        -o-text-overflow: ellipsis;
-        text-overflow: ellipsis;
+        position: absolute;
       }
     "#},
-      Browsers {
-        safari: Some(4 << 16),
-        opera: Some(10 << 16),
-        ..Browsers::default()
-      },
     );
 
-    prefix_test(
+    test(
       r#"
       .foo {
-        -o-text-overflow: ellipsis;
-        text-overflow: ellipsis;
+        position: -webkit-sticky;
+        position: sticky;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-overflow: ellipsis;
+        position: -webkit-sticky;
+        position: sticky;
       }
     "#},
-      Browsers {
-        safari: Some(4 << 16),
-        opera: Some(14 << 16),
-        ..Browsers::default()
-      },
-    );
-  }
-
-  #[test]
-  fn test_ui() {
-    minify_test(".foo { resize: both }", ".foo{resize:both}");
-    minify_test(".foo { resize: Horizontal }", ".foo{resize:horizontal}");
-    minify_test(".foo { cursor: ew-resize }", ".foo{cursor:ew-resize}");
-    minify_test(
-      ".foo { cursor: url(\"test.cur\"), ew-resize }",
-      ".foo{cursor:url(test.cur),ew-resize}",
-    );
-    minify_test(
-      ".foo { cursor: url(\"test.cur\"), url(\"foo.cur\"), ew-resize }",
-      ".foo{cursor:url(test.cur),url(foo.cur),ew-resize}",
-    );
-    minify_test(".foo { caret-color: auto }", ".foo{caret-color:auto}");
-    minify_test(".foo { caret-color: yellow }", ".foo{caret-color:#ff0}");
-    minify_test(".foo { caret-shape: block }", ".foo{caret-shape:block}");
-    minify_test(".foo { caret: yellow block }", ".foo{caret:#ff0 block}");
-    minify_test(".foo { caret: block yellow }", ".foo{caret:#ff0 block}");
-    minify_test(".foo { caret: block }", ".foo{caret:block}");
-    minify_test(".foo { caret: yellow }", ".foo{caret:#ff0}");
-    minify_test(".foo { caret: auto auto }", ".foo{caret:auto}");
-    minify_test(".foo { caret: auto }", ".foo{caret:auto}");
-    minify_test(".foo { caret: yellow auto }", ".foo{caret:#ff0}");
-    minify_test(".foo { caret: auto block }", ".foo{caret:block}");
-    minify_test(".foo { user-select: none }", ".foo{user-select:none}");
-    minify_test(".foo { -webkit-user-select: none }", ".foo{-webkit-user-select:none}");
-    minify_test(".foo { accent-color: auto }", ".foo{accent-color:auto}");
-    minify_test(".foo { accent-color: yellow }", ".foo{accent-color:#ff0}");
-    minify_test(".foo { appearance: None }", ".foo{appearance:none}");
-    minify_test(
-      ".foo { -webkit-appearance: textfield }",
-      ".foo{-webkit-appearance:textfield}",
     );
 
     prefix_test(
       r#"
       .foo {
-        user-select: none;
+        position: sticky;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-user-select: none;
-        -moz-user-select: none;
-        -ms-user-select: none;
-        user-select: none;
+        position: -webkit-sticky;
+        position: sticky;
       }
     "#},
       Browsers {
         safari: Some(8 << 16),
-        opera: Some(5 << 16),
-        firefox: Some(10 << 16),
-        ie: Some(10 << 16),
         ..Browsers::default()
       },
     );
@@ -12326,393 +14483,209 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        -webkit-user-select: none;
-        -moz-user-select: none;
-        -ms-user-select: none;
-        user-select: none;
+        position: -webkit-sticky;
+        position: sticky;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-user-select: none;
-        user-select: none;
+        position: sticky;
       }
     "#},
       Browsers {
-        safari: Some(8 << 16),
-        opera: Some(80 << 16),
-        firefox: Some(80 << 16),
-        edge: Some(80 << 16),
+        safari: Some(13 << 16),
         ..Browsers::default()
       },
     );
 
-    prefix_test(
+    test(
       r#"
       .foo {
-        -webkit-user-select: none;
-        -moz-user-select: none;
-        -ms-user-select: none;
-        user-select: none;
+        top: 0;
+        left: 0;
+        bottom: 0;
+        right: 0;
       }
     "#,
       indoc! {r#"
       .foo {
-        user-select: none;
+        inset: 0;
       }
     "#},
-      Browsers {
-        opera: Some(80 << 16),
-        firefox: Some(80 << 16),
-        edge: Some(80 << 16),
-        ..Browsers::default()
-      },
     );
 
-    prefix_test(
+    test(
       r#"
       .foo {
-        appearance: none;
+        top: 2px;
+        left: 4px;
+        bottom: 2px;
+        right: 4px;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-appearance: none;
-        -moz-appearance: none;
-        -ms-appearance: none;
-        appearance: none;
+        inset: 2px 4px;
       }
     "#},
-      Browsers {
-        safari: Some(8 << 16),
-        chrome: Some(80 << 16),
-        firefox: Some(10 << 16),
-        ie: Some(11 << 16),
-        ..Browsers::default()
-      },
     );
 
-    prefix_test(
+    test(
       r#"
       .foo {
-        -webkit-appearance: none;
-        -moz-appearance: none;
-        -ms-appearance: none;
-        appearance: none;
+        top: 1px;
+        left: 2px;
+        bottom: 3px;
+        right: 4px;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-appearance: none;
-        appearance: none;
+        inset: 1px 4px 3px 2px;
       }
     "#},
-      Browsers {
-        safari: Some(15 << 16),
-        chrome: Some(85 << 16),
-        firefox: Some(80 << 16),
-        edge: Some(85 << 16),
-        ..Browsers::default()
-      },
     );
 
+    minify_test(".foo { inset: 0 }", ".foo{inset:0}");
+    minify_test(".foo { inset: 1px 2px 1px 2px }", ".foo{inset:1px 2px}");
+    minify_test(".foo { inset: 1px 2px 3px 4px }", ".foo{inset:1px 2px 3px 4px}");
+    minify_test(".foo { inset: 1px; top: 2px }", ".foo{inset:2px 1px 1px}");
+
     prefix_test(
       r#"
       .foo {
-        -webkit-appearance: none;
-        -moz-appearance: none;
-        -ms-appearance: none;
-        appearance: none;
+        inset: 0;
       }
     "#,
       indoc! {r#"
       .foo {
-        appearance: none;
+        top: 0;
+        bottom: 0;
+        left: 0;
+        right: 0;
       }
     "#},
       Browsers {
-        chrome: Some(85 << 16),
-        firefox: Some(80 << 16),
-        edge: Some(85 << 16),
+        safari: Some(13 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { caret-color: lch(50.998% 135.363 338) }",
-      indoc! { r#"
-        .foo {
-          caret-color: #ee00be;
-          caret-color: color(display-p3 .972962 -.362078 .804206);
-          caret-color: lch(50.998% 135.363 338);
-        }
-      "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        safari: Some(14 << 16),
-        ..Browsers::default()
-      },
-    );
-
-    prefix_test(
-      ".foo { caret: lch(50.998% 135.363 338) block }",
-      indoc! { r#"
-        .foo {
-          caret: #ee00be block;
-          caret: color(display-p3 .972962 -.362078 .804206) block;
-          caret: lch(50.998% 135.363 338) block;
-        }
-      "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        safari: Some(14 << 16),
-        ..Browsers::default()
-      },
-    );
-
-    prefix_test(
-      ".foo { caret: lch(50.998% 135.363 338) var(--foo) }",
-      indoc! { r#"
-        .foo {
-          caret: #ee00be var(--foo);
-        }
-
-        @supports (color: lab(0% 0 0)) {
-          .foo {
-            caret: lab(50.998% 125.506 -50.7078) var(--foo);
-          }
-        }
-      "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
-    );
-  }
-
-  #[test]
-  fn test_list() {
-    minify_test(".foo { list-style-type: disc; }", ".foo{list-style-type:disc}");
-    minify_test(".foo { list-style-type: \"★\"; }", ".foo{list-style-type:\"★\"}");
-    minify_test(
-      ".foo { list-style-type: symbols(cyclic '○' '●'); }",
-      ".foo{list-style-type:symbols(cyclic \"○\" \"●\")}",
-    );
-    minify_test(
-      ".foo { list-style-type: symbols('○' '●'); }",
-      ".foo{list-style-type:symbols(\"○\" \"●\")}",
-    );
-    minify_test(
-      ".foo { list-style-type: symbols(symbolic '○' '●'); }",
-      ".foo{list-style-type:symbols(\"○\" \"●\")}",
-    );
-    minify_test(
-      ".foo { list-style-type: symbols(symbolic url('ellipse.png')); }",
-      ".foo{list-style-type:symbols(url(ellipse.png))}",
-    );
-    minify_test(
-      ".foo { list-style-image: url('ellipse.png'); }",
-      ".foo{list-style-image:url(ellipse.png)}",
-    );
-    minify_test(
-      ".foo { list-style-position: outside; }",
-      ".foo{list-style-position:outside}",
-    );
-    minify_test(
-      ".foo { list-style: \"★\" url(ellipse.png) outside; }",
-      ".foo{list-style:\"★\" url(ellipse.png)}",
-    );
-
-    test(
-      r#"
-      .foo {
-        list-style-type: disc;
-        list-style-image: url(ellipse.png);
-        list-style-position: outside;
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        list-style: url("ellipse.png");
-      }
-    "#},
-    );
-
-    test(
       r#"
       .foo {
-        list-style: \"★\" url(ellipse.png) outside;
-        list-style-image: none;
+        inset: 1px 2px 3px 4px;
       }
     "#,
       indoc! {r#"
       .foo {
-        list-style: \"★\";
+        top: 1px;
+        bottom: 3px;
+        left: 4px;
+        right: 2px;
       }
     "#},
+      Browsers {
+        safari: Some(13 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    prefix_test(
       r#"
       .foo {
-        list-style: \"★\" url(ellipse.png) outside;
-        list-style-image: var(--img);
+        top: 0;
+        right: 0;
+        bottom: 0;
+        left: 0;
       }
     "#,
       indoc! {r#"
       .foo {
-        list-style: \"★\" url("ellipse.png");
-        list-style-image: var(--img);
+        inset: 0;
       }
     "#},
-    );
-
-    prefix_test(
-      ".foo { list-style-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
-      indoc! { r#"
-        .foo {
-          list-style-image: -webkit-gradient(linear, 0 0, 0 100%, from(#ff0f0e), to(#7773ff));
-          list-style-image: -webkit-linear-gradient(#ff0f0e, #7773ff);
-          list-style-image: linear-gradient(#ff0f0e, #7773ff);
-          list-style-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
-        }
-      "#},
-      Browsers {
-        chrome: Some(8 << 16),
-        ..Browsers::default()
-      },
-    );
-
-    prefix_test(
-      ".foo { list-style: \"★\" linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
-      indoc! { r#"
-        .foo {
-          list-style: "★" linear-gradient(#ff0f0e, #7773ff);
-          list-style: "★" linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
-        }
-      "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
-    );
-
-    prefix_test(
-      ".foo { list-style: var(--foo) linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
-      indoc! { r#"
-        .foo {
-          list-style: var(--foo) linear-gradient(#ff0f0e, #7773ff);
-        }
-
-        @supports (color: lab(0% 0 0)) {
-          .foo {
-            list-style: var(--foo) linear-gradient(lab(56.208% 94.4644 98.8928), lab(51% 70.4544 -115.586));
-          }
-        }
-      "#},
       Browsers {
+        safari: Some(15 << 16),
         chrome: Some(90 << 16),
         ..Browsers::default()
       },
     );
-  }
-
-  #[test]
-  fn test_image_set() {
-    // Spec: https://drafts.csswg.org/css-images-4/#image-set-notation
-    // WPT: https://github.com/web-platform-tests/wpt/blob/master/css/css-images/image-set/image-set-parsing.html
-    // test image-set(<string>)
-    minify_test(
-      ".foo { background: image-set(\"foo.png\" 2x, url(bar.png) 1x) }",
-      ".foo{background:image-set(\"foo.png\" 2x,\"bar.png\" 1x)}",
-    );
-
-    // test image-set(type(<string>))
-    minify_test(
-      ".foo { background: image-set('foo.webp' type('webp'), url(foo.jpg)) }",
-      ".foo{background:image-set(\"foo.webp\" 1x type(\"webp\"),\"foo.jpg\" 1x)}",
-    );
-    minify_test(
-      ".foo { background: image-set('foo.avif' 2x type('image/avif'), url(foo.png)) }",
-      ".foo{background:image-set(\"foo.avif\" 2x type(\"image/avif\"),\"foo.png\" 1x)}",
-    );
-    minify_test(
-      ".foo { background: image-set(url('example.png') 3x type('image/png')) }",
-      ".foo{background:image-set(\"example.png\" 3x type(\"image/png\"))}",
-    );
-
-    minify_test(
-      ".foo { background: image-set(url(example.png) type('image/png') 1x) }",
-      ".foo{background:image-set(\"example.png\" 1x type(\"image/png\"))}",
-    );
-
-    minify_test(
-      ".foo { background: -webkit-image-set(url(\"foo.png\") 2x, url(bar.png) 1x) }",
-      ".foo{background:-webkit-image-set(url(foo.png) 2x,url(bar.png) 1x)}",
-    );
 
     test(
       r#"
       .foo {
-        background: -webkit-image-set(url("foo.png") 2x, url(bar.png) 1x);
-        background: image-set(url("foo.png") 2x, url(bar.png) 1x);
+        inset-block-start: 2px;
+        inset-block-end: 2px;
+        inset-inline-start: 4px;
+        inset-inline-end: 4px;
       }
     "#,
       indoc! {r#"
       .foo {
-        background: -webkit-image-set(url("foo.png") 2x, url("bar.png") 1x);
-        background: image-set("foo.png" 2x, "bar.png" 1x);
+        inset-block: 2px;
+        inset-inline: 4px;
       }
     "#},
     );
 
-    // test image-set(<gradient>)
     test(
       r#"
       .foo {
-        background: image-set(linear-gradient(cornflowerblue, white) 1x, url("detailed-gradient.png") 3x);
+        inset-block-start: 2px;
+        inset-block-end: 3px;
+        inset-inline-start: 4px;
+        inset-inline-end: 5px;
       }
     "#,
       indoc! {r#"
       .foo {
-        background: image-set(linear-gradient(#6495ed, #fff) 1x, "detailed-gradient.png" 3x);
+        inset-block: 2px 3px;
+        inset-inline: 4px 5px;
       }
     "#},
     );
 
-    prefix_test(
+    test(
       r#"
       .foo {
-        background: image-set(url("foo.png") 2x, url(bar.png) 1x);
+        inset-block-start: 2px;
+        inset-block-end: 3px;
+        inset: 4px;
+        inset-inline-start: 4px;
+        inset-inline-end: 5px;
       }
     "#,
       indoc! {r#"
       .foo {
-        background: -webkit-image-set(url("foo.png") 2x, url("bar.png") 1x);
-        background: image-set("foo.png" 2x, "bar.png" 1x);
+        inset: 4px;
+        inset-inline: 4px 5px;
       }
     "#},
-      Browsers {
-        chrome: Some(85 << 16),
-        firefox: Some(80 << 16),
-        ..Browsers::default()
-      },
     );
 
     prefix_test(
       r#"
       .foo {
-        background: -webkit-image-set(url("foo.png") 2x, url(bar.png) 1x);
-        background: image-set(url("foo.png") 2x, url(bar.png) 1x);
+        inset-inline-start: 2px;
       }
     "#,
       indoc! {r#"
-      .foo {
-        background: image-set("foo.png" 2x, "bar.png" 1x);
+      .foo:not(:lang(ae)):not(:lang(ar)):not(:lang(arc)):not(:lang(bcc)):not(:lang(bqi)):not(:lang(ckb)):not(:lang(dv)):not(:lang(fa)):not(:lang(glk)):not(:lang(he)):not(:lang(ku)):not(:lang(mzn)):not(:lang(nqo)):not(:lang(pnb)):not(:lang(ps)):not(:lang(sd)):not(:lang(ug)):not(:lang(ur)):not(:lang(yi)) {
+        left: 2px;
       }
-    "#},
+
+      .foo:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        right: 2px;
+      }
+
+      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        right: 2px;
+      }
+    "#
+      },
       Browsers {
-        firefox: Some(80 << 16),
+        safari: Some(8 << 16),
         ..Browsers::default()
       },
     );
@@ -12720,405 +14693,418 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        background: -webkit-image-set(url("foo.png") 2x, url(bar.png) 1x);
+        inset-inline-start: 2px;
+        inset-inline-end: 4px;
       }
     "#,
       indoc! {r#"
-      .foo {
-        background: -webkit-image-set(url("foo.png") 2x, url("bar.png") 1x);
+      .foo:not(:lang(ae)):not(:lang(ar)):not(:lang(arc)):not(:lang(bcc)):not(:lang(bqi)):not(:lang(ckb)):not(:lang(dv)):not(:lang(fa)):not(:lang(glk)):not(:lang(he)):not(:lang(ku)):not(:lang(mzn)):not(:lang(nqo)):not(:lang(pnb)):not(:lang(ps)):not(:lang(sd)):not(:lang(ug)):not(:lang(ur)):not(:lang(yi)) {
+        left: 2px;
+        right: 4px;
       }
-    "#},
+
+      .foo:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        left: 4px;
+        right: 2px;
+      }
+
+      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        left: 4px;
+        right: 2px;
+      }
+    "#
+      },
       Browsers {
-        chrome: Some(95 << 16),
+        safari: Some(8 << 16),
         ..Browsers::default()
       },
     );
 
-    for property in &[
-      "background",
-      "background-image",
-      "border-image-source",
-      "border-image",
-      "border-image-source",
-      "-webkit-mask-image",
-      "-webkit-mask",
-      "list-style-image",
-      "list-style",
-    ] {
-      prefix_test(
-        &format!(
-          r#"
-        .foo {{
-          {}: url(foo.png);
-          {}: image-set(url("foo.png") 2x, url(bar.png) 1x);
-        }}
-      "#,
-          property, property
-        ),
-        &format!(
-          indoc! {r#"
-        .foo {{
-          {}: url("foo.png");
-          {}: -webkit-image-set(url("foo.png") 2x, url("bar.png") 1x);
-          {}: image-set("foo.png" 2x, "bar.png" 1x);
-        }}
-      "#},
-          property, property, property
-        ),
-        Browsers {
-          ie: Some(11 << 16),
-          chrome: Some(95 << 16),
-          ..Browsers::default()
-        },
-      );
+    prefix_test(
+      r#"
+      .foo {
+        inset-inline: 2px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        left: 2px;
+        right: 2px;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
 
-      prefix_test(
-        &format!(
-          r#"
-        .foo {{
-          {}: url(foo.png);
-          {}: image-set(url("foo.png") 2x, url(bar.png) 1x);
-        }}
-      "#,
-          property, property
-        ),
-        &format!(
-          indoc! {r#"
-        .foo {{
-          {}: -webkit-image-set(url("foo.png") 2x, url("bar.png") 1x);
-          {}: image-set("foo.png" 2x, "bar.png" 1x);
-        }}
-      "#},
-          property, property
-        ),
-        Browsers {
-          chrome: Some(95 << 16),
-          ..Browsers::default()
-        },
-      );
-    }
+    prefix_test(
+      r#"
+      .foo {
+        inset-block-start: 2px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        top: 2px;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        inset-block-end: 2px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        bottom: 2px;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        top: 1px;
+        left: 2px;
+        bottom: 3px;
+        right: 4px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        top: 1px;
+        bottom: 3px;
+        left: 2px;
+        right: 4px;
+      }
+    "#},
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
   }
 
   #[test]
-  fn test_color() {
-    minify_test(".foo { color: yellow }", ".foo{color:#ff0}");
-    minify_test(".foo { color: rgb(255, 255, 0) }", ".foo{color:#ff0}");
-    minify_test(".foo { color: rgba(255, 255, 0, 1) }", ".foo{color:#ff0}");
-    minify_test(".foo { color: rgba(255, 255, 0, 0.8) }", ".foo{color:#ff0c}");
-    minify_test(".foo { color: rgb(128, 128, 128) }", ".foo{color:gray}");
-    minify_test(".foo { color: rgb(123, 255, 255) }", ".foo{color:#7bffff}");
-    minify_test(".foo { color: rgba(123, 255, 255, 0.5) }", ".foo{color:#7bffff80}");
-    minify_test(".foo { color: rgb(123 255 255) }", ".foo{color:#7bffff}");
-    minify_test(".foo { color: rgb(123 255 255 / .5) }", ".foo{color:#7bffff80}");
-    minify_test(".foo { color: rgb(123 255 255 / 50%) }", ".foo{color:#7bffff80}");
-    minify_test(".foo { color: rgb(48% 100% 100% / 50%) }", ".foo{color:#7affff80}");
-    minify_test(".foo { color: hsl(100deg, 100%, 50%) }", ".foo{color:#5f0}");
-    minify_test(".foo { color: hsl(100, 100%, 50%) }", ".foo{color:#5f0}");
-    minify_test(".foo { color: hsl(100 100% 50%) }", ".foo{color:#5f0}");
-    minify_test(".foo { color: hsl(100, 100%, 50%, .8) }", ".foo{color:#5f0c}");
-    minify_test(".foo { color: hsl(100 100% 50% / .8) }", ".foo{color:#5f0c}");
-    minify_test(".foo { color: hsla(100, 100%, 50%, .8) }", ".foo{color:#5f0c}");
-    minify_test(".foo { color: hsla(100 100% 50% / .8) }", ".foo{color:#5f0c}");
-    minify_test(".foo { color: transparent }", ".foo{color:#0000}");
-    minify_test(".foo { color: currentColor }", ".foo{color:currentColor}");
-    minify_test(".foo { color: hwb(194 0% 0%) }", ".foo{color:#00c4ff}");
-    minify_test(".foo { color: hwb(194 0% 0% / 50%) }", ".foo{color:#00c4ff80}");
-    minify_test(".foo { color: hwb(194 0% 50%) }", ".foo{color:#006280}");
-    minify_test(".foo { color: hwb(194 50% 0%) }", ".foo{color:#80e1ff}");
-    minify_test(".foo { color: hwb(194 50% 50%) }", ".foo{color:gray}");
-    // minify_test(".foo { color: ActiveText }", ".foo{color:ActiveTet}");
-    minify_test(
-      ".foo { color: lab(29.2345% 39.3825 20.0664); }",
-      ".foo{color:lab(29.2345% 39.3825 20.0664)}",
+  fn test_overflow() {
+    minify_test(".foo { overflow: hidden }", ".foo{overflow:hidden}");
+    minify_test(".foo { overflow: hidden hidden }", ".foo{overflow:hidden}");
+    minify_test(".foo { overflow: hidden auto }", ".foo{overflow:hidden auto}");
+
+    test(
+      r#"
+      .foo {
+        overflow-x: hidden;
+        overflow-y: auto;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        overflow: hidden auto;
+      }
+    "#},
     );
-    minify_test(
-      ".foo { color: lab(29.2345% 39.3825 20.0664 / 100%); }",
-      ".foo{color:lab(29.2345% 39.3825 20.0664)}",
+
+    test(
+      r#"
+      .foo {
+        overflow: hidden;
+        overflow-y: auto;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        overflow: hidden auto;
+      }
+    "#},
     );
-    minify_test(
-      ".foo { color: lab(29.2345% 39.3825 20.0664 / 50%); }",
-      ".foo{color:lab(29.2345% 39.3825 20.0664/.5)}",
+    test(
+      r#"
+      .foo {
+        overflow: hidden;
+        overflow-y: var(--y);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        overflow: hidden;
+        overflow-y: var(--y);
+      }
+    "#},
     );
-    minify_test(
-      ".foo { color: lch(29.2345% 44.2 27); }",
-      ".foo{color:lch(29.2345% 44.2 27)}",
+    prefix_test(
+      r#"
+      .foo {
+        overflow: hidden auto;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        overflow-x: hidden;
+        overflow-y: auto;
+      }
+    "#},
+      Browsers {
+        chrome: Some(67 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { color: lch(29.2345% 44.2 45deg); }",
-      ".foo{color:lch(29.2345% 44.2 45)}",
-    );
-    minify_test(
-      ".foo { color: lch(29.2345% 44.2 .5turn); }",
-      ".foo{color:lch(29.2345% 44.2 180)}",
-    );
-    minify_test(
-      ".foo { color: lch(29.2345% 44.2 27 / 100%); }",
-      ".foo{color:lch(29.2345% 44.2 27)}",
-    );
-    minify_test(
-      ".foo { color: lch(29.2345% 44.2 27 / 50%); }",
-      ".foo{color:lch(29.2345% 44.2 27/.5)}",
-    );
-    minify_test(
-      ".foo { color: oklab(40.101% 0.1147 0.0453); }",
-      ".foo{color:oklab(40.101% .1147 .0453)}",
-    );
-    minify_test(
-      ".foo { color: oklch(40.101% 0.12332 21.555); }",
-      ".foo{color:oklch(40.101% .12332 21.555)}",
-    );
-    minify_test(
-      ".foo { color: oklch(40.101% 0.12332 .5turn); }",
-      ".foo{color:oklch(40.101% .12332 180)}",
-    );
-    minify_test(
-      ".foo { color: color(display-p3 1 0.5 0); }",
-      ".foo{color:color(display-p3 1 .5)}",
-    );
-    minify_test(
-      ".foo { color: color(display-p3 100% 50% 0%); }",
-      ".foo{color:color(display-p3 1 .5)}",
-    );
-    minify_test(
-      ".foo { color: color(xyz-d50 0.2005 0.14089 0.4472); }",
-      ".foo{color:color(xyz-d50 .2005 .14089 .4472)}",
-    );
-    minify_test(
-      ".foo { color: color(xyz-d50 20.05% 14.089% 44.72%); }",
-      ".foo{color:color(xyz-d50 .2005 .14089 .4472)}",
-    );
-    minify_test(
-      ".foo { color: color(xyz-d65 0.2005 0.14089 0.4472); }",
-      ".foo{color:color(xyz .2005 .14089 .4472)}",
-    );
-    minify_test(
-      ".foo { color: color(xyz-d65 20.05% 14.089% 44.72%); }",
-      ".foo{color:color(xyz .2005 .14089 .4472)}",
-    );
-    minify_test(
-      ".foo { color: color(xyz 0.2005 0.14089 0.4472); }",
-      ".foo{color:color(xyz .2005 .14089 .4472)}",
-    );
-    minify_test(
-      ".foo { color: color(xyz 20.05% 14.089% 44.72%); }",
-      ".foo{color:color(xyz .2005 .14089 .4472)}",
-    );
-    minify_test(".foo { color: color(xyz 0.2005 0 0); }", ".foo{color:color(xyz .2005)}");
-    minify_test(".foo { color: color(xyz 0 0 0); }", ".foo{color:color(xyz)}");
-    minify_test(".foo { color: color(xyz 0 1 0); }", ".foo{color:color(xyz 0 1)}");
-    minify_test(".foo { color: color(xyz 0 1); }", ".foo{color:color(xyz 0 1)}");
-    minify_test(".foo { color: color(xyz 1); }", ".foo{color:color(xyz 1)}");
-    minify_test(".foo { color: color(xyz); }", ".foo{color:color(xyz)}");
-    minify_test(
-      ".foo { color: color(xyz 0 1 0 / 20%); }",
-      ".foo{color:color(xyz 0 1/.2)}",
-    );
-    minify_test(".foo { color: color(xyz / 20%); }", ".foo{color:color(xyz/.2)}");
-    minify_test(
-      ".foo { color: color(display-p3 100% 50% 0 / 20%); }",
-      ".foo{color:color(display-p3 1 .5/.2)}",
-    );
-    minify_test(
-      ".foo { color: color(display-p3 100% / 20%); }",
-      ".foo{color:color(display-p3 1/.2)}",
-    );
-    minify_test(".foo { color: hsl(none none none) }", ".foo{color:#000}");
-    minify_test(".foo { color: hwb(none none none) }", ".foo{color:red}");
-    minify_test(".foo { color: rgb(none none none) }", ".foo{color:#000}");
-
-    // If the browser doesn't support `#rrggbbaa` color syntax, it is converted to `transparent`.
-    attr_test(
-      "color: rgba(0, 0, 0, 0)",
-      "color:transparent",
-      true,
-      Some(Browsers {
-        chrome: Some(61 << 16), // Chrome >= 62 supports `#rrggbbaa` color.
-        ..Browsers::default()
-      }),
-    );
-
-    attr_test(
-      "color: #0000",
-      "color:transparent",
-      true,
-      Some(Browsers {
-        chrome: Some(61 << 16), // Chrome >= 62 supports `#rrggbbaa` color.
-        ..Browsers::default()
-      }),
-    );
-
-    attr_test(
-      "color: transparent",
-      "color:transparent",
-      true,
-      Some(Browsers {
-        chrome: Some(61 << 16),
-        ..Browsers::default()
-      }),
-    );
-
-    attr_test(
-      "color: rgba(0, 0, 0, 0)",
-      "color: rgba(0, 0, 0, 0)",
-      false,
-      Some(Browsers {
-        chrome: Some(61 << 16),
-        ..Browsers::default()
-      }),
-    );
-
-    attr_test(
-      "color: rgba(255, 0, 0, 0)",
-      "color:rgba(255,0,0,0)",
-      true,
-      Some(Browsers {
-        chrome: Some(61 << 16),
-        ..Browsers::default()
-      }),
-    );
-
-    attr_test(
-      "color: rgba(255, 0, 0, 0)",
-      "color:#f000",
-      true,
-      Some(Browsers {
-        chrome: Some(62 << 16),
-        ..Browsers::default()
-      }),
-    );
-
     prefix_test(
-      ".foo { color: rgba(123, 456, 789, 0.5) }",
-      indoc! { r#"
-        .foo {
-          color: #7bffff80;
-        }
-      "#},
+      r#"
+      .foo {
+        overflow: hidden hidden;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        overflow: hidden;
+      }
+    "#},
       Browsers {
-        chrome: Some(95 << 16),
+        chrome: Some(67 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
-      ".foo { color: rgba(123, 255, 255, 0.5) }",
-      indoc! { r#"
-        .foo {
-          color: rgba(123, 255, 255, .5);
-        }
-      "#},
+      r#"
+      .foo {
+        overflow: hidden auto;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        overflow: hidden auto;
+      }
+    "#},
       Browsers {
-        ie: Some(11 << 16),
+        chrome: Some(68 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
-      ".foo { color: #7bffff80 }",
-      indoc! { r#"
-        .foo {
-          color: rgba(123, 255, 255, .5);
-        }
-      "#},
+      r#"
+      .foo {
+        overflow-x: scroll;
+        overflow-y: clip;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        overflow-x: scroll;
+        overflow-y: clip;
+      }
+    "#},
       Browsers {
-        ie: Some(11 << 16),
+        safari: Some(13 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
-      ".foo { color: rgba(123, 456, 789, 0.5) }",
-      indoc! { r#"
-        .foo {
-          color: rgba(123, 255, 255, .5);
-        }
-      "#},
+      r#"
+      .foo {
+        overflow-x: clip;
+        overflow-y: clip;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        overflow: clip;
+      }
+    "#},
       Browsers {
-        firefox: Some(48 << 16),
-        safari: Some(10 << 16),
-        ios_saf: Some(9 << 16),
+        safari: Some(13 << 16),
         ..Browsers::default()
       },
     );
+    minify_test(".foo { overflow: visible scroll }", ".foo{overflow:visible scroll}");
+    minify_test(".foo { overflow-x: auto; overflow-y: auto }", ".foo{overflow:auto}");
 
+    minify_test(".foo { text-overflow: ellipsis }", ".foo{text-overflow:ellipsis}");
     prefix_test(
-      ".foo { color: rgba(123, 456, 789, 0.5) }",
-      indoc! { r#"
-        .foo {
-          color: #7bffff80;
-        }
-      "#},
+      r#"
+      .foo {
+        text-overflow: ellipsis;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -o-text-overflow: ellipsis;
+        text-overflow: ellipsis;
+      }
+    "#},
       Browsers {
-        firefox: Some(49 << 16),
-        safari: Some(10 << 16),
-        ios_saf: Some(10 << 16),
+        safari: Some(4 << 16),
+        opera: Some(10 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { background-color: lab(40% 56.6 39) }",
-      indoc! { r#"
-        .foo {
-          background-color: #b32323;
-          background-color: lab(40% 56.6 39);
-        }
-      "#},
+      r#"
+      .foo {
+        -o-text-overflow: ellipsis;
+        text-overflow: ellipsis;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        text-overflow: ellipsis;
+      }
+    "#},
       Browsers {
-        chrome: Some(90 << 16),
+        safari: Some(4 << 16),
+        opera: Some(14 << 16),
         ..Browsers::default()
       },
     );
+  }
+
+  #[test]
+  fn test_ui() {
+    minify_test(".foo { resize: both }", ".foo{resize:both}");
+    minify_test(".foo { resize: Horizontal }", ".foo{resize:horizontal}");
+    minify_test(".foo { cursor: ew-resize }", ".foo{cursor:ew-resize}");
+    minify_test(
+      ".foo { cursor: url(\"test.cur\"), ew-resize }",
+      ".foo{cursor:url(test.cur),ew-resize}",
+    );
+    minify_test(
+      ".foo { cursor: url(\"test.cur\"), url(\"foo.cur\"), ew-resize }",
+      ".foo{cursor:url(test.cur),url(foo.cur),ew-resize}",
+    );
+    minify_test(
+      ".foo { cursor: url(a.png) 4 4, pointer }",
+      ".foo{cursor:url(a.png) 4 4,pointer}",
+    );
+    minify_test(
+      ".foo { cursor: url(a.png) 4.5 0, url(b.cur), url(\"c.svg\") 1 2, Auto }",
+      ".foo{cursor:url(a.png) 4.5 0,url(b.cur),url(c.svg) 1 2,auto}",
+    );
+    minify_test(".foo { cursor: url(a.png) }", ".foo{cursor:url(a.png)}");
+    minify_test(
+      ".foo { cursor: url(a.png) 4, pointer }",
+      ".foo{cursor:url(a.png) 4,pointer}",
+    );
+    assert!(crate::properties::ui::Cursor::parse_string("url(a.png)").is_err());
+    assert!(crate::properties::ui::Cursor::parse_string("url(a.png), url(b.png)").is_err());
+    assert!(crate::properties::ui::Cursor::parse_string("url(a.png) pointer").is_err());
+    assert!(crate::properties::ui::Cursor::parse_string("pointer, url(a.png)").is_err());
+    minify_test(".foo { caret-color: auto }", ".foo{caret-color:auto}");
+    minify_test(".foo { caret-color: yellow }", ".foo{caret-color:#ff0}");
+    minify_test(".foo { caret-shape: block }", ".foo{caret-shape:block}");
+    minify_test(".foo { caret: yellow block }", ".foo{caret:#ff0 block}");
+    minify_test(".foo { caret: block yellow }", ".foo{caret:#ff0 block}");
+    minify_test(".foo { caret: block }", ".foo{caret:block}");
+    minify_test(".foo { caret: yellow }", ".foo{caret:#ff0}");
+    minify_test(".foo { caret: auto auto }", ".foo{caret:auto}");
+    minify_test(".foo { caret: auto }", ".foo{caret:auto}");
+    minify_test(".foo { caret: yellow auto }", ".foo{caret:#ff0}");
+    minify_test(".foo { caret: auto block }", ".foo{caret:block}");
+    minify_test(".foo { user-select: none }", ".foo{user-select:none}");
+    minify_test(".foo { -webkit-user-select: none }", ".foo{-webkit-user-select:none}");
+    minify_test(".foo { accent-color: auto }", ".foo{accent-color:auto}");
+    minify_test(".foo { accent-color: yellow }", ".foo{accent-color:#ff0}");
+    minify_test(".foo { appearance: None }", ".foo{appearance:none}");
+    minify_test(
+      ".foo { -webkit-appearance: textfield }",
+      ".foo{-webkit-appearance:textfield}",
+    );
 
     prefix_test(
-      ".foo { background-color: lch(40% 68.735435 34.568626) }",
-      indoc! { r#"
-        .foo {
-          background-color: #b32323;
-          background-color: lch(40% 68.7354 34.5686);
-        }
-      "#},
+      r#"
+      .foo {
+        user-select: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-user-select: none;
+        -moz-user-select: none;
+        -ms-user-select: none;
+        user-select: none;
+      }
+    "#},
       Browsers {
-        chrome: Some(90 << 16),
+        safari: Some(8 << 16),
+        opera: Some(5 << 16),
+        firefox: Some(10 << 16),
+        ie: Some(10 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { background-color: oklab(59.686% 0.1009 0.1192); }",
-      indoc! { r#"
-        .foo {
-          background-color: #c65d07;
-          background-color: lab(52.2319% 40.1449 59.9171);
-        }
-      "#},
+      r#"
+      .foo {
+        -webkit-user-select: none;
+        -moz-user-select: none;
+        -ms-user-select: none;
+        user-select: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-user-select: none;
+        user-select: none;
+      }
+    "#},
       Browsers {
-        chrome: Some(90 << 16),
+        safari: Some(8 << 16),
+        opera: Some(80 << 16),
+        firefox: Some(80 << 16),
+        edge: Some(80 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { background-color: oklch(40% 0.1268735435 34.568626) }",
-      indoc! { r#"
-        .foo {
-          background-color: #7e250f;
-          background-color: lab(29.2661% 38.2437 35.3889);
-        }
-      "#},
+      r#"
+      .foo {
+        -webkit-user-select: none;
+        -moz-user-select: none;
+        -ms-user-select: none;
+        user-select: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        user-select: none;
+      }
+    "#},
       Browsers {
-        chrome: Some(90 << 16),
+        opera: Some(80 << 16),
+        firefox: Some(80 << 16),
+        edge: Some(80 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { background-color: lab(40% 56.6 39) }",
-      indoc! { r#"
-        .foo {
-          background-color: lab(40% 56.6 39);
-        }
-      "#},
+      r#"
+      .foo {
+        user-select: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-user-select: none;
+        user-select: none;
+      }
+    "#},
       Browsers {
         safari: Some(15 << 16),
         ..Browsers::default()
@@ -13126,101 +15112,137 @@ mod tests {
     );
 
     prefix_test(
-      ".foo { background-color: oklab(59.686% 0.1009 0.1192); }",
-      indoc! { r#"
-        .foo {
-          background-color: #c65d07;
-          background-color: lab(52.2319% 40.1449 59.9171);
-        }
-      "#},
+      r#"
+      .foo {
+        -webkit-user-select: none;
+        user-select: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        user-select: none;
+      }
+    "#},
       Browsers {
-        chrome: Some(90 << 16),
-        safari: Some(15 << 16),
+        safari: Some(17 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { background-color: oklab(59.686% 0.1009 0.1192); }",
-      indoc! { r#"
-        .foo {
-          background-color: #c65d07;
-          background-color: color(display-p3 .724144 .386777 .148795);
-          background-color: lab(52.2319% 40.1449 59.9171);
-        }
-      "#},
+      r#"
+      .foo {
+        -webkit-user-select: none;
+        -moz-user-select: none;
+        user-select: text;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-user-select: none;
+        -moz-user-select: none;
+        user-select: text;
+      }
+    "#},
       Browsers {
-        chrome: Some(90 << 16),
-        safari: Some(14 << 16),
+        firefox: Some(80 << 16),
+        edge: Some(80 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { background-color: lab(40% 56.6 39) }",
-      indoc! { r#"
-        .foo {
-          background-color: #b32323;
-          background-color: color(display-p3 .643308 .192455 .167712);
-          background-color: lab(40% 56.6 39);
-        }
-      "#},
+      r#"
+      .foo {
+        appearance: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-appearance: none;
+        -moz-appearance: none;
+        -ms-appearance: none;
+        appearance: none;
+      }
+    "#},
       Browsers {
-        chrome: Some(90 << 16),
-        safari: Some(14 << 16),
+        safari: Some(8 << 16),
+        chrome: Some(80 << 16),
+        firefox: Some(10 << 16),
+        ie: Some(11 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { background-color: oklch(59.686% 0.15619 49.7694); }",
-      indoc! { r#"
-        .foo {
-          background-color: #c65d06;
-          background-color: lab(52.2321% 40.1417 59.9527);
-        }
-      "#},
+      r#"
+      .foo {
+        -webkit-appearance: none;
+        -moz-appearance: none;
+        -ms-appearance: none;
+        appearance: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-appearance: none;
+        appearance: none;
+      }
+    "#},
       Browsers {
-        chrome: Some(90 << 16),
         safari: Some(15 << 16),
+        chrome: Some(85 << 16),
+        firefox: Some(80 << 16),
+        edge: Some(85 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { background-color: color(sRGB 0.41587 0.503670 0.36664); }",
-      indoc! { r#"
-        .foo {
-          background-color: #6a805d;
-          background-color: color(srgb .41587 .50367 .36664);
-        }
-      "#},
+      r#"
+      .foo {
+        -webkit-appearance: none;
+        -moz-appearance: none;
+        -ms-appearance: none;
+        appearance: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        appearance: none;
+      }
+    "#},
       Browsers {
-        chrome: Some(90 << 16),
+        chrome: Some(85 << 16),
+        firefox: Some(80 << 16),
+        edge: Some(85 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { background-color: color(display-p3 0.43313 0.50108 0.37950); }",
+      ".foo { caret-color: lch(50.998% 135.363 338) }",
       indoc! { r#"
         .foo {
-          background-color: #6a805d;
-          background-color: color(display-p3 .43313 .50108 .3795);
+          caret-color: #ee00be;
+          caret-color: color(display-p3 0.972962 -0.362078 0.804206);
+          caret-color: lch(50.998% 135.363 338);
         }
       "#},
       Browsers {
         chrome: Some(90 << 16),
+        safari: Some(14 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { background-color: color(display-p3 0.43313 0.50108 0.37950); }",
+      ".foo { caret: lch(50.998% 135.363 338) block }",
       indoc! { r#"
         .foo {
-          background-color: #6a805d;
-          background-color: color(display-p3 .43313 .50108 .3795);
+          caret: #ee00be block;
+          caret: color(display-p3 0.972962 -0.362078 0.804206) block;
+          caret: lch(50.998% 135.363 338) block;
         }
       "#},
       Browsers {
@@ -13231,172 +15253,142 @@ mod tests {
     );
 
     prefix_test(
-      ".foo { background-color: color(display-p3 0.43313 0.50108 0.37950); }",
+      ".foo { caret: lch(50.998% 135.363 338) var(--foo) }",
       indoc! { r#"
         .foo {
-          background-color: color(display-p3 .43313 .50108 .3795);
+          caret: #ee00be var(--foo);
         }
-      "#},
-      Browsers {
-        safari: Some(14 << 16),
-        ..Browsers::default()
-      },
-    );
 
-    prefix_test(
-      ".foo { background-color: color(display-p3 0.43313 0.50108 0.37950); }",
-      indoc! { r#"
-        .foo {
-          background-color: #6a805d;
-          background-color: color(display-p3 .43313 .50108 .3795);
+        @supports (color: lab(0% 0 0)) {
+          .foo {
+            caret: lab(50.998% 125.506 -50.7078) var(--foo);
+          }
         }
       "#},
       Browsers {
         chrome: Some(90 << 16),
-        safari: Some(15 << 16),
         ..Browsers::default()
       },
     );
+  }
 
-    prefix_test(
-      ".foo { background-color: color(display-p3 0.43313 0.50108 0.37950); }",
-      indoc! { r#"
-        .foo {
-          background-color: #6a805d;
-          background-color: color(display-p3 .43313 .50108 .3795);
-        }
-      "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+  #[test]
+  fn test_list() {
+    minify_test(".foo { list-style-type: disc; }", ".foo{list-style-type:disc}");
+    minify_test(".foo { list-style-type: \"★\"; }", ".foo{list-style-type:\"★\"}");
+    minify_test(
+      ".foo { list-style-type: symbols(cyclic '○' '●'); }",
+      ".foo{list-style-type:symbols(cyclic \"○\" \"●\")}",
     );
-
-    prefix_test(
-      ".foo { background-color: color(a98-rgb 0.44091 0.49971 0.37408); }",
-      indoc! { r#"
-        .foo {
-          background-color: #6a805d;
-          background-color: color(a98-rgb .44091 .49971 .37408);
-        }
-      "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+    minify_test(
+      ".foo { list-style-type: symbols('○' '●'); }",
+      ".foo{list-style-type:symbols(\"○\" \"●\")}",
     );
-
-    prefix_test(
-      ".foo { background-color: color(a98-rgb 0.44091 0.49971 0.37408); }",
-      indoc! { r#"
-        .foo {
-          background-color: color(a98-rgb .44091 .49971 .37408);
-        }
-      "#},
-      Browsers {
-        safari: Some(15 << 16),
-        ..Browsers::default()
-      },
+    minify_test(
+      ".foo { list-style-type: symbols(symbolic '○' '●'); }",
+      ".foo{list-style-type:symbols(\"○\" \"●\")}",
     );
-
-    prefix_test(
-      ".foo { background-color: color(prophoto-rgb 0.36589 0.41717 0.31333); }",
-      indoc! { r#"
-        .foo {
-          background-color: #6a805d;
-          background-color: color(prophoto-rgb .36589 .41717 .31333);
-        }
-      "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+    minify_test(
+      ".foo { list-style-type: symbols(symbolic url('ellipse.png')); }",
+      ".foo{list-style-type:symbols(url(ellipse.png))}",
+    );
+    minify_test(
+      ".foo { list-style-image: url('ellipse.png'); }",
+      ".foo{list-style-image:url(ellipse.png)}",
+    );
+    minify_test(
+      ".foo { list-style-position: outside; }",
+      ".foo{list-style-position:outside}",
+    );
+    minify_test(
+      ".foo { list-style: \"★\" url(ellipse.png) outside; }",
+      ".foo{list-style:\"★\" url(ellipse.png)}",
     );
 
-    prefix_test(
-      ".foo { background-color: color(rec2020 0.42210 0.47580 0.35605); }",
-      indoc! { r#"
-        .foo {
-          background-color: #728765;
-          background-color: color(rec2020 .4221 .4758 .35605);
-        }
-      "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+    test(
+      r#"
+      .foo {
+        list-style-type: disc;
+        list-style-image: url(ellipse.png);
+        list-style-position: outside;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        list-style: url("ellipse.png");
+      }
+    "#},
     );
 
-    prefix_test(
-      ".foo { background-color: color(xyz-d50 0.2005 0.14089 0.4472); }",
-      indoc! { r#"
-        .foo {
-          background-color: #7654cd;
-          background-color: color(xyz-d50 .2005 .14089 .4472);
-        }
-      "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+    test(
+      r#"
+      .foo {
+        list-style: \"★\" url(ellipse.png) outside;
+        list-style-image: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        list-style: \"★\";
+      }
+    "#},
     );
 
-    prefix_test(
-      ".foo { background-color: color(xyz-d65 0.21661 0.14602 0.59452); }",
-      indoc! { r#"
-        .foo {
-          background-color: #7654cd;
-          background-color: color(xyz .21661 .14602 .59452);
-        }
-      "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+    test(
+      r#"
+      .foo {
+        list-style: \"★\" url(ellipse.png) outside;
+        list-style-image: var(--img);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        list-style: \"★\" url("ellipse.png");
+        list-style-image: var(--img);
+      }
+    "#},
     );
 
     prefix_test(
-      ".foo { background-color: lch(50.998% 135.363 338) }",
+      ".foo { list-style-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
       indoc! { r#"
         .foo {
-          background-color: #ee00be;
-          background-color: color(display-p3 .972962 -.362078 .804206);
-          background-color: lch(50.998% 135.363 338);
+          list-style-image: -webkit-gradient(linear, 0 0, 0 100%, from(#ff0f0e), to(#7773ff));
+          list-style-image: -webkit-linear-gradient(#ff0f0e, #7773ff);
+          list-style-image: linear-gradient(#ff0f0e, #7773ff);
+          list-style-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
         }
       "#},
       Browsers {
-        chrome: Some(90 << 16),
-        safari: Some(14 << 16),
+        chrome: Some(8 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { color: lch(50.998% 135.363 338) }",
+      ".foo { list-style: \"★\" linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
       indoc! { r#"
         .foo {
-          color: #ee00be;
-          color: color(display-p3 .972962 -.362078 .804206);
-          color: lch(50.998% 135.363 338);
+          list-style: "★" linear-gradient(#ff0f0e, #7773ff);
+          list-style: "★" linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
         }
       "#},
       Browsers {
         chrome: Some(90 << 16),
-        safari: Some(14 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { background: var(--image) lch(40% 68.735435 34.568626) }",
+      ".foo { list-style: var(--foo) linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
       indoc! { r#"
         .foo {
-          background: var(--image) #b32323;
+          list-style: var(--foo) linear-gradient(#ff0f0e, #7773ff);
         }
 
         @supports (color: lab(0% 0 0)) {
           .foo {
-            background: var(--image) lab(40% 56.6 39);
+            list-style: var(--foo) linear-gradient(lab(56.208% 94.4644 98.8928), lab(51% 70.4544 -115.586));
           }
         }
       "#},
@@ -13405,69 +15397,206 @@ mod tests {
         ..Browsers::default()
       },
     );
+  }
 
-    prefix_test(
-      r#"
+  #[test]
+  fn test_counters() {
+    minify_test(".foo { counter-reset: none }", ".foo{counter-reset:none}");
+    minify_test(".foo { counter-reset: section }", ".foo{counter-reset:section}");
+    minify_test(".foo { counter-reset: section 0 }", ".foo{counter-reset:section}");
+    minify_test(".foo { counter-reset: section 2 }", ".foo{counter-reset:section 2}");
+    minify_test(".foo { counter-reset: section -1 }", ".foo{counter-reset:section -1}");
+    minify_test(
+      ".foo { counter-reset: chapter 0 section 3 figure }",
+      ".foo{counter-reset:chapter section 3 figure}",
+    );
+    minify_test(".foo { counter-set: section 0 }", ".foo{counter-set:section}");
+    minify_test(
+      ".foo { counter-set: section 5 page }",
+      ".foo{counter-set:section 5 page}",
+    );
+    minify_test(".foo { counter-increment: none }", ".foo{counter-increment:none}");
+    minify_test(
+      ".foo { counter-increment: section 1 }",
+      ".foo{counter-increment:section}",
+    );
+    minify_test(
+      ".foo { counter-increment: section 0 }",
+      ".foo{counter-increment:section 0}",
+    );
+    minify_test(
+      ".foo { counter-increment: chapter 1 section 2 }",
+      ".foo{counter-increment:chapter section 2}",
+    );
+    minify_test(".foo { counter-reset: inherit }", ".foo{counter-reset:inherit}");
+    test(
+      ".foo { counter-reset: chapter 0 section 3; counter-increment: section 1 figure -2 }",
+      indoc! {r#"
       .foo {
-        --a: rgb(0 0 0 / var(--alpha));
-        --b: rgb(50% 50% 50% / var(--alpha));
-        --c: rgb(var(--x) 0 0);
-        --d: rgb(0 var(--x) 0);
-        --e: rgb(0 0 var(--x));
-        --f: rgb(var(--x) 0 0 / var(--alpha));
-        --g: rgb(0 var(--x) 0 / var(--alpha));
-        --h: rgb(0 0 var(--x) / var(--alpha));
-        --i: rgb(none 0 0 / var(--alpha));
+        counter-reset: chapter section 3;
+        counter-increment: section figure -2;
       }
-      "#,
+    "#},
+    );
+
+    let counters =
+      |s: &'static str| match Property::parse_string("counter-reset".into(), s, ParserOptions::default()) {
+        Ok(Property::CounterReset(counters)) => Some(counters),
+        _ => None,
+      };
+    assert!(counters("section 1.5").is_none());
+    assert!(counters("3").is_none());
+    assert!(counters("section none").is_none());
+    assert!(counters("").is_none());
+  }
+
+  #[test]
+  fn test_content() {
+    minify_test(".foo { content: normal }", ".foo{content:normal}");
+    minify_test(".foo { content: none }", ".foo{content:none}");
+    minify_test(".foo { content: 'foo' }", ".foo{content:\"foo\"}");
+    minify_test(
+      ".foo { content: \"a\" url(foo.png) counter(c) attr(title) }",
+      ".foo{content:\"a\" url(foo.png) counter(c) attr(title)}",
+    );
+    minify_test(
+      ".foo { content: open-quote \"x\" close-quote no-open-quote no-close-quote }",
+      ".foo{content:open-quote \"x\" close-quote no-open-quote no-close-quote}",
+    );
+    minify_test(
+      ".foo { content: counter(item, upper-roman) \". \" }",
+      ".foo{content:counter(item,upper-roman) \". \"}",
+    );
+    minify_test(
+      ".foo { content: counters(item, \".\", lower-alpha) }",
+      ".foo{content:counters(item,\".\",lower-alpha)}",
+    );
+    minify_test(
+      ".foo { content: counters(item, \".\") }",
+      ".foo{content:counters(item,\".\")}",
+    );
+    minify_test(
+      ".foo { content: url(icon.png) / \"Icon\" }",
+      ".foo{content:url(icon.png)/\"Icon\"}",
+    );
+    minify_test(
+      ".foo { content: \"→\" / \"Next \" counter(page) attr(title) }",
+      ".foo{content:\"→\"/\"Next \" counter(page) attr(title)}",
+    );
+    minify_test(r#".foo { content: "say \"hi\"" }"#, r#".foo{content:'say "hi"'}"#);
+    minify_test(r#".foo { content: 'it\'s' }"#, r#".foo{content:"it's"}"#);
+    minify_test(r#".foo { content: "\"it's\"" }"#, r#".foo{content:"\"it's\""}"#);
+    minify_test(".foo { content: var(--foo) }", ".foo{content:var(--foo)}");
+    test(
+      ".foo { content: url(icon.png) / \"Icon\" }",
       indoc! { r#"
         .foo {
-          --a: rgba(0, 0, 0, var(--alpha));
-          --b: rgba(128, 128, 128, var(--alpha));
-          --c: rgb(var(--x) 0 0);
-          --d: rgb(0 var(--x) 0);
-          --e: rgb(0 0 var(--x));
-          --f: rgb(var(--x) 0 0 / var(--alpha));
-          --g: rgb(0 var(--x) 0 / var(--alpha));
-          --h: rgb(0 0 var(--x) / var(--alpha));
-          --i: rgb(none 0 0 / var(--alpha));
+          content: url("icon.png") / "Icon";
         }
       "#},
-      Browsers {
-        safari: Some(11 << 16),
-        ..Browsers::default()
-      },
     );
-
-    prefix_test(
-      r#"
-      .foo {
-        --a: rgb(0 0 0 / var(--alpha));
-        --b: rgb(50% 50% 50% / var(--alpha));
-        --c: rgb(var(--x) 0 0);
-        --d: rgb(0 var(--x) 0);
-        --e: rgb(0 0 var(--x));
-        --f: rgb(var(--x) 0 0 / var(--alpha));
-        --g: rgb(0 var(--x) 0 / var(--alpha));
-        --h: rgb(0 0 var(--x) / var(--alpha));
-        --i: rgb(none 0 0 / var(--alpha));
-      }
-      "#,
+    test(
+      ".foo { content: \"Chapter \" counter(chapter, upper-roman) }",
       indoc! { r#"
         .foo {
-          --a: rgb(0 0 0 / var(--alpha));
-          --b: rgb(128 128 128 / var(--alpha));
-          --c: rgb(var(--x) 0 0);
-          --d: rgb(0 var(--x) 0);
-          --e: rgb(0 0 var(--x));
-          --f: rgb(var(--x) 0 0 / var(--alpha));
-          --g: rgb(0 var(--x) 0 / var(--alpha));
-          --h: rgb(0 0 var(--x) / var(--alpha));
-          --i: rgb(none 0 0 / var(--alpha));
+          content: "Chapter " counter(chapter, upper-roman);
         }
       "#},
+    );
+  }
+
+  #[test]
+  fn test_image_set() {
+    // Spec: https://drafts.csswg.org/css-images-4/#image-set-notation
+    // WPT: https://github.com/web-platform-tests/wpt/blob/master/css/css-images/image-set/image-set-parsing.html
+    // test image-set(<string>)
+    minify_test(
+      ".foo { background: image-set(\"foo.png\" 2x, url(bar.png) 1x) }",
+      ".foo{background:image-set(\"foo.png\" 2x,\"bar.png\" 1x)}",
+    );
+
+    // test image-set(type(<string>))
+    minify_test(
+      ".foo { background: image-set('foo.webp' type('webp'), url(foo.jpg)) }",
+      ".foo{background:image-set(\"foo.webp\" 1x type(\"webp\"),\"foo.jpg\" 1x)}",
+    );
+    minify_test(
+      ".foo { background: image-set('foo.avif' 2x type('image/avif'), url(foo.png)) }",
+      ".foo{background:image-set(\"foo.avif\" 2x type(\"image/avif\"),\"foo.png\" 1x)}",
+    );
+    minify_test(
+      ".foo { background: image-set(url('example.png') 3x type('image/png')) }",
+      ".foo{background:image-set(\"example.png\" 3x type(\"image/png\"))}",
+    );
+
+    minify_test(
+      ".foo { background: image-set(url(example.png) type('image/png') 1x) }",
+      ".foo{background:image-set(\"example.png\" 1x type(\"image/png\"))}",
+    );
+
+    // test mixed resolution units
+    minify_test(
+      ".foo { background: image-set(\"a.png\" 1x, \"b.png\" 2dppx, \"c.png\" 300dpi) }",
+      ".foo{background:image-set(\"a.png\" 1x,\"b.png\" 2x,\"c.png\" 300dpi)}",
+    );
+    minify_test(
+      ".foo { background: image-set(\"a.png\" 1x, \"b.png\" 2x type(\"image/png\")) }",
+      ".foo{background:image-set(\"a.png\" 1x,\"b.png\" 2x type(\"image/png\"))}",
+    );
+    minify_test(
+      ".foo { background: -webkit-image-set(url(foo.png) 96dpi, url(bar.png) 192dpi) }",
+      ".foo{background:-webkit-image-set(url(foo.png) 1x,url(bar.png) 2x)}",
+    );
+
+    minify_test(
+      ".foo { background: -webkit-image-set(url(\"foo.png\") 2x, url(bar.png) 1x) }",
+      ".foo{background:-webkit-image-set(url(foo.png) 2x,url(bar.png) 1x)}",
+    );
+
+    test(
+      r#"
+      .foo {
+        background: -webkit-image-set(url("foo.png") 2x, url(bar.png) 1x);
+        background: image-set(url("foo.png") 2x, url(bar.png) 1x);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        background: -webkit-image-set(url("foo.png") 2x, url("bar.png") 1x);
+        background: image-set("foo.png" 2x, "bar.png" 1x);
+      }
+    "#},
+    );
+
+    // test image-set(<gradient>)
+    test(
+      r#"
+      .foo {
+        background: image-set(linear-gradient(cornflowerblue, white) 1x, url("detailed-gradient.png") 3x);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        background: image-set(linear-gradient(#6495ed, #fff) 1x, "detailed-gradient.png" 3x);
+      }
+    "#},
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        background: image-set(url("foo.png") 2x, url(bar.png) 1x);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        background: -webkit-image-set(url("foo.png") 2x, url("bar.png") 1x);
+        background: image-set("foo.png" 2x, "bar.png" 1x);
+      }
+    "#},
       Browsers {
-        safari: Some(13 << 16),
+        chrome: Some(85 << 16),
+        firefox: Some(80 << 16),
         ..Browsers::default()
       },
     );
@@ -13475,32 +15604,17 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        --a: hsl(270 100% 50% / var(--alpha));
-        --b: hsl(var(--x) 0 0);
-        --c: hsl(0 var(--x) 0);
-        --d: hsl(0 0 var(--x));
-        --e: hsl(var(--x) 0 0 / var(--alpha));
-        --f: hsl(0 var(--x) 0 / var(--alpha));
-        --g: hsl(0 0 var(--x) / var(--alpha));
-        --h: hsl(270 100% 50% / calc(var(--alpha) / 2));
-        --i: hsl(none 100% 50% / var(--alpha));
+        background: image-set("a.png" 1x, "b.png" 192dpi);
       }
-      "#,
-      indoc! { r#"
-        .foo {
-          --a: hsla(270, 100%, 50%, var(--alpha));
-          --b: hsl(var(--x) 0 0);
-          --c: hsl(0 var(--x) 0);
-          --d: hsl(0 0 var(--x));
-          --e: hsl(var(--x) 0 0 / var(--alpha));
-          --f: hsl(0 var(--x) 0 / var(--alpha));
-          --g: hsl(0 0 var(--x) / var(--alpha));
-          --h: hsla(270, 100%, 50%, calc(var(--alpha) / 2));
-          --i: hsl(none 100% 50% / var(--alpha));
-        }
-      "#},
+    "#,
+      indoc! {r#"
+      .foo {
+        background: -webkit-image-set(url("a.png") 1x, url("b.png") 2x);
+        background: image-set("a.png" 1x, "b.png" 192dpi);
+      }
+    "#},
       Browsers {
-        safari: Some(11 << 16),
+        chrome: Some(85 << 16),
         ..Browsers::default()
       },
     );
@@ -13508,505 +15622,1382 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        --a: hsl(270 100% 50% / var(--alpha));
-        --b: hsl(var(--x) 0 0);
-        --c: hsl(0 var(--x) 0);
-        --d: hsl(0 0 var(--x));
-        --e: hsl(var(--x) 0 0 / var(--alpha));
-        --f: hsl(0 var(--x) 0 / var(--alpha));
-        --g: hsl(0 0 var(--x) / var(--alpha));
-        --h: hsl(270 100% 50% / calc(var(--alpha) / 2));
-        --i: hsl(none 100% 50% / var(--alpha));
+        background: -webkit-image-set(url("foo.png") 2x, url(bar.png) 1x);
+        background: image-set(url("foo.png") 2x, url(bar.png) 1x);
       }
-      "#,
-      indoc! { r#"
-        .foo {
-          --a: hsl(270 100% 50% / var(--alpha));
-          --b: hsl(var(--x) 0 0);
-          --c: hsl(0 var(--x) 0);
-          --d: hsl(0 0 var(--x));
-          --e: hsl(var(--x) 0 0 / var(--alpha));
-          --f: hsl(0 var(--x) 0 / var(--alpha));
-          --g: hsl(0 0 var(--x) / var(--alpha));
-          --h: hsl(270 100% 50% / calc(var(--alpha) / 2));
-          --i: hsl(none 100% 50% / var(--alpha));
-        }
-      "#},
+    "#,
+      indoc! {r#"
+      .foo {
+        background: image-set("foo.png" 2x, "bar.png" 1x);
+      }
+    "#},
       Browsers {
-        safari: Some(13 << 16),
+        firefox: Some(80 << 16),
         ..Browsers::default()
       },
     );
 
-    test(
+    prefix_test(
       r#"
       .foo {
-        --a: rgb(50% 50% 50% / calc(100% / 2));
-        --b: hsl(calc(360deg / 2) 50% 50%);
-        --c: oklab(40.101% calc(0.1 + 0.2) 0.0453);
-        --d: color(display-p3 0.43313 0.50108 calc(0.1 + 0.2));
-        --e: rgb(calc(255 / 2), calc(255 / 2), calc(255 / 2));
+        background: -webkit-image-set(url("foo.png") 2x, url(bar.png) 1x);
       }
-      "#,
-      indoc! { r#"
-        .foo {
-          --a: #80808080;
-          --b: #40bfbf;
-          --c: oklab(40.101% .3 .0453);
-          --d: color(display-p3 .43313 .50108 .3);
-          --e: gray;
-        }
-      "#},
+    "#,
+      indoc! {r#"
+      .foo {
+        background: -webkit-image-set(url("foo.png") 2x, url("bar.png") 1x);
+      }
+    "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
     );
-  }
 
-  #[test]
-  fn test_relative_color() {
-    fn test(input: &str, output: &str) {
-      let output = CssColor::parse_string(output)
-        .unwrap()
-        .to_css_string(PrinterOptions {
-          minify: true,
-          ..PrinterOptions::default()
-        })
-        .unwrap();
-      minify_test(
-        &format!(".foo {{ color: {} }}", input),
-        &format!(".foo{{color:{}}}", output),
+    for property in &[
+      "background",
+      "background-image",
+      "border-image-source",
+      "border-image",
+      "border-image-source",
+      "-webkit-mask-image",
+      "-webkit-mask",
+      "list-style-image",
+      "list-style",
+    ] {
+      prefix_test(
+        &format!(
+          r#"
+        .foo {{
+          {}: url(foo.png);
+          {}: image-set(url("foo.png") 2x, url(bar.png) 1x);
+        }}
+      "#,
+          property, property
+        ),
+        &format!(
+          indoc! {r#"
+        .foo {{
+          {}: url("foo.png");
+          {}: -webkit-image-set(url("foo.png") 2x, url("bar.png") 1x);
+          {}: image-set("foo.png" 2x, "bar.png" 1x);
+        }}
+      "#},
+          property, property, property
+        ),
+        Browsers {
+          ie: Some(11 << 16),
+          chrome: Some(95 << 16),
+          ..Browsers::default()
+        },
       );
-    }
 
-    test("lab(from indianred calc(l * .8) a b)", "lab(43.1402% 45.7516 23.1557)");
-    test("lch(from indianred calc(l + 10%) c h)", "lch(63.9252% 51.2776 26.8448)");
-    test("lch(from indianred l calc(c - 50) h)", "lch(53.9252% 1.27763 26.8448)");
-    test(
-      "lch(from indianred l c calc(h + 180deg))",
-      "lch(53.9252% 51.2776 206.845)",
+      prefix_test(
+        &format!(
+          r#"
+        .foo {{
+          {}: url(foo.png);
+          {}: image-set(url("foo.png") 2x, url(bar.png) 1x);
+        }}
+      "#,
+          property, property
+        ),
+        &format!(
+          indoc! {r#"
+        .foo {{
+          {}: -webkit-image-set(url("foo.png") 2x, url("bar.png") 1x);
+          {}: image-set("foo.png" 2x, "bar.png" 1x);
+        }}
+      "#},
+          property, property
+        ),
+        Browsers {
+          chrome: Some(95 << 16),
+          ..Browsers::default()
+        },
+      );
+    }
+  }
+
+  #[test]
+  fn test_color() {
+    minify_test(".foo { color: yellow }", ".foo{color:#ff0}");
+    minify_test(".foo { color: rgb(255, 255, 0) }", ".foo{color:#ff0}");
+    minify_test(".foo { color: rgba(255, 255, 0, 1) }", ".foo{color:#ff0}");
+    minify_test(".foo { color: rgba(255, 255, 0, 0.8) }", ".foo{color:#ff0c}");
+    minify_test(".foo { color: rgb(128, 128, 128) }", ".foo{color:gray}");
+    minify_test(".foo { color: rgb(123, 255, 255) }", ".foo{color:#7bffff}");
+    minify_test(".foo { color: rgba(123, 255, 255, 0.5) }", ".foo{color:#7bffff80}");
+    minify_test(".foo { color: rgb(123 255 255) }", ".foo{color:#7bffff}");
+    minify_test(".foo { color: rgb(123 255 255 / .5) }", ".foo{color:#7bffff80}");
+    minify_test(".foo { color: rgb(123 255 255 / 50%) }", ".foo{color:#7bffff80}");
+    minify_test(".foo { color: rgb(48% 100% 100% / 50%) }", ".foo{color:#7affff80}");
+    minify_test(".foo { color: hsl(100deg, 100%, 50%) }", ".foo{color:#5f0}");
+    minify_test(".foo { color: hsl(100, 100%, 50%) }", ".foo{color:#5f0}");
+    minify_test(".foo { color: hsl(100 100% 50%) }", ".foo{color:#5f0}");
+    minify_test(".foo { color: hsl(100, 100%, 50%, .8) }", ".foo{color:#5f0c}");
+    minify_test(".foo { color: hsl(100 100% 50% / .8) }", ".foo{color:#5f0c}");
+    minify_test(".foo { color: hsla(100, 100%, 50%, .8) }", ".foo{color:#5f0c}");
+    minify_test(".foo { color: hsla(100 100% 50% / .8) }", ".foo{color:#5f0c}");
+    minify_test(".foo { color: transparent }", ".foo{color:#0000}");
+    minify_test(".foo { color: currentColor }", ".foo{color:currentColor}");
+    minify_test(".foo { color: hwb(194 0% 0%) }", ".foo{color:#00c4ff}");
+    minify_test(".foo { color: hwb(194 0% 0% / 50%) }", ".foo{color:#00c4ff80}");
+    minify_test(".foo { color: hwb(194 0% 50%) }", ".foo{color:#006280}");
+    minify_test(".foo { color: hwb(194 50% 0%) }", ".foo{color:#80e1ff}");
+    minify_test(".foo { color: hwb(194 50% 50%) }", ".foo{color:gray}");
+    minify_test(".foo { color: hwb(0.5turn 0% 0%) }", ".foo{color:#0ff}");
+    minify_test(".foo { color: hwb(194 80% 40%) }", ".foo{color:#aaa}");
+    minify_test(".foo { color: hwb(120 20% 80%) }", ".foo{color:#333}");
+    minify_test(".foo { color: hwb(0 100% 100%) }", ".foo{color:gray}");
+
+    for (input, output) in [
+      ("#ffffff", "#fff"),
+      ("white", "#fff"),
+      ("#ff0000", "red"),
+      ("#000080", "navy"),
+      ("#c0c0c0", "silver"),
+      ("#808080", "gray"),
+      ("#f0ffff", "azure"),
+      ("#aabbcc", "#abc"),
+      ("#abcdef", "#abcdef"),
+      ("#ff00ff", "#f0f"),
+      ("#11223344", "#1234"),
+      ("#ffffff80", "#ffffff80"),
+      ("rgba(0, 0, 0, 0)", "#0000"),
+      ("rgb(255 0 0 / 50%)", "#ff000080"),
+      ("lightgoldenrodyellow", "#fafad2"),
+      ("darkslategray", "#2f4f4f"),
+    ] {
+      minify_test(
+        &format!(".foo {{ color: {} }}", input),
+        &format!(".foo{{color:{}}}", output),
+      );
+    }
+    minify_test(".foo { color: ActiveText }", ".foo{color:ActiveText}");
+    minify_test(".foo { color: canvastext }", ".foo{color:CanvasText}");
+    minify_test(".foo { color: LINKTEXT }", ".foo{color:LinkText}");
+    minify_test(".foo { background: ButtonFace }", ".foo{background:ButtonFace}");
+    minify_test(
+      ".foo { border: 1px solid buttonborder }",
+      ".foo{border:1px solid ButtonBorder}",
     );
-    test("lch(from orchid l 30 h)", "lch(62.7526% 30 326.969)");
-    test("lch(from orchid l 30 h)", "lch(62.7526% 30 326.969)");
-    test("lch(from peru calc(l * 0.8) c h)", "lch(49.8022% 54.0117 63.6804)");
-    test("rgb(from indianred 255 g b)", "rgb(255, 92, 92)");
-    test("rgb(from indianred r g b / .5)", "rgba(205, 92, 92, .5)");
-    test(
-      "rgb(from rgba(205, 92, 92, .5) r g b / calc(alpha + .2))",
-      "rgba(205, 92, 92, .7)",
+    minify_test(".foo { color: Menu }", ".foo{color:Menu}");
+    prefix_test(
+      ".foo { color: Canvas }",
+      indoc! { r#"
+        .foo {
+          color: Canvas;
+        }
+      "#},
+      Browsers {
+        ie: Some(11 << 16),
+        ..Browsers::default()
+      },
     );
-    test(
-      "rgb(from rgba(205, 92, 92, .5) r g b / calc(alpha + 20%))",
-      "rgba(205, 92, 92, .7)",
+    assert!(CssColor::parse_string("CanvasTexts").is_err());
+    assert!(CssColor::parse_string("color-mix(in srgb, Canvas, red)").is_err());
+    assert!(CssColor::parse_string("rgb(from Canvas r g b)").is_err());
+    assert!(CssColor::parse_string("color(from currentColor srgb r g b)").is_err());
+    minify_test(
+      ".foo { color: lab(29.2345% 39.3825 20.0664); }",
+      ".foo{color:lab(29.2345% 39.3825 20.0664)}",
     );
-    test("lch(from indianred l sin(c) h)", "lch(53.9252% .84797 26.8448)");
-    test("lch(from indianred l sqrt(c) h)", "lch(53.9252% 7.16084 26.8448)");
-    test("lch(from indianred l c sin(h))", "lch(53.9252% 51.2776 .990043)");
-
-    // The following tests were converted from WPT: https://github.com/web-platform-tests/wpt/blob/master/css/css-color/parsing/relative-color-valid.html
-    // Find: test_valid_value\(`color`, `(.*?)`,\s*`(.*?)`\)
-    // Replace: test("$1", "$2")
-
-    // Testing no modifications.
-    test("rgb(from rebeccapurple r g b)", "#639");
-    test("rgb(from rebeccapurple r g b / alpha)", "#639");
-    test("rgb(from rgb(20%, 40%, 60%, 80%) r g b / alpha)", "#369c");
-    test("rgb(from hsl(120deg 20% 50% / .5) r g b / alpha)", "#66996680");
-
-    // Test nesting relative colors.
-    test("rgb(from rgb(from rebeccapurple r g b) r g b)", "#639");
-
-    // Testing non-sRGB origin colors to see gamut mapping.
-    test("rgb(from color(display-p3 0 1 0) r g b / alpha)", "#00f942"); // Naive clip based mapping would give rgb(0, 255, 0).
-    test("rgb(from lab(100% 104.3 -50.9) r g b)", "#fff"); // Naive clip based mapping would give rgb(255, 150, 255).
-    test("rgb(from lab(0% 104.3 -50.9) r g b)", "#2a0022"); // Naive clip based mapping would give rgb(90, 0, 76). NOTE: 0% lightness in Lab/LCH does not automatically correspond with sRGB black.
-    test("rgb(from lch(100% 116 334) r g b)", "#fff"); // Naive clip based mapping would give rgb(255, 150, 255).
-    test("rgb(from lch(0% 116 334) r g b)", "#2a0022"); // Naive clip based mapping would give rgb(90, 0, 76). NOTE: 0% lightness in Lab/LCH does not automatically correspond with sRGB black.
-    test("rgb(from oklab(100% 0.365 -0.16) r g b)", "#fff"); // Naive clip based mapping would give rgb(255, 92, 255).
-    test("rgb(from oklab(0% 0.365 -0.16) r g b)", "#000"); // Naive clip based mapping would give rgb(19, 0, 24).
-    test("rgb(from oklch(100% 0.399 336.3) r g b)", "#fff"); // Naive clip based mapping would give rgb(255, 91, 255).
-    test("rgb(from oklch(0% 0.399 336.3) r g b)", "#000"); // Naive clip based mapping would give rgb(20, 0, 24).
-
-    // Testing replacement with 0.
-    test("rgb(from rebeccapurple 0 0 0)", "rgb(0, 0, 0)");
-    test("rgb(from rebeccapurple 0 0 0 / 0)", "rgba(0, 0, 0, 0)");
-    test("rgb(from rebeccapurple 0 g b / alpha)", "rgb(0, 51, 153)");
-    test("rgb(from rebeccapurple r 0 b / alpha)", "rgb(102, 0, 153)");
-    test("rgb(from rebeccapurple r g 0 / alpha)", "rgb(102, 51, 0)");
-    test("rgb(from rebeccapurple r g b / 0)", "rgba(102, 51, 153, 0)");
-    test(
-      "rgb(from rgb(20%, 40%, 60%, 80%) 0 g b / alpha)",
-      "rgba(0, 102, 153, 0.8)",
+    minify_test(
+      ".foo { color: lab(29.2345% 39.3825 20.0664 / 100%); }",
+      ".foo{color:lab(29.2345% 39.3825 20.0664)}",
     );
-    test(
-      "rgb(from rgb(20%, 40%, 60%, 80%) r 0 b / alpha)",
-      "rgba(51, 0, 153, 0.8)",
+    minify_test(
+      ".foo { color: lab(29.2345% 39.3825 20.0664 / 50%); }",
+      ".foo{color:lab(29.2345% 39.3825 20.0664/.5)}",
     );
-    test(
-      "rgb(from rgb(20%, 40%, 60%, 80%) r g 0 / alpha)",
-      "rgba(51, 102, 0, 0.8)",
+    minify_test(
+      ".foo { color: lch(29.2345% 44.2 27); }",
+      ".foo{color:lch(29.2345% 44.2 27)}",
     );
-    test("rgb(from rgb(20%, 40%, 60%, 80%) r g b / 0)", "rgba(51, 102, 153, 0)");
-
-    // Testing replacement with a number.
-    test("rgb(from rebeccapurple 25 g b / alpha)", "rgb(25, 51, 153)");
-    test("rgb(from rebeccapurple r 25 b / alpha)", "rgb(102, 25, 153)");
-    test("rgb(from rebeccapurple r g 25 / alpha)", "rgb(102, 51, 25)");
-    test("rgb(from rebeccapurple r g b / .25)", "rgba(102, 51, 153, 0.25)");
-    test(
-      "rgb(from rgb(20%, 40%, 60%, 80%) 25 g b / alpha)",
-      "rgba(25, 102, 153, 0.8)",
+    minify_test(
+      ".foo { color: lch(29.2345% 44.2 45deg); }",
+      ".foo{color:lch(29.2345% 44.2 45)}",
     );
-    test(
-      "rgb(from rgb(20%, 40%, 60%, 80%) r 25 b / alpha)",
-      "rgba(51, 25, 153, 0.8)",
+    minify_test(
+      ".foo { color: lch(29.2345% 44.2 .5turn); }",
+      ".foo{color:lch(29.2345% 44.2 180)}",
     );
-    test(
-      "rgb(from rgb(20%, 40%, 60%, 80%) r g 25 / alpha)",
-      "rgba(51, 102, 25, 0.8)",
+    minify_test(
+      ".foo { color: lch(29.2345% 44.2 27 / 100%); }",
+      ".foo{color:lch(29.2345% 44.2 27)}",
     );
-    test(
-      "rgb(from rgb(20%, 40%, 60%, 80%) r g b / .20)",
-      "rgba(51, 102, 153, 0.2)",
+    minify_test(
+      ".foo { color: lch(29.2345% 44.2 27 / 50%); }",
+      ".foo{color:lch(29.2345% 44.2 27/.5)}",
     );
-
-    // Testing replacement with a percentage.
-    test("rgb(from rebeccapurple 20% g b / alpha)", "rgb(51, 51, 153)");
-    test("rgb(from rebeccapurple r 20% b / alpha)", "rgb(102, 51, 153)");
-    test("rgb(from rebeccapurple r g 20% / alpha)", "rgb(102, 51, 51)");
-    test("rgb(from rebeccapurple r g b / 20%)", "rgba(102, 51, 153, 0.2)");
-    test(
-      "rgb(from rgb(20%, 40%, 60%, 80%) 20% g b / alpha)",
-      "rgba(51, 102, 153, 0.8)",
+    minify_test(
+      ".foo { color: lab(none 39.3825 20.0664); }",
+      ".foo{color:lab(none 39.3825 20.0664)}",
     );
-    test(
-      "rgb(from rgb(20%, 40%, 60%, 80%) r 20% b / alpha)",
-      "rgba(51, 51, 153, 0.8)",
+    minify_test(
+      ".foo { color: lab(29.2345% none 20.0664 / none); }",
+      ".foo{color:lab(29.2345% none 20.0664/none)}",
     );
-    test(
-      "rgb(from rgb(20%, 40%, 60%, 80%) r g 20% / alpha)",
-      "rgba(51, 102, 51, 0.8)",
+    minify_test(
+      ".foo { color: LCH(29.2345% 44.2 none / .5); }",
+      ".foo{color:lch(29.2345% 44.2 none/.5)}",
     );
-    test(
-      "rgb(from rgb(20%, 40%, 60%, 80%) r g b / 20%)",
-      "rgba(51, 102, 153, 0.2)",
+    minify_test(
+      ".foo { color: oklab(40.101% 0.1147 0.0453); }",
+      ".foo{color:oklab(40.101% .1147 .0453)}",
+    );
+    minify_test(
+      ".foo { color: oklch(40.101% 0.12332 21.555); }",
+      ".foo{color:oklch(40.101% .12332 21.555)}",
+    );
+    minify_test(
+      ".foo { color: oklch(40.101% 0.12332 .5turn); }",
+      ".foo{color:oklch(40.101% .12332 180)}",
     );
 
-    // Testing replacement with a number for r, g, b but percent for alpha.
-    test("rgb(from rebeccapurple 25 g b / 25%)", "rgba(25, 51, 153, 0.25)");
-    test("rgb(from rebeccapurple r 25 b / 25%)", "rgba(102, 25, 153, 0.25)");
-    test("rgb(from rebeccapurple r g 25 / 25%)", "rgba(102, 51, 25, 0.25)");
-    test(
-      "rgb(from rgb(20%, 40%, 60%, 80%) 25 g b / 25%)",
-      "rgba(25, 102, 153, 0.25)",
+    // Out of gamut colors are mapped into sRGB by reducing chroma, preserving lightness and hue.
+    let color = CssColor::parse_string("oklch(70% 0.4 150)").unwrap();
+    let mapped = crate::values::color::OKLCH::from(&color.to_rgb());
+    assert!(mapped.c < 0.4);
+    assert!((mapped.l - 0.7).abs() < 0.05);
+    assert!((mapped.h - 150.0).abs() < 5.0);
+    minify_test(
+      ".foo { color: color(display-p3 1 0.5 0); }",
+      ".foo{color:color(display-p3 1 .5)}",
     );
-    test(
-      "rgb(from rgb(20%, 40%, 60%, 80%) r 25 b / 25%)",
-      "rgba(51, 25, 153, 0.25)",
+    minify_test(
+      ".foo { color: color(display-p3 100% 50% 0%); }",
+      ".foo{color:color(display-p3 1 .5)}",
     );
-    test(
-      "rgb(from rgb(20%, 40%, 60%, 80%) r g 25 / 25%)",
-      "rgba(51, 102, 25, 0.25)",
+    minify_test(
+      ".foo { color: color(xyz-d50 0.2005 0.14089 0.4472); }",
+      ".foo{color:color(xyz-d50 .2005 .14089 .4472)}",
     );
-
-    // Testing permutation.
-    test("rgb(from rebeccapurple g b r)", "rgb(51, 153, 102)");
-    test("rgb(from rebeccapurple b alpha r / g)", "rgba(153, 255, 102, 0.2)");
-    test("rgb(from rebeccapurple r r r / r)", "rgba(102, 102, 102, 0.4)");
-    test(
-      "rgb(from rebeccapurple alpha alpha alpha / alpha)",
-      "rgb(255, 255, 255)",
+    minify_test(
+      ".foo { color: color(xyz-d50 20.05% 14.089% 44.72%); }",
+      ".foo{color:color(xyz-d50 .2005 .14089 .4472)}",
     );
-    test("rgb(from rgb(20%, 40%, 60%, 80%) g b r)", "rgb(102, 153, 51)");
-    test(
-      "rgb(from rgb(20%, 40%, 60%, 80%) b alpha r / g)",
-      "rgba(153, 204, 51, 0.4)",
+    minify_test(
+      ".foo { color: color(xyz-d65 0.2005 0.14089 0.4472); }",
+      ".foo{color:color(xyz .2005 .14089 .4472)}",
     );
-    test("rgb(from rgb(20%, 40%, 60%, 80%) r r r / r)", "rgba(51, 51, 51, 0.2)");
-    test(
-      "rgb(from rgb(20%, 40%, 60%, 80%) alpha alpha alpha / alpha)",
-      "rgba(204, 204, 204, 0.8)",
+    minify_test(
+      ".foo { color: color(xyz-d65 20.05% 14.089% 44.72%); }",
+      ".foo{color:color(xyz .2005 .14089 .4472)}",
     );
-
-    // Testing mixes of number and percentage. (These would not be allowed in the non-relative syntax).
-    test("rgb(from rebeccapurple r 20% 10)", "rgb(102, 51, 10)");
-    test("rgb(from rebeccapurple r 10 20%)", "rgb(102, 10, 51)");
-    test("rgb(from rebeccapurple 0% 10 10)", "rgb(0, 10, 10)");
-    test("rgb(from rgb(20%, 40%, 60%, 80%) r 20% 10)", "rgb(51, 51, 10)");
-    test("rgb(from rgb(20%, 40%, 60%, 80%) r 10 20%)", "rgb(51, 10, 51)");
-    test("rgb(from rgb(20%, 40%, 60%, 80%) 0% 10 10)", "rgb(0, 10, 10)");
-
-    // Testing with calc().
-    test("rgb(from rebeccapurple calc(r) calc(g) calc(b))", "rgb(102, 51, 153)");
-    test("rgb(from rebeccapurple r calc(g * 2) 10)", "rgb(102, 102, 10)");
-    test("rgb(from rebeccapurple b calc(r * .5) 10)", "rgb(153, 51, 10)");
-    test("rgb(from rebeccapurple r calc(g * .5 + g * .5) 10)", "rgb(102, 51, 10)");
-    test("rgb(from rebeccapurple r calc(b * .5 - g * .5) 10)", "rgb(102, 51, 10)");
-    test(
-      "rgb(from rgb(20%, 40%, 60%, 80%) calc(r) calc(g) calc(b) / calc(alpha))",
-      "rgba(51, 102, 153, 0.8)",
+    minify_test(
+      ".foo { color: color(xyz 0.2005 0.14089 0.4472); }",
+      ".foo{color:color(xyz .2005 .14089 .4472)}",
     );
-
-    // Testing with 'none'.
-    test("rgb(from rebeccapurple none none none)", "rgb(0, 0, 0)");
-    test("rgb(from rebeccapurple none none none / none)", "rgba(0, 0, 0, 0)");
-    test("rgb(from rebeccapurple r g none)", "rgb(102, 51, 0)");
-    test("rgb(from rebeccapurple r g none / alpha)", "rgb(102, 51, 0)");
-    test("rgb(from rebeccapurple r g b / none)", "rgba(102, 51, 153, 0)");
-    test(
-      "rgb(from rgb(20% 40% 60% / 80%) r g none / alpha)",
-      "rgba(51, 102, 0, 0.8)",
+    minify_test(
+      ".foo { color: color(xyz 20.05% 14.089% 44.72%); }",
+      ".foo{color:color(xyz .2005 .14089 .4472)}",
     );
-    test("rgb(from rgb(20% 40% 60% / 80%) r g b / none)", "rgba(51, 102, 153, 0)");
-    // FIXME: Clarify with spec editors if 'none' should pass through to the constants.
-    test("rgb(from rgb(none none none) r g b)", "rgb(0, 0, 0)");
-    test("rgb(from rgb(none none none / none) r g b / alpha)", "rgba(0, 0, 0, 0)");
-    test("rgb(from rgb(20% none 60%) r g b)", "rgb(51, 0, 153)");
-    test(
-      "rgb(from rgb(20% 40% 60% / none) r g b / alpha)",
-      "rgba(51, 102, 153, 0)",
+    minify_test(".foo { color: color(xyz 0.2005 0 0); }", ".foo{color:color(xyz .2005)}");
+    minify_test(".foo { color: color(xyz 0 0 0); }", ".foo{color:color(xyz)}");
+    minify_test(".foo { color: color(xyz 0 1 0); }", ".foo{color:color(xyz 0 1)}");
+    minify_test(".foo { color: color(xyz 0 1); }", ".foo{color:color(xyz 0 1)}");
+    minify_test(".foo { color: color(xyz 1); }", ".foo{color:color(xyz 1)}");
+    minify_test(".foo { color: color(xyz); }", ".foo{color:color(xyz)}");
+    minify_test(
+      ".foo { color: color(xyz 0 1 0 / 20%); }",
+      ".foo{color:color(xyz 0 1/.2)}",
     );
-
-    // hsl(from ...)
-
-    // Testing no modifications.
-    test("hsl(from rebeccapurple h s l)", "rgb(102, 51, 153)");
-    test("hsl(from rebeccapurple h s l / alpha)", "rgb(102, 51, 153)");
-    test(
-      "hsl(from rgb(20%, 40%, 60%, 80%) h s l / alpha)",
-      "rgba(51, 102, 153, 0.8)",
+    minify_test(".foo { color: color(xyz / 20%); }", ".foo{color:color(xyz/.2)}");
+    minify_test(
+      ".foo { color: color(display-p3 100% 50% 0 / 20%); }",
+      ".foo{color:color(display-p3 1 .5/.2)}",
     );
-    test(
-      "hsl(from hsl(120deg 20% 50% / .5) h s l / alpha)",
-      "rgba(102, 153, 102, 0.5)",
+    minify_test(
+      ".foo { color: color(display-p3 100% / 20%); }",
+      ".foo{color:color(display-p3 1/.2)}",
     );
+    minify_test(".foo { color: hsl(none none none) }", ".foo{color:#000}");
+    minify_test(".foo { color: hwb(none none none) }", ".foo{color:red}");
+    minify_test(".foo { color: rgb(none none none) }", ".foo{color:#000}");
 
-    // Test nesting relative colors.
-    test("hsl(from hsl(from rebeccapurple h s l) h s l)", "rgb(102, 51, 153)");
-
-    // Testing non-sRGB origin colors to see gamut mapping.
-    test("hsl(from color(display-p3 0 1 0) h s l / alpha)", "rgb(0, 249, 66)"); // Naive clip based mapping would give rgb(0, 255, 0).
-    test("hsl(from lab(100% 104.3 -50.9) h s l)", "rgb(255, 255, 255)"); // Naive clip based mapping would give rgb(255, 150, 255).
-    test("hsl(from lab(0% 104.3 -50.9) h s l)", "rgb(42, 0, 34)"); // Naive clip based mapping would give rgb(90, 0, 76). NOTE: 0% lightness in Lab/LCH does not automatically correspond with sRGB black,
-    test("hsl(from lch(100% 116 334) h s l)", "rgb(255, 255, 255)"); // Naive clip based mapping would give rgb(255, 150, 255).
-    test("hsl(from lch(0% 116 334) h s l)", "rgb(42, 0, 34)"); // Naive clip based mapping would give rgb(90, 0, 76). NOTE: 0% lightness in Lab/LCH does not automatically correspond with sRGB black,
-    test("hsl(from oklab(100% 0.365 -0.16) h s l)", "rgb(255, 255, 255)"); // Naive clip based mapping would give rgb(255, 92, 255).
-    test("hsl(from oklab(0% 0.365 -0.16) h s l)", "rgb(0, 0, 0)"); // Naive clip based mapping would give rgb(19, 0, 24).
-    test("hsl(from oklch(100% 0.399 336.3) h s l)", "rgb(255, 255, 255)"); // Naive clip based mapping would give rgb(255, 91, 255).
-    test("hsl(from oklch(0% 0.399 336.3) h s l)", "rgb(0, 0, 0)"); // Naive clip based mapping would give rgb(20, 0, 24).
-
-    // Testing replacement with 0.
-    test("hsl(from rebeccapurple 0 0% 0%)", "rgb(0, 0, 0)");
-    test("hsl(from rebeccapurple 0deg 0% 0%)", "rgb(0, 0, 0)");
-    test("hsl(from rebeccapurple 0 0% 0% / 0)", "rgba(0, 0, 0, 0)");
-    test("hsl(from rebeccapurple 0deg 0% 0% / 0)", "rgba(0, 0, 0, 0)");
-    test("hsl(from rebeccapurple 0 s l / alpha)", "rgb(153, 51, 51)");
-    test("hsl(from rebeccapurple 0deg s l / alpha)", "rgb(153, 51, 51)");
-    test("hsl(from rebeccapurple h 0% l / alpha)", "rgb(102, 102, 102)");
-    test("hsl(from rebeccapurple h s 0% / alpha)", "rgb(0, 0, 0)");
-    test("hsl(from rebeccapurple h s l / 0)", "rgba(102, 51, 153, 0)");
-    test(
-      "hsl(from rgb(20%, 40%, 60%, 80%) 0 s l / alpha)",
-      "rgba(153, 51, 51, 0.8)",
+    // If the browser doesn't support `#rrggbbaa` color syntax, it is converted to `transparent`.
+    attr_test(
+      "color: rgba(0, 0, 0, 0)",
+      "color:transparent",
+      true,
+      Some(Browsers {
+        chrome: Some(61 << 16), // Chrome >= 62 supports `#rrggbbaa` color.
+        ..Browsers::default()
+      }),
     );
-    test(
-      "hsl(from rgb(20%, 40%, 60%, 80%) 0deg s l / alpha)",
-      "rgba(153, 51, 51, 0.8)",
+
+    attr_test(
+      "color: #0000",
+      "color:transparent",
+      true,
+      Some(Browsers {
+        chrome: Some(61 << 16), // Chrome >= 62 supports `#rrggbbaa` color.
+        ..Browsers::default()
+      }),
     );
-    test(
-      "hsl(from rgb(20%, 40%, 60%, 80%) h 0% l / alpha)",
-      "rgba(102, 102, 102, 0.8)",
+
+    attr_test(
+      "color: transparent",
+      "color:transparent",
+      true,
+      Some(Browsers {
+        chrome: Some(61 << 16),
+        ..Browsers::default()
+      }),
     );
-    test("hsl(from rgb(20%, 40%, 60%, 80%) h s 0% / alpha)", "rgba(0, 0, 0, 0.8)");
-    test("hsl(from rgb(20%, 40%, 60%, 80%) h s l / 0)", "rgba(51, 102, 153, 0)");
 
-    // Testing replacement with a constant.
-    test("hsl(from rebeccapurple 25 s l / alpha)", "rgb(153, 93, 51)");
-    test("hsl(from rebeccapurple 25deg s l / alpha)", "rgb(153, 93, 51)");
-    test("hsl(from rebeccapurple h 20% l / alpha)", "rgb(102, 82, 122)");
-    test("hsl(from rebeccapurple h s 20% / alpha)", "rgb(51, 25, 77)");
-    test("hsl(from rebeccapurple h s l / .25)", "rgba(102, 51, 153, 0.25)");
-    test(
-      "hsl(from rgb(20%, 40%, 60%, 80%) 25 s l / alpha)",
-      "rgba(153, 93, 51, 0.8)",
+    attr_test(
+      "color: rgba(0, 0, 0, 0)",
+      "color: rgba(0, 0, 0, 0)",
+      false,
+      Some(Browsers {
+        chrome: Some(61 << 16),
+        ..Browsers::default()
+      }),
     );
-    test(
-      "hsl(from rgb(20%, 40%, 60%, 80%) 25deg s l / alpha)",
-      "rgba(153, 93, 51, 0.8)",
+
+    attr_test(
+      "color: rgba(255, 0, 0, 0)",
+      "color:rgba(255,0,0,0)",
+      true,
+      Some(Browsers {
+        chrome: Some(61 << 16),
+        ..Browsers::default()
+      }),
     );
-    test(
-      "hsl(from rgb(20%, 40%, 60%, 80%) h 20% l / alpha)",
-      "rgba(82, 102, 122, 0.8)",
+
+    attr_test(
+      "color: rgba(255, 0, 0, 0)",
+      "color:#f000",
+      true,
+      Some(Browsers {
+        chrome: Some(62 << 16),
+        ..Browsers::default()
+      }),
     );
-    test(
-      "hsl(from rgb(20%, 40%, 60%, 80%) h s 20% / alpha)",
-      "rgba(25, 51, 77, 0.8)",
+
+    prefix_test(
+      ".foo { color: rgba(123, 456, 789, 0.5) }",
+      indoc! { r#"
+        .foo {
+          color: #7bffff80;
+        }
+      "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
     );
-    test(
-      "hsl(from rgb(20%, 40%, 60%, 80%) h s l / .2)",
-      "rgba(51, 102, 153, 0.2)",
+
+    prefix_test(
+      ".foo { color: rgba(123, 255, 255, 0.5) }",
+      indoc! { r#"
+        .foo {
+          color: rgba(123, 255, 255, 0.5);
+        }
+      "#},
+      Browsers {
+        ie: Some(11 << 16),
+        ..Browsers::default()
+      },
     );
 
-    // Testing valid permutation (types match).
-    test("hsl(from rebeccapurple h l s)", "rgb(128, 77, 179)");
-    test("hsl(from rebeccapurple h alpha l / s)", "rgba(102, 0, 204, 0.5)");
-    test("hsl(from rebeccapurple h l l / l)", "rgba(102, 61, 143, 0.4)");
-    test("hsl(from rebeccapurple h alpha alpha / alpha)", "rgb(255, 255, 255)");
-    test("hsl(from rgb(20%, 40%, 60%, 80%) h l s)", "rgb(77, 128, 179)");
-    test(
-      "hsl(from rgb(20%, 40%, 60%, 80%) h alpha l / s)",
-      "rgba(20, 102, 184, 0.5)",
+    prefix_test(
+      ".foo { color: #7bffff80 }",
+      indoc! { r#"
+        .foo {
+          color: rgba(123, 255, 255, 0.5);
+        }
+      "#},
+      Browsers {
+        ie: Some(11 << 16),
+        ..Browsers::default()
+      },
     );
-    test("hsl(from rgb(20%, 40%, 60%, 80%) h l l / l)", "rgba(61, 102, 143, 0.4)");
-    test(
-      "hsl(from rgb(20%, 40%, 60%, 80%) h alpha alpha / alpha)",
-      "rgba(163, 204, 245, 0.8)",
+
+    prefix_test(
+      ".foo { color: rgba(123, 456, 789, 0.5) }",
+      indoc! { r#"
+        .foo {
+          color: rgba(123, 255, 255, 0.5);
+        }
+      "#},
+      Browsers {
+        firefox: Some(48 << 16),
+        safari: Some(10 << 16),
+        ios_saf: Some(9 << 16),
+        ..Browsers::default()
+      },
     );
 
-    // Testing with calc().
-    test("hsl(from rebeccapurple calc(h) calc(s) calc(l))", "rgb(102, 51, 153)");
-    test(
-      "hsl(from rgb(20%, 40%, 60%, 80%) calc(h) calc(s) calc(l) / calc(alpha))",
-      "rgba(51, 102, 153, 0.8)",
+    prefix_test(
+      ".foo { color: rgba(123, 456, 789, 0.5) }",
+      indoc! { r#"
+        .foo {
+          color: #7bffff80;
+        }
+      "#},
+      Browsers {
+        firefox: Some(49 << 16),
+        safari: Some(10 << 16),
+        ios_saf: Some(10 << 16),
+        ..Browsers::default()
+      },
     );
 
-    // Testing with 'none'.
-    test("hsl(from rebeccapurple none none none)", "rgb(0, 0, 0)");
-    test("hsl(from rebeccapurple none none none / none)", "rgba(0, 0, 0, 0)");
-    test("hsl(from rebeccapurple h s none)", "rgb(0, 0, 0)");
-    test("hsl(from rebeccapurple h s none / alpha)", "rgb(0, 0, 0)");
-    test("hsl(from rebeccapurple h s l / none)", "rgba(102, 51, 153, 0)");
-    test("hsl(from rebeccapurple none s l / alpha)", "rgb(153, 51, 51)");
-    test(
-      "hsl(from hsl(120deg 20% 50% / .5) h s none / alpha)",
-      "rgba(0, 0, 0, 0.5)",
-    );
-    test(
-      "hsl(from hsl(120deg 20% 50% / .5) h s l / none)",
-      "rgba(102, 153, 102, 0)",
-    );
-    test(
-      "hsl(from hsl(120deg 20% 50% / .5) none s l / alpha)",
-      "rgba(153, 102, 102, 0.5)",
-    );
-    // FIXME: Clarify with spec editors if 'none' should pass through to the constants.
-    test("hsl(from hsl(none none none) h s l)", "rgb(0, 0, 0)");
-    test("hsl(from hsl(none none none / none) h s l / alpha)", "rgba(0, 0, 0, 0)");
-    test("hsl(from hsl(120deg none 50% / .5) h s l)", "rgb(128, 128, 128)");
-    test(
-      "hsl(from hsl(120deg 20% 50% / none) h s l / alpha)",
-      "rgba(102, 153, 102, 0)",
-    );
-    test(
-      "hsl(from hsl(none 20% 50% / .5) h s l / alpha)",
-      "rgba(153, 102, 102, 0.5)",
+    prefix_test(
+      ".foo { background-color: lab(40% 56.6 39) }",
+      indoc! { r#"
+        .foo {
+          background-color: #b32323;
+          background-color: lab(40% 56.6 39);
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
-    // hwb(from ...)
-
-    // Testing no modifications.
-    test("hwb(from rebeccapurple h w b)", "rgb(102, 51, 153)");
-    test("hwb(from rebeccapurple h w b / alpha)", "rgb(102, 51, 153)");
-    test(
-      "hwb(from rgb(20%, 40%, 60%, 80%) h w b / alpha)",
-      "rgba(51, 102, 153, 0.8)",
-    );
-    test(
-      "hwb(from hsl(120deg 20% 50% / .5) h w b / alpha)",
-      "rgba(102, 153, 102, 0.5)",
+    prefix_test(
+      ".foo { background-color: lch(40% 68.735435 34.568626) }",
+      indoc! { r#"
+        .foo {
+          background-color: #b32323;
+          background-color: lch(40% 68.7354 34.5686);
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
-    // Test nesting relative colors.
-    test("hwb(from hwb(from rebeccapurple h w b) h w b)", "rgb(102, 51, 153)");
-
-    // Testing non-sRGB origin colors to see gamut mapping.
-    test("hwb(from color(display-p3 0 1 0) h w b / alpha)", "rgb(0, 249, 66)"); // Naive clip based mapping would give rgb(0, 255, 0).
-    test("hwb(from lab(100% 104.3 -50.9) h w b)", "rgb(255, 255, 255)"); // Naive clip based mapping would give rgb(255, 150, 255).
-    test("hwb(from lab(0% 104.3 -50.9) h w b)", "rgb(42, 0, 34)"); // Naive clip based mapping would give rgb(90, 0, 76). NOTE: 0% lightness in Lab/LCH does not automatically correspond with sRGB black,
-    test("hwb(from lch(100% 116 334) h w b)", "rgb(255, 255, 255)"); // Naive clip based mapping would give rgb(255, 150, 255).
-    test("hwb(from lch(0% 116 334) h w b)", "rgb(42, 0, 34)"); // Naive clip based mapping would give rgb(90, 0, 76). NOTE: 0% lightness in Lab/LCH does not automatically correspond with sRGB black,
-    test("hwb(from oklab(100% 0.365 -0.16) h w b)", "rgb(255, 255, 255)"); // Naive clip based mapping would give rgb(255, 92, 255).
-    test("hwb(from oklab(0% 0.365 -0.16) h w b)", "rgb(0, 0, 0)"); // Naive clip based mapping would give rgb(19, 0, 24).
-    test("hwb(from oklch(100% 0.399 336.3) h w b)", "rgb(255, 255, 255)"); // Naive clip based mapping would give rgb(255, 91, 255).
-    test("hwb(from oklch(0% 0.399 336.3) h w b)", "rgb(0, 0, 0)"); // Naive clip based mapping would give rgb(20, 0, 24).
-
-    // Testing replacement with 0.
-    test("hwb(from rebeccapurple 0 0% 0%)", "rgb(255, 0, 0)");
-    test("hwb(from rebeccapurple 0deg 0% 0%)", "rgb(255, 0, 0)");
-    test("hwb(from rebeccapurple 0 0% 0% / 0)", "rgba(255, 0, 0, 0)");
-    test("hwb(from rebeccapurple 0deg 0% 0% / 0)", "rgba(255, 0, 0, 0)");
-    test("hwb(from rebeccapurple 0 w b / alpha)", "rgb(153, 51, 51)");
-    test("hwb(from rebeccapurple 0deg w b / alpha)", "rgb(153, 51, 51)");
-    test("hwb(from rebeccapurple h 0% b / alpha)", "rgb(77, 0, 153)");
-    test("hwb(from rebeccapurple h w 0% / alpha)", "rgb(153, 51, 255)");
-    test("hwb(from rebeccapurple h w b / 0)", "rgba(102, 51, 153, 0)");
-    test(
-      "hwb(from rgb(20%, 40%, 60%, 80%) 0 w b / alpha)",
-      "rgba(153, 51, 51, 0.8)",
-    );
-    test(
-      "hwb(from rgb(20%, 40%, 60%, 80%) 0deg w b / alpha)",
-      "rgba(153, 51, 51, 0.8)",
-    );
-    test(
-      "hwb(from rgb(20%, 40%, 60%, 80%) h 0% b / alpha)",
-      "rgba(0, 77, 153, 0.8)",
-    );
-    test(
-      "hwb(from rgb(20%, 40%, 60%, 80%) h w 0% / alpha)",
-      "rgba(51, 153, 255, 0.8)",
+    prefix_test(
+      ".foo { background-color: oklab(59.686% 0.1009 0.1192); }",
+      indoc! { r#"
+        .foo {
+          background-color: #c65d07;
+          background-color: lab(52.2319% 40.1449 59.9171);
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    test("hwb(from rgb(20%, 40%, 60%, 80%) h w b / 0)", "rgba(51, 102, 153, 0)");
 
-    // Testing replacement with a constant.
-    test("hwb(from rebeccapurple 25 w b / alpha)", "rgb(153, 93, 51)");
-    test("hwb(from rebeccapurple 25deg w b / alpha)", "rgb(153, 93, 51)");
-    test("hwb(from rebeccapurple h 20% b / alpha)", "rgb(102, 51, 153)");
-    test("hwb(from rebeccapurple h w 20% / alpha)", "rgb(128, 51, 204)");
-    test("hwb(from rebeccapurple h w b / .2)", "rgba(102, 51, 153, 0.2)");
-    test(
-      "hwb(from rgb(20%, 40%, 60%, 80%) 25 w b / alpha)",
-      "rgba(153, 93, 51, 0.8)",
+    prefix_test(
+      ".foo { background-color: oklch(40% 0.1268735435 34.568626) }",
+      indoc! { r#"
+        .foo {
+          background-color: #7e250f;
+          background-color: lab(29.2661% 38.2437 35.3889);
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    test(
-      "hwb(from rgb(20%, 40%, 60%, 80%) 25deg w b / alpha)",
-      "rgba(153, 93, 51, 0.8)",
+
+    prefix_test(
+      ".foo { background-color: lab(40% 56.6 39) }",
+      indoc! { r#"
+        .foo {
+          background-color: lab(40% 56.6 39);
+        }
+      "#},
+      Browsers {
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
     );
-    test(
-      "hwb(from rgb(20%, 40%, 60%, 80%) h 20% b / alpha)",
-      "rgba(51, 102, 153, 0.8)",
+
+    prefix_test(
+      ".foo { background-color: oklab(59.686% 0.1009 0.1192); }",
+      indoc! { r#"
+        .foo {
+          background-color: #c65d07;
+          background-color: lab(52.2319% 40.1449 59.9171);
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
     );
-    test(
-      "hwb(from rgb(20%, 40%, 60%, 80%) h w 20% / alpha)",
-      "rgba(51, 128, 204, 0.8)",
+
+    prefix_test(
+      ".foo { background-color: oklab(59.686% 0.1009 0.1192); }",
+      indoc! { r#"
+        .foo {
+          background-color: #c65d07;
+          background-color: color(display-p3 0.724144 0.386777 0.148795);
+          background-color: lab(52.2319% 40.1449 59.9171);
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
     );
-    test(
-      "hwb(from rgb(20%, 40%, 60%, 80%) h w b / .2)",
-      "rgba(51, 102, 153, 0.2)",
+
+    prefix_test(
+      ".foo { background-color: lab(40% 56.6 39) }",
+      indoc! { r#"
+        .foo {
+          background-color: #b32323;
+          background-color: color(display-p3 0.643308 0.192455 0.167712);
+          background-color: lab(40% 56.6 39);
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
     );
 
-    // Testing valid permutation (types match).
-    test("hwb(from rebeccapurple h b w)", "rgb(153, 102, 204)");
-    test("hwb(from rebeccapurple h alpha w / b)", "rgba(213, 213, 213, 0.4)");
-    test("hwb(from rebeccapurple h w w / w)", "rgba(128, 51, 204, 0.2)");
-    test("hwb(from rebeccapurple h alpha alpha / alpha)", "rgb(128, 128, 128)");
-    test("hwb(from rgb(20%, 40%, 60%, 80%) h b w)", "rgb(102, 153, 204)");
-    test(
-      "hwb(from rgb(20%, 40%, 60%, 80%) h alpha w / b)",
-      "rgba(204, 204, 204, 0.4)",
+    prefix_test(
+      ".foo { background-color: oklch(59.686% 0.15619 49.7694); }",
+      indoc! { r#"
+        .foo {
+          background-color: #c65d06;
+          background-color: lab(52.2321% 40.1417 59.9527);
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
     );
-    test("hwb(from rgb(20%, 40%, 60%, 80%) h w w / w)", "rgba(51, 128, 204, 0.2)");
-    test(
-      "hwb(from rgb(20%, 40%, 60%, 80%) h alpha alpha / alpha)",
-      "rgba(128, 128, 128, 0.8)",
+
+    prefix_test(
+      ".foo { background-color: color(sRGB 0.41587 0.503670 0.36664); }",
+      indoc! { r#"
+        .foo {
+          background-color: #6a805d;
+          background-color: color(srgb 0.41587 0.50367 0.36664);
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
-    // Testing with calc().
-    test("hwb(from rebeccapurple calc(h) calc(w) calc(b))", "rgb(102, 51, 153)");
-    test(
-      "hwb(from rgb(20%, 40%, 60%, 80%) calc(h) calc(w) calc(b) / calc(alpha))",
-      "rgba(51, 102, 153, 0.8)",
+    prefix_test(
+      ".foo { background-color: color(display-p3 0.43313 0.50108 0.37950); }",
+      indoc! { r#"
+        .foo {
+          background-color: #6a805d;
+          background-color: color(display-p3 0.43313 0.50108 0.3795);
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
-    // Testing with 'none'.
-    test("hwb(from rebeccapurple none none none)", "rgb(255, 0, 0)");
+    prefix_test(
+      ".foo { background-color: color(display-p3 0.43313 0.50108 0.37950); }",
+      indoc! { r#"
+        .foo {
+          background-color: #6a805d;
+          background-color: color(display-p3 0.43313 0.50108 0.3795);
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { background-color: color(display-p3 0.43313 0.50108 0.37950); }",
+      indoc! { r#"
+        .foo {
+          background-color: color(display-p3 0.43313 0.50108 0.3795);
+        }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { background-color: color(display-p3 0.43313 0.50108 0.37950); }",
+      indoc! { r#"
+        .foo {
+          background-color: #6a805d;
+          background-color: color(display-p3 0.43313 0.50108 0.3795);
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { background-color: color(display-p3 0.43313 0.50108 0.37950); }",
+      indoc! { r#"
+        .foo {
+          background-color: #6a805d;
+          background-color: color(display-p3 0.43313 0.50108 0.3795);
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { background-color: color(a98-rgb 0.44091 0.49971 0.37408); }",
+      indoc! { r#"
+        .foo {
+          background-color: #6a805d;
+          background-color: color(a98-rgb 0.44091 0.49971 0.37408);
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { background-color: color(a98-rgb 0.44091 0.49971 0.37408); }",
+      indoc! { r#"
+        .foo {
+          background-color: color(a98-rgb 0.44091 0.49971 0.37408);
+        }
+      "#},
+      Browsers {
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { background-color: color(prophoto-rgb 0.36589 0.41717 0.31333); }",
+      indoc! { r#"
+        .foo {
+          background-color: #6a805d;
+          background-color: color(prophoto-rgb 0.36589 0.41717 0.31333);
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { background-color: color(rec2020 0.42210 0.47580 0.35605); }",
+      indoc! { r#"
+        .foo {
+          background-color: #728765;
+          background-color: color(rec2020 0.4221 0.4758 0.35605);
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { background-color: color(xyz-d50 0.2005 0.14089 0.4472); }",
+      indoc! { r#"
+        .foo {
+          background-color: #7654cd;
+          background-color: color(xyz-d50 0.2005 0.14089 0.4472);
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { background-color: color(xyz-d65 0.21661 0.14602 0.59452); }",
+      indoc! { r#"
+        .foo {
+          background-color: #7654cd;
+          background-color: color(xyz 0.21661 0.14602 0.59452);
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { background-color: lch(50.998% 135.363 338) }",
+      indoc! { r#"
+        .foo {
+          background-color: #ee00be;
+          background-color: color(display-p3 0.972962 -0.362078 0.804206);
+          background-color: lch(50.998% 135.363 338);
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { color: lch(50.998% 135.363 338) }",
+      indoc! { r#"
+        .foo {
+          color: #ee00be;
+          color: color(display-p3 0.972962 -0.362078 0.804206);
+          color: lch(50.998% 135.363 338);
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { background: var(--image) lch(40% 68.735435 34.568626) }",
+      indoc! { r#"
+        .foo {
+          background: var(--image) #b32323;
+        }
+
+        @supports (color: lab(0% 0 0)) {
+          .foo {
+            background: var(--image) lab(40% 56.6 39);
+          }
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        --a: rgb(0 0 0 / var(--alpha));
+        --b: rgb(50% 50% 50% / var(--alpha));
+        --c: rgb(var(--x) 0 0);
+        --d: rgb(0 var(--x) 0);
+        --e: rgb(0 0 var(--x));
+        --f: rgb(var(--x) 0 0 / var(--alpha));
+        --g: rgb(0 var(--x) 0 / var(--alpha));
+        --h: rgb(0 0 var(--x) / var(--alpha));
+        --i: rgb(none 0 0 / var(--alpha));
+      }
+      "#,
+      indoc! { r#"
+        .foo {
+          --a: rgba(0, 0, 0, var(--alpha));
+          --b: rgba(128, 128, 128, var(--alpha));
+          --c: rgb(var(--x) 0 0);
+          --d: rgb(0 var(--x) 0);
+          --e: rgb(0 0 var(--x));
+          --f: rgb(var(--x) 0 0 / var(--alpha));
+          --g: rgb(0 var(--x) 0 / var(--alpha));
+          --h: rgb(0 0 var(--x) / var(--alpha));
+          --i: rgb(none 0 0 / var(--alpha));
+        }
+      "#},
+      Browsers {
+        safari: Some(11 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        --a: rgb(0 0 0 / var(--alpha));
+        --b: rgb(50% 50% 50% / var(--alpha));
+        --c: rgb(var(--x) 0 0);
+        --d: rgb(0 var(--x) 0);
+        --e: rgb(0 0 var(--x));
+        --f: rgb(var(--x) 0 0 / var(--alpha));
+        --g: rgb(0 var(--x) 0 / var(--alpha));
+        --h: rgb(0 0 var(--x) / var(--alpha));
+        --i: rgb(none 0 0 / var(--alpha));
+      }
+      "#,
+      indoc! { r#"
+        .foo {
+          --a: rgb(0 0 0 / var(--alpha));
+          --b: rgb(128 128 128 / var(--alpha));
+          --c: rgb(var(--x) 0 0);
+          --d: rgb(0 var(--x) 0);
+          --e: rgb(0 0 var(--x));
+          --f: rgb(var(--x) 0 0 / var(--alpha));
+          --g: rgb(0 var(--x) 0 / var(--alpha));
+          --h: rgb(0 0 var(--x) / var(--alpha));
+          --i: rgb(none 0 0 / var(--alpha));
+        }
+      "#},
+      Browsers {
+        safari: Some(13 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        --a: hsl(270 100% 50% / var(--alpha));
+        --b: hsl(var(--x) 0 0);
+        --c: hsl(0 var(--x) 0);
+        --d: hsl(0 0 var(--x));
+        --e: hsl(var(--x) 0 0 / var(--alpha));
+        --f: hsl(0 var(--x) 0 / var(--alpha));
+        --g: hsl(0 0 var(--x) / var(--alpha));
+        --h: hsl(270 100% 50% / calc(var(--alpha) / 2));
+        --i: hsl(none 100% 50% / var(--alpha));
+      }
+      "#,
+      indoc! { r#"
+        .foo {
+          --a: hsla(270, 100%, 50%, var(--alpha));
+          --b: hsl(var(--x) 0 0);
+          --c: hsl(0 var(--x) 0);
+          --d: hsl(0 0 var(--x));
+          --e: hsl(var(--x) 0 0 / var(--alpha));
+          --f: hsl(0 var(--x) 0 / var(--alpha));
+          --g: hsl(0 0 var(--x) / var(--alpha));
+          --h: hsla(270, 100%, 50%, calc(var(--alpha) / 2));
+          --i: hsl(none 100% 50% / var(--alpha));
+        }
+      "#},
+      Browsers {
+        safari: Some(11 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        --a: hsl(270 100% 50% / var(--alpha));
+        --b: hsl(var(--x) 0 0);
+        --c: hsl(0 var(--x) 0);
+        --d: hsl(0 0 var(--x));
+        --e: hsl(var(--x) 0 0 / var(--alpha));
+        --f: hsl(0 var(--x) 0 / var(--alpha));
+        --g: hsl(0 0 var(--x) / var(--alpha));
+        --h: hsl(270 100% 50% / calc(var(--alpha) / 2));
+        --i: hsl(none 100% 50% / var(--alpha));
+      }
+      "#,
+      indoc! { r#"
+        .foo {
+          --a: hsl(270 100% 50% / var(--alpha));
+          --b: hsl(var(--x) 0 0);
+          --c: hsl(0 var(--x) 0);
+          --d: hsl(0 0 var(--x));
+          --e: hsl(var(--x) 0 0 / var(--alpha));
+          --f: hsl(0 var(--x) 0 / var(--alpha));
+          --g: hsl(0 0 var(--x) / var(--alpha));
+          --h: hsl(270 100% 50% / calc(var(--alpha) / 2));
+          --i: hsl(none 100% 50% / var(--alpha));
+        }
+      "#},
+      Browsers {
+        safari: Some(13 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    test(
+      r#"
+      .foo {
+        --a: rgb(50% 50% 50% / calc(100% / 2));
+        --b: hsl(calc(360deg / 2) 50% 50%);
+        --c: oklab(40.101% calc(0.1 + 0.2) 0.0453);
+        --d: color(display-p3 0.43313 0.50108 calc(0.1 + 0.2));
+        --e: rgb(calc(255 / 2), calc(255 / 2), calc(255 / 2));
+      }
+      "#,
+      indoc! { r#"
+        .foo {
+          --a: #80808080;
+          --b: #40bfbf;
+          --c: oklab(40.101% 0.3 0.0453);
+          --d: color(display-p3 0.43313 0.50108 0.3);
+          --e: gray;
+        }
+      "#},
+    );
+  }
+
+  #[test]
+  fn test_relative_color() {
+    fn test(input: &str, output: &str) {
+      let output = CssColor::parse_string(output)
+        .unwrap()
+        .to_css_string(PrinterOptions {
+          minify: true,
+          ..PrinterOptions::default()
+        })
+        .unwrap();
+      minify_test(
+        &format!(".foo {{ color: {} }}", input),
+        &format!(".foo{{color:{}}}", output),
+      );
+    }
+
+    test("lab(from indianred calc(l * .8) a b)", "lab(43.1402% 45.7516 23.1557)");
+    test("lch(from indianred calc(l + 10%) c h)", "lch(63.9252% 51.2776 26.8448)");
+    test("lch(from indianred l calc(c - 50) h)", "lch(53.9252% 1.27763 26.8448)");
+    test(
+      "lch(from indianred l c calc(h + 180deg))",
+      "lch(53.9252% 51.2776 206.845)",
+    );
+    test("lch(from orchid l 30 h)", "lch(62.7526% 30 326.969)");
+    test("lch(from orchid l 30 h)", "lch(62.7526% 30 326.969)");
+    test("lch(from peru calc(l * 0.8) c h)", "lch(49.8022% 54.0117 63.6804)");
+    test("rgb(from indianred 255 g b)", "rgb(255, 92, 92)");
+    test("rgb(from indianred r g b / .5)", "rgba(205, 92, 92, .5)");
+    test(
+      "rgb(from rgba(205, 92, 92, .5) r g b / calc(alpha + .2))",
+      "rgba(205, 92, 92, .7)",
+    );
+    test(
+      "rgb(from rgba(205, 92, 92, .5) r g b / calc(alpha + 20%))",
+      "rgba(205, 92, 92, .7)",
+    );
+    test("lch(from indianred l sin(c) h)", "lch(53.9252% .84797 26.8448)");
+    test("lch(from indianred l sqrt(c) h)", "lch(53.9252% 7.16084 26.8448)");
+    test("lch(from indianred l c sin(h))", "lch(53.9252% 51.2776 .990043)");
+
+    // The following tests were converted from WPT: https://github.com/web-platform-tests/wpt/blob/master/css/css-color/parsing/relative-color-valid.html
+    // Find: test_valid_value\(`color`, `(.*?)`,\s*`(.*?)`\)
+    // Replace: test("$1", "$2")
+
+    // Testing no modifications.
+    test("rgb(from rebeccapurple r g b)", "#639");
+    test("rgb(from rebeccapurple r g b / alpha)", "#639");
+    test("rgb(from rgb(20%, 40%, 60%, 80%) r g b / alpha)", "#369c");
+    test("rgb(from hsl(120deg 20% 50% / .5) r g b / alpha)", "#66996680");
+
+    // Test nesting relative colors.
+    test("rgb(from rgb(from rebeccapurple r g b) r g b)", "#639");
+
+    // Testing non-sRGB origin colors to see gamut mapping.
+    test("rgb(from color(display-p3 0 1 0) r g b / alpha)", "#00f942"); // Naive clip based mapping would give rgb(0, 255, 0).
+    test("rgb(from lab(100% 104.3 -50.9) r g b)", "#fff"); // Naive clip based mapping would give rgb(255, 150, 255).
+    test("rgb(from lab(0% 104.3 -50.9) r g b)", "#2a0022"); // Naive clip based mapping would give rgb(90, 0, 76). NOTE: 0% lightness in Lab/LCH does not automatically correspond with sRGB black.
+    test("rgb(from lch(100% 116 334) r g b)", "#fff"); // Naive clip based mapping would give rgb(255, 150, 255).
+    test("rgb(from lch(0% 116 334) r g b)", "#2a0022"); // Naive clip based mapping would give rgb(90, 0, 76). NOTE: 0% lightness in Lab/LCH does not automatically correspond with sRGB black.
+    test("rgb(from oklab(100% 0.365 -0.16) r g b)", "#fff"); // Naive clip based mapping would give rgb(255, 92, 255).
+    test("rgb(from oklab(0% 0.365 -0.16) r g b)", "#000"); // Naive clip based mapping would give rgb(19, 0, 24).
+    test("rgb(from oklch(100% 0.399 336.3) r g b)", "#fff"); // Naive clip based mapping would give rgb(255, 91, 255).
+    test("rgb(from oklch(0% 0.399 336.3) r g b)", "#000"); // Naive clip based mapping would give rgb(20, 0, 24).
+
+    // Testing replacement with 0.
+    test("rgb(from rebeccapurple 0 0 0)", "rgb(0, 0, 0)");
+    test("rgb(from rebeccapurple 0 0 0 / 0)", "rgba(0, 0, 0, 0)");
+    test("rgb(from rebeccapurple 0 g b / alpha)", "rgb(0, 51, 153)");
+    test("rgb(from rebeccapurple r 0 b / alpha)", "rgb(102, 0, 153)");
+    test("rgb(from rebeccapurple r g 0 / alpha)", "rgb(102, 51, 0)");
+    test("rgb(from rebeccapurple r g b / 0)", "rgba(102, 51, 153, 0)");
+    test(
+      "rgb(from rgb(20%, 40%, 60%, 80%) 0 g b / alpha)",
+      "rgba(0, 102, 153, 0.8)",
+    );
+    test(
+      "rgb(from rgb(20%, 40%, 60%, 80%) r 0 b / alpha)",
+      "rgba(51, 0, 153, 0.8)",
+    );
+    test(
+      "rgb(from rgb(20%, 40%, 60%, 80%) r g 0 / alpha)",
+      "rgba(51, 102, 0, 0.8)",
+    );
+    test("rgb(from rgb(20%, 40%, 60%, 80%) r g b / 0)", "rgba(51, 102, 153, 0)");
+
+    // Testing replacement with a number.
+    test("rgb(from rebeccapurple 25 g b / alpha)", "rgb(25, 51, 153)");
+    test("rgb(from rebeccapurple r 25 b / alpha)", "rgb(102, 25, 153)");
+    test("rgb(from rebeccapurple r g 25 / alpha)", "rgb(102, 51, 25)");
+    test("rgb(from rebeccapurple r g b / .25)", "rgba(102, 51, 153, 0.25)");
+    test(
+      "rgb(from rgb(20%, 40%, 60%, 80%) 25 g b / alpha)",
+      "rgba(25, 102, 153, 0.8)",
+    );
+    test(
+      "rgb(from rgb(20%, 40%, 60%, 80%) r 25 b / alpha)",
+      "rgba(51, 25, 153, 0.8)",
+    );
+    test(
+      "rgb(from rgb(20%, 40%, 60%, 80%) r g 25 / alpha)",
+      "rgba(51, 102, 25, 0.8)",
+    );
+    test(
+      "rgb(from rgb(20%, 40%, 60%, 80%) r g b / .20)",
+      "rgba(51, 102, 153, 0.2)",
+    );
+
+    // Testing replacement with a percentage.
+    test("rgb(from rebeccapurple 20% g b / alpha)", "rgb(51, 51, 153)");
+    test("rgb(from rebeccapurple r 20% b / alpha)", "rgb(102, 51, 153)");
+    test("rgb(from rebeccapurple r g 20% / alpha)", "rgb(102, 51, 51)");
+    test("rgb(from rebeccapurple r g b / 20%)", "rgba(102, 51, 153, 0.2)");
+    test(
+      "rgb(from rgb(20%, 40%, 60%, 80%) 20% g b / alpha)",
+      "rgba(51, 102, 153, 0.8)",
+    );
+    test(
+      "rgb(from rgb(20%, 40%, 60%, 80%) r 20% b / alpha)",
+      "rgba(51, 51, 153, 0.8)",
+    );
+    test(
+      "rgb(from rgb(20%, 40%, 60%, 80%) r g 20% / alpha)",
+      "rgba(51, 102, 51, 0.8)",
+    );
+    test(
+      "rgb(from rgb(20%, 40%, 60%, 80%) r g b / 20%)",
+      "rgba(51, 102, 153, 0.2)",
+    );
+
+    // Testing replacement with a number for r, g, b but percent for alpha.
+    test("rgb(from rebeccapurple 25 g b / 25%)", "rgba(25, 51, 153, 0.25)");
+    test("rgb(from rebeccapurple r 25 b / 25%)", "rgba(102, 25, 153, 0.25)");
+    test("rgb(from rebeccapurple r g 25 / 25%)", "rgba(102, 51, 25, 0.25)");
+    test(
+      "rgb(from rgb(20%, 40%, 60%, 80%) 25 g b / 25%)",
+      "rgba(25, 102, 153, 0.25)",
+    );
+    test(
+      "rgb(from rgb(20%, 40%, 60%, 80%) r 25 b / 25%)",
+      "rgba(51, 25, 153, 0.25)",
+    );
+    test(
+      "rgb(from rgb(20%, 40%, 60%, 80%) r g 25 / 25%)",
+      "rgba(51, 102, 25, 0.25)",
+    );
+
+    // Testing permutation.
+    test("rgb(from rebeccapurple g b r)", "rgb(51, 153, 102)");
+    test("rgb(from rebeccapurple b alpha r / g)", "rgba(153, 255, 102, 0.2)");
+    test("rgb(from rebeccapurple r r r / r)", "rgba(102, 102, 102, 0.4)");
+    test(
+      "rgb(from rebeccapurple alpha alpha alpha / alpha)",
+      "rgb(255, 255, 255)",
+    );
+    test("rgb(from rgb(20%, 40%, 60%, 80%) g b r)", "rgb(102, 153, 51)");
+    test(
+      "rgb(from rgb(20%, 40%, 60%, 80%) b alpha r / g)",
+      "rgba(153, 204, 51, 0.4)",
+    );
+    test("rgb(from rgb(20%, 40%, 60%, 80%) r r r / r)", "rgba(51, 51, 51, 0.2)");
+    test(
+      "rgb(from rgb(20%, 40%, 60%, 80%) alpha alpha alpha / alpha)",
+      "rgba(204, 204, 204, 0.8)",
+    );
+
+    // Testing mixes of number and percentage. (These would not be allowed in the non-relative syntax).
+    test("rgb(from rebeccapurple r 20% 10)", "rgb(102, 51, 10)");
+    test("rgb(from rebeccapurple r 10 20%)", "rgb(102, 10, 51)");
+    test("rgb(from rebeccapurple 0% 10 10)", "rgb(0, 10, 10)");
+    test("rgb(from rgb(20%, 40%, 60%, 80%) r 20% 10)", "rgb(51, 51, 10)");
+    test("rgb(from rgb(20%, 40%, 60%, 80%) r 10 20%)", "rgb(51, 10, 51)");
+    test("rgb(from rgb(20%, 40%, 60%, 80%) 0% 10 10)", "rgb(0, 10, 10)");
+
+    // Testing with calc().
+    test("rgb(from rebeccapurple calc(r) calc(g) calc(b))", "rgb(102, 51, 153)");
+    test("rgb(from rebeccapurple r calc(g * 2) 10)", "rgb(102, 102, 10)");
+    test("rgb(from rebeccapurple b calc(r * .5) 10)", "rgb(153, 51, 10)");
+    test("rgb(from rebeccapurple r calc(g * .5 + g * .5) 10)", "rgb(102, 51, 10)");
+    test("rgb(from rebeccapurple r calc(b * .5 - g * .5) 10)", "rgb(102, 51, 10)");
+    test(
+      "rgb(from rgb(20%, 40%, 60%, 80%) calc(r) calc(g) calc(b) / calc(alpha))",
+      "rgba(51, 102, 153, 0.8)",
+    );
+
+    // Testing with 'none'.
+    test("rgb(from rebeccapurple none none none)", "rgb(0, 0, 0)");
+    test("rgb(from rebeccapurple none none none / none)", "rgba(0, 0, 0, 0)");
+    test("rgb(from rebeccapurple r g none)", "rgb(102, 51, 0)");
+    test("rgb(from rebeccapurple r g none / alpha)", "rgb(102, 51, 0)");
+    test("rgb(from rebeccapurple r g b / none)", "rgba(102, 51, 153, 0)");
+    test(
+      "rgb(from rgb(20% 40% 60% / 80%) r g none / alpha)",
+      "rgba(51, 102, 0, 0.8)",
+    );
+    test("rgb(from rgb(20% 40% 60% / 80%) r g b / none)", "rgba(51, 102, 153, 0)");
+    // FIXME: Clarify with spec editors if 'none' should pass through to the constants.
+    test("rgb(from rgb(none none none) r g b)", "rgb(0, 0, 0)");
+    test("rgb(from rgb(none none none / none) r g b / alpha)", "rgba(0, 0, 0, 0)");
+    test("rgb(from rgb(20% none 60%) r g b)", "rgb(51, 0, 153)");
+    test(
+      "rgb(from rgb(20% 40% 60% / none) r g b / alpha)",
+      "rgba(51, 102, 153, 0)",
+    );
+
+    // hsl(from ...)
+
+    // Testing no modifications.
+    test("hsl(from rebeccapurple h s l)", "rgb(102, 51, 153)");
+    test("hsl(from rebeccapurple h s l / alpha)", "rgb(102, 51, 153)");
+    test(
+      "hsl(from rgb(20%, 40%, 60%, 80%) h s l / alpha)",
+      "rgba(51, 102, 153, 0.8)",
+    );
+    test(
+      "hsl(from hsl(120deg 20% 50% / .5) h s l / alpha)",
+      "rgba(102, 153, 102, 0.5)",
+    );
+
+    // Test nesting relative colors.
+    test("hsl(from hsl(from rebeccapurple h s l) h s l)", "rgb(102, 51, 153)");
+
+    // Testing non-sRGB origin colors to see gamut mapping.
+    test("hsl(from color(display-p3 0 1 0) h s l / alpha)", "rgb(0, 249, 66)"); // Naive clip based mapping would give rgb(0, 255, 0).
+    test("hsl(from lab(100% 104.3 -50.9) h s l)", "rgb(255, 255, 255)"); // Naive clip based mapping would give rgb(255, 150, 255).
+    test("hsl(from lab(0% 104.3 -50.9) h s l)", "rgb(42, 0, 34)"); // Naive clip based mapping would give rgb(90, 0, 76). NOTE: 0% lightness in Lab/LCH does not automatically correspond with sRGB black,
+    test("hsl(from lch(100% 116 334) h s l)", "rgb(255, 255, 255)"); // Naive clip based mapping would give rgb(255, 150, 255).
+    test("hsl(from lch(0% 116 334) h s l)", "rgb(42, 0, 34)"); // Naive clip based mapping would give rgb(90, 0, 76). NOTE: 0% lightness in Lab/LCH does not automatically correspond with sRGB black,
+    test("hsl(from oklab(100% 0.365 -0.16) h s l)", "rgb(255, 255, 255)"); // Naive clip based mapping would give rgb(255, 92, 255).
+    test("hsl(from oklab(0% 0.365 -0.16) h s l)", "rgb(0, 0, 0)"); // Naive clip based mapping would give rgb(19, 0, 24).
+    test("hsl(from oklch(100% 0.399 336.3) h s l)", "rgb(255, 255, 255)"); // Naive clip based mapping would give rgb(255, 91, 255).
+    test("hsl(from oklch(0% 0.399 336.3) h s l)", "rgb(0, 0, 0)"); // Naive clip based mapping would give rgb(20, 0, 24).
+
+    // Testing replacement with 0.
+    test("hsl(from rebeccapurple 0 0% 0%)", "rgb(0, 0, 0)");
+    test("hsl(from rebeccapurple 0deg 0% 0%)", "rgb(0, 0, 0)");
+    test("hsl(from rebeccapurple 0 0% 0% / 0)", "rgba(0, 0, 0, 0)");
+    test("hsl(from rebeccapurple 0deg 0% 0% / 0)", "rgba(0, 0, 0, 0)");
+    test("hsl(from rebeccapurple 0 s l / alpha)", "rgb(153, 51, 51)");
+    test("hsl(from rebeccapurple 0deg s l / alpha)", "rgb(153, 51, 51)");
+    test("hsl(from rebeccapurple h 0% l / alpha)", "rgb(102, 102, 102)");
+    test("hsl(from rebeccapurple h s 0% / alpha)", "rgb(0, 0, 0)");
+    test("hsl(from rebeccapurple h s l / 0)", "rgba(102, 51, 153, 0)");
+    test(
+      "hsl(from rgb(20%, 40%, 60%, 80%) 0 s l / alpha)",
+      "rgba(153, 51, 51, 0.8)",
+    );
+    test(
+      "hsl(from rgb(20%, 40%, 60%, 80%) 0deg s l / alpha)",
+      "rgba(153, 51, 51, 0.8)",
+    );
+    test(
+      "hsl(from rgb(20%, 40%, 60%, 80%) h 0% l / alpha)",
+      "rgba(102, 102, 102, 0.8)",
+    );
+    test("hsl(from rgb(20%, 40%, 60%, 80%) h s 0% / alpha)", "rgba(0, 0, 0, 0.8)");
+    test("hsl(from rgb(20%, 40%, 60%, 80%) h s l / 0)", "rgba(51, 102, 153, 0)");
+
+    // Testing replacement with a constant.
+    test("hsl(from rebeccapurple 25 s l / alpha)", "rgb(153, 93, 51)");
+    test("hsl(from rebeccapurple 25deg s l / alpha)", "rgb(153, 93, 51)");
+    test("hsl(from rebeccapurple h 20% l / alpha)", "rgb(102, 82, 122)");
+    test("hsl(from rebeccapurple h s 20% / alpha)", "rgb(51, 25, 77)");
+    test("hsl(from rebeccapurple h s l / .25)", "rgba(102, 51, 153, 0.25)");
+    test(
+      "hsl(from rgb(20%, 40%, 60%, 80%) 25 s l / alpha)",
+      "rgba(153, 93, 51, 0.8)",
+    );
+    test(
+      "hsl(from rgb(20%, 40%, 60%, 80%) 25deg s l / alpha)",
+      "rgba(153, 93, 51, 0.8)",
+    );
+    test(
+      "hsl(from rgb(20%, 40%, 60%, 80%) h 20% l / alpha)",
+      "rgba(82, 102, 122, 0.8)",
+    );
+    test(
+      "hsl(from rgb(20%, 40%, 60%, 80%) h s 20% / alpha)",
+      "rgba(25, 51, 77, 0.8)",
+    );
+    test(
+      "hsl(from rgb(20%, 40%, 60%, 80%) h s l / .2)",
+      "rgba(51, 102, 153, 0.2)",
+    );
+
+    // Testing valid permutation (types match).
+    test("hsl(from rebeccapurple h l s)", "rgb(128, 77, 179)");
+    test("hsl(from rebeccapurple h alpha l / s)", "rgba(102, 0, 204, 0.5)");
+    test("hsl(from rebeccapurple h l l / l)", "rgba(102, 61, 143, 0.4)");
+    test("hsl(from rebeccapurple h alpha alpha / alpha)", "rgb(255, 255, 255)");
+    test("hsl(from rgb(20%, 40%, 60%, 80%) h l s)", "rgb(77, 128, 179)");
+    test(
+      "hsl(from rgb(20%, 40%, 60%, 80%) h alpha l / s)",
+      "rgba(20, 102, 184, 0.5)",
+    );
+    test("hsl(from rgb(20%, 40%, 60%, 80%) h l l / l)", "rgba(61, 102, 143, 0.4)");
+    test(
+      "hsl(from rgb(20%, 40%, 60%, 80%) h alpha alpha / alpha)",
+      "rgba(163, 204, 245, 0.8)",
+    );
+
+    // Testing with calc().
+    test("hsl(from rebeccapurple calc(h) calc(s) calc(l))", "rgb(102, 51, 153)");
+    test(
+      "hsl(from rgb(20%, 40%, 60%, 80%) calc(h) calc(s) calc(l) / calc(alpha))",
+      "rgba(51, 102, 153, 0.8)",
+    );
+
+    // Testing with 'none'.
+    test("hsl(from rebeccapurple none none none)", "rgb(0, 0, 0)");
+    test("hsl(from rebeccapurple none none none / none)", "rgba(0, 0, 0, 0)");
+    test("hsl(from rebeccapurple h s none)", "rgb(0, 0, 0)");
+    test("hsl(from rebeccapurple h s none / alpha)", "rgb(0, 0, 0)");
+    test("hsl(from rebeccapurple h s l / none)", "rgba(102, 51, 153, 0)");
+    test("hsl(from rebeccapurple none s l / alpha)", "rgb(153, 51, 51)");
+    test(
+      "hsl(from hsl(120deg 20% 50% / .5) h s none / alpha)",
+      "rgba(0, 0, 0, 0.5)",
+    );
+    test(
+      "hsl(from hsl(120deg 20% 50% / .5) h s l / none)",
+      "rgba(102, 153, 102, 0)",
+    );
+    test(
+      "hsl(from hsl(120deg 20% 50% / .5) none s l / alpha)",
+      "rgba(153, 102, 102, 0.5)",
+    );
+    // FIXME: Clarify with spec editors if 'none' should pass through to the constants.
+    test("hsl(from hsl(none none none) h s l)", "rgb(0, 0, 0)");
+    test("hsl(from hsl(none none none / none) h s l / alpha)", "rgba(0, 0, 0, 0)");
+    test("hsl(from hsl(120deg none 50% / .5) h s l)", "rgb(128, 128, 128)");
+    test(
+      "hsl(from hsl(120deg 20% 50% / none) h s l / alpha)",
+      "rgba(102, 153, 102, 0)",
+    );
+    test(
+      "hsl(from hsl(none 20% 50% / .5) h s l / alpha)",
+      "rgba(153, 102, 102, 0.5)",
+    );
+
+    // hwb(from ...)
+
+    // Testing no modifications.
+    test("hwb(from rebeccapurple h w b)", "rgb(102, 51, 153)");
+    test("hwb(from rebeccapurple h w b / alpha)", "rgb(102, 51, 153)");
+    test(
+      "hwb(from rgb(20%, 40%, 60%, 80%) h w b / alpha)",
+      "rgba(51, 102, 153, 0.8)",
+    );
+    test(
+      "hwb(from hsl(120deg 20% 50% / .5) h w b / alpha)",
+      "rgba(102, 153, 102, 0.5)",
+    );
+
+    // Test nesting relative colors.
+    test("hwb(from hwb(from rebeccapurple h w b) h w b)", "rgb(102, 51, 153)");
+
+    // Testing non-sRGB origin colors to see gamut mapping.
+    test("hwb(from color(display-p3 0 1 0) h w b / alpha)", "rgb(0, 249, 66)"); // Naive clip based mapping would give rgb(0, 255, 0).
+    test("hwb(from lab(100% 104.3 -50.9) h w b)", "rgb(255, 255, 255)"); // Naive clip based mapping would give rgb(255, 150, 255).
+    test("hwb(from lab(0% 104.3 -50.9) h w b)", "rgb(42, 0, 34)"); // Naive clip based mapping would give rgb(90, 0, 76). NOTE: 0% lightness in Lab/LCH does not automatically correspond with sRGB black,
+    test("hwb(from lch(100% 116 334) h w b)", "rgb(255, 255, 255)"); // Naive clip based mapping would give rgb(255, 150, 255).
+    test("hwb(from lch(0% 116 334) h w b)", "rgb(42, 0, 34)"); // Naive clip based mapping would give rgb(90, 0, 76). NOTE: 0% lightness in Lab/LCH does not automatically correspond with sRGB black,
+    test("hwb(from oklab(100% 0.365 -0.16) h w b)", "rgb(255, 255, 255)"); // Naive clip based mapping would give rgb(255, 92, 255).
+    test("hwb(from oklab(0% 0.365 -0.16) h w b)", "rgb(0, 0, 0)"); // Naive clip based mapping would give rgb(19, 0, 24).
+    test("hwb(from oklch(100% 0.399 336.3) h w b)", "rgb(255, 255, 255)"); // Naive clip based mapping would give rgb(255, 91, 255).
+    test("hwb(from oklch(0% 0.399 336.3) h w b)", "rgb(0, 0, 0)"); // Naive clip based mapping would give rgb(20, 0, 24).
+
+    // Testing replacement with 0.
+    test("hwb(from rebeccapurple 0 0% 0%)", "rgb(255, 0, 0)");
+    test("hwb(from rebeccapurple 0deg 0% 0%)", "rgb(255, 0, 0)");
+    test("hwb(from rebeccapurple 0 0% 0% / 0)", "rgba(255, 0, 0, 0)");
+    test("hwb(from rebeccapurple 0deg 0% 0% / 0)", "rgba(255, 0, 0, 0)");
+    test("hwb(from rebeccapurple 0 w b / alpha)", "rgb(153, 51, 51)");
+    test("hwb(from rebeccapurple 0deg w b / alpha)", "rgb(153, 51, 51)");
+    test("hwb(from rebeccapurple h 0% b / alpha)", "rgb(77, 0, 153)");
+    test("hwb(from rebeccapurple h w 0% / alpha)", "rgb(153, 51, 255)");
+    test("hwb(from rebeccapurple h w b / 0)", "rgba(102, 51, 153, 0)");
+    test(
+      "hwb(from rgb(20%, 40%, 60%, 80%) 0 w b / alpha)",
+      "rgba(153, 51, 51, 0.8)",
+    );
+    test(
+      "hwb(from rgb(20%, 40%, 60%, 80%) 0deg w b / alpha)",
+      "rgba(153, 51, 51, 0.8)",
+    );
+    test(
+      "hwb(from rgb(20%, 40%, 60%, 80%) h 0% b / alpha)",
+      "rgba(0, 77, 153, 0.8)",
+    );
+    test(
+      "hwb(from rgb(20%, 40%, 60%, 80%) h w 0% / alpha)",
+      "rgba(51, 153, 255, 0.8)",
+    );
+    test("hwb(from rgb(20%, 40%, 60%, 80%) h w b / 0)", "rgba(51, 102, 153, 0)");
+
+    // Testing replacement with a constant.
+    test("hwb(from rebeccapurple 25 w b / alpha)", "rgb(153, 93, 51)");
+    test("hwb(from rebeccapurple 25deg w b / alpha)", "rgb(153, 93, 51)");
+    test("hwb(from rebeccapurple h 20% b / alpha)", "rgb(102, 51, 153)");
+    test("hwb(from rebeccapurple h w 20% / alpha)", "rgb(128, 51, 204)");
+    test("hwb(from rebeccapurple h w b / .2)", "rgba(102, 51, 153, 0.2)");
+    test(
+      "hwb(from rgb(20%, 40%, 60%, 80%) 25 w b / alpha)",
+      "rgba(153, 93, 51, 0.8)",
+    );
+    test(
+      "hwb(from rgb(20%, 40%, 60%, 80%) 25deg w b / alpha)",
+      "rgba(153, 93, 51, 0.8)",
+    );
+    test(
+      "hwb(from rgb(20%, 40%, 60%, 80%) h 20% b / alpha)",
+      "rgba(51, 102, 153, 0.8)",
+    );
+    test(
+      "hwb(from rgb(20%, 40%, 60%, 80%) h w 20% / alpha)",
+      "rgba(51, 128, 204, 0.8)",
+    );
+    test(
+      "hwb(from rgb(20%, 40%, 60%, 80%) h w b / .2)",
+      "rgba(51, 102, 153, 0.2)",
+    );
+
+    // Testing valid permutation (types match).
+    test("hwb(from rebeccapurple h b w)", "rgb(153, 102, 204)");
+    test("hwb(from rebeccapurple h alpha w / b)", "rgba(213, 213, 213, 0.4)");
+    test("hwb(from rebeccapurple h w w / w)", "rgba(128, 51, 204, 0.2)");
+    test("hwb(from rebeccapurple h alpha alpha / alpha)", "rgb(128, 128, 128)");
+    test("hwb(from rgb(20%, 40%, 60%, 80%) h b w)", "rgb(102, 153, 204)");
+    test(
+      "hwb(from rgb(20%, 40%, 60%, 80%) h alpha w / b)",
+      "rgba(204, 204, 204, 0.4)",
+    );
+    test("hwb(from rgb(20%, 40%, 60%, 80%) h w w / w)", "rgba(51, 128, 204, 0.2)");
+    test(
+      "hwb(from rgb(20%, 40%, 60%, 80%) h alpha alpha / alpha)",
+      "rgba(128, 128, 128, 0.8)",
+    );
+
+    // Testing with calc().
+    test("hwb(from rebeccapurple calc(h) calc(w) calc(b))", "rgb(102, 51, 153)");
+    test(
+      "hwb(from rgb(20%, 40%, 60%, 80%) calc(h) calc(w) calc(b) / calc(alpha))",
+      "rgba(51, 102, 153, 0.8)",
+    );
+
+    // Testing with 'none'.
+    test("hwb(from rebeccapurple none none none)", "rgb(255, 0, 0)");
     test("hwb(from rebeccapurple none none none / none)", "rgba(255, 0, 0, 0)");
     test("hwb(from rebeccapurple h w none)", "rgb(153, 51, 255)");
     test("hwb(from rebeccapurple h w none / alpha)", "rgb(153, 51, 255)");
@@ -15434,6 +18425,16 @@ mod tests {
       ".foo{--color:lch(49.4431% 40.4806 162.546)}",
     );
 
+    assert!(CssColor::parse_string("color-mix(in srgb, currentColor, red)").is_err());
+    assert!(CssColor::parse_string("color-mix(in oklch, red, currentColor 20%)").is_err());
+    let property = Property::parse_string(
+      "color".into(),
+      "color-mix(in srgb, currentColor, red)",
+      ParserOptions::default(),
+    )
+    .unwrap();
+    assert!(matches!(property, Property::Unparsed(_)));
+
     // regex for converting web platform tests:
     // test_computed_value\(.*?, `(.*?)`, `(.*?)`\);
     // minify_test(".foo { color: $1 }", ".foo{color:$2}");
@@ -16858,6 +19859,38 @@ mod tests {
       ".foo { grid-template-columns: repeat(5, auto); }",
       ".foo{grid-template-columns:repeat(5,auto)}",
     );
+    minify_test(
+      ".foo { grid-template-columns: repeat(2, 1fr); }",
+      ".foo{grid-template-columns:1fr 1fr}",
+    );
+    minify_test(
+      ".foo { grid-template-columns: repeat(1, 100px 1fr); }",
+      ".foo{grid-template-columns:100px 1fr}",
+    );
+    minify_test(
+      ".foo { grid-template-columns: [full-start] repeat(2, 10px) [full-end]; }",
+      ".foo{grid-template-columns:[full-start]10px 10px[full-end]}",
+    );
+    minify_test(
+      ".foo { grid-template-columns: repeat(3, minmax(0, 1fr)); }",
+      ".foo{grid-template-columns:repeat(3,minmax(0,1fr))}",
+    );
+    minify_test(
+      ".foo { grid-template-columns: repeat(2, fit-content(200px)); }",
+      ".foo{grid-template-columns:repeat(2,fit-content(200px))}",
+    );
+    minify_test(
+      ".foo { grid-template-columns: repeat(2, [a] 1fr [b]); }",
+      ".foo{grid-template-columns:repeat(2,[a]1fr[b])}",
+    );
+    minify_test(
+      ".foo { grid-template-columns: repeat(auto-fit, minmax(200px, 1fr)); }",
+      ".foo{grid-template-columns:repeat(auto-fit,minmax(200px,1fr))}",
+    );
+    minify_test(
+      ".foo { grid-template-columns: repeat(auto-fill, [col-start] 100px [col-end]) 50px; }",
+      ".foo{grid-template-columns:repeat(auto-fill,[col-start]100px[col-end]) 50px}",
+    );
     minify_test(
       ".foo { grid-template-columns: repeat(auto-fill, 250px); }",
       ".foo{grid-template-columns:repeat(auto-fill,250px)}",
@@ -16962,6 +19995,26 @@ mod tests {
     "#},
     );
 
+    minify_test(
+      r#"
+      .foo {
+        grid-template-areas: "a   b"
+                             "c   d";
+      }
+    "#,
+      ".foo{grid-template-areas:\"a b\"\"c d\"}",
+    );
+    minify_test(
+      ".foo { grid-template-areas: \"a a\" \"a a\" \"b .\"; }",
+      ".foo{grid-template-areas:\"a a\"\"a a\"\"b.\"}",
+    );
+    use crate::properties::grid::{GridTemplate, GridTemplateAreas};
+    assert!(<GridTemplateAreas as Parse>::parse_string(r#""a b" "c""#).is_err());
+    assert!(<GridTemplateAreas as Parse>::parse_string(r#""a b" "b b""#).is_err());
+    assert!(<GridTemplateAreas as Parse>::parse_string(r#""a . a""#).is_err());
+    assert!(<GridTemplateAreas as Parse>::parse_string(r#""a" "." "a""#).is_err());
+    assert!(<GridTemplate as Parse>::parse_string(r#""a b" "b b" / 1fr 1fr"#).is_err());
+
     minify_test(
       r#"
       .foo {
@@ -16991,6 +20044,20 @@ mod tests {
     "#,
       ".foo{grid-template:[header-top]\"a a a\"[header-bottom main-top]\"b b b\"1fr[main-bottom]/auto 1fr auto}",
     );
+    minify_test(
+      r#"
+      .foo {
+        grid-template: [top] "a b" 100px [middle]
+                             "c d" 1fr [bottom]
+                       / [left] 1fr [center] 2fr [right];
+      }
+    "#,
+      ".foo{grid-template:[top]\"a b\"100px[middle]\"c d\"1fr[bottom]/[left]1fr[center]2fr[right]}",
+    );
+    minify_test(
+      ".foo { grid-template: repeat(2, 1fr) / repeat(3, [col] 100px); }",
+      ".foo{grid-template:1fr 1fr/repeat(3,[col]100px)}",
+    );
 
     minify_test(
       ".foo { grid-template: auto 1fr / auto 1fr auto; }",
@@ -17640,6 +20707,36 @@ mod tests {
       ".foo { color: var(--color, rgb(255, 255, 0)); }",
       ".foo{color:var(--color,#ff0)}",
     );
+    minify_test(
+      ".foo { width: var(--a, var(--b, var(--c,  10px ))); }",
+      ".foo{width:var(--a,var(--b,var(--c,10px)))}",
+    );
+    minify_test(
+      ".foo { font-family: var(--font, Helvetica Neue , Arial,  sans-serif); }",
+      ".foo{font-family:var(--font,Helvetica Neue,Arial,sans-serif)}",
+    );
+    minify_test(
+      ".foo { --test: var(--foo, 1px, 2px); }",
+      ".foo{--test:var(--foo,1px,2px)}",
+    );
+    minify_test(
+      ".foo { transition: var(--a, opacity .5s, transform 1s), color 2s; }",
+      ".foo{transition:var(--a,opacity .5s,transform 1s),color 2s}",
+    );
+    // Zero lengths keep their unit, since the fallback may be substituted into a context such as calc().
+    minify_test(
+      ".foo { width: calc(var(--a, 0px) + 10px); }",
+      ".foo{width:calc(var(--a,0px) + 10px)}",
+    );
+    test(
+      ".foo { width: var(--a, var(--b, 10px)); margin: var(--m, 1px, 2px) }",
+      indoc! {r#"
+      .foo {
+        width: var(--a, var(--b, 10px));
+        margin: var(--m, 1px, 2px);
+      }
+      "#},
+    );
     minify_test(
       ".foo { color: var(--color, #ffff00); }",
       ".foo{color:var(--color,#ff0)}",
@@ -17649,6 +20746,42 @@ mod tests {
       ".foo{color:var(--color,rgb(var(--red),var(--green),0))}",
     );
     minify_test(".foo { --test: .5s; }", ".foo{--test:.5s}");
+    minify_test(
+      ".foo { padding-top: env(safe-area-inset-top); }",
+      ".foo{padding-top:env(safe-area-inset-top)}",
+    );
+    minify_test(
+      ".foo { padding-top: env(safe-area-inset-top, 0px); }",
+      ".foo{padding-top:env(safe-area-inset-top,0px)}",
+    );
+    minify_test(
+      ".foo { padding-top: env(safe-area-inset-top, calc(10px + 1em)); }",
+      ".foo{padding-top:env(safe-area-inset-top,calc(10px + 1em))}",
+    );
+    minify_test(
+      ".foo { height: calc(100vh - env(safe-area-inset-bottom, 20px)); }",
+      ".foo{height:calc(100vh - env(safe-area-inset-bottom,20px))}",
+    );
+    minify_test(
+      ".foo { padding: env(safe-area-inset-top) env(safe-area-inset-right); }",
+      ".foo{padding:env(safe-area-inset-top)env(safe-area-inset-right)}",
+    );
+    minify_test(
+      ".foo { --test: env(safe-area-inset-top, var(--fallback)); }",
+      ".foo{--test:env(safe-area-inset-top,var(--fallback))}",
+    );
+    minify_test(
+      ".foo { width: env(viewport-segment-width 0 0); }",
+      ".foo{width:env(viewport-segment-width 0 0)}",
+    );
+    test(
+      ".foo { padding: env(safe-area-inset-top, 0px) env(safe-area-inset-right, 0px); }",
+      indoc! {r#"
+      .foo {
+        padding: env(safe-area-inset-top, 0px) env(safe-area-inset-right, 0px);
+      }
+      "#},
+    );
     minify_test(".foo { --theme-sizes-1\\/12: 2 }", ".foo{--theme-sizes-1\\/12:2}");
 
     prefix_test(
@@ -17710,7 +20843,7 @@ mod tests {
 
       @supports (color: color(display-p3 0 0 0)) {
         .foo {
-          --custom: color(display-p3 .643308 .192455 .167712);
+          --custom: color(display-p3 0.643308 0.192455 0.167712);
         }
       }
 
@@ -17735,7 +20868,7 @@ mod tests {
     "#,
       indoc! {r#"
       .foo {
-        --custom: color(display-p3 .643308 .192455 .167712);
+        --custom: color(display-p3 0.643308 0.192455 0.167712);
       }
 
       @supports (color: lab(0% 0 0)) {
@@ -17792,7 +20925,7 @@ mod tests {
     "#,
       indoc! {r#"
       .foo {
-        --custom: color(display-p3 .724144 .386777 .148795);
+        --custom: color(display-p3 0.724144 0.386777 0.148795);
       }
 
       @supports (color: lab(0% 0 0)) {
@@ -17820,7 +20953,7 @@ mod tests {
 
       @supports (color: color(display-p3 0 0 0)) {
         .foo {
-          --custom: color(display-p3 .724144 .386777 .148795);
+          --custom: color(display-p3 0.724144 0.386777 0.148795);
         }
       }
 
@@ -17852,8 +20985,8 @@ mod tests {
 
       @supports (color: color(display-p3 0 0 0)) {
         .foo {
-          --foo: color(display-p3 .724144 .386777 .148795);
-          --bar: color(display-p3 .643308 .192455 .167712);
+          --foo: color(display-p3 0.724144 0.386777 0.148795);
+          --bar: color(display-p3 0.643308 0.192455 0.167712);
         }
       }
 
@@ -18085,11 +21218,11 @@ mod tests {
       @supports (color: color(display-p3 0 0 0)) {
         @keyframes foo {
           from {
-            --custom: color(display-p3 .643308 .192455 .167712);
+            --custom: color(display-p3 0.643308 0.192455 0.167712);
           }
 
           to {
-            --custom: color(display-p3 .972962 -.362078 .804206);
+            --custom: color(display-p3 0.972962 -0.362078 0.804206);
           }
         }
       }
@@ -18215,7 +21348,34 @@ mod tests {
         color: #ff0;
       }
     "#},
-    )
+    );
+
+    minify_test("@charset \"UTF-8\"; .foo { color: red }", ".foo{color:red}");
+    minify_test("@charset \"utf-8\";\n.foo { color: red }", ".foo{color:red}");
+    minify_test("\u{feff}.foo { color: red }", ".foo{color:red}");
+    minify_test("\u{feff}@charset \"UTF-8\"; .foo { color: red }", ".foo{color:red}");
+    test("\u{feff}.foo { color: red }", ".foo {\n  color: red;\n}\n");
+    error_test(
+      "@charset \"iso-8859-15\"; .foo { color: red }",
+      ParserError::UnsupportedCharset("iso-8859-15".into()),
+    );
+    error_test(
+      "\u{feff}@charset \"windows-1252\"; .foo { color: red }",
+      ParserError::UnsupportedCharset("windows-1252".into()),
+    );
+    error_test(
+      ".foo { color: red } @charset \"Shift_JIS\"; .bar { color: red }",
+      ParserError::UnsupportedCharset("Shift_JIS".into()),
+    );
+
+    let res = StyleSheet::parse("\u{feff}.foo { color: red }", ParserOptions::default()).unwrap();
+    assert_eq!(
+      match &res.rules.0[0] {
+        CssRule::Style(style) => (style.loc.line, style.loc.column),
+        _ => unreachable!(),
+      },
+      (0, 1)
+    );
   }
 
   #[test]
@@ -18420,7 +21580,7 @@ mod tests {
         }
 
         figure > figcaption > p {
-          font-size: .9rem;
+          font-size: 0.9rem;
         }
       "#},
     );
@@ -18895,110 +22055,228 @@ mod tests {
         }
       "#,
       indoc! {r#"
-        .foo .bar.baz {
-          background: green;
+        .foo .bar.baz {
+          background: green;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        .foo .bar {
+          @nest .baz& {
+            background: green;
+          }
+        }
+      "#,
+      indoc! {r#"
+        .baz:is(.foo .bar) {
+          background: green;
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        .foo .bar {
+          @nest .baz & {
+            background: green;
+          }
+        }
+      "#,
+      indoc! {r#"
+        .baz :is(.foo .bar) {
+          background: green;
+        }
+      "#},
+    );
+
+    nesting_test_no_targets(
+      r#"
+        .foo {
+          color: blue;
+          @nest .bar & {
+            color: red;
+            &.baz {
+              color: green;
+            }
+          }
+        }
+      "#,
+      indoc! {r#"
+        .foo {
+          color: #00f;
+
+          @nest .bar & {
+            color: red;
+
+            &.baz {
+              color: green;
+            }
+          }
+        }
+      "#},
+    );
+
+    nesting_test_no_targets(
+      r#"
+        .foo {
+          color: blue;
+          &div {
+            color: red;
+          }
+
+          &span {
+            color: purple;
+          }
+        }
+      "#,
+      indoc! {r#"
+        .foo {
+          color: #00f;
+
+          &div {
+            color: red;
+          }
+
+          &span {
+            color: purple;
+          }
+        }
+      "#},
+    );
+
+    nesting_test_no_targets(
+      r#"
+        .error, .invalid {
+          &:hover > .baz { color: red; }
+        }
+      "#,
+      indoc! {r#"
+        .error, .invalid {
+          &:hover > .baz {
+            color: red;
+          }
+        }
+      "#},
+    );
+
+    nesting_test(
+      r#"
+        .foo {
+          > .bar {
+            color: red;
+          }
+
+          + .baz {
+            color: green;
+          }
+
+          h1 {
+            color: blue;
+          }
+        }
+      "#,
+      indoc! {r#"
+        .foo > .bar {
+          color: red;
+        }
+
+        .foo + .baz {
+          color: green;
+        }
+
+        .foo h1 {
+          color: #00f;
         }
       "#},
     );
 
-    nesting_test(
+    nesting_test_no_targets(
       r#"
-        .foo .bar {
-          @nest .baz& {
-            background: green;
+        .foo {
+          > .bar {
+            color: red;
           }
         }
       "#,
       indoc! {r#"
-        .baz:is(.foo .bar) {
-          background: green;
+        .foo {
+          & > .bar {
+            color: red;
+          }
         }
       "#},
     );
 
-    nesting_test(
+    let targets = Browsers {
+      chrome: Some(80 << 16),
+      ..Browsers::default()
+    };
+
+    nesting_test_with_targets(
       r#"
-        .foo .bar {
-          @nest .baz & {
-            background: green;
+        .foo, .bar {
+          &:hover {
+            color: red;
           }
         }
       "#,
       indoc! {r#"
-        .baz :is(.foo .bar) {
-          background: green;
+        .foo:hover, .bar:hover {
+          color: red;
         }
       "#},
+      targets,
     );
 
-    nesting_test_no_targets(
+    nesting_test_with_targets(
       r#"
-        .foo {
+        .a, .b {
           color: blue;
-          @nest .bar & {
+          & .c, & .d {
             color: red;
-            &.baz {
+            > .e {
               color: green;
             }
           }
         }
       "#,
       indoc! {r#"
-        .foo {
+        .a, .b {
           color: #00f;
+        }
 
-          @nest .bar & {
-            color: red;
+        .a .c, .a .d, .b .c, .b .d {
+          color: red;
+        }
 
-            &.baz {
-              color: green;
-            }
-          }
+        .a .c > .e, .a .d > .e, .b .c > .e, .b .d > .e {
+          color: green;
         }
       "#},
+      targets,
     );
 
-    nesting_test_no_targets(
+    nesting_test_with_targets(
       r#"
-        .foo {
+        .a, .b {
           color: blue;
-          &div {
+          & + & {
             color: red;
           }
-
-          &span {
-            color: purple;
-          }
         }
       "#,
       indoc! {r#"
-        .foo {
+        .a, .b {
           color: #00f;
-
-          &div {
-            color: red;
-          }
-
-          &span {
-            color: purple;
-          }
         }
-      "#},
-    );
 
-    nesting_test_no_targets(
-      r#"
-        .error, .invalid {
-          &:hover > .baz { color: red; }
-        }
-      "#,
-      indoc! {r#"
-        .error, .invalid {
-          &:hover > .baz {
-            color: red;
-          }
+        :is(.a, .b) + :is(.a, .b) {
+          color: red;
         }
       "#},
+      targets,
     );
   }
 
@@ -19228,6 +22506,34 @@ mod tests {
       Default::default(),
     );
 
+    css_modules_test(
+      r#"
+      :global(#foo) .bar, #baz :global(.qux) {
+        color: red;
+      }
+
+      :global(.foo):hover, :local(#id):focus {
+        color: green;
+      }
+    "#,
+      indoc! {r#"
+      #foo .EgL3uq_bar, #EgL3uq_baz .qux {
+        color: red;
+      }
+
+      .foo:hover, #EgL3uq_id:focus {
+        color: green;
+      }
+    "#},
+      map! {
+        "bar" => "EgL3uq_bar",
+        "baz" => "EgL3uq_baz",
+        "id" => "EgL3uq_id"
+      },
+      HashMap::new(),
+      Default::default(),
+    );
+
     // :global(:local(.hi)) {
     //   color: green;
     // }
@@ -19697,40 +23003,182 @@ mod tests {
         to { color: yellow }
       }
 
-      @counter-style circles {
-        symbols: Ⓐ Ⓑ Ⓒ;
+      @counter-style circles {
+        symbols: Ⓐ Ⓑ Ⓒ;
+      }
+
+      @keyframes fade {
+        from { opacity: 0 }
+        to { opacity: 1 }
+      }
+    "#;
+
+    let expected = indoc! {r#"
+      .foo {
+        color: red;
+      }
+
+      #id {
+        animation: 2s test;
+      }
+
+      @keyframes test {
+        from {
+          color: red;
+        }
+
+        to {
+          color: #ff0;
+        }
+      }
+    "#};
+
+    let mut stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
+    stylesheet
+      .minify(MinifyOptions {
+        unused_symbols: vec!["bar", "other_id", "fade", "circles"]
+          .iter()
+          .map(|s| String::from(*s))
+          .collect(),
+        ..MinifyOptions::default()
+      })
+      .unwrap();
+    let res = stylesheet.to_css(PrinterOptions::default()).unwrap();
+    assert_eq!(res.code, expected);
+
+    let source = r#"
+      .foo {
+        color: red;
+
+        &.bar {
+          color: green;
+        }
+      }
+    "#;
+
+    let expected = indoc! {r#"
+      .foo {
+        color: red;
+      }
+    "#};
+
+    let mut stylesheet = StyleSheet::parse(
+      &source,
+      ParserOptions {
+        nesting: true,
+        ..ParserOptions::default()
+      },
+    )
+    .unwrap();
+    stylesheet
+      .minify(MinifyOptions {
+        unused_symbols: vec!["bar"].iter().map(|s| String::from(*s)).collect(),
+        ..MinifyOptions::default()
+      })
+      .unwrap();
+    let res = stylesheet.to_css(PrinterOptions::default()).unwrap();
+    assert_eq!(res.code, expected);
+
+    let source = r#"
+      .foo {
+        color: red;
+
+        &.bar {
+          color: purple;
+        }
+
+        @nest &.bar {
+          color: orange;
+        }
+
+        @nest :not(&) {
+          color: green;
+        }
+
+        @media (orientation: portrait) {
+          color: brown;
+        }
+      }
+
+      .x {
+        color: purple;
+
+        &.y {
+          color: green;
+        }
+      }
+    "#;
+
+    let expected = indoc! {r#"
+      :not(.foo) {
+        color: green;
+      }
+    "#};
+
+    let mut stylesheet = StyleSheet::parse(
+      &source,
+      ParserOptions {
+        nesting: true,
+        ..ParserOptions::default()
+      },
+    )
+    .unwrap();
+    stylesheet
+      .minify(MinifyOptions {
+        unused_symbols: vec!["foo", "x"].iter().map(|s| String::from(*s)).collect(),
+        ..MinifyOptions::default()
+      })
+      .unwrap();
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        targets: Some(Browsers {
+          chrome: Some(95 << 16),
+          ..Browsers::default()
+        }),
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(res.code, expected);
+
+    let source = r#"
+      @property --EgL3uq_foo {
+        syntax: "<color>";
+        inherits: false;
+        initial-value: #ff0;
+      }
+
+      @font-palette-values --EgL3uq_Cooler {
+        font-family: Bixa;
+        base-palette: 1;
+        override-colors: 1 #7EB7E4;
+      }
+
+      .EgL3uq_foo {
+        --EgL3uq_foo: red;
       }
 
-      @keyframes fade {
-        from { opacity: 0 }
-        to { opacity: 1 }
+      .EgL3uq_bar {
+        color: green;
       }
     "#;
 
     let expected = indoc! {r#"
-      .foo {
-        color: red;
-      }
-
-      #id {
-        animation: 2s test;
-      }
-
-      @keyframes test {
-        from {
-          color: red;
-        }
-
-        to {
-          color: #ff0;
-        }
+      .EgL3uq_bar {
+        color: green;
       }
     "#};
 
-    let mut stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
+    let mut stylesheet = StyleSheet::parse(
+      &source,
+      ParserOptions {
+        nesting: true,
+        ..ParserOptions::default()
+      },
+    )
+    .unwrap();
     stylesheet
       .minify(MinifyOptions {
-        unused_symbols: vec!["bar", "other_id", "fade", "circles"]
+        unused_symbols: vec!["--EgL3uq_foo", "--EgL3uq_Cooler"]
           .iter()
           .map(|s| String::from(*s))
           .collect(),
@@ -19739,335 +23187,945 @@ mod tests {
       .unwrap();
     let res = stylesheet.to_css(PrinterOptions::default()).unwrap();
     assert_eq!(res.code, expected);
+  }
 
-    let source = r#"
-      .foo {
-        color: red;
+  #[test]
+  fn test_remove_unused_at_rules() {
+    fn remove_unused_test(source: &str, expected: &str) {
+      minify_printer_test(
+        source,
+        expected,
+        MinifyOptions {
+          remove_unused_keyframes: true,
+          remove_unused_font_faces: true,
+          ..MinifyOptions::default()
+        },
+        PrinterOptions {
+          minify: true,
+          ..PrinterOptions::default()
+        },
+      );
+    }
 
-        &.bar {
-          color: green;
-        }
-      }
-    "#;
+    remove_unused_test(
+      "@keyframes fade { from { opacity: 0 } } @keyframes spin { to { rotate: 1turn } } .foo { animation: 1s fade }",
+      "@keyframes fade{0%{opacity:0}}.foo{animation:1s fade}",
+    );
+    remove_unused_test(
+      "@keyframes fade { from { opacity: 0 } } .foo { animation-name: spin, fade }",
+      "@keyframes fade{0%{opacity:0}}.foo{animation-name:spin,fade}",
+    );
+    remove_unused_test(
+      "@media (min-width: 100px) { @keyframes fade { from { opacity: 0 } } } .foo { color: red }",
+      ".foo{color:red}",
+    );
+    remove_unused_test(
+      "@keyframes fade { from { opacity: 0 } } .foo { animation: var(--duration) fade }",
+      "@keyframes fade{0%{opacity:0}}.foo{animation:var(--duration) fade}",
+    );
+    remove_unused_test(
+      "@keyframes fade { from { opacity: 0 } } .foo { animation-name: var(--name) }",
+      "@keyframes fade{0%{opacity:0}}.foo{animation-name:var(--name)}",
+    );
+    remove_unused_test(
+      "@font-face { font-family: Foo; src: url(foo.woff) } @font-face { font-family: Bar; src: url(bar.woff) } .foo { font-family: foo, sans-serif }",
+      "@font-face{font-family:Foo;src:url(foo.woff)}.foo{font-family:foo,sans-serif}",
+    );
+    remove_unused_test(
+      "@font-face { font-family: \"Foo Bar\"; src: url(foo.woff) } .foo { font: 12px Foo Bar }",
+      "@font-face{font-family:Foo Bar;src:url(foo.woff)}.foo{font:12px Foo Bar}",
+    );
+    remove_unused_test(
+      "@font-face { font-family: Foo; src: url(foo.woff) } .foo { font-family: var(--font) }",
+      "@font-face{font-family:Foo;src:url(foo.woff)}.foo{font-family:var(--font)}",
+    );
 
-    let expected = indoc! {r#"
-      .foo {
-        color: red;
-      }
-    "#};
+    let mut stylesheet = StyleSheet::parse(
+      "@keyframes fade { from { opacity: 0 } } @keyframes spin { to { rotate: 1turn } } .foo { @nest .bar & { @media print { animation: 1s fade } } }",
+      ParserOptions {
+        nesting: true,
+        ..ParserOptions::default()
+      },
+    )
+    .unwrap();
+    let targets = Some(Browsers {
+      chrome: Some(95 << 16),
+      ..Browsers::default()
+    });
+    stylesheet
+      .minify(MinifyOptions {
+        targets,
+        remove_unused_keyframes: true,
+        ..MinifyOptions::default()
+      })
+      .unwrap();
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        targets,
+        minify: true,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(
+      res.code,
+      "@keyframes fade{0%{opacity:0}}@media print{.bar .foo{animation:1s fade}}"
+    );
+  }
+
+  #[test]
+  fn test_svg() {
+    minify_test(".foo { fill: yellow; }", ".foo{fill:#ff0}");
+    minify_test(".foo { fill: url(#foo); }", ".foo{fill:url(#foo)}");
+    minify_test(".foo { fill: url(#foo) none; }", ".foo{fill:url(#foo) none}");
+    minify_test(".foo { fill: url(#foo) yellow; }", ".foo{fill:url(#foo) #ff0}");
+    minify_test(".foo { fill: none; }", ".foo{fill:none}");
+    minify_test(".foo { fill: context-fill; }", ".foo{fill:context-fill}");
+    minify_test(".foo { fill: context-stroke; }", ".foo{fill:context-stroke}");
+
+    minify_test(".foo { stroke: yellow; }", ".foo{stroke:#ff0}");
+    minify_test(".foo { stroke: url(#foo); }", ".foo{stroke:url(#foo)}");
+    minify_test(".foo { stroke: url(#foo) none; }", ".foo{stroke:url(#foo) none}");
+    minify_test(".foo { stroke: url(#foo) yellow; }", ".foo{stroke:url(#foo) #ff0}");
+    minify_test(".foo { stroke: none; }", ".foo{stroke:none}");
+    minify_test(".foo { stroke: context-fill; }", ".foo{stroke:context-fill}");
+    minify_test(".foo { stroke: context-stroke; }", ".foo{stroke:context-stroke}");
+
+    minify_test(".foo { marker-start: url(#foo); }", ".foo{marker-start:url(#foo)}");
+
+    minify_test(".foo { stroke-dasharray: 4 1 2; }", ".foo{stroke-dasharray:4 1 2}");
+    minify_test(".foo { stroke-dasharray: 4,1,2; }", ".foo{stroke-dasharray:4 1 2}");
+    minify_test(".foo { stroke-dasharray: 4, 1, 2; }", ".foo{stroke-dasharray:4 1 2}");
+    minify_test(
+      ".foo { stroke-dasharray: 4px, 1px, 2px; }",
+      ".foo{stroke-dasharray:4 1 2}",
+    );
+
+    minify_test(".foo { mask: url('foo.svg'); }", ".foo{mask:url(foo.svg)}");
+    minify_test(
+      ".foo { mask: url(masks.svg#star) luminance }",
+      ".foo{mask:url(masks.svg#star) luminance}",
+    );
+    minify_test(
+      ".foo { mask: url(masks.svg#star) 40px 20px }",
+      ".foo{mask:url(masks.svg#star) 40px 20px}",
+    );
+    minify_test(
+      ".foo { mask: url(masks.svg#star) 0 0 / 50px 50px }",
+      ".foo{mask:url(masks.svg#star) 0 0/50px 50px}",
+    );
+    minify_test(
+      ".foo { mask: url(masks.svg#star) repeat-x }",
+      ".foo{mask:url(masks.svg#star) repeat-x}",
+    );
+    minify_test(
+      ".foo { mask: url(masks.svg#star) stroke-box }",
+      ".foo{mask:url(masks.svg#star) stroke-box}",
+    );
+    minify_test(
+      ".foo { mask: url(masks.svg#star) stroke-box stroke-box }",
+      ".foo{mask:url(masks.svg#star) stroke-box}",
+    );
+    minify_test(
+      ".foo { mask: url(masks.svg#star) border-box }",
+      ".foo{mask:url(masks.svg#star)}",
+    );
+    minify_test(
+      ".foo { mask: url(masks.svg#star) left / 16px repeat-y, url(masks.svg#circle) right / 16px repeat-y }",
+      ".foo{mask:url(masks.svg#star) 0/16px repeat-y,url(masks.svg#circle) 100%/16px repeat-y}",
+    );
+    minify_test(
+      ".foo { mask: url(a.svg) center / 10px 20px repeat-y content-box exclude alpha }",
+      ".foo{mask:url(a.svg) 50%/10px 20px repeat-y content-box exclude alpha}",
+    );
+    minify_test(
+      ".foo { mask: url(a.svg) 0 0 / 16px, url(b.svg) 100% 0 / 16px no-repeat }",
+      ".foo{mask:url(a.svg) 0 0/16px,url(b.svg) 100% 0/16px no-repeat}",
+    );
+
+    minify_test(
+      ".foo { mask-border: url('border-mask.png') 25; }",
+      ".foo{mask-border:url(border-mask.png) 25}",
+    );
+    minify_test(
+      ".foo { mask-border: url('border-mask.png') 25 / 35px / 12px space alpha; }",
+      ".foo{mask-border:url(border-mask.png) 25/35px/12px space}",
+    );
+    minify_test(
+      ".foo { mask-border: url('border-mask.png') 25 / 35px / 12px space luminance; }",
+      ".foo{mask-border:url(border-mask.png) 25/35px/12px space luminance}",
+    );
+    minify_test(
+      ".foo { mask-border: url('border-mask.png') luminance 25 / 35px / 12px space; }",
+      ".foo{mask-border:url(border-mask.png) 25/35px/12px space luminance}",
+    );
+
+    minify_test(
+      ".foo { clip-path: url('clip.svg#star'); }",
+      ".foo{clip-path:url(clip.svg#star)}",
+    );
+    minify_test(".foo { clip-path: margin-box; }", ".foo{clip-path:margin-box}");
+    minify_test(
+      ".foo { clip-path: inset(100px 50px); }",
+      ".foo{clip-path:inset(100px 50px)}",
+    );
+    minify_test(
+      ".foo { clip-path: inset(100px 50px round 5px); }",
+      ".foo{clip-path:inset(100px 50px round 5px)}",
+    );
+    minify_test(
+      ".foo { clip-path: inset(100px 50px round 5px 5px 5px 5px); }",
+      ".foo{clip-path:inset(100px 50px round 5px)}",
+    );
+    minify_test(".foo { clip-path: circle(50px); }", ".foo{clip-path:circle(50px)}");
+    minify_test(
+      ".foo { clip-path: circle(50px at center center); }",
+      ".foo{clip-path:circle(50px)}",
+    );
+    minify_test(
+      ".foo { clip-path: circle(50px at 50% 50%); }",
+      ".foo{clip-path:circle(50px)}",
+    );
+    minify_test(
+      ".foo { clip-path: circle(50px at 0 100px); }",
+      ".foo{clip-path:circle(50px at 0 100px)}",
+    );
+    minify_test(
+      ".foo { clip-path: circle(closest-side at 0 100px); }",
+      ".foo{clip-path:circle(at 0 100px)}",
+    );
+    minify_test(
+      ".foo { clip-path: circle(farthest-side at 0 100px); }",
+      ".foo{clip-path:circle(farthest-side at 0 100px)}",
+    );
+    minify_test(
+      ".foo { clip-path: circle(closest-side at 50% 50%); }",
+      ".foo{clip-path:circle()}",
+    );
+    minify_test(
+      ".foo { clip-path: ellipse(50px 60px at 0 10% 20%); }",
+      ".foo{clip-path:ellipse(50px 60px at 0 10% 20%)}",
+    );
+    minify_test(
+      ".foo { clip-path: ellipse(50px 60px at center center); }",
+      ".foo{clip-path:ellipse(50px 60px)}",
+    );
+    minify_test(
+      ".foo { clip-path: ellipse(closest-side closest-side at 50% 50%); }",
+      ".foo{clip-path:ellipse()}",
+    );
+    minify_test(
+      ".foo { clip-path: ellipse(closest-side closest-side at 10% 20%); }",
+      ".foo{clip-path:ellipse(at 10% 20%)}",
+    );
+    minify_test(
+      ".foo { clip-path: ellipse(farthest-side closest-side at 10% 20%); }",
+      ".foo{clip-path:ellipse(farthest-side closest-side at 10% 20%)}",
+    );
+    minify_test(
+      ".foo { clip-path: polygon(50% 0%, 100% 50%, 50% 100%, 0% 50%); }",
+      ".foo{clip-path:polygon(50% 0%,100% 50%,50% 100%,0% 50%)}",
+    );
+    minify_test(
+      ".foo { clip-path: polygon(nonzero, 50% 0%, 100% 50%, 50% 100%, 0% 50%); }",
+      ".foo{clip-path:polygon(50% 0%,100% 50%,50% 100%,0% 50%)}",
+    );
+    minify_test(
+      ".foo { clip-path: polygon(evenodd, 50% 0%, 100% 50%, 50% 100%, 0% 50%); }",
+      ".foo{clip-path:polygon(evenodd,50% 0%,100% 50%,50% 100%,0% 50%)}",
+    );
+    minify_test(
+      ".foo { clip-path: padding-box circle(50px at 0 100px); }",
+      ".foo{clip-path:circle(50px at 0 100px) padding-box}",
+    );
+    minify_test(
+      ".foo { clip-path: circle(50px at 0 100px) padding-box; }",
+      ".foo{clip-path:circle(50px at 0 100px) padding-box}",
+    );
+    minify_test(
+      ".foo { clip-path: circle(50px at 0 100px) border-box; }",
+      ".foo{clip-path:circle(50px at 0 100px)}",
+    );
 
-    let mut stylesheet = StyleSheet::parse(
-      &source,
-      ParserOptions {
-        nesting: true,
-        ..ParserOptions::default()
+    prefix_test(
+      ".foo { clip-path: circle(50px); }",
+      indoc! { r#"
+        .foo {
+          -webkit-clip-path: circle(50px);
+          clip-path: circle(50px);
+        }
+      "#},
+      Browsers {
+        chrome: Some(30 << 16),
+        ..Browsers::default()
       },
-    )
-    .unwrap();
-    stylesheet
-      .minify(MinifyOptions {
-        unused_symbols: vec!["bar"].iter().map(|s| String::from(*s)).collect(),
-        ..MinifyOptions::default()
-      })
-      .unwrap();
-    let res = stylesheet.to_css(PrinterOptions::default()).unwrap();
-    assert_eq!(res.code, expected);
-
-    let source = r#"
-      .foo {
-        color: red;
+    );
 
-        &.bar {
-          color: purple;
+    prefix_test(
+      ".foo { clip-path: circle(50px); }",
+      indoc! { r#"
+        .foo {
+          clip-path: circle(50px);
         }
+      "#},
+      Browsers {
+        chrome: Some(80 << 16),
+        ..Browsers::default()
+      },
+    );
 
-        @nest &.bar {
-          color: orange;
+    prefix_test(
+      ".foo { clip-path: circle(50px); }",
+      indoc! { r#"
+        .foo {
+          -webkit-clip-path: circle(50px);
+          clip-path: circle(50px);
         }
+      "#},
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
 
-        @nest :not(&) {
-          color: green;
+    prefix_test(
+      ".foo { clip-path: circle(50px); }",
+      indoc! { r#"
+        .foo {
+          clip-path: circle(50px);
         }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
 
-        @media (orientation: portrait) {
-          color: brown;
+    prefix_test(
+      ".foo { fill: lch(50.998% 135.363 338) }",
+      indoc! { r#"
+        .foo {
+          fill: #ee00be;
+          fill: color(display-p3 0.972962 -0.362078 0.804206);
+          fill: lch(50.998% 135.363 338);
         }
-      }
-
-      .x {
-        color: purple;
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
 
-        &.y {
-          color: green;
+    prefix_test(
+      ".foo { stroke: lch(50.998% 135.363 338) }",
+      indoc! { r#"
+        .foo {
+          stroke: #ee00be;
+          stroke: color(display-p3 0.972962 -0.362078 0.804206);
+          stroke: lch(50.998% 135.363 338);
         }
-      }
-    "#;
-
-    let expected = indoc! {r#"
-      :not(.foo) {
-        color: green;
-      }
-    "#};
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
 
-    let mut stylesheet = StyleSheet::parse(
-      &source,
-      ParserOptions {
-        nesting: true,
-        ..ParserOptions::default()
+    prefix_test(
+      ".foo { fill: url(#foo) lch(50.998% 135.363 338) }",
+      indoc! { r##"
+        .foo {
+          fill: url("#foo") #ee00be;
+          fill: url("#foo") color(display-p3 0.972962 -0.362078 0.804206);
+          fill: url("#foo") lch(50.998% 135.363 338);
+        }
+      "##},
+      Browsers {
+        chrome: Some(90 << 16),
+        safari: Some(14 << 16),
+        ..Browsers::default()
       },
-    )
-    .unwrap();
-    stylesheet
-      .minify(MinifyOptions {
-        unused_symbols: vec!["foo", "x"].iter().map(|s| String::from(*s)).collect(),
-        ..MinifyOptions::default()
-      })
-      .unwrap();
-    let res = stylesheet
-      .to_css(PrinterOptions {
-        targets: Some(Browsers {
-          chrome: Some(95 << 16),
-          ..Browsers::default()
-        }),
-        ..PrinterOptions::default()
-      })
-      .unwrap();
-    assert_eq!(res.code, expected);
+    );
 
-    let source = r#"
-      @property --EgL3uq_foo {
-        syntax: "<color>";
-        inherits: false;
-        initial-value: #ff0;
-      }
+    prefix_test(
+      ".foo { fill: var(--url) lch(50.998% 135.363 338) }",
+      indoc! { r#"
+        .foo {
+          fill: var(--url) #ee00be;
+        }
 
-      @font-palette-values --EgL3uq_Cooler {
-        font-family: Bixa;
-        base-palette: 1;
-        override-colors: 1 #7EB7E4;
-      }
+        @supports (color: lab(0% 0 0)) {
+          .foo {
+            fill: var(--url) lab(50.998% 125.506 -50.7078);
+          }
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
 
-      .EgL3uq_foo {
-        --EgL3uq_foo: red;
-      }
+    prefix_test(
+      ".foo { mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
+      indoc! { r#"
+        .foo {
+          -webkit-mask-image: -webkit-gradient(linear, 0 0, 0 100%, from(#ff0f0e), to(#7773ff));
+          -webkit-mask-image: -webkit-linear-gradient(#ff0f0e, #7773ff);
+          -webkit-mask-image: linear-gradient(#ff0f0e, #7773ff);
+          mask-image: linear-gradient(#ff0f0e, #7773ff);
+          -webkit-mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+          mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+        }
+      "#},
+      Browsers {
+        chrome: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
 
-      .EgL3uq_bar {
-        color: green;
-      }
-    "#;
+    prefix_test(
+      ".foo { mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
+      indoc! { r#"
+        .foo {
+          -webkit-mask-image: linear-gradient(#ff0f0e, #7773ff);
+          mask-image: linear-gradient(#ff0f0e, #7773ff);
+          -webkit-mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+          mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+        }
+      "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
+    );
 
-    let expected = indoc! {r#"
-      .EgL3uq_bar {
-        color: green;
-      }
-    "#};
+    prefix_test(
+      ".foo { mask-image: linear-gradient(red, green) }",
+      indoc! { r#"
+        .foo {
+          -webkit-mask-image: linear-gradient(red, green);
+          mask-image: linear-gradient(red, green);
+        }
+      "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
+    );
 
-    let mut stylesheet = StyleSheet::parse(
-      &source,
-      ParserOptions {
-        nesting: true,
-        ..ParserOptions::default()
+    prefix_test(
+      ".foo { -webkit-mask-image: url(x.svg); mask-image: url(x.svg); }",
+      indoc! { r#"
+        .foo {
+          -webkit-mask-image: url("x.svg");
+          mask-image: url("x.svg");
+        }
+      "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
       },
-    )
-    .unwrap();
-    stylesheet
-      .minify(MinifyOptions {
-        unused_symbols: vec!["--EgL3uq_foo", "--EgL3uq_Cooler"]
-          .iter()
-          .map(|s| String::from(*s))
-          .collect(),
-        ..MinifyOptions::default()
-      })
-      .unwrap();
-    let res = stylesheet.to_css(PrinterOptions::default()).unwrap();
-    assert_eq!(res.code, expected);
-  }
+    );
 
-  #[test]
-  fn test_svg() {
-    minify_test(".foo { fill: yellow; }", ".foo{fill:#ff0}");
-    minify_test(".foo { fill: url(#foo); }", ".foo{fill:url(#foo)}");
-    minify_test(".foo { fill: url(#foo) none; }", ".foo{fill:url(#foo) none}");
-    minify_test(".foo { fill: url(#foo) yellow; }", ".foo{fill:url(#foo) #ff0}");
-    minify_test(".foo { fill: none; }", ".foo{fill:none}");
-    minify_test(".foo { fill: context-fill; }", ".foo{fill:context-fill}");
-    minify_test(".foo { fill: context-stroke; }", ".foo{fill:context-stroke}");
+    prefix_test(
+      ".foo { mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 40px 20px }",
+      indoc! { r#"
+        .foo {
+          -webkit-mask: -webkit-gradient(linear, 0 0, 0 100%, from(#ff0f0e), to(#7773ff)) 40px 20px;
+          -webkit-mask: -webkit-linear-gradient(#ff0f0e, #7773ff) 40px 20px;
+          -webkit-mask: linear-gradient(#ff0f0e, #7773ff) 40px 20px;
+          mask: linear-gradient(#ff0f0e, #7773ff) 40px 20px;
+          -webkit-mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 40px 20px;
+          mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 40px 20px;
+        }
+      "#},
+      Browsers {
+        chrome: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
 
-    minify_test(".foo { stroke: yellow; }", ".foo{stroke:#ff0}");
-    minify_test(".foo { stroke: url(#foo); }", ".foo{stroke:url(#foo)}");
-    minify_test(".foo { stroke: url(#foo) none; }", ".foo{stroke:url(#foo) none}");
-    minify_test(".foo { stroke: url(#foo) yellow; }", ".foo{stroke:url(#foo) #ff0}");
-    minify_test(".foo { stroke: none; }", ".foo{stroke:none}");
-    minify_test(".foo { stroke: context-fill; }", ".foo{stroke:context-fill}");
-    minify_test(".foo { stroke: context-stroke; }", ".foo{stroke:context-stroke}");
+    prefix_test(
+      ".foo { mask: -webkit-linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 40px 20px }",
+      indoc! { r#"
+        .foo {
+          -webkit-mask: -webkit-gradient(linear, 0 0, 0 100%, from(#ff0f0e), to(#7773ff)) 40px 20px;
+          -webkit-mask: -webkit-linear-gradient(#ff0f0e, #7773ff) 40px 20px;
+        }
+      "#},
+      Browsers {
+        chrome: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
 
-    minify_test(".foo { marker-start: url(#foo); }", ".foo{marker-start:url(#foo)}");
+    prefix_test(
+      ".foo { mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 40px var(--foo) }",
+      indoc! { r#"
+        .foo {
+          -webkit-mask: linear-gradient(#ff0f0e, #7773ff) 40px var(--foo);
+          mask: linear-gradient(#ff0f0e, #7773ff) 40px var(--foo);
+        }
 
-    minify_test(".foo { stroke-dasharray: 4 1 2; }", ".foo{stroke-dasharray:4 1 2}");
-    minify_test(".foo { stroke-dasharray: 4,1,2; }", ".foo{stroke-dasharray:4 1 2}");
-    minify_test(".foo { stroke-dasharray: 4, 1, 2; }", ".foo{stroke-dasharray:4 1 2}");
-    minify_test(
-      ".foo { stroke-dasharray: 4px, 1px, 2px; }",
-      ".foo{stroke-dasharray:4 1 2}",
+        @supports (color: lab(0% 0 0)) {
+          .foo {
+            -webkit-mask: linear-gradient(lab(56.208% 94.4644 98.8928), lab(51% 70.4544 -115.586)) 40px var(--foo);
+            mask: linear-gradient(lab(56.208% 94.4644 98.8928), lab(51% 70.4544 -115.586)) 40px var(--foo);
+          }
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
-    minify_test(".foo { mask: url('foo.svg'); }", ".foo{mask:url(foo.svg)}");
-    minify_test(
+    prefix_test(
       ".foo { mask: url(masks.svg#star) luminance }",
-      ".foo{mask:url(masks.svg#star) luminance}",
-    );
-    minify_test(
-      ".foo { mask: url(masks.svg#star) 40px 20px }",
-      ".foo{mask:url(masks.svg#star) 40px 20px}",
-    );
-    minify_test(
-      ".foo { mask: url(masks.svg#star) 0 0 / 50px 50px }",
-      ".foo{mask:url(masks.svg#star) 0 0/50px 50px}",
-    );
-    minify_test(
-      ".foo { mask: url(masks.svg#star) repeat-x }",
-      ".foo{mask:url(masks.svg#star) repeat-x}",
-    );
-    minify_test(
-      ".foo { mask: url(masks.svg#star) stroke-box }",
-      ".foo{mask:url(masks.svg#star) stroke-box}",
+      indoc! { r#"
+        .foo {
+          -webkit-mask: url("masks.svg#star");
+          -webkit-mask-source-type: luminance;
+          mask: url("masks.svg#star") luminance;
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { mask: url(masks.svg#star) stroke-box stroke-box }",
-      ".foo{mask:url(masks.svg#star) stroke-box}",
+
+    prefix_test(
+      ".foo { mask: url(a.svg) no-repeat, url(b.svg) 50% / contain exclude }",
+      indoc! { r#"
+        .foo {
+          -webkit-mask: url("a.svg") no-repeat, url("b.svg") 50% / contain;
+          -webkit-mask-composite: source-over, xor;
+          mask: url("a.svg") no-repeat, url("b.svg") 50% / contain exclude;
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { mask: url(masks.svg#star) border-box }",
-      ".foo{mask:url(masks.svg#star)}",
+
+    prefix_test(
+      ".foo { mask-image: url(masks.svg#star) }",
+      indoc! { r#"
+        .foo {
+          -webkit-mask-image: url("masks.svg#star");
+          mask-image: url("masks.svg#star");
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { mask: url(masks.svg#star) left / 16px repeat-y, url(masks.svg#circle) right / 16px repeat-y }",
-      ".foo{mask:url(masks.svg#star) 0/16px repeat-y,url(masks.svg#circle) 100%/16px repeat-y}",
+
+    prefix_test(
+      r#"
+        .foo {
+          mask-image: url(masks.svg#star);
+          mask-position: 25% 75%;
+          mask-size: cover;
+          mask-repeat: no-repeat;
+          mask-clip: padding-box;
+          mask-origin: content-box;
+          mask-composite: subtract;
+          mask-mode: luminance;
+        }
+      "#,
+      indoc! { r#"
+        .foo {
+          -webkit-mask: url("masks.svg#star") 25% 75% / cover no-repeat content-box padding-box;
+          -webkit-mask-composite: source-out;
+          -webkit-mask-source-type: luminance;
+          mask: url("masks.svg#star") 25% 75% / cover no-repeat content-box padding-box subtract luminance;
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
-    minify_test(
-      ".foo { mask-border: url('border-mask.png') 25; }",
-      ".foo{mask-border:url(border-mask.png) 25}",
+    prefix_test(
+      r#"
+        .foo {
+          mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+          mask-position: 25% 75%;
+          mask-size: cover;
+          mask-repeat: no-repeat;
+          mask-clip: padding-box;
+          mask-origin: content-box;
+          mask-composite: subtract;
+          mask-mode: luminance;
+        }
+      "#,
+      indoc! { r#"
+        .foo {
+          -webkit-mask: linear-gradient(#ff0f0e, #7773ff) 25% 75% / cover no-repeat content-box padding-box;
+          mask: linear-gradient(#ff0f0e, #7773ff) 25% 75% / cover no-repeat content-box padding-box subtract luminance;
+          -webkit-mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 25% 75% / cover no-repeat content-box padding-box;
+          -webkit-mask-composite: source-out;
+          -webkit-mask-source-type: luminance;
+          mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 25% 75% / cover no-repeat content-box padding-box subtract luminance;
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { mask-border: url('border-mask.png') 25 / 35px / 12px space alpha; }",
-      ".foo{mask-border:url(border-mask.png) 25/35px/12px space}",
+
+    prefix_test(
+      r#"
+        .foo {
+          mask-composite: subtract;
+        }
+      "#,
+      indoc! { r#"
+        .foo {
+          -webkit-mask-composite: source-out;
+          mask-composite: subtract;
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { mask-border: url('border-mask.png') 25 / 35px / 12px space luminance; }",
-      ".foo{mask-border:url(border-mask.png) 25/35px/12px space luminance}",
+
+    prefix_test(
+      r#"
+        .foo {
+          mask-mode: luminance;
+        }
+      "#,
+      indoc! { r#"
+        .foo {
+          -webkit-mask-source-type: luminance;
+          mask-mode: luminance;
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { mask-border: url('border-mask.png') luminance 25 / 35px / 12px space; }",
-      ".foo{mask-border:url(border-mask.png) 25/35px/12px space luminance}",
+
+    prefix_test(
+      r#"
+        .foo {
+          mask-border: url('border-mask.png') 25 / 35px / 12px space luminance;
+        }
+      "#,
+      indoc! { r#"
+        .foo {
+          -webkit-mask-box-image: url("border-mask.png") 25 / 35px / 12px space;
+          mask-border: url("border-mask.png") 25 / 35px / 12px space luminance;
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
-    minify_test(
-      ".foo { clip-path: url('clip.svg#star'); }",
-      ".foo{clip-path:url(clip.svg#star)}",
-    );
-    minify_test(".foo { clip-path: margin-box; }", ".foo{clip-path:margin-box}");
-    minify_test(
-      ".foo { clip-path: inset(100px 50px); }",
-      ".foo{clip-path:inset(100px 50px)}",
+    prefix_test(
+      r#"
+        .foo {
+          mask-border: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 25 / 35px / 12px space luminance;
+        }
+      "#,
+      indoc! { r#"
+        .foo {
+          -webkit-mask-box-image: linear-gradient(#ff0f0e, #7773ff) 25 / 35px / 12px space;
+          mask-border: linear-gradient(#ff0f0e, #7773ff) 25 / 35px / 12px space luminance;
+          -webkit-mask-box-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 25 / 35px / 12px space;
+          mask-border: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 25 / 35px / 12px space luminance;
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { clip-path: inset(100px 50px round 5px); }",
-      ".foo{clip-path:inset(100px 50px round 5px)}",
+
+    prefix_test(
+      r#"
+        .foo {
+          mask-border-source: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+        }
+      "#,
+      indoc! { r#"
+        .foo {
+          -webkit-mask-box-image-source: linear-gradient(#ff0f0e, #7773ff);
+          mask-border-source: linear-gradient(#ff0f0e, #7773ff);
+          -webkit-mask-box-image-source: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+          mask-border-source: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { clip-path: inset(100px 50px round 5px 5px 5px 5px); }",
-      ".foo{clip-path:inset(100px 50px round 5px)}",
+
+    prefix_test(
+      r#"
+        .foo {
+          mask-border-source: url(foo.png);
+          mask-border-slice: 10 40 10 40;
+          mask-border-width: 10px;
+          mask-border-outset: 0;
+          mask-border-repeat: round round;
+          mask-border-mode: luminance;
+        }
+      "#,
+      indoc! { r#"
+        .foo {
+          -webkit-mask-box-image: url("foo.png") 10 40 / 10px round;
+          mask-border: url("foo.png") 10 40 / 10px round luminance;
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(".foo { clip-path: circle(50px); }", ".foo{clip-path:circle(50px)}");
-    minify_test(
-      ".foo { clip-path: circle(50px at center center); }",
-      ".foo{clip-path:circle(50px)}",
+
+    prefix_test(
+      r#"
+        .foo {
+          -webkit-mask-box-image-source: url(foo.png);
+          -webkit-mask-box-image-slice: 10 40 10 40;
+          -webkit-mask-box-image-width: 10px;
+          -webkit-mask-box-image-outset: 0;
+          -webkit-mask-box-image-repeat: round round;
+        }
+      "#,
+      indoc! { r#"
+        .foo {
+          -webkit-mask-box-image: url("foo.png") 10 40 / 10px round;
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { clip-path: circle(50px at 50% 50%); }",
-      ".foo{clip-path:circle(50px)}",
+
+    prefix_test(
+      r#"
+        .foo {
+          mask-border-slice: 10 40 10 40;
+        }
+      "#,
+      indoc! { r#"
+        .foo {
+          -webkit-mask-box-image-slice: 10 40;
+          mask-border-slice: 10 40;
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { clip-path: circle(50px at 0 100px); }",
-      ".foo{clip-path:circle(50px at 0 100px)}",
+
+    prefix_test(
+      r#"
+        .foo {
+          mask-border-slice: var(--foo);
+        }
+      "#,
+      indoc! { r#"
+        .foo {
+          -webkit-mask-box-image-slice: var(--foo);
+          mask-border-slice: var(--foo);
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { clip-path: circle(closest-side at 0 100px); }",
-      ".foo{clip-path:circle(at 0 100px)}",
+
+    prefix_test(
+      r#"
+        .foo {
+          mask-border: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) var(--foo);
+        }
+      "#,
+      indoc! { r#"
+        .foo {
+          -webkit-mask-box-image: linear-gradient(#ff0f0e, #7773ff) var(--foo);
+          mask-border: linear-gradient(#ff0f0e, #7773ff) var(--foo);
+        }
+
+        @supports (color: lab(0% 0 0)) {
+          .foo {
+            -webkit-mask-box-image: linear-gradient(lab(56.208% 94.4644 98.8928), lab(51% 70.4544 -115.586)) var(--foo);
+            mask-border: linear-gradient(lab(56.208% 94.4644 98.8928), lab(51% 70.4544 -115.586)) var(--foo);
+          }
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { clip-path: circle(farthest-side at 0 100px); }",
-      ".foo{clip-path:circle(farthest-side at 0 100px)}",
+
+    prefix_test(
+      r#"
+        .foo {
+          transition: mask 200ms;
+        }
+      "#,
+      indoc! { r#"
+        .foo {
+          transition: -webkit-mask 0.2s, mask 0.2s;
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { clip-path: circle(closest-side at 50% 50%); }",
-      ".foo{clip-path:circle()}",
+
+    prefix_test(
+      r#"
+        .foo {
+          transition: mask-border 200ms;
+        }
+      "#,
+      indoc! { r#"
+        .foo {
+          transition: -webkit-mask-box-image 0.2s, mask-border 0.2s;
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { clip-path: ellipse(50px 60px at 0 10% 20%); }",
-      ".foo{clip-path:ellipse(50px 60px at 0 10% 20%)}",
+
+    prefix_test(
+      r#"
+        .foo {
+          transition-property: mask;
+        }
+      "#,
+      indoc! { r#"
+        .foo {
+          transition-property: -webkit-mask, mask;
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { clip-path: ellipse(50px 60px at center center); }",
-      ".foo{clip-path:ellipse(50px 60px)}",
+
+    prefix_test(
+      r#"
+        .foo {
+          transition-property: mask-border;
+        }
+      "#,
+      indoc! { r#"
+        .foo {
+          transition-property: -webkit-mask-box-image, mask-border;
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { clip-path: ellipse(closest-side closest-side at 50% 50%); }",
-      ".foo{clip-path:ellipse()}",
+
+    prefix_test(
+      r#"
+        .foo {
+          transition-property: mask-composite, mask-mode;
+        }
+      "#,
+      indoc! { r#"
+        .foo {
+          transition-property: -webkit-mask-composite, mask-composite, -webkit-mask-source-type, mask-mode;
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
+  }
+
+  #[test]
+  fn test_filter() {
     minify_test(
-      ".foo { clip-path: ellipse(closest-side closest-side at 10% 20%); }",
-      ".foo{clip-path:ellipse(at 10% 20%)}",
+      ".foo { filter: url('filters.svg#filter-id'); }",
+      ".foo{filter:url(filters.svg#filter-id)}",
     );
+    minify_test(".foo { filter: blur(5px); }", ".foo{filter:blur(5px)}");
+    minify_test(".foo { filter: blur(0px); }", ".foo{filter:blur()}");
+    minify_test(".foo { filter: brightness(10%); }", ".foo{filter:brightness(10%)}");
+    minify_test(".foo { filter: brightness(100%); }", ".foo{filter:brightness()}");
     minify_test(
-      ".foo { clip-path: ellipse(farthest-side closest-side at 10% 20%); }",
-      ".foo{clip-path:ellipse(farthest-side closest-side at 10% 20%)}",
+      ".foo { filter: drop-shadow(16px 16px 20px yellow); }",
+      ".foo{filter:drop-shadow(16px 16px 20px #ff0)}",
     );
     minify_test(
-      ".foo { clip-path: polygon(50% 0%, 100% 50%, 50% 100%, 0% 50%); }",
-      ".foo{clip-path:polygon(50% 0%,100% 50%,50% 100%,0% 50%)}",
+      ".foo { filter: contrast(175%) brightness(3%); }",
+      ".foo{filter:contrast(175%)brightness(3%)}",
     );
+    minify_test(".foo { filter: hue-rotate(0) }", ".foo{filter:hue-rotate()}");
     minify_test(
-      ".foo { clip-path: polygon(nonzero, 50% 0%, 100% 50%, 50% 100%, 0% 50%); }",
-      ".foo{clip-path:polygon(50% 0%,100% 50%,50% 100%,0% 50%)}",
+      ".foo { filter: hue-rotate(calc(45deg * 2)) }",
+      ".foo{filter:hue-rotate(90deg)}",
     );
     minify_test(
-      ".foo { clip-path: polygon(evenodd, 50% 0%, 100% 50%, 50% 100%, 0% 50%); }",
-      ".foo{clip-path:polygon(evenodd,50% 0%,100% 50%,50% 100%,0% 50%)}",
+      ".foo { backdrop-filter: blur(10px) brightness(0.8) }",
+      ".foo{backdrop-filter:blur(10px)brightness(.8)}",
     );
     minify_test(
-      ".foo { clip-path: padding-box circle(50px at 0 100px); }",
-      ".foo{clip-path:circle(50px at 0 100px) padding-box}",
+      ".foo { backdrop-filter: blur(4px) saturate(180%) hue-rotate(90deg) }",
+      ".foo{backdrop-filter:blur(4px)saturate(180%)hue-rotate(90deg)}",
     );
     minify_test(
-      ".foo { clip-path: circle(50px at 0 100px) padding-box; }",
-      ".foo{clip-path:circle(50px at 0 100px) padding-box}",
+      ".foo { filter: drop-shadow(red 1px 2px) }",
+      ".foo{filter:drop-shadow(1px 2px red)}",
     );
     minify_test(
-      ".foo { clip-path: circle(50px at 0 100px) border-box; }",
-      ".foo{clip-path:circle(50px at 0 100px)}",
+      ".foo { filter: drop-shadow(2px 4px 6px rgba(0, 0, 0, 0.5)) }",
+      ".foo{filter:drop-shadow(2px 4px 6px #00000080)}",
     );
 
     prefix_test(
-      ".foo { clip-path: circle(50px); }",
+      ".foo { filter: blur(5px) }",
       indoc! { r#"
         .foo {
-          -webkit-clip-path: circle(50px);
-          clip-path: circle(50px);
+          -webkit-filter: blur(5px);
+          filter: blur(5px);
         }
       "#},
       Browsers {
-        chrome: Some(30 << 16),
+        chrome: Some(20 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { clip-path: circle(50px); }",
+      ".foo { filter: blur(5px) }",
       indoc! { r#"
         .foo {
-          clip-path: circle(50px);
+          filter: blur(5px);
         }
       "#},
       Browsers {
@@ -20077,2349 +24135,3044 @@ mod tests {
     );
 
     prefix_test(
-      ".foo { clip-path: circle(50px); }",
+      ".foo { backdrop-filter: blur(5px) }",
       indoc! { r#"
         .foo {
-          -webkit-clip-path: circle(50px);
-          clip-path: circle(50px);
+          backdrop-filter: blur(5px);
         }
       "#},
       Browsers {
-        safari: Some(8 << 16),
+        chrome: Some(80 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { clip-path: circle(50px); }",
+      ".foo { backdrop-filter: blur(5px) }",
       indoc! { r#"
         .foo {
-          clip-path: circle(50px);
+          -webkit-backdrop-filter: blur(5px);
+          backdrop-filter: blur(5px);
         }
       "#},
       Browsers {
-        safari: Some(14 << 16),
+        safari: Some(15 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { fill: lch(50.998% 135.363 338) }",
+      ".foo { backdrop-filter: blur(10px) brightness(0.8) }",
       indoc! { r#"
         .foo {
-          fill: #ee00be;
-          fill: color(display-p3 .972962 -.362078 .804206);
-          fill: lch(50.998% 135.363 338);
+          -webkit-backdrop-filter: blur(10px) brightness(0.8);
+          backdrop-filter: blur(10px) brightness(0.8);
         }
       "#},
       Browsers {
-        chrome: Some(90 << 16),
-        safari: Some(14 << 16),
+        safari: Some(15 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { stroke: lch(50.998% 135.363 338) }",
+      ".foo { filter: var(--foo) }",
       indoc! { r#"
         .foo {
-          stroke: #ee00be;
-          stroke: color(display-p3 .972962 -.362078 .804206);
-          stroke: lch(50.998% 135.363 338);
+          -webkit-filter: var(--foo);
+          filter: var(--foo);
         }
       "#},
       Browsers {
-        chrome: Some(90 << 16),
-        safari: Some(14 << 16),
+        chrome: Some(20 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { fill: url(#foo) lch(50.998% 135.363 338) }",
-      indoc! { r##"
+      ".foo { filter: drop-shadow(16px 16px 20px lab(40% 56.6 39)) }",
+      indoc! { r#"
         .foo {
-          fill: url("#foo") #ee00be;
-          fill: url("#foo") color(display-p3 .972962 -.362078 .804206);
-          fill: url("#foo") lch(50.998% 135.363 338);
+          -webkit-filter: drop-shadow(16px 16px 20px #b32323);
+          filter: drop-shadow(16px 16px 20px #b32323);
+          filter: drop-shadow(16px 16px 20px lab(40% 56.6 39));
         }
-      "##},
+      "#},
       Browsers {
-        chrome: Some(90 << 16),
-        safari: Some(14 << 16),
+        chrome: Some(20 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { fill: var(--url) lch(50.998% 135.363 338) }",
+      ".foo { filter: contrast(175%) drop-shadow(16px 16px 20px lab(40% 56.6 39)) }",
       indoc! { r#"
         .foo {
-          fill: var(--url) #ee00be;
-        }
-
-        @supports (color: lab(0% 0 0)) {
-          .foo {
-            fill: var(--url) lab(50.998% 125.506 -50.7078);
-          }
+          filter: contrast(175%) drop-shadow(16px 16px 20px #b32323);
+          filter: contrast(175%) drop-shadow(16px 16px 20px lab(40% 56.6 39));
         }
       "#},
       Browsers {
-        chrome: Some(90 << 16),
+        chrome: Some(4 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
+      ".foo { filter: drop-shadow(16px 16px 20px lab(40% 56.6 39)) drop-shadow(16px 16px 20px yellow) }",
       indoc! { r#"
         .foo {
-          -webkit-mask-image: -webkit-gradient(linear, 0 0, 0 100%, from(#ff0f0e), to(#7773ff));
-          -webkit-mask-image: -webkit-linear-gradient(#ff0f0e, #7773ff);
-          -webkit-mask-image: linear-gradient(#ff0f0e, #7773ff);
-          mask-image: linear-gradient(#ff0f0e, #7773ff);
-          -webkit-mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
-          mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+          filter: drop-shadow(16px 16px 20px #b32323) drop-shadow(16px 16px 20px #ff0);
+          filter: drop-shadow(16px 16px 20px lab(40% 56.6 39)) drop-shadow(16px 16px 20px #ff0);
         }
       "#},
       Browsers {
-        chrome: Some(8 << 16),
+        chrome: Some(4 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
+      ".foo { filter: var(--foo) drop-shadow(16px 16px 20px lab(40% 56.6 39)) }",
       indoc! { r#"
         .foo {
-          -webkit-mask-image: linear-gradient(#ff0f0e, #7773ff);
-          mask-image: linear-gradient(#ff0f0e, #7773ff);
-          -webkit-mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
-          mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+          filter: var(--foo) drop-shadow(16px 16px 20px #b32323);
+        }
+
+        @supports (color: lab(0% 0 0)) {
+          .foo {
+            filter: var(--foo) drop-shadow(16px 16px 20px lab(40% 56.6 39));
+          }
+        }
+      "#},
+      Browsers {
+        chrome: Some(4 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_viewport() {
+    minify_test(
+      r#"
+    @viewport {
+      width: 100vw;
+    }"#,
+      "@viewport{width:100vw}",
+    );
+    minify_test(
+      r#"
+    @-ms-viewport {
+      width: device-width;
+    }"#,
+      "@-ms-viewport{width:device-width}",
+    );
+  }
+
+  #[test]
+  fn test_custom_media() {
+    custom_media_test(
+      r#"
+      @custom-media --modern (color), (hover);
+
+      @media (--modern) and (width > 1024px) {
+        .a {
+          color: green;
+        }
+      }
+      "#,
+      indoc! {r#"
+      @media ((color) or (hover)) and (width > 1024px) {
+        .a {
+          color: green;
+        }
+      }
+      "#},
+    );
+
+    custom_media_test(
+      r#"
+      @custom-media --color (color);
+
+      @media (--color) and (width > 1024px) {
+        .a {
+          color: green;
+        }
+      }
+      "#,
+      indoc! {r#"
+      @media (color) and (width > 1024px) {
+        .a {
+          color: green;
+        }
+      }
+      "#},
+    );
+
+    custom_media_test(
+      r#"
+      @custom-media --a (color);
+      @custom-media --b (--a);
+
+      @media (--b) and (width > 1024px) {
+        .a {
+          color: green;
+        }
+      }
+      "#,
+      indoc! {r#"
+      @media (color) and (width > 1024px) {
+        .a {
+          color: green;
+        }
+      }
+      "#},
+    );
+
+    custom_media_test(
+      r#"
+      @custom-media --not-color not (color);
+
+      @media not (--not-color) {
+        .a {
+          color: green;
+        }
+      }
+      "#,
+      indoc! {r#"
+      @media (color) {
+        .a {
+          color: green;
+        }
+      }
+      "#},
+    );
+
+    custom_media_test(
+      r#"
+      @custom-media --color-print print and (color);
+
+      @media (--color-print) {
+        .a {
+          color: green;
+        }
+      }
+      "#,
+      indoc! {r#"
+      @media print and (color) {
+        .a {
+          color: green;
         }
+      }
       "#},
-      Browsers {
-        chrome: Some(95 << 16),
-        ..Browsers::default()
-      },
     );
 
-    prefix_test(
-      ".foo { mask-image: linear-gradient(red, green) }",
-      indoc! { r#"
-        .foo {
-          -webkit-mask-image: linear-gradient(red, green);
-          mask-image: linear-gradient(red, green);
+    custom_media_test(
+      r#"
+      @custom-media --color-print print and (color);
+
+      @media print and (--color-print) {
+        .a {
+          color: green;
+        }
+      }
+      "#,
+      indoc! {r#"
+      @media print and (color) {
+        .a {
+          color: green;
         }
+      }
       "#},
-      Browsers {
-        chrome: Some(95 << 16),
-        ..Browsers::default()
-      },
     );
 
-    prefix_test(
-      ".foo { -webkit-mask-image: url(x.svg); mask-image: url(x.svg); }",
-      indoc! { r#"
-        .foo {
-          -webkit-mask-image: url("x.svg");
-          mask-image: url("x.svg");
+    custom_media_test(
+      r#"
+      @custom-media --not-color-print not print and (color);
+
+      @media not print and (--not-color-print) {
+        .a {
+          color: green;
+        }
+      }
+      "#,
+      indoc! {r#"
+      @media not print and (color) {
+        .a {
+          color: green;
         }
+      }
       "#},
-      Browsers {
-        chrome: Some(95 << 16),
-        ..Browsers::default()
-      },
     );
 
-    prefix_test(
-      ".foo { mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 40px 20px }",
-      indoc! { r#"
-        .foo {
-          -webkit-mask: -webkit-gradient(linear, 0 0, 0 100%, from(#ff0f0e), to(#7773ff)) 40px 20px;
-          -webkit-mask: -webkit-linear-gradient(#ff0f0e, #7773ff) 40px 20px;
-          -webkit-mask: linear-gradient(#ff0f0e, #7773ff) 40px 20px;
-          mask: linear-gradient(#ff0f0e, #7773ff) 40px 20px;
-          -webkit-mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 40px 20px;
-          mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 40px 20px;
+    custom_media_test(
+      r#"
+      @custom-media --print print;
+
+      @media (--print) {
+        .a {
+          color: green;
         }
+      }
+      "#,
+      indoc! {r#"
+      @media print {
+        .a {
+          color: green;
+        }
+      }
       "#},
-      Browsers {
-        chrome: Some(8 << 16),
-        ..Browsers::default()
-      },
     );
 
-    prefix_test(
-      ".foo { mask: -webkit-linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 40px 20px }",
-      indoc! { r#"
-        .foo {
-          -webkit-mask: -webkit-gradient(linear, 0 0, 0 100%, from(#ff0f0e), to(#7773ff)) 40px 20px;
-          -webkit-mask: -webkit-linear-gradient(#ff0f0e, #7773ff) 40px 20px;
+    custom_media_test(
+      r#"
+      @custom-media --print print;
+
+      @media not (--print) {
+        .a {
+          color: green;
+        }
+      }
+      "#,
+      indoc! {r#"
+      @media not print {
+        .a {
+          color: green;
         }
+      }
       "#},
-      Browsers {
-        chrome: Some(8 << 16),
-        ..Browsers::default()
-      },
     );
 
-    prefix_test(
-      ".foo { mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 40px var(--foo) }",
-      indoc! { r#"
-        .foo {
-          -webkit-mask: linear-gradient(#ff0f0e, #7773ff) 40px var(--foo);
-          mask: linear-gradient(#ff0f0e, #7773ff) 40px var(--foo);
-        }
+    custom_media_test(
+      r#"
+      @custom-media --print not print;
 
-        @supports (color: lab(0% 0 0)) {
-          .foo {
-            -webkit-mask: linear-gradient(lab(56.208% 94.4644 98.8928), lab(51% 70.4544 -115.586)) 40px var(--foo);
-            mask: linear-gradient(lab(56.208% 94.4644 98.8928), lab(51% 70.4544 -115.586)) 40px var(--foo);
-          }
+      @media not (--print) {
+        .a {
+          color: green;
+        }
+      }
+      "#,
+      indoc! {r#"
+      @media print {
+        .a {
+          color: green;
         }
+      }
       "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
     );
 
-    prefix_test(
-      ".foo { mask: url(masks.svg#star) luminance }",
-      indoc! { r#"
-        .foo {
-          -webkit-mask: url("masks.svg#star");
-          -webkit-mask-source-type: luminance;
-          mask: url("masks.svg#star") luminance;
-        }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
-    );
+    custom_media_test(
+      r#"
+      @custom-media --print print;
 
-    prefix_test(
-      ".foo { mask-image: url(masks.svg#star) }",
-      indoc! { r#"
-        .foo {
-          -webkit-mask-image: url("masks.svg#star");
-          mask-image: url("masks.svg#star");
+      @media ((--print)) {
+        .a {
+          color: green;
         }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+      }
+      "#,
+      indoc! {r#"
+      @media print {
+        .a {
+          color: green;
+        }
+      }
+      "#},
     );
 
-    prefix_test(
+    custom_media_test(
       r#"
-        .foo {
-          mask-image: url(masks.svg#star);
-          mask-position: 25% 75%;
-          mask-size: cover;
-          mask-repeat: no-repeat;
-          mask-clip: padding-box;
-          mask-origin: content-box;
-          mask-composite: subtract;
-          mask-mode: luminance;
+      @custom-media --color (color);
+      @custom-media --print print;
+
+      @media (--print) and (--color) {
+        .a {
+          color: green;
         }
+      }
       "#,
-      indoc! { r#"
-        .foo {
-          -webkit-mask: url("masks.svg#star") 25% 75% / cover no-repeat content-box padding-box;
-          -webkit-mask-composite: source-out;
-          -webkit-mask-source-type: luminance;
-          mask: url("masks.svg#star") 25% 75% / cover no-repeat content-box padding-box subtract luminance;
+      indoc! {r#"
+      @media print and (color) {
+        .a {
+          color: green;
         }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+      }
+      "#},
     );
 
-    prefix_test(
+    custom_media_test(
       r#"
-        .foo {
-          mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
-          mask-position: 25% 75%;
-          mask-size: cover;
-          mask-repeat: no-repeat;
-          mask-clip: padding-box;
-          mask-origin: content-box;
-          mask-composite: subtract;
-          mask-mode: luminance;
+      @custom-media --color (color);
+      @custom-media --not-print not print;
+
+      @media (--not-print) and (--color) {
+        .a {
+          color: green;
         }
+      }
       "#,
-      indoc! { r#"
-        .foo {
-          -webkit-mask: linear-gradient(#ff0f0e, #7773ff) 25% 75% / cover no-repeat content-box padding-box;
-          -webkit-mask-composite: source-out;
-          -webkit-mask-source-type: luminance;
-          mask: linear-gradient(#ff0f0e, #7773ff) 25% 75% / cover no-repeat content-box padding-box subtract luminance;
-          -webkit-mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 25% 75% / cover no-repeat content-box padding-box;
-          -webkit-mask-composite: source-out;
-          -webkit-mask-source-type: luminance;
-          mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 25% 75% / cover no-repeat content-box padding-box subtract luminance;
+      indoc! {r#"
+      @media not print and (color) {
+        .a {
+          color: green;
+        }
+      }
+      "#},
+    );
+
+    custom_media_test(
+      r#"
+      @custom-media --color (color);
+      @custom-media --screen screen;
+      @custom-media --print print;
+
+      @media (--print) and (--color), (--screen) and (--color) {
+        .a {
+          color: green;
         }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+      }
+      "#,
+      indoc! {r#"
+      @media print and (color), screen and (color) {
+        .a {
+          color: green;
+        }
+      }
+      "#},
     );
 
-    prefix_test(
+    custom_media_test(
       r#"
-        .foo {
-          mask-composite: subtract;
+      @custom-media --color print and (color), print and (script);
+
+      @media (--color) {
+        .a {
+          color: green;
         }
+      }
       "#,
-      indoc! { r#"
-        .foo {
-          -webkit-mask-composite: source-out;
-          mask-composite: subtract;
+      indoc! {r#"
+      @media print and ((color) or (script)) {
+        .a {
+          color: green;
         }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+      }
+      "#},
     );
 
-    prefix_test(
+    custom_media_test(
       r#"
-        .foo {
-          mask-mode: luminance;
+      @custom-media --color (color);
+      @custom-media --not-color not all and (--color);
+
+      @media (--not-color) {
+        .a {
+          color: green;
         }
+      }
       "#,
-      indoc! { r#"
-        .foo {
-          -webkit-mask-source-type: luminance;
-          mask-mode: luminance;
+      indoc! {r#"
+        @media not all and (color) {
+          .a {
+            color: green;
+          }
         }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+      "#},
     );
 
-    prefix_test(
+    custom_media_test(
       r#"
-        .foo {
-          mask-border: url('border-mask.png') 25 / 35px / 12px space luminance;
+      @custom-media --color (color);
+
+      @media not all and (--color) {
+        .a {
+          color: green;
         }
+      }
       "#,
-      indoc! { r#"
-        .foo {
-          -webkit-mask-box-image: url("border-mask.png") 25 / 35px / 12px space;
-          mask-border: url("border-mask.png") 25 / 35px / 12px space luminance;
+      indoc! {r#"
+        @media not all and (color) {
+          .a {
+            color: green;
+          }
         }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+      "#},
     );
 
-    prefix_test(
+    custom_media_test(
       r#"
-        .foo {
-          mask-border: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 25 / 35px / 12px space luminance;
+      @media (--print) {
+        .a {
+          color: green;
         }
+      }
+
+      @custom-media --print print;
       "#,
-      indoc! { r#"
-        .foo {
-          -webkit-mask-box-image: linear-gradient(#ff0f0e, #7773ff) 25 / 35px / 12px space;
-          mask-border: linear-gradient(#ff0f0e, #7773ff) 25 / 35px / 12px space luminance;
-          -webkit-mask-box-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 25 / 35px / 12px space;
-          mask-border: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 25 / 35px / 12px space luminance;
+      indoc! {r#"
+      @media print {
+        .a {
+          color: green;
         }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+      }
+      "#},
     );
 
-    prefix_test(
+    custom_media_test(
       r#"
+      @custom-media --not-width not (min-width: 300px);
+      @media screen and ((prefers-color-scheme: dark) or (--not-width)) {
         .foo {
-          mask-border-source: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+          order: 6;
         }
+      }
       "#,
-      indoc! { r#"
+      indoc! {r#"
+      @media screen and ((prefers-color-scheme: dark) or (not (min-width: 300px))) {
         .foo {
-          -webkit-mask-box-image-source: linear-gradient(#ff0f0e, #7773ff);
-          mask-border-source: linear-gradient(#ff0f0e, #7773ff);
-          -webkit-mask-box-image-source: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
-          mask-border-source: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+          order: 6;
         }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+      }
+      "#},
     );
 
-    prefix_test(
+    fn custom_media_error_test(source: &str, err: Error<MinifyErrorKind>) {
+      let mut stylesheet = StyleSheet::parse(
+        &source,
+        ParserOptions {
+          filename: "test.css".into(),
+          custom_media: true,
+          ..ParserOptions::default()
+        },
+      )
+      .unwrap();
+      let res = stylesheet.minify(MinifyOptions {
+        targets: Some(Browsers {
+          chrome: Some(95 << 16),
+          ..Browsers::default()
+        }),
+        ..MinifyOptions::default()
+      });
+      assert_eq!(res, Err(err))
+    }
+
+    custom_media_error_test(
       r#"
-        .foo {
-          mask-border-source: url(foo.png);
-          mask-border-slice: 10 40 10 40;
-          mask-border-width: 10px;
-          mask-border-outset: 0;
-          mask-border-repeat: round round;
-          mask-border-mode: luminance;
+      @custom-media --color-print print and (color);
+
+      @media screen and (--color-print) {
+        .a {
+          color: green;
         }
+      }
       "#,
-      indoc! { r#"
-        .foo {
-          -webkit-mask-box-image: url("foo.png") 10 40 / 10px round;
-          mask-border: url("foo.png") 10 40 / 10px round luminance;
-        }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
+      Error {
+        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
+          custom_media_loc: Location {
+            source_index: 0,
+            line: 1,
+            column: 7,
+          },
+        },
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 3,
+          column: 7,
+        }),
       },
     );
 
-    prefix_test(
+    custom_media_error_test(
       r#"
-        .foo {
-          -webkit-mask-box-image-source: url(foo.png);
-          -webkit-mask-box-image-slice: 10 40 10 40;
-          -webkit-mask-box-image-width: 10px;
-          -webkit-mask-box-image-outset: 0;
-          -webkit-mask-box-image-repeat: round round;
+      @custom-media --color-print print and (color);
+
+      @media not print and (--color-print) {
+        .a {
+          color: green;
         }
+      }
       "#,
-      indoc! { r#"
-        .foo {
-          -webkit-mask-box-image: url("foo.png") 10 40 / 10px round;
-        }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
+      Error {
+        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
+          custom_media_loc: Location {
+            source_index: 0,
+            line: 1,
+            column: 7,
+          },
+        },
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 3,
+          column: 7,
+        }),
       },
     );
 
-    prefix_test(
+    custom_media_error_test(
       r#"
-        .foo {
-          mask-border-slice: 10 40 10 40;
-        }
+      @custom-media --color-print print and (color);
+      @custom-media --color-screen screen and (color);
+
+      @media (--color-print) or (--color-screen) {}
       "#,
-      indoc! { r#"
-        .foo {
-          -webkit-mask-box-image-slice: 10 40;
-          mask-border-slice: 10 40;
-        }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
+      Error {
+        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
+          custom_media_loc: Location {
+            source_index: 0,
+            line: 2,
+            column: 7,
+          },
+        },
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 4,
+          column: 7,
+        }),
       },
     );
 
-    prefix_test(
+    custom_media_error_test(
       r#"
-        .foo {
-          mask-border-slice: var(--foo);
-        }
+      @custom-media --color-print print and (color);
+      @custom-media --color-screen screen and (color);
+
+      @media (--color-print) and (--color-screen) {}
       "#,
-      indoc! { r#"
-        .foo {
-          -webkit-mask-box-image-slice: var(--foo);
-          mask-border-slice: var(--foo);
-        }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
+      Error {
+        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
+          custom_media_loc: Location {
+            source_index: 0,
+            line: 2,
+            column: 7,
+          },
+        },
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 4,
+          column: 7,
+        }),
       },
     );
 
-    prefix_test(
+    custom_media_error_test(
       r#"
-        .foo {
-          mask-border: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) var(--foo);
-        }
-      "#,
-      indoc! { r#"
-        .foo {
-          -webkit-mask-box-image: linear-gradient(#ff0f0e, #7773ff) var(--foo);
-          mask-border: linear-gradient(#ff0f0e, #7773ff) var(--foo);
-        }
+      @custom-media --screen screen;
+      @custom-media --print print;
 
-        @supports (color: lab(0% 0 0)) {
-          .foo {
-            -webkit-mask-box-image: linear-gradient(lab(56.208% 94.4644 98.8928), lab(51% 70.4544 -115.586)) var(--foo);
-            mask-border: linear-gradient(lab(56.208% 94.4644 98.8928), lab(51% 70.4544 -115.586)) var(--foo);
-          }
-        }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
+      @media (--print) and (--screen) {}
+      "#,
+      Error {
+        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
+          custom_media_loc: Location {
+            source_index: 0,
+            line: 1,
+            column: 7,
+          },
+        },
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 4,
+          column: 7,
+        }),
       },
     );
 
-    prefix_test(
+    custom_media_error_test(
       r#"
-        .foo {
-          transition: mask 200ms;
+      @custom-media --not-print not print and (color);
+      @custom-media --not-screen not screen and (color);
+
+      @media ((script) or ((--not-print) and (--not-screen))) {
+        .a {
+          color: green;
         }
+      }
       "#,
-      indoc! { r#"
-        .foo {
-          transition: -webkit-mask .2s, mask .2s;
-        }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
+      Error {
+        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
+          custom_media_loc: Location {
+            source_index: 0,
+            line: 2,
+            column: 7,
+          },
+        },
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 4,
+          column: 7,
+        }),
       },
     );
 
-    prefix_test(
+    custom_media_error_test(
       r#"
-        .foo {
-          transition: mask-border 200ms;
+      @custom-media --color screen and (color), print and (color);
+
+      @media (--color) {
+        .a {
+          color: green;
         }
+      }
       "#,
-      indoc! { r#"
-        .foo {
-          transition: -webkit-mask-box-image .2s, mask-border .2s;
-        }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
+      Error {
+        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
+          custom_media_loc: Location {
+            source_index: 0,
+            line: 1,
+            column: 7,
+          },
+        },
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 3,
+          column: 7,
+        }),
       },
     );
 
-    prefix_test(
+    custom_media_error_test(
       r#"
-        .foo {
-          transition-property: mask;
+      @media (--not-defined) {
+        .a {
+          color: green;
         }
+      }
       "#,
-      indoc! { r#"
-        .foo {
-          transition-property: -webkit-mask, mask;
-        }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
+      Error {
+        kind: MinifyErrorKind::CustomMediaNotDefined {
+          name: "--not-defined".into(),
+        },
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 1,
+          column: 7,
+        }),
       },
     );
 
-    prefix_test(
+    custom_media_error_test(
       r#"
-        .foo {
-          transition-property: mask-border;
+      @custom-media --circular-mq-a (--circular-mq-b);
+      @custom-media --circular-mq-b (--circular-mq-a);
+
+      @media (--circular-mq-a) {
+        body {
+          order: 3;
         }
+      }
       "#,
-      indoc! { r#"
-        .foo {
-          transition-property: -webkit-mask-box-image, mask-border;
-        }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
+      Error {
+        kind: MinifyErrorKind::CircularCustomMedia {
+          name: "--circular-mq-a".into(),
+        },
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 4,
+          column: 7,
+        }),
       },
     );
+  }
+
+  #[test]
+  fn test_dependencies() {
+    fn dep_test(source: &str, expected: &str, deps: Vec<(&str, &str)>) {
+      let mut stylesheet = StyleSheet::parse(
+        &source,
+        ParserOptions {
+          filename: "test.css".into(),
+          ..ParserOptions::default()
+        },
+      )
+      .unwrap();
+      stylesheet.minify(MinifyOptions::default()).unwrap();
+      let res = stylesheet
+        .to_css(PrinterOptions {
+          analyze_dependencies: Some(Default::default()),
+          minify: true,
+          ..PrinterOptions::default()
+        })
+        .unwrap();
+      assert_eq!(res.code, expected);
+      let dependencies = res.dependencies.unwrap();
+      assert_eq!(dependencies.len(), deps.len());
+      for (i, (url, placeholder)) in deps.into_iter().enumerate() {
+        match &dependencies[i] {
+          Dependency::Url(dep) => {
+            assert_eq!(dep.url, url);
+            assert_eq!(dep.placeholder, placeholder);
+          }
+          Dependency::Import(dep) => {
+            assert_eq!(dep.url, url);
+            assert_eq!(dep.placeholder, placeholder);
+          }
+        }
+      }
+    }
+
+    fn dep_error_test(source: &str, error: PrinterErrorKind) {
+      let stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
+      let res = stylesheet.to_css(PrinterOptions {
+        analyze_dependencies: Some(Default::default()),
+        ..PrinterOptions::default()
+      });
+      match res {
+        Err(e) => assert_eq!(e.kind, error),
+        _ => unreachable!(),
+      }
+    }
+
+    dep_test(
+      ".foo { background: image-set('./img12x.png', './img21x.png' 2x)}",
+      ".foo{background:image-set(\"hXFI8W\" 1x,\"5TkpBa\" 2x)}",
+      vec![("./img12x.png", "hXFI8W"), ("./img21x.png", "5TkpBa")],
+    );
+
+    dep_test(
+      ".foo { background: image-set(url(./img12x.png), url('./img21x.png') 2x)}",
+      ".foo{background:image-set(\"hXFI8W\" 1x,\"5TkpBa\" 2x)}",
+      vec![("./img12x.png", "hXFI8W"), ("./img21x.png", "5TkpBa")],
+    );
+
+    dep_test(
+      ".foo { --test: url(/foo.png) }",
+      ".foo{--test:url(\"lDnnrG\")}",
+      vec![("/foo.png", "lDnnrG")],
+    );
 
-    prefix_test(
-      r#"
-        .foo {
-          transition-property: mask-composite, mask-mode;
-        }
-      "#,
-      indoc! { r#"
-        .foo {
-          transition-property: -webkit-mask-composite, mask-composite, -webkit-mask-source-type, mask-mode;
-        }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+    dep_test(
+      ".foo { --test: url(\"/foo.png\") }",
+      ".foo{--test:url(\"lDnnrG\")}",
+      vec![("/foo.png", "lDnnrG")],
     );
-  }
 
-  #[test]
-  fn test_filter() {
-    minify_test(
-      ".foo { filter: url('filters.svg#filter-id'); }",
-      ".foo{filter:url(filters.svg#filter-id)}",
+    dep_test(
+      ".foo { --test: url(\"http://example.com/foo.png\") }",
+      ".foo{--test:url(\"3X1zSW\")}",
+      vec![("http://example.com/foo.png", "3X1zSW")],
     );
-    minify_test(".foo { filter: blur(5px); }", ".foo{filter:blur(5px)}");
-    minify_test(".foo { filter: blur(0px); }", ".foo{filter:blur()}");
-    minify_test(".foo { filter: brightness(10%); }", ".foo{filter:brightness(10%)}");
-    minify_test(".foo { filter: brightness(100%); }", ".foo{filter:brightness()}");
-    minify_test(
-      ".foo { filter: drop-shadow(16px 16px 20px yellow); }",
-      ".foo{filter:drop-shadow(16px 16px 20px #ff0)}",
+
+    dep_test(
+      ".foo { --test: url(\"data:image/svg+xml;utf8,<svg></svg>\") }",
+      ".foo{--test:url(\"-vl-rG\")}",
+      vec![("data:image/svg+xml;utf8,<svg></svg>", "-vl-rG")],
     );
-    minify_test(
-      ".foo { filter: contrast(175%) brightness(3%); }",
-      ".foo{filter:contrast(175%)brightness(3%)}",
+
+    dep_test(
+      ".foo { background: url(\"foo.png\") var(--test) }",
+      ".foo{background:url(\"Vwkwkq\") var(--test)}",
+      vec![("foo.png", "Vwkwkq")],
     );
-    minify_test(".foo { filter: hue-rotate(0) }", ".foo{filter:hue-rotate()}");
 
-    prefix_test(
-      ".foo { filter: blur(5px) }",
-      indoc! { r#"
-        .foo {
-          -webkit-filter: blur(5px);
-          filter: blur(5px);
-        }
-      "#},
-      Browsers {
-        chrome: Some(20 << 16),
-        ..Browsers::default()
-      },
+    dep_error_test(
+      ".foo { --test: url(\"foo.png\") }",
+      PrinterErrorKind::AmbiguousUrlInCustomProperty { url: "foo.png".into() },
     );
 
-    prefix_test(
-      ".foo { filter: blur(5px) }",
-      indoc! { r#"
-        .foo {
-          filter: blur(5px);
-        }
-      "#},
-      Browsers {
-        chrome: Some(80 << 16),
-        ..Browsers::default()
-      },
+    dep_error_test(
+      ".foo { --test: url(foo.png) }",
+      PrinterErrorKind::AmbiguousUrlInCustomProperty { url: "foo.png".into() },
     );
 
-    prefix_test(
-      ".foo { backdrop-filter: blur(5px) }",
-      indoc! { r#"
-        .foo {
-          backdrop-filter: blur(5px);
-        }
-      "#},
-      Browsers {
-        chrome: Some(80 << 16),
-        ..Browsers::default()
+    dep_error_test(
+      ".foo { --test: url(./foo.png) }",
+      PrinterErrorKind::AmbiguousUrlInCustomProperty {
+        url: "./foo.png".into(),
       },
     );
 
-    prefix_test(
-      ".foo { backdrop-filter: blur(5px) }",
-      indoc! { r#"
-        .foo {
-          -webkit-backdrop-filter: blur(5px);
-          backdrop-filter: blur(5px);
-        }
-      "#},
-      Browsers {
-        safari: Some(15 << 16),
-        ..Browsers::default()
-      },
+    dep_test(
+      ".foo { behavior: url(#foo) }",
+      ".foo{behavior:url(\"Zn9-2q\")}",
+      vec![("#foo", "Zn9-2q")],
     );
 
-    prefix_test(
-      ".foo { filter: var(--foo) }",
-      indoc! { r#"
-        .foo {
-          -webkit-filter: var(--foo);
-          filter: var(--foo);
-        }
-      "#},
-      Browsers {
-        chrome: Some(20 << 16),
-        ..Browsers::default()
-      },
+    dep_test(
+      ".foo { --foo: url(#foo) }",
+      ".foo{--foo:url(\"Zn9-2q\")}",
+      vec![("#foo", "Zn9-2q")],
     );
 
-    prefix_test(
-      ".foo { filter: drop-shadow(16px 16px 20px lab(40% 56.6 39)) }",
-      indoc! { r#"
-        .foo {
-          -webkit-filter: drop-shadow(16px 16px 20px #b32323);
-          filter: drop-shadow(16px 16px 20px #b32323);
-          filter: drop-shadow(16px 16px 20px lab(40% 56.6 39));
-        }
-      "#},
-      Browsers {
-        chrome: Some(20 << 16),
-        ..Browsers::default()
-      },
+    dep_test(
+      "@import \"test.css\"; .foo { color: red }",
+      "@import \"hHsogW\";.foo{color:red}",
+      vec![("test.css", "hHsogW")],
     );
 
-    prefix_test(
-      ".foo { filter: contrast(175%) drop-shadow(16px 16px 20px lab(40% 56.6 39)) }",
-      indoc! { r#"
-        .foo {
-          filter: contrast(175%) drop-shadow(16px 16px 20px #b32323);
-          filter: contrast(175%) drop-shadow(16px 16px 20px lab(40% 56.6 39));
-        }
-      "#},
-      Browsers {
-        chrome: Some(4 << 16),
-        ..Browsers::default()
-      },
+    dep_test(
+      "@import url(\"a.css\") screen; .foo { color: red }",
+      "@import \"rRcgVG\" screen;.foo{color:red}",
+      vec![("a.css", "rRcgVG")],
     );
 
-    prefix_test(
-      ".foo { filter: drop-shadow(16px 16px 20px lab(40% 56.6 39)) drop-shadow(16px 16px 20px yellow) }",
-      indoc! { r#"
-        .foo {
-          filter: drop-shadow(16px 16px 20px #b32323) drop-shadow(16px 16px 20px #ff0);
-          filter: drop-shadow(16px 16px 20px lab(40% 56.6 39)) drop-shadow(16px 16px 20px #ff0);
-        }
-      "#},
-      Browsers {
-        chrome: Some(4 << 16),
-        ..Browsers::default()
-      },
+    dep_test(
+      ".foo { background: url(./img.png) }",
+      ".foo{background:url(\"2ooLIa\")}",
+      vec![("./img.png", "2ooLIa")],
     );
 
-    prefix_test(
-      ".foo { filter: var(--foo) drop-shadow(16px 16px 20px lab(40% 56.6 39)) }",
-      indoc! { r#"
-        .foo {
-          filter: var(--foo) drop-shadow(16px 16px 20px #b32323);
-        }
+    dep_test(
+      "@font-face { font-family: Test; src: url(a.woff2) format(\"woff2\"), url(a.woff) format(\"woff\") }",
+      "@font-face{font-family:Test;src:url(\"uWni_W\")format(\"woff2\"),url(\"9B-h3q\")format(\"woff\")}",
+      vec![("a.woff2", "uWni_W"), ("a.woff", "9B-h3q")],
+    );
 
-        @supports (color: lab(0% 0 0)) {
-          .foo {
-            filter: var(--foo) drop-shadow(16px 16px 20px lab(40% 56.6 39));
-          }
-        }
-      "#},
-      Browsers {
-        chrome: Some(4 << 16),
-        ..Browsers::default()
+    let stylesheet = StyleSheet::parse(
+      "@import url(\"a.css\") supports(display: grid) screen;\n.foo { background: url(./img.png) }",
+      ParserOptions {
+        filename: "test.css".into(),
+        ..ParserOptions::default()
       },
-    );
+    )
+    .unwrap();
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        analyze_dependencies: Some(DependencyOptions { remove_imports: true }),
+        minify: true,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(res.code, ".foo{background:url(\"2ooLIa\")}");
+    let dependencies = res.dependencies.unwrap();
+    assert_eq!(dependencies.len(), 2);
+    match &dependencies[0] {
+      Dependency::Import(dep) => {
+        assert_eq!(dep.url, "a.css");
+        assert_eq!(dep.media.as_deref(), Some("screen"));
+        assert_eq!(dep.supports.as_deref(), Some("(display: grid)"));
+        assert_eq!(dep.loc.file_path, "test.css");
+        assert_eq!(dep.loc.start.line, 1);
+      }
+      _ => unreachable!(),
+    }
+    match &dependencies[1] {
+      Dependency::Url(dep) => {
+        assert_eq!(dep.url, "./img.png");
+        assert_eq!(dep.loc.start, DependencyLocation { line: 2, column: 24 });
+        assert_eq!(dep.loc.end, DependencyLocation { line: 2, column: 32 });
+      }
+      _ => unreachable!(),
+    }
   }
 
   #[test]
-  fn test_viewport() {
-    minify_test(
-      r#"
-    @viewport {
-      width: 100vw;
-    }"#,
-      "@viewport{width:100vw}",
+  fn test_api() {
+    let stylesheet = StyleSheet::parse(".foo:hover { color: red }", ParserOptions::default()).unwrap();
+    match &stylesheet.rules.0[0] {
+      CssRule::Style(s) => {
+        assert_eq!(&s.selectors.to_string(), ".foo:hover");
+      }
+      _ => unreachable!(),
+    }
+
+    let color = CssColor::parse_string("#f0f").unwrap();
+    assert_eq!(color.to_css_string(PrinterOptions::default()).unwrap(), "#f0f");
+
+    let rgba = |s: &str| CssColor::parse_string(s).unwrap().to_rgba();
+    assert_eq!(rgba("hsl(120 100% 50%)"), Some(cssparser::RGBA::new(0, 255, 0, 255)));
+    assert_eq!(
+      rgba("hwb(194 0% 0% / 50%)"),
+      Some(cssparser::RGBA::new(0, 196, 255, 128))
+    );
+    assert_eq!(rgba("rebeccapurple"), Some(cssparser::RGBA::new(102, 51, 153, 255)));
+    assert_eq!(rgba("lab(40% 56.6 39)"), Some(cssparser::RGBA::new(179, 35, 35, 255)));
+    assert_eq!(rgba("currentColor"), None);
+
+    let rule = CssRule::parse_string(".foo { color: red }", ParserOptions::default()).unwrap();
+    assert_eq!(
+      rule.to_css_string(PrinterOptions::default()).unwrap(),
+      indoc! {r#"
+    .foo {
+      color: red;
+    }"#}
+    );
+
+    let property = Property::parse_string("color".into(), "#f0f", ParserOptions::default()).unwrap();
+    assert_eq!(
+      property.to_css_string(false, PrinterOptions::default()).unwrap(),
+      "color: #f0f"
+    );
+    assert_eq!(
+      property.to_css_string(true, PrinterOptions::default()).unwrap(),
+      "color: #f0f !important"
+    );
+
+    let declaration = Declaration::parse_string("color: var(--x, red)", ParserOptions::default()).unwrap();
+    assert!(matches!(declaration.property, Property::Unparsed(_)));
+    assert!(!declaration.important);
+    assert_eq!(
+      declaration.to_css_string(PrinterOptions::default()).unwrap(),
+      "color: var(--x, red)"
+    );
+
+    let declaration =
+      Declaration::parse_string("color: var(--x, red) !important", ParserOptions::default()).unwrap();
+    assert!(declaration.important);
+    assert_eq!(
+      declaration
+        .to_css_string(PrinterOptions {
+          minify: true,
+          ..PrinterOptions::default()
+        })
+        .unwrap(),
+      "color:var(--x,red)!important"
     );
-    minify_test(
-      r#"
-    @-ms-viewport {
-      width: device-width;
-    }"#,
-      "@-ms-viewport{width:device-width}",
+
+    let err = Declaration::parse_string("color red", ParserOptions::default()).unwrap_err();
+    assert_eq!(
+      err.kind,
+      cssparser::ParseErrorKind::Basic(cssparser::BasicParseErrorKind::UnexpectedToken(
+        cssparser::Token::Ident("red".into())
+      ))
     );
-  }
+    assert_eq!(err.location, SourceLocation { line: 0, column: 7 });
+    assert!(Declaration::parse_string("color: red; width: 10px", ParserOptions::default()).is_err());
 
-  #[test]
-  fn test_custom_media() {
-    custom_media_test(
-      r#"
-      @custom-media --modern (color), (hover);
+    let code = indoc! { r#"
+      .foo {
+        color: green;
+      }
 
-      @media (--modern) and (width > 1024px) {
-        .a {
-          color: green;
-        }
+      .bar {
+        color: red;
+        background: pink;
       }
-      "#,
-      indoc! {r#"
-      @media ((color) or (hover)) and (width > 1024px) {
-        .a {
+
+      @media print {
+        .baz {
           color: green;
         }
       }
-      "#},
-    );
+    "#};
+    let stylesheet = StyleSheet::parse(code, ParserOptions::default()).unwrap();
+    if let CssRule::Style(style) = &stylesheet.rules.0[1] {
+      let (key, val) = style.property_location(code, 0).unwrap();
+      assert_eq!(
+        key,
+        SourceLocation { line: 5, column: 3 }..SourceLocation { line: 5, column: 8 }
+      );
+      assert_eq!(
+        val,
+        SourceLocation { line: 5, column: 10 }..SourceLocation { line: 5, column: 13 }
+      );
+    }
 
-    custom_media_test(
-      r#"
-      @custom-media --color (color);
+    if let CssRule::Style(style) = &stylesheet.rules.0[1] {
+      let (key, val) = style.property_location(code, 1).unwrap();
+      assert_eq!(
+        key,
+        SourceLocation { line: 6, column: 3 }..SourceLocation { line: 6, column: 13 }
+      );
+      assert_eq!(
+        val,
+        SourceLocation { line: 6, column: 15 }..SourceLocation { line: 6, column: 19 }
+      );
+    }
+    if let CssRule::Media(media) = &stylesheet.rules.0[2] {
+      if let CssRule::Style(style) = &media.rules.0[0] {
+        let (key, val) = style.property_location(code, 0).unwrap();
+        assert_eq!(
+          key,
+          SourceLocation { line: 11, column: 5 }..SourceLocation { line: 11, column: 10 }
+        );
+        assert_eq!(
+          val,
+          SourceLocation { line: 11, column: 12 }..SourceLocation { line: 11, column: 17 }
+        );
+      }
+    }
+  }
 
-      @media (--color) and (width > 1024px) {
-        .a {
-          color: green;
-        }
+  #[test]
+  fn test_logical_fallback() {
+    logical_fallback_test(
+      r#"
+      .foo {
+        color: red;
+        margin-inline-start: 2px;
       }
-      "#,
+    "#,
       indoc! {r#"
-      @media (color) and (width > 1024px) {
-        .a {
-          color: green;
-        }
+      .foo {
+        color: red;
+        margin-left: 2px;
       }
-      "#},
+    "#},
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+      LogicalFallback::Ltr,
     );
 
-    custom_media_test(
+    logical_fallback_test(
       r#"
-      @custom-media --a (color);
-      @custom-media --b (--a);
-
-      @media (--b) and (width > 1024px) {
-        .a {
-          color: green;
-        }
+      .foo {
+        color: red;
+        margin-inline-start: 2px;
       }
-      "#,
+    "#,
       indoc! {r#"
-      @media (color) and (width > 1024px) {
-        .a {
-          color: green;
-        }
+      .foo {
+        color: red;
+        margin-left: 2px;
       }
-      "#},
+
+      :where([dir="rtl"]) .foo, .foo:where([dir="rtl"]) {
+        margin-right: 2px;
+        margin-left: initial;
+      }
+    "#},
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+      LogicalFallback::LtrWithRtlAttribute,
     );
 
-    custom_media_test(
+    logical_fallback_test(
       r#"
-      @custom-media --not-color not (color);
-
-      @media not (--not-color) {
-        .a {
-          color: green;
-        }
+      .foo {
+        color: red;
+        margin-inline-start: 2px !important;
       }
-      "#,
+    "#,
       indoc! {r#"
-      @media (color) {
-        .a {
-          color: green;
-        }
+      .foo {
+        color: red;
+        margin-left: 2px !important;
       }
-      "#},
+
+      :where([dir="rtl"]) .foo, .foo:where([dir="rtl"]) {
+        margin-right: 2px !important;
+        margin-left: initial !important;
+      }
+    "#},
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+      LogicalFallback::LtrWithRtlAttribute,
     );
 
-    custom_media_test(
+    logical_fallback_test(
       r#"
-      @custom-media --color-print print and (color);
-
-      @media (--color-print) {
-        .a {
-          color: green;
-        }
+      .foo {
+        margin-inline: 2px 4px;
+        padding-block: 1px 3px;
       }
-      "#,
+    "#,
       indoc! {r#"
-      @media print and (color) {
-        .a {
-          color: green;
-        }
+      .foo {
+        margin-left: 2px;
+        margin-right: 4px;
+        padding-top: 1px;
+        padding-bottom: 3px;
       }
-      "#},
+
+      :where([dir="rtl"]) .foo, .foo:where([dir="rtl"]) {
+        margin-left: 4px;
+        margin-right: 2px;
+      }
+    "#},
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+      LogicalFallback::LtrWithRtlAttribute,
     );
 
-    custom_media_test(
+    logical_fallback_test(
       r#"
-      @custom-media --color-print print and (color);
-
-      @media print and (--color-print) {
-        .a {
-          color: green;
-        }
+      .foo {
+        inset-inline: 2px 4px;
       }
-      "#,
+    "#,
       indoc! {r#"
-      @media print and (color) {
-        .a {
-          color: green;
-        }
+      .foo {
+        left: 2px;
+        right: 4px;
       }
-      "#},
+    "#},
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+      LogicalFallback::Ltr,
     );
 
-    custom_media_test(
+    logical_fallback_test(
       r#"
-      @custom-media --not-color-print not print and (color);
-
-      @media not print and (--not-color-print) {
-        .a {
-          color: green;
-        }
+      .foo, .bar > .baz {
+        border-inline-start: 1px solid red;
       }
-      "#,
+    "#,
       indoc! {r#"
-      @media not print and (color) {
-        .a {
-          color: green;
-        }
+      .foo, .bar > .baz {
+        border-left: 1px solid red;
       }
-      "#},
+
+      :where([dir="rtl"]) .foo, .foo:where([dir="rtl"]), :where([dir="rtl"]) .bar > .baz, .bar > .baz:where([dir="rtl"]) {
+        border-right: 1px solid red;
+        border-left: initial;
+      }
+    "#},
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+      LogicalFallback::LtrWithRtlAttribute,
     );
+  }
 
-    custom_media_test(
-      r#"
-      @custom-media --print print;
+  #[test]
+  fn test_layer() {
+    minify_test("@layer foo;", "@layer foo;");
+    minify_test("@layer foo, bar;", "@layer foo,bar;");
+    minify_test("@layer foo.bar;", "@layer foo.bar;");
+    minify_test("@layer foo.bar, baz;", "@layer foo.bar,baz;");
 
-      @media (--print) {
-        .a {
-          color: green;
+    minify_test(
+      r#"
+      @layer foo {
+        .bar {
+          color: red;
         }
       }
-      "#,
-      indoc! {r#"
-      @media print {
-        .a {
-          color: green;
+    "#,
+      "@layer foo{.bar{color:red}}",
+    );
+    minify_test(
+      r#"
+      @layer foo.bar {
+        .bar {
+          color: red;
         }
       }
-      "#},
+    "#,
+      "@layer foo.bar{.bar{color:red}}",
     );
+    minify_test(r#"
+      @layer base {
+        p { max-width: 70ch; }
+      }
 
-    custom_media_test(
-      r#"
-      @custom-media --print print;
+      @layer framework {
+        @layer base {
+          p { margin-block: 0.75em; }
+        }
 
-      @media not (--print) {
-        .a {
-          color: green;
+        @layer theme {
+          p { color: #222; }
         }
       }
-      "#,
-      indoc! {r#"
-      @media not print {
-        .a {
-          color: green;
+    "#, "@layer base{p{max-width:70ch}}@layer framework{@layer base{p{margin-block:.75em}}@layer theme{p{color:#222}}}");
+    minify_test(
+      r#"
+      @layer {
+        .bar {
+          color: red;
         }
       }
-      "#},
+    "#,
+      "@layer{.bar{color:red}}",
     );
-
-    custom_media_test(
+    minify_test("@layer reset, base, components;", "@layer reset,base,components;");
+    test(
       r#"
-      @custom-media --print not print;
+      @layer reset, base;
 
-      @media not (--print) {
-        .a {
-          color: green;
+      @layer base {
+        p {
+          color: red;
         }
       }
-      "#,
-      indoc! {r#"
-      @media print {
-        .a {
-          color: green;
+
+      @layer reset {
+        p {
+          margin: 0;
         }
       }
-      "#},
-    );
 
-    custom_media_test(
-      r#"
-      @custom-media --print print;
-
-      @media ((--print)) {
-        .a {
+      @layer base.typography {
+        h1 {
           color: green;
         }
       }
-      "#,
+    "#,
       indoc! {r#"
-      @media print {
-        .a {
-          color: green;
+      @layer reset, base;
+
+      @layer base {
+        p {
+          color: red;
         }
       }
-      "#},
-    );
-
-    custom_media_test(
-      r#"
-      @custom-media --color (color);
-      @custom-media --print print;
 
-      @media (--print) and (--color) {
-        .a {
-          color: green;
+      @layer reset {
+        p {
+          margin: 0;
         }
       }
-      "#,
-      indoc! {r#"
-      @media print and (color) {
-        .a {
+
+      @layer base.typography {
+        h1 {
           color: green;
         }
       }
-      "#},
+    "#},
     );
 
-    custom_media_test(
+    // Empty layer blocks are removed, but still declare the layer order.
+    minify_test(
+      "@layer foo {} @layer bar { .a { color: red } }",
+      "@layer foo;@layer bar{.a{color:red}}",
+    );
+    minify_test("@layer foo {} @layer bar {}", "@layer foo,bar;");
+    minify_test(
+      "@layer foo; @layer foo {} @layer bar { .a { color: red } }",
+      "@layer foo;@layer bar{.a{color:red}}",
+    );
+    minify_test("@layer {} .a { color: red }", ".a{color:red}");
+
+    // Anonymous layers are distinct and cannot be merged.
+    minify_test(
+      "@layer { .a { color: red } } @layer { .b { color: red } }",
+      "@layer{.a{color:red}}@layer{.b{color:red}}",
+    );
+    error_test("@layer;", ParserError::UnexpectedToken(Token::Semicolon));
+    error_test("@layer foo, bar {};", ParserError::AtRuleBodyInvalid);
+    minify_test("@import 'test.css' layer;", "@import \"test.css\" layer;");
+    minify_test("@import 'test.css' layer(foo);", "@import \"test.css\" layer(foo);");
+    minify_test(
+      "@import 'test.css' layer(foo.bar);",
+      "@import \"test.css\" layer(foo.bar);",
+    );
+    error_test(
+      "@import 'test.css' layer(foo, bar) {};",
+      ParserError::UnexpectedToken(Token::Comma),
+    );
+  }
+
+  #[test]
+  fn test_property() {
+    minify_test(
       r#"
-      @custom-media --color (color);
-      @custom-media --not-print not print;
+      @property --property-name {
+        syntax: '<color>';
+        inherits: false;
+        initial-value: yellow;
+      }
+    "#,
+      "@property --property-name{syntax:\"<color>\";inherits:false;initial-value:#ff0}",
+    );
 
-      @media (--not-print) and (--color) {
-        .a {
-          color: green;
-        }
+    minify_test(
+      r#"
+      @property --property-name {
+        syntax: '<length>';
+        inherits: true;
+        initial-value: 25px;
       }
-      "#,
-      indoc! {r#"
-      @media not print and (color) {
-        .a {
-          color: green;
-        }
+    "#,
+      "@property --property-name{syntax:\"<length>\";inherits:true;initial-value:25px}",
+    );
+
+    error_test(
+      r#"
+      @property --property-name {
+        syntax: '<color>';
+        inherits: false;
+        initial-value: 25px;
       }
-      "#},
+    "#,
+      ParserError::UnexpectedToken(crate::properties::custom::Token::Dimension {
+        has_sign: false,
+        value: 25.0,
+        int_value: Some(25),
+        unit: "px".into(),
+      }),
     );
 
-    custom_media_test(
+    error_test(
       r#"
-      @custom-media --color (color);
-      @custom-media --screen screen;
-      @custom-media --print print;
+      @property --property-name {
+        syntax: '<length>';
+        inherits: false;
+        initial-value: var(--some-value);
+      }
+    "#,
+      ParserError::UnexpectedToken(crate::properties::custom::Token::Function("var".into())),
+    );
 
-      @media (--print) and (--color), (--screen) and (--color) {
-        .a {
-          color: green;
-        }
+    error_test(
+      r#"
+      @property --property-name {
+        syntax: '<color>';
+        inherits: false;
       }
-      "#,
-      indoc! {r#"
-      @media print and (color), screen and (color) {
-        .a {
-          color: green;
-        }
+    "#,
+      ParserError::MissingDescriptor("initial-value"),
+    );
+
+    minify_test(
+      r#"
+      @property --property-name {
+        syntax: '*';
+        inherits: false;
       }
-      "#},
+    "#,
+      "@property --property-name{syntax:\"*\";inherits:false}",
     );
 
-    custom_media_test(
+    error_test(
       r#"
-      @custom-media --color print and (color), print and (script);
+      @property --property-name {
+        syntax: '*';
+      }
+    "#,
+      ParserError::MissingDescriptor("inherits"),
+    );
 
-      @media (--color) {
-        .a {
-          color: green;
-        }
+    error_test(
+      r#"
+      @property --property-name {
+        inherits: false;
       }
-      "#,
-      indoc! {r#"
-      @media print and ((color) or (script)) {
-        .a {
-          color: green;
-        }
+    "#,
+      ParserError::MissingDescriptor("syntax"),
+    );
+
+    error_test(
+      r#"
+      @property property-name {
+        syntax: '*';
+        inherits: false;
       }
-      "#},
+    "#,
+      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("property-name".into())),
     );
 
-    custom_media_test(
+    minify_test(
       r#"
-      @custom-media --color (color);
-      @custom-media --not-color not all and (--color);
+      @property --property-name {
+        syntax: 'custom | <color>';
+        inherits: false;
+        initial-value: yellow;
+      }
+    "#,
+      "@property --property-name{syntax:\"custom|<color>\";inherits:false;initial-value:#ff0}",
+    );
 
-      @media (--not-color) {
-        .a {
-          color: green;
-        }
+    minify_test(r#"
+      @property --property-name {
+        syntax: '<transform-list>';
+        inherits: false;
+        initial-value: translate(200px,300px) translate(100px,200px) scale(2);
+      }
+    "#, "@property --property-name{syntax:\"<transform-list>\";inherits:false;initial-value:matrix(2,0,0,2,300,500)}");
+
+    minify_test(
+      r#"
+      @property --property-name {
+        syntax: '<time>';
+        inherits: false;
+        initial-value: 1000ms;
       }
-      "#,
-      indoc! {r#"
-        @media not all and (color) {
-          .a {
-            color: green;
-          }
-        }
-      "#},
+    "#,
+      "@property --property-name{syntax:\"<time>\";inherits:false;initial-value:1s}",
     );
 
-    custom_media_test(
+    minify_test(
       r#"
-      @custom-media --color (color);
-
-      @media not all and (--color) {
-        .a {
-          color: green;
-        }
+      @property --property-name {
+        syntax: '<url>';
+        inherits: false;
+        initial-value: url("foo.png");
       }
-      "#,
-      indoc! {r#"
-        @media not all and (color) {
-          .a {
-            color: green;
-          }
-        }
-      "#},
+    "#,
+      "@property --property-name{syntax:\"<url>\";inherits:false;initial-value:url(foo.png)}",
     );
 
-    custom_media_test(
+    minify_test(
       r#"
-      @media (--print) {
-        .a {
-          color: green;
-        }
+      @property --property-name {
+        syntax: '<image>';
+        inherits: false;
+        initial-value: linear-gradient(yellow, blue);
       }
+    "#,
+      "@property --property-name{syntax:\"<image>\";inherits:false;initial-value:linear-gradient(#ff0,#00f)}",
+    );
 
-      @custom-media --print print;
-      "#,
-      indoc! {r#"
-      @media print {
-        .a {
-          color: green;
-        }
+    minify_test(
+      r#"
+      @property --property-name {
+        initial-value: linear-gradient(yellow, blue);
+        inherits: false;
+        syntax: '<image>';
       }
-      "#},
+    "#,
+      "@property --property-name{syntax:\"<image>\";inherits:false;initial-value:linear-gradient(#ff0,#00f)}",
     );
 
-    custom_media_test(
+    test(
       r#"
-      @custom-media --not-width not (min-width: 300px);
-      @media screen and ((prefers-color-scheme: dark) or (--not-width)) {
-        .foo {
-          order: 6;
-        }
+      @property --property-name {
+        syntax: '<length>|none';
+        inherits: false;
+        initial-value: none;
       }
-      "#,
+    "#,
       indoc! {r#"
-      @media screen and ((prefers-color-scheme: dark) or (not (min-width: 300px))) {
-        .foo {
-          order: 6;
-        }
+      @property --property-name {
+        syntax: "<length> | none";
+        inherits: false;
+        initial-value: none;
       }
-      "#},
+    "#},
     );
 
-    fn custom_media_error_test(source: &str, err: Error<MinifyErrorKind>) {
-      let mut stylesheet = StyleSheet::parse(
-        &source,
-        ParserOptions {
-          filename: "test.css".into(),
-          custom_media: true,
-          ..ParserOptions::default()
-        },
-      )
-      .unwrap();
-      let res = stylesheet.minify(MinifyOptions {
-        targets: Some(Browsers {
-          chrome: Some(95 << 16),
-          ..Browsers::default()
-        }),
-        ..MinifyOptions::default()
-      });
-      assert_eq!(res, Err(err))
-    }
-
-    custom_media_error_test(
+    minify_test(
       r#"
-      @custom-media --color-print print and (color);
-
-      @media screen and (--color-print) {
-        .a {
-          color: green;
-        }
+      @property --property-name {
+        syntax: '<color>#';
+        inherits: false;
+        initial-value: yellow, blue;
       }
-      "#,
-      Error {
-        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
-          custom_media_loc: Location {
-            source_index: 0,
-            line: 1,
-            column: 7,
-          },
-        },
-        loc: Some(ErrorLocation {
-          filename: "test.css".into(),
-          line: 3,
-          column: 7,
-        }),
-      },
+    "#,
+      "@property --property-name{syntax:\"<color>#\";inherits:false;initial-value:#ff0,#00f}",
     );
-
-    custom_media_error_test(
+    minify_test(
       r#"
-      @custom-media --color-print print and (color);
-
-      @media not print and (--color-print) {
-        .a {
-          color: green;
-        }
+      @property --property-name {
+        syntax: '<color>+';
+        inherits: false;
+        initial-value: yellow blue;
       }
-      "#,
-      Error {
-        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
-          custom_media_loc: Location {
-            source_index: 0,
-            line: 1,
-            column: 7,
-          },
-        },
-        loc: Some(ErrorLocation {
-          filename: "test.css".into(),
-          line: 3,
-          column: 7,
-        }),
-      },
+    "#,
+      "@property --property-name{syntax:\"<color>+\";inherits:false;initial-value:#ff0 #00f}",
     );
+  }
 
-    custom_media_error_test(
-      r#"
-      @custom-media --color-print print and (color);
-      @custom-media --color-screen screen and (color);
+  #[test]
+  fn test_quoting_unquoting_urls() {
+    // Quotes remain double quotes when not minifying
+    test(
+      r#".foo {
+      background-image: url("0123abcd");
+    }"#,
+      r#".foo {
+  background-image: url("0123abcd");
+}
+"#,
+    );
 
-      @media (--color-print) or (--color-screen) {}
-      "#,
-      Error {
-        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
-          custom_media_loc: Location {
-            source_index: 0,
-            line: 2,
-            column: 7,
-          },
-        },
-        loc: Some(ErrorLocation {
-          filename: "test.css".into(),
-          line: 4,
-          column: 7,
-        }),
-      },
+    // Quotes removed when minifying
+    minify_test(
+      r#".foo {
+      background-image: url("0123abcd");
+    }"#,
+      r#".foo{background-image:url(0123abcd)}"#,
     );
 
-    custom_media_error_test(
-      r#"
-      @custom-media --color-print print and (color);
-      @custom-media --color-screen screen and (color);
+    // Doubles quotes added if not present when not minifying
+    test(
+      r#".foo {
+      background-image: url(0123abcd);
+    }"#,
+      r#".foo {
+  background-image: url("0123abcd");
+}
+"#,
+    );
 
-      @media (--color-print) and (--color-screen) {}
-      "#,
-      Error {
-        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
-          custom_media_loc: Location {
-            source_index: 0,
-            line: 2,
-            column: 7,
-          },
-        },
-        loc: Some(ErrorLocation {
-          filename: "test.css".into(),
-          line: 4,
-          column: 7,
-        }),
-      },
+    // No quotes changed if not present when not minifying
+    minify_test(
+      r#".foo {
+      background-image: url(0123abcd);
+    }"#,
+      r#".foo{background-image:url(0123abcd)}"#,
     );
+  }
+
+  #[test]
+  fn test_zindex() {
+    minify_test(".foo { z-index: 2 }", ".foo{z-index:2}");
+    minify_test(".foo { z-index: -2 }", ".foo{z-index:-2}");
+    minify_test(".foo { z-index: 999999 }", ".foo{z-index:999999}");
+    minify_test(".foo { z-index: 9999999 }", ".foo{z-index:9999999}");
+    minify_test(".foo { z-index: -9999999 }", ".foo{z-index:-9999999}");
+  }
+
+  #[test]
+  fn test_input_source_map() {
+    let source = r#".imported {
+      content: "yay, file support!";
+    }
 
-    custom_media_error_test(
-      r#"
-      @custom-media --screen screen;
-      @custom-media --print print;
+    .selector {
+      margin: 1em;
+      background-color: #f60;
+    }
 
-      @media (--print) and (--screen) {}
-      "#,
-      Error {
-        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
-          custom_media_loc: Location {
-            source_index: 0,
-            line: 1,
-            column: 7,
-          },
-        },
-        loc: Some(ErrorLocation {
-          filename: "test.css".into(),
-          line: 4,
-          column: 7,
-        }),
-      },
-    );
+    .selector .nested {
+      margin: 0.5em;
+    }
 
-    custom_media_error_test(
-      r#"
-      @custom-media --not-print not print and (color);
-      @custom-media --not-screen not screen and (color);
+    /*# sourceMappingURL=data:application/json;base64,ewoJInZlcnNpb24iOiAzLAoJInNvdXJjZVJvb3QiOiAicm9vdCIsCgkiZmlsZSI6ICJzdGRvdXQiLAoJInNvdXJjZXMiOiBbCgkJInN0ZGluIiwKCQkic2Fzcy9fdmFyaWFibGVzLnNjc3MiLAoJCSJzYXNzL19kZW1vLnNjc3MiCgldLAoJInNvdXJjZXNDb250ZW50IjogWwoJCSJAaW1wb3J0IFwiX3ZhcmlhYmxlc1wiO1xuQGltcG9ydCBcIl9kZW1vXCI7XG5cbi5zZWxlY3RvciB7XG4gIG1hcmdpbjogJHNpemU7XG4gIGJhY2tncm91bmQtY29sb3I6ICRicmFuZENvbG9yO1xuXG4gIC5uZXN0ZWQge1xuICAgIG1hcmdpbjogJHNpemUgLyAyO1xuICB9XG59IiwKCQkiJGJyYW5kQ29sb3I6ICNmNjA7XG4kc2l6ZTogMWVtOyIsCgkJIi5pbXBvcnRlZCB7XG4gIGNvbnRlbnQ6IFwieWF5LCBmaWxlIHN1cHBvcnQhXCI7XG59IgoJXSwKCSJtYXBwaW5ncyI6ICJBRUFBLFNBQVMsQ0FBQztFQUNSLE9BQU8sRUFBRSxvQkFBcUI7Q0FDL0I7O0FGQ0QsU0FBUyxDQUFDO0VBQ1IsTUFBTSxFQ0hELEdBQUc7RURJUixnQkFBZ0IsRUNMTCxJQUFJO0NEVWhCOztBQVBELFNBQVMsQ0FJUCxPQUFPLENBQUM7RUFDTixNQUFNLEVDUEgsS0FBRztDRFFQIiwKCSJuYW1lcyI6IFtdCn0= */"#;
 
-      @media ((script) or ((--not-print) and (--not-screen))) {
-        .a {
-          color: green;
-        }
-      }
-      "#,
-      Error {
-        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
-          custom_media_loc: Location {
-            source_index: 0,
-            line: 2,
-            column: 7,
-          },
-        },
-        loc: Some(ErrorLocation {
-          filename: "test.css".into(),
-          line: 4,
-          column: 7,
-        }),
-      },
+    let mut stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
+    stylesheet.minify(MinifyOptions::default()).unwrap();
+    let mut sm = parcel_sourcemap::SourceMap::new("/");
+    stylesheet
+      .to_css(PrinterOptions {
+        source_map: Some(&mut sm),
+        minify: true,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    let map = sm.to_json(None).unwrap();
+    assert_eq!(
+      map,
+      r#"{"version":3,"sourceRoot":null,"mappings":"AAAA,uCCGA,2CAAA","sources":["sass/_demo.scss","stdin"],"sourcesContent":[".imported {\n  content: \"yay, file support!\";\n}","@import \"_variables\";\n@import \"_demo\";\n\n.selector {\n  margin: $size;\n  background-color: $brandColor;\n\n  .nested {\n    margin: $size / 2;\n  }\n}"],"names":[]}"#
     );
+  }
 
-    custom_media_error_test(
-      r#"
-      @custom-media --color screen and (color), print and (color);
+  #[test]
+  fn test_source_map() {
+    let source = indoc! {r#"
+      .foo {
+        color: red;
+      }
 
-      @media (--color) {
-        .a {
-          color: green;
+      @media print {
+        .bar, .baz {
+          color: blue;
         }
       }
-      "#,
-      Error {
-        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
-          custom_media_loc: Location {
-            source_index: 0,
-            line: 1,
-            column: 7,
-          },
-        },
-        loc: Some(ErrorLocation {
-          filename: "test.css".into(),
-          line: 3,
-          column: 7,
-        }),
+    "#};
+
+    let stylesheet = StyleSheet::parse(
+      &source,
+      ParserOptions {
+        filename: "test.css".into(),
+        ..ParserOptions::default()
       },
-    );
+    )
+    .unwrap();
+    let mut sm = parcel_sourcemap::SourceMap::new("/");
+    sm.add_source("test.css");
+    sm.set_source_content(0, source).unwrap();
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        source_map: Some(&mut sm),
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(
+      res.code,
+      indoc! {r#"
+        .foo {
+          color: red;
+        }
 
-    custom_media_error_test(
-      r#"
-      @media (--not-defined) {
-        .a {
-          color: green;
+        @media print {
+          .bar, .baz {
+            color: #00f;
+          }
         }
-      }
-      "#,
-      Error {
-        kind: MinifyErrorKind::CustomMediaNotDefined {
-          name: "--not-defined".into(),
-        },
-        loc: Some(ErrorLocation {
-          filename: "test.css".into(),
-          line: 1,
-          column: 7,
-        }),
-      },
+      "#}
     );
 
-    custom_media_error_test(
-      r#"
-      @custom-media --circular-mq-a (--circular-mq-b);
-      @custom-media --circular-mq-b (--circular-mq-a);
+    let json = sm.to_json(None).unwrap();
+    let mut decoded = parcel_sourcemap::SourceMap::from_json("/", &json).unwrap();
+    let mut original = |line, column| {
+      let mapping = decoded.find_closest_mapping(line, column).unwrap();
+      let original = mapping.original.unwrap();
+      (original.original_line, original.original_column)
+    };
 
-      @media (--circular-mq-a) {
-        body {
-          order: 3;
-        }
-      }
-      "#,
-      Error {
-        kind: MinifyErrorKind::CircularCustomMedia {
-          name: "--circular-mq-a".into(),
-        },
-        loc: Some(ErrorLocation {
-          filename: "test.css".into(),
-          line: 4,
-          column: 7,
-        }),
-      },
-    );
+    assert_eq!(original(0, 0), (0, 0));
+    assert_eq!(original(4, 0), (4, 0));
+    assert_eq!(original(5, 2), (5, 2));
   }
 
   #[test]
-  fn test_dependencies() {
-    fn dep_test(source: &str, expected: &str, deps: Vec<(&str, &str)>) {
-      let mut stylesheet = StyleSheet::parse(
-        &source,
-        ParserOptions {
-          filename: "test.css".into(),
-          ..ParserOptions::default()
-        },
-      )
-      .unwrap();
-      stylesheet.minify(MinifyOptions::default()).unwrap();
-      let res = stylesheet
-        .to_css(PrinterOptions {
-          analyze_dependencies: Some(Default::default()),
-          minify: true,
-          ..PrinterOptions::default()
-        })
-        .unwrap();
-      assert_eq!(res.code, expected);
-      let dependencies = res.dependencies.unwrap();
-      assert_eq!(dependencies.len(), deps.len());
-      for (i, (url, placeholder)) in deps.into_iter().enumerate() {
-        match &dependencies[i] {
-          Dependency::Url(dep) => {
-            assert_eq!(dep.url, url);
-            assert_eq!(dep.placeholder, placeholder);
-          }
-          Dependency::Import(dep) => {
-            assert_eq!(dep.url, url);
-            assert_eq!(dep.placeholder, placeholder);
-          }
-        }
-      }
-    }
+  fn test_to_css_string() {
+    let source = ".foo { color: red }\n@media print { .bar { color: blue } }\n";
+    let stylesheet = StyleSheet::parse(
+      &source,
+      ParserOptions {
+        filename: "test.css".into(),
+        ..ParserOptions::default()
+      },
+    )
+    .unwrap();
 
-    fn dep_error_test(source: &str, error: PrinterErrorKind) {
-      let stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
-      let res = stylesheet.to_css(PrinterOptions {
-        analyze_dependencies: Some(Default::default()),
+    let (code, map) = stylesheet
+      .to_css_string(PrinterOptions {
+        minify: true,
         ..PrinterOptions::default()
-      });
-      match res {
-        Err(e) => assert_eq!(e.kind, error),
-        _ => unreachable!(),
-      }
-    }
+      })
+      .unwrap();
+    assert!(map.is_none());
 
-    dep_test(
-      ".foo { background: image-set('./img12x.png', './img21x.png' 2x)}",
-      ".foo{background:image-set(\"hXFI8W\" 1x,\"5TkpBa\" 2x)}",
-      vec![("./img12x.png", "hXFI8W"), ("./img21x.png", "5TkpBa")],
+    let mut manual = String::new();
+    let mut printer = crate::printer::Printer::new(
+      &mut manual,
+      PrinterOptions {
+        minify: true,
+        ..PrinterOptions::default()
+      },
     );
+    stylesheet.rules.to_css(&mut printer).unwrap();
+    printer.newline().unwrap();
+    assert_eq!(code, manual);
+    assert_eq!(code, ".foo{color:red}@media print{.bar{color:#00f}}");
 
-    dep_test(
-      ".foo { background: image-set(url(./img12x.png), url('./img21x.png') 2x)}",
-      ".foo{background:image-set(\"hXFI8W\" 1x,\"5TkpBa\" 2x)}",
-      vec![("./img12x.png", "hXFI8W"), ("./img21x.png", "5TkpBa")],
-    );
+    let mut sm = parcel_sourcemap::SourceMap::new("/");
+    sm.add_source("test.css");
+    let (code, map) = stylesheet
+      .to_css_string(PrinterOptions {
+        source_map: Some(&mut sm),
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    let mut manual_sm = parcel_sourcemap::SourceMap::new("/");
+    manual_sm.add_source("test.css");
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        source_map: Some(&mut manual_sm),
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(code, res.code);
+    assert_eq!(map.unwrap().to_json(None).unwrap(), manual_sm.to_json(None).unwrap());
+    assert_eq!(sm.to_json(None).unwrap(), manual_sm.to_json(None).unwrap());
+  }
 
-    dep_test(
-      ".foo { --test: url(/foo.png) }",
-      ".foo{--test:url(\"lDnnrG\")}",
-      vec![("/foo.png", "lDnnrG")],
+  #[test]
+  fn test_duplicate_declarations() {
+    minify_test(".foo { color: red; color: red }", ".foo{color:red}");
+    minify_test(
+      ".foo { color: red; color: blue; color: red }",
+      ".foo{color:#00f;color:red}",
     );
-
-    dep_test(
-      ".foo { --test: url(\"/foo.png\") }",
-      ".foo{--test:url(\"lDnnrG\")}",
-      vec![("/foo.png", "lDnnrG")],
+    minify_test(
+      ".foo { color: red; color: lab(40% 56.6 39); color: red }",
+      ".foo{color:lab(40% 56.6 39);color:red}",
     );
-
-    dep_test(
-      ".foo { --test: url(\"http://example.com/foo.png\") }",
-      ".foo{--test:url(\"3X1zSW\")}",
-      vec![("http://example.com/foo.png", "3X1zSW")],
+    minify_test(".foo { --x: 1px; --x: 1px }", ".foo{--x:1px}");
+    minify_test(".foo { margin-top: 10px; margin: 20px }", ".foo{margin:20px}");
+    minify_test(".foo { margin: 20px; margin-top: 10px }", ".foo{margin:10px 20px 20px}");
+    minify_test(
+      ".foo { color: red !important; color: red }",
+      ".foo{color:red;color:red!important}",
+    );
+    minify_test(
+      ".foo { color: red !important; color: red !important }",
+      ".foo{color:red!important}",
     );
+  }
 
-    dep_test(
-      ".foo { --test: url(\"data:image/svg+xml;utf8,<svg></svg>\") }",
-      ".foo{--test:url(\"-vl-rG\")}",
-      vec![("data:image/svg+xml;utf8,<svg></svg>", "-vl-rG")],
-    );
+  #[test]
+  fn test_error_recovery() {
+    use std::sync::{Arc, RwLock};
+    let warnings = Some(Arc::new(RwLock::new(Vec::new())));
+    test_with_options(
+      r#"
+      h1(>h1) {
+        color: red;
+      }
 
-    dep_test(
-      ".foo { background: url(\"foo.png\") var(--test) }",
-      ".foo{background:url(\"Vwkwkq\") var(--test)}",
-      vec![("foo.png", "Vwkwkq")],
-    );
+      .foo {
+        color: red;
+      }
 
-    dep_error_test(
-      ".foo { --test: url(\"foo.png\") }",
-      PrinterErrorKind::AmbiguousUrlInCustomProperty { url: "foo.png".into() },
-    );
+      .clearfix {
+        *zoom: 1;
+        background: red;
+      }
 
-    dep_error_test(
-      ".foo { --test: url(foo.png) }",
-      PrinterErrorKind::AmbiguousUrlInCustomProperty { url: "foo.png".into() },
-    );
+      @media (hover) {
+        h1(>h1) {
+          color: red;
+        }
 
-    dep_error_test(
-      ".foo { --test: url(./foo.png) }",
-      PrinterErrorKind::AmbiguousUrlInCustomProperty {
-        url: "./foo.png".into(),
+        .bar {
+          color: red;
+        }
+      }
+    "#,
+      indoc! { r#"
+      .foo {
+        color: red;
+      }
+
+      .clearfix {
+        background: red;
+      }
+
+      @media (hover) {
+        .bar {
+          color: red;
+        }
+      }
+    "#},
+      ParserOptions {
+        filename: "test.css".into(),
+        error_recovery: true,
+        warnings: warnings.clone(),
+        ..ParserOptions::default()
       },
     );
-
-    dep_test(
-      ".foo { behavior: url(#foo) }",
-      ".foo{behavior:url(\"Zn9-2q\")}",
-      vec![("#foo", "Zn9-2q")],
+    let w = warnings.unwrap();
+    let warnings = w.read().unwrap();
+    assert_eq!(
+      *warnings,
+      vec![
+        Error {
+          kind: ParserError::SelectorError(SelectorError::EmptySelector),
+          loc: Some(ErrorLocation {
+            filename: "test.css".into(),
+            line: 1,
+            column: 7
+          })
+        },
+        Error {
+          kind: ParserError::UnexpectedToken(Token::Delim('*')),
+          loc: Some(ErrorLocation {
+            filename: "test.css".into(),
+            line: 10,
+            column: 9
+          })
+        },
+        Error {
+          kind: ParserError::SelectorError(SelectorError::EmptySelector),
+          loc: Some(ErrorLocation {
+            filename: "test.css".into(),
+            line: 15,
+            column: 9
+          })
+        },
+      ]
     );
 
-    dep_test(
-      ".foo { --foo: url(#foo) }",
-      ".foo{--foo:url(\"Zn9-2q\")}",
-      vec![("#foo", "Zn9-2q")],
-    );
+    // In strict mode, a single invalid declaration fails the whole stylesheet.
+    // With error recovery, only that declaration is dropped.
+    let source = ".foo {\n  color: red;\n  *zoom: 1;\n  width: 10px;\n}";
+    error_test(source, ParserError::UnexpectedToken(Token::Delim('*')));
 
-    dep_test(
-      "@import \"test.css\"; .foo { color: red }",
-      "@import \"hHsogW\";.foo{color:red}",
-      vec![("test.css", "hHsogW")],
+    let warnings = Some(Arc::new(RwLock::new(Vec::new())));
+    test_with_options(
+      source,
+      indoc! { r#"
+      .foo {
+        color: red;
+        width: 10px;
+      }
+      "#},
+      ParserOptions {
+        filename: "test.css".into(),
+        error_recovery: true,
+        warnings: warnings.clone(),
+        ..ParserOptions::default()
+      },
+    );
+    let w = warnings.unwrap();
+    let warnings = w.read().unwrap();
+    assert_eq!(
+      *warnings,
+      vec![Error {
+        kind: ParserError::UnexpectedToken(Token::Delim('*')),
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 2,
+          column: 3
+        })
+      }]
     );
   }
 
   #[test]
-  fn test_api() {
-    let stylesheet = StyleSheet::parse(".foo:hover { color: red }", ParserOptions::default()).unwrap();
-    match &stylesheet.rules.0[0] {
-      CssRule::Style(s) => {
-        assert_eq!(&s.selectors.to_string(), ".foo:hover");
-      }
-      _ => unreachable!(),
-    }
+  fn test_contain() {
+    minify_test(".foo { contain: none }", ".foo{contain:none}");
+    minify_test(".foo { contain: paint }", ".foo{contain:paint}");
+    minify_test(".foo { contain: paint layout }", ".foo{contain:layout paint}");
+    minify_test(".foo { contain: Style inline-size }", ".foo{contain:inline-size style}");
+    minify_test(".foo { contain: paint style layout }", ".foo{contain:content}");
+    minify_test(".foo { contain: style paint layout size }", ".foo{contain:strict}");
+    minify_test(".foo { contain: strict }", ".foo{contain:strict}");
+    minify_test(".foo { contain: content }", ".foo{contain:content}");
+    minify_test(
+      ".foo { contain: inline-size layout style paint }",
+      ".foo{contain:inline-size layout style paint}",
+    );
 
-    let color = CssColor::parse_string("#f0f").unwrap();
-    assert_eq!(color.to_css_string(PrinterOptions::default()).unwrap(), "#f0f");
+    // Invalid values are preserved as is.
+    minify_test(".foo { contain: layout foo }", ".foo{contain:layout foo}");
+    minify_test(".foo { contain: strict paint }", ".foo{contain:strict paint}");
 
-    let rule = CssRule::parse_string(".foo { color: red }", ParserOptions::default()).unwrap();
+    use crate::properties::contain::Contain;
     assert_eq!(
-      rule.to_css_string(PrinterOptions::default()).unwrap(),
-      indoc! {r#"
-    .foo {
-      color: red;
-    }"#}
-    );
+      Contain::parse_string("paint layout").unwrap(),
+      Contain::Layout | Contain::Paint
+    );
+    assert!(Contain::parse_string("layout foo").is_err());
+    assert!(Contain::parse_string("paint paint").is_err());
+    assert!(Contain::parse_string("size inline-size").is_err());
+    assert!(Contain::parse_string("none paint").is_err());
+    assert!(Contain::parse_string("layout strict").is_err());
+  }
 
-    let property = Property::parse_string("color".into(), "#f0f", ParserOptions::default()).unwrap();
-    assert_eq!(
-      property.to_css_string(false, PrinterOptions::default()).unwrap(),
-      "color: #f0f"
+  #[test]
+  fn test_will_change() {
+    minify_test(".foo { will-change: auto }", ".foo{will-change:auto}");
+    minify_test(".foo { will-change: transform }", ".foo{will-change:transform}");
+    minify_test(
+      ".foo { will-change: Transform, opacity, scroll-position, contents }",
+      ".foo{will-change:transform,opacity,scroll-position,contents}",
     );
-    assert_eq!(
-      property.to_css_string(true, PrinterOptions::default()).unwrap(),
-      "color: #f0f !important"
+    minify_test(
+      ".foo { will-change: transform, opacity, transform }",
+      ".foo{will-change:transform,opacity}",
     );
+    minify_test(".foo { will-change: --foo, --Foo }", ".foo{will-change:--foo,--Foo}");
 
-    let code = indoc! { r#"
-      .foo {
-        color: green;
-      }
+    use crate::properties::ui::{WillChange, WillChangeFeature};
+    assert_eq!(
+      WillChange::parse_string("opacity, contents").unwrap(),
+      WillChange::Features(smallvec::smallvec![
+        WillChangeFeature::Property("opacity".into()),
+        WillChangeFeature::Contents
+      ])
+    );
+    assert!(WillChange::parse_string("none").is_err());
+    assert!(WillChange::parse_string("transform, auto").is_err());
+    assert!(WillChange::parse_string("all").is_err());
+    assert!(WillChange::parse_string("will-change").is_err());
+  }
 
-      .bar {
-        color: red;
-        background: pink;
+  #[test]
+  fn test_container_queries() {
+    // with name
+    minify_test(
+      r#"
+      @container my-layout (inline-size > 45em) {
+        .foo {
+          color: red;
+        }
       }
+    "#,
+      "@container my-layout (inline-size>45em){.foo{color:red}}",
+    );
 
-      @media print {
-        .baz {
-          color: green;
+    minify_test(
+      r#"
+      @container my-layout ( not (width > 500px) ) {
+        .foo {
+          color: red;
         }
       }
-    "#};
-    let stylesheet = StyleSheet::parse(code, ParserOptions::default()).unwrap();
-    if let CssRule::Style(style) = &stylesheet.rules.0[1] {
-      let (key, val) = style.property_location(code, 0).unwrap();
-      assert_eq!(
-        key,
-        SourceLocation { line: 5, column: 3 }..SourceLocation { line: 5, column: 8 }
-      );
-      assert_eq!(
-        val,
-        SourceLocation { line: 5, column: 10 }..SourceLocation { line: 5, column: 13 }
-      );
-    }
+    "#,
+      "@container my-layout (not (width>500px)){.foo{color:red}}",
+    );
 
-    if let CssRule::Style(style) = &stylesheet.rules.0[1] {
-      let (key, val) = style.property_location(code, 1).unwrap();
-      assert_eq!(
-        key,
-        SourceLocation { line: 6, column: 3 }..SourceLocation { line: 6, column: 13 }
-      );
-      assert_eq!(
-        val,
-        SourceLocation { line: 6, column: 15 }..SourceLocation { line: 6, column: 19 }
-      );
-    }
-    if let CssRule::Media(media) = &stylesheet.rules.0[2] {
-      if let CssRule::Style(style) = &media.rules.0[0] {
-        let (key, val) = style.property_location(code, 0).unwrap();
-        assert_eq!(
-          key,
-          SourceLocation { line: 11, column: 5 }..SourceLocation { line: 11, column: 10 }
-        );
-        assert_eq!(
-          val,
-          SourceLocation { line: 11, column: 12 }..SourceLocation { line: 11, column: 17 }
-        );
+    minify_test(
+      r#"
+      @container my-layout not (width > 500px) {
+        .foo {
+          color: red;
+        }
       }
-    }
-  }
+    "#,
+      "@container my-layout not (width>500px){.foo{color:red}}",
+    );
 
-  #[test]
-  fn test_layer() {
-    minify_test("@layer foo;", "@layer foo;");
-    minify_test("@layer foo, bar;", "@layer foo,bar;");
-    minify_test("@layer foo.bar;", "@layer foo.bar;");
-    minify_test("@layer foo.bar, baz;", "@layer foo.bar,baz;");
+    minify_test(
+      r#"
+      @container not (width > 500px) {
+        .foo {
+          color: red;
+        }
+      }
+    "#,
+      "@container not (width>500px){.foo{color:red}}",
+    );
 
     minify_test(
       r#"
-      @layer foo {
-        .bar {
+      @container my-layout ((width: 100px) and (not (height: 100px))) {
+        .foo {
           color: red;
         }
       }
     "#,
-      "@layer foo{.bar{color:red}}",
+      "@container my-layout ((width:100px) and (not (height:100px))){.foo{color:red}}",
     );
+
     minify_test(
       r#"
-      @layer foo.bar {
-        .bar {
+      @container my-layout (width = max(10px, 10em)) {
+        .foo {
           color: red;
         }
       }
     "#,
-      "@layer foo.bar{.bar{color:red}}",
+      "@container my-layout (width=max(10px,10em)){.foo{color:red}}",
     );
-    minify_test(r#"
-      @layer base {
-        p { max-width: 70ch; }
-      }
-
-      @layer framework {
-        @layer base {
-          p { margin-block: 0.75em; }
-        }
 
-        @layer theme {
-          p { color: #222; }
-        }
-      }
-    "#, "@layer base{p{max-width:70ch}}@layer framework{@layer base{p{margin-block:.75em}}@layer theme{p{color:#222}}}");
+    // without name
     minify_test(
       r#"
-      @layer {
-        .bar {
+      @container (inline-size > 45em) {
+        .foo {
           color: red;
         }
       }
     "#,
-      "@layer{.bar{color:red}}",
-    );
-    error_test("@layer;", ParserError::UnexpectedToken(Token::Semicolon));
-    error_test("@layer foo, bar {};", ParserError::AtRuleBodyInvalid);
-    minify_test("@import 'test.css' layer;", "@import \"test.css\" layer;");
-    minify_test("@import 'test.css' layer(foo);", "@import \"test.css\" layer(foo);");
-    minify_test(
-      "@import 'test.css' layer(foo.bar);",
-      "@import \"test.css\" layer(foo.bar);",
-    );
-    error_test(
-      "@import 'test.css' layer(foo, bar) {};",
-      ParserError::UnexpectedToken(Token::Comma),
+      "@container (inline-size>45em){.foo{color:red}}",
     );
-  }
 
-  #[test]
-  fn test_property() {
     minify_test(
       r#"
-      @property --property-name {
-        syntax: '<color>';
-        inherits: false;
-        initial-value: yellow;
+      @container (inline-size > 45em) and (inline-size < 100em) {
+        .foo {
+          color: red;
+        }
       }
     "#,
-      "@property --property-name{syntax:\"<color>\";inherits:false;initial-value:#ff0}",
+      "@container (inline-size>45em) and (inline-size<100em){.foo{color:red}}",
     );
 
+    // calc()
     minify_test(
       r#"
-      @property --property-name {
-        syntax: '<length>';
-        inherits: true;
-        initial-value: 25px;
+      @container (width > calc(100vw - 50px)) {
+        .foo {
+          color: red;
+        }
       }
     "#,
-      "@property --property-name{syntax:\"<length>\";inherits:true;initial-value:25px}",
+      "@container (width>calc(100vw - 50px)){.foo{color:red}}",
     );
 
-    error_test(
+    minify_test(
       r#"
-      @property --property-name {
-        syntax: '<color>';
-        inherits: false;
-        initial-value: 25px;
+      @container (calc(100vh - 50px) <= height ) {
+        .foo {
+          color: red;
+        }
       }
     "#,
-      ParserError::UnexpectedToken(crate::properties::custom::Token::Dimension {
-        has_sign: false,
-        value: 25.0,
-        int_value: Some(25),
-        unit: "px".into(),
-      }),
+      "@container (height>=calc(100vh - 50px)){.foo{color:red}}",
     );
 
-    error_test(
+    // merge adjacent
+    minify_test(
       r#"
-      @property --property-name {
-        syntax: '<length>';
-        inherits: false;
-        initial-value: var(--some-value);
+      @container my-layout (inline-size > 45em) {
+        .foo {
+          color: red;
+        }
+      }
+
+      @container my-layout (inline-size > 45em) {
+        .foo {
+          background: yellow;
+        }
+
+        .bar {
+          color: white;
+        }
       }
     "#,
-      ParserError::UnexpectedToken(crate::properties::custom::Token::Function("var".into())),
+      "@container my-layout (inline-size>45em){.foo{color:red;background:#ff0}.bar{color:#fff}}",
     );
 
-    error_test(
+    minify_test(
       r#"
-      @property --property-name {
-        syntax: '<color>';
-        inherits: false;
-      }
+    .foo {
+      container-name: foo bar;
+      container-type: size inline-size;
+    }
     "#,
-      ParserError::AtRuleBodyInvalid,
+      ".foo{container:foo bar/size}",
     );
-
     minify_test(
       r#"
-      @property --property-name {
-        syntax: '*';
-        inherits: false;
+    .foo {
+      container-name: foo bar;
+      container-type: normal;
+    }
+    "#,
+      ".foo{container:foo bar}",
+    );
+    minify_test(
+      ".foo{ container-type: inline-size }",
+      ".foo{container-type:inline-size}",
+    );
+    minify_test(".foo{ container-name: none; }", ".foo{container-name:none}");
+    minify_test(".foo{ container-name: foo; }", ".foo{container-name:foo}");
+    minify_test(".foo{ container: foo / normal; }", ".foo{container:foo}");
+    minify_test(
+      ".foo{ container: foo / inline-size; }",
+      ".foo{container:foo/inline-size}",
+    );
+    minify_test(".foo { width: calc(1cqw + 2cqw) }", ".foo{width:3cqw}");
+    minify_test(".foo { width: calc(1cqh + 2cqh) }", ".foo{width:3cqh}");
+    minify_test(".foo { width: calc(1cqi + 2cqi) }", ".foo{width:3cqi}");
+    minify_test(".foo { width: calc(1cqb + 2cqb) }", ".foo{width:3cqb}");
+    minify_test(".foo { width: calc(1cqmin + 2cqmin) }", ".foo{width:3cqmin}");
+    minify_test(".foo { width: calc(1cqmax + 2cqmax) }", ".foo{width:3cqmax}");
+    minify_test(".foo { width: 50cqw }", ".foo{width:50cqw}");
+    minify_test(".foo { height: 50cqh }", ".foo{height:50cqh}");
+    minify_test(".foo { width: 50cqi }", ".foo{width:50cqi}");
+    minify_test(".foo { height: 50cqb }", ".foo{height:50cqb}");
+    minify_test(".foo { width: 50cqmin }", ".foo{width:50cqmin}");
+    minify_test(".foo { width: 50cqmax }", ".foo{width:50cqmax}");
+    minify_test(".foo { width: calc(10px + 5cqw) }", ".foo{width:calc(10px + 5cqw)}");
+    minify_test(
+      ".foo { width: calc(10cqi - 2cqi + 1em) }",
+      ".foo{width:calc(8cqi + 1em)}",
+    );
+    prefix_test(
+      ".foo { width: calc(10px + 5cqw) }",
+      indoc! {r#"
+        .foo {
+          width: calc(10px + 5cqw);
+        }
+      "#},
+      Browsers {
+        safari: Some(13 << 16),
+        ..Browsers::default()
+      },
+    );
+    minify_test(
+      ".foo{ container: foo / size; container-type: inline-size }",
+      ".foo{container:foo/inline-size}",
+    );
+    minify_test(
+      ".foo{ container: foo; container-name: bar baz }",
+      ".foo{container:bar baz}",
+    );
+    test(
+      r#"
+      .foo {
+        container: sidebar / inline-size;
       }
     "#,
-      "@property --property-name{syntax:\"*\";inherits:false}",
+      indoc! {r#"
+      .foo {
+        container: sidebar / inline-size;
+      }
+    "#},
     );
 
-    error_test(
+    // nested rules
+    test(
       r#"
-      @property --property-name {
-        syntax: '*';
+      @container sidebar (min-width: 200px) {
+        .card {
+          display: grid;
+        }
+
+        @media (hover) {
+          .card:hover {
+            color: red;
+          }
+        }
       }
     "#,
-      ParserError::AtRuleBodyInvalid,
-    );
+      indoc! {r#"
+      @container sidebar (min-width: 200px) {
+        .card {
+          display: grid;
+        }
 
-    error_test(
+        @media (hover) {
+          .card:hover {
+            color: red;
+          }
+        }
+      }
+    "#},
+    );
+    test(
       r#"
-      @property --property-name {
-        inherits: false;
+      @container (inline-size > 45em) {
+        .card {
+          display: grid;
+        }
       }
     "#,
-      ParserError::AtRuleBodyInvalid,
+      indoc! {r#"
+      @container (inline-size > 45em) {
+        .card {
+          display: grid;
+        }
+      }
+    "#},
     );
 
-    error_test(
+    // Unlike in @media, there is no need to convert the range syntax in @container,
+    // because browsers all support this syntax.
+    prefix_test(
       r#"
-      @property property-name {
-        syntax: '*';
-        inherits: false;
+      @container (width > 100px) {
+        .foo { padding: 5px; }
       }
-    "#,
-      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("property-name".into())),
+      "#,
+      indoc! { r#"
+        @container (width > 100px) {
+          .foo {
+            padding: 5px;
+          }
+        }
+      "#},
+      Browsers {
+        chrome: Some(105 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      @container (min-width: 100px) {
+        .foo { padding: 5px; }
+      }
+      "#,
+      indoc! { r#"
+        @container (min-width: 100px) {
+          .foo {
+            padding: 5px;
+          }
+        }
+      "#},
+      Browsers {
+        chrome: Some(105 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    // Disallow 'none', 'not', 'and', 'or' as a `<container-name>`
+    // https://github.com/w3c/csswg-drafts/issues/7203#issuecomment-1144257312
+    // https://chromium-review.googlesource.com/c/chromium/src/+/3698402
+    error_test(
+      "@container none (width < 100vw) {}",
+      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("none".into())),
     );
 
-    minify_test(
-      r#"
-      @property --property-name {
-        syntax: 'custom | <color>';
-        inherits: false;
-        initial-value: yellow;
-      }
-    "#,
-      "@property --property-name{syntax:\"custom|<color>\";inherits:false;initial-value:#ff0}",
+    error_test(
+      "@container and (width < 100vw) {}",
+      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("and".into())),
     );
 
-    minify_test(r#"
-      @property --property-name {
-        syntax: '<transform-list>';
-        inherits: false;
-        initial-value: translate(200px,300px) translate(100px,200px) scale(2);
-      }
-    "#, "@property --property-name{syntax:\"<transform-list>\";inherits:false;initial-value:matrix(2,0,0,2,300,500)}");
+    error_test(
+      "@container or (width < 100vw) {}",
+      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("or".into())),
+    );
 
-    minify_test(
-      r#"
-      @property --property-name {
-        syntax: '<time>';
-        inherits: false;
-        initial-value: 1000ms;
-      }
-    "#,
-      "@property --property-name{syntax:\"<time>\";inherits:false;initial-value:1s}",
+    // Disallow CSS wide keywords as a `<container-name>`
+    error_test(
+      "@container revert-layer (width < 100vw) {}",
+      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("revert-layer".into())),
     );
 
-    minify_test(
-      r#"
-      @property --property-name {
-        syntax: '<url>';
-        inherits: false;
-        initial-value: url("foo.png");
-      }
-    "#,
-      "@property --property-name{syntax:\"<url>\";inherits:false;initial-value:url(foo.png)}",
+    error_test(
+      "@container initial (width < 100vw) {}",
+      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("initial".into())),
     );
 
-    minify_test(
-      r#"
-      @property --property-name {
-        syntax: '<image>';
-        inherits: false;
-        initial-value: linear-gradient(yellow, blue);
-      }
-    "#,
-      "@property --property-name{syntax:\"<image>\";inherits:false;initial-value:linear-gradient(#ff0,#00f)}",
+    // <ident> contains spaces
+    // https://github.com/web-platform-tests/wpt/blob/39f0da08fbbe33d0582a35749b6dbf8bd067a52d/css/css-contain/container-queries/at-container-parsing.html#L160-L178
+    error_test(
+      "@container foo bar (width < 100vw) {}",
+      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("bar".into())),
     );
+  }
 
+  #[test]
+  fn test_unknown_properties() {
     minify_test(
-      r#"
-      @property --property-name {
-        initial-value: linear-gradient(yellow, blue);
-        inherits: false;
-        syntax: '<image>';
-      }
-    "#,
-      "@property --property-name{syntax:\"<image>\";inherits:false;initial-value:linear-gradient(#ff0,#00f)}",
+      ".foo { -webkit-font-smoothing: antialiased }",
+      ".foo{-webkit-font-smoothing:antialiased}",
     );
-
+    minify_test(".foo { foo-bar:   1px    solid   bar  }", ".foo{foo-bar:1px solid bar}");
+    minify_test(".foo { foo-bar: a ,  b }", ".foo{foo-bar:a,b}");
+    minify_test(".foo { foo-bar: baz(1px, 2px) }", ".foo{foo-bar:baz(1px,2px)}");
+    minify_test(".foo { foo-bar: baz !important }", ".foo{foo-bar:baz!important}");
     test(
       r#"
-      @property --property-name {
-        syntax: '<length>|none';
-        inherits: false;
-        initial-value: none;
+      .foo {
+        -webkit-font-smoothing: antialiased;
+        foo-bar:   1px    solid   bar  ;
+        foo-baz: a ,  b;
+        foo-qux: baz(1px, 2px);
       }
     "#,
       indoc! {r#"
-      @property --property-name {
-        syntax: "<length> | none";
-        inherits: false;
-        initial-value: none;
+      .foo {
+        -webkit-font-smoothing: antialiased;
+        foo-bar: 1px solid bar;
+        foo-baz: a, b;
+        foo-qux: baz(1px, 2px);
       }
     "#},
     );
-
-    minify_test(
-      r#"
-      @property --property-name {
-        syntax: '<color>#';
-        inherits: false;
-        initial-value: yellow, blue;
-      }
-    "#,
-      "@property --property-name{syntax:\"<color>#\";inherits:false;initial-value:#ff0,#00f}",
-    );
-    minify_test(
-      r#"
-      @property --property-name {
-        syntax: '<color>+';
-        inherits: false;
-        initial-value: yellow blue;
-      }
-    "#,
-      "@property --property-name{syntax:\"<color>+\";inherits:false;initial-value:#ff0 #00f}",
-    );
   }
 
   #[test]
-  fn test_quoting_unquoting_urls() {
-    // Quotes remain double quotes when not minifying
+  fn test_unknown_at_rules() {
+    minify_test("@foo;", "@foo;");
+    minify_test("@foo bar;", "@foo bar;");
+    minify_test("@foo (bar: baz);", "@foo (bar: baz);");
     test(
-      r#".foo {
-      background-image: url("0123abcd");
+      r#"@foo test {
+      div {
+        color: red;
+      }
     }"#,
-      r#".foo {
-  background-image: url("0123abcd");
-}
-"#,
+      indoc! {r#"
+      @foo test {
+        div { color: red; }
+      }
+      "#},
     );
-
-    // Quotes removed when minifying
     minify_test(
-      r#".foo {
-      background-image: url("0123abcd");
+      r#"@foo test {
+      div {
+        color: red;
+      }
     }"#,
-      r#".foo{background-image:url(0123abcd)}"#,
+      "@foo test{div { color: red; }}",
+    );
+    minify_test(
+      r#"@foo test {
+        foo: bar;
+      }"#,
+      "@foo test{foo: bar;}",
     );
-
-    // Doubles quotes added if not present when not minifying
     test(
-      r#".foo {
-      background-image: url(0123abcd);
-    }"#,
-      r#".foo {
-  background-image: url("0123abcd");
-}
-"#,
+      r#"@foo {
+        foo: bar;
+      }"#,
+      indoc! {r#"
+      @foo {
+        foo: bar;
+      }
+      "#},
     );
-
-    // No quotes changed if not present when not minifying
     minify_test(
-      r#".foo {
-      background-image: url(0123abcd);
-    }"#,
-      r#".foo{background-image:url(0123abcd)}"#,
+      r#"@foo {
+        foo: bar;
+      }"#,
+      "@foo{foo: bar;}",
     );
   }
 
   #[test]
-  fn test_zindex() {
-    minify_test(".foo { z-index: 2 }", ".foo{z-index:2}");
-    minify_test(".foo { z-index: -2 }", ".foo{z-index:-2}");
-    minify_test(".foo { z-index: 999999 }", ".foo{z-index:999999}");
-    minify_test(".foo { z-index: 9999999 }", ".foo{z-index:9999999}");
-    minify_test(".foo { z-index: -9999999 }", ".foo{z-index:-9999999}");
+  fn test_resolution() {
+    prefix_test(
+      r#"
+      @media (resolution: 1dppx) {
+        body {
+          background: red;
+        }
+      }
+      "#,
+      indoc! { r#"
+      @media (resolution: 1dppx) {
+        body {
+          background: red;
+        }
+      }
+      "#},
+      Browsers {
+        chrome: Some(50 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      @media (resolution: 1dppx) {
+        body {
+          background: red;
+        }
+      }
+      "#,
+      indoc! { r#"
+      @media (resolution: 1x) {
+        body {
+          background: red;
+        }
+      }
+      "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
+    );
   }
 
   #[test]
-  fn test_input_source_map() {
-    let source = r#".imported {
-      content: "yay, file support!";
-    }
+  fn test_visitor() {
+    use crate::values::length::LengthValue;
+    use crate::values::url::Url;
+    use crate::visitor::Visitor;
 
-    .selector {
-      margin: 1em;
-      background-color: #f60;
-    }
+    struct CdnVisitor;
 
-    .selector .nested {
-      margin: 0.5em;
+    impl<'i> Visitor<'i> for CdnVisitor {
+      fn visit_url(&mut self, url: &mut Url<'i>) {
+        url.url = format!("https://cdn.example.com/{}", url.url).into();
+      }
     }
 
-    /*# sourceMappingURL=data:application/json;base64,ewoJInZlcnNpb24iOiAzLAoJInNvdXJjZVJvb3QiOiAicm9vdCIsCgkiZmlsZSI6ICJzdGRvdXQiLAoJInNvdXJjZXMiOiBbCgkJInN0ZGluIiwKCQkic2Fzcy9fdmFyaWFibGVzLnNjc3MiLAoJCSJzYXNzL19kZW1vLnNjc3MiCgldLAoJInNvdXJjZXNDb250ZW50IjogWwoJCSJAaW1wb3J0IFwiX3ZhcmlhYmxlc1wiO1xuQGltcG9ydCBcIl9kZW1vXCI7XG5cbi5zZWxlY3RvciB7XG4gIG1hcmdpbjogJHNpemU7XG4gIGJhY2tncm91bmQtY29sb3I6ICRicmFuZENvbG9yO1xuXG4gIC5uZXN0ZWQge1xuICAgIG1hcmdpbjogJHNpemUgLyAyO1xuICB9XG59IiwKCQkiJGJyYW5kQ29sb3I6ICNmNjA7XG4kc2l6ZTogMWVtOyIsCgkJIi5pbXBvcnRlZCB7XG4gIGNvbnRlbnQ6IFwieWF5LCBmaWxlIHN1cHBvcnQhXCI7XG59IgoJXSwKCSJtYXBwaW5ncyI6ICJBRUFBLFNBQVMsQ0FBQztFQUNSLE9BQU8sRUFBRSxvQkFBcUI7Q0FDL0I7O0FGQ0QsU0FBUyxDQUFDO0VBQ1IsTUFBTSxFQ0hELEdBQUc7RURJUixnQkFBZ0IsRUNMTCxJQUFJO0NEVWhCOztBQVBELFNBQVMsQ0FJUCxPQUFPLENBQUM7RUFDTixNQUFNLEVDUEgsS0FBRztDRFFQIiwKCSJuYW1lcyI6IFtdCn0= */"#;
+    let mut stylesheet = StyleSheet::parse(
+      r#"
+      .foo {
+        background: url(bg.png) red;
+        --icon: url(icon.svg);
+      }
 
-    let mut stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
-    stylesheet.minify(MinifyOptions::default()).unwrap();
-    let mut sm = parcel_sourcemap::SourceMap::new("/");
-    stylesheet
+      @media (min-width: 100px) {
+        .bar {
+          list-style-image: url(bullet.png);
+          mask-image: linear-gradient(red, blue), url(mask.png) !important;
+        }
+      }
+
+      @font-face {
+        font-family: Test;
+        src: url(test.woff2);
+      }
+    "#,
+      ParserOptions::default(),
+    )
+    .unwrap();
+    stylesheet.visit(&mut CdnVisitor);
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        minify: true,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(res.code, ".foo{background:red url(https://cdn.example.com/bg.png);--icon:url(https://cdn.example.com/icon.svg)}@media (min-width:100px){.bar{list-style-image:url(https://cdn.example.com/bullet.png);mask-image:linear-gradient(red,#00f),url(https://cdn.example.com/mask.png)!important}}@font-face{font-family:Test;src:url(https://cdn.example.com/test.woff2)}");
+
+    let mut attr = StyleAttribute::parse(
+      "background: url(bg.png); color: var(--foo, url(a.png))",
+      ParserOptions::default(),
+    )
+    .unwrap();
+    attr.visit(&mut CdnVisitor);
+    let res = attr
       .to_css(PrinterOptions {
-        source_map: Some(&mut sm),
         minify: true,
         ..PrinterOptions::default()
       })
       .unwrap();
-    let map = sm.to_json(None).unwrap();
     assert_eq!(
-      map,
-      r#"{"version":3,"sourceRoot":null,"mappings":"AAAA,uCCGA,2CAAA","sources":["sass/_demo.scss","stdin"],"sourcesContent":[".imported {\n  content: \"yay, file support!\";\n}","@import \"_variables\";\n@import \"_demo\";\n\n.selector {\n  margin: $size;\n  background-color: $brandColor;\n\n  .nested {\n    margin: $size / 2;\n  }\n}"],"names":[]}"#
+      res.code,
+      "background:url(https://cdn.example.com/bg.png);color:var(--foo,url(https://cdn.example.com/a.png))"
     );
-  }
 
-  #[test]
-  fn test_error_recovery() {
-    use std::sync::{Arc, RwLock};
-    let warnings = Some(Arc::new(RwLock::new(Vec::new())));
-    test_with_options(
+    let mut stylesheet = StyleSheet::parse(
       r#"
-      h1(>h1) {
-        color: red;
-      }
-
+      @import "a.css";
       .foo {
-        color: red;
+        mask: url(mask.png);
+        border-image: url(border.png);
+        cursor: url(cursor.cur), pointer;
+        filter: url(filter.svg);
       }
+    "#,
+      ParserOptions::default(),
+    )
+    .unwrap();
+    stylesheet.visit(&mut CdnVisitor);
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        minify: true,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(res.code, "@import \"https://cdn.example.com/a.css\";.foo{mask:url(https://cdn.example.com/mask.png);border-image:url(https://cdn.example.com/border.png);cursor:url(https://cdn.example.com/cursor.cur),pointer;filter:url(https://cdn.example.com/filter.svg)}");
 
-      .clearfix {
-        *zoom: 1;
-        background: red;
-      }
+    struct DoubleVisitor;
 
-      @media (hover) {
-        h1(>h1) {
-          color: red;
-        }
+    impl<'i> Visitor<'i> for DoubleVisitor {
+      fn visit_color(&mut self, color: &mut CssColor) {
+        *color = CssColor::parse_string("green").unwrap();
+      }
 
-        .bar {
-          color: red;
+      fn visit_length(&mut self, length: &mut LengthValue) {
+        if let LengthValue::Px(value) = length {
+          *value *= 2.0;
         }
       }
-    "#,
-      indoc! { r#"
+    }
+
+    let mut stylesheet = StyleSheet::parse(
+      r#"
       .foo {
-        color: red;
+        margin: 1px 2px;
+        border: 1px solid red;
+        box-shadow: 2px 2px blue;
+        transform: translate(3px, 4px);
+        gap: 5px;
       }
+    "#,
+      ParserOptions::default(),
+    )
+    .unwrap();
+    stylesheet.visit(&mut DoubleVisitor);
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        minify: true,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(
+      res.code,
+      ".foo{margin:2px 4px;border:2px solid green;box-shadow:4px 4px green;transform:translate(6px,8px);gap:10px}"
+    );
+  }
 
-      .clearfix {
-        background: red;
-      }
+  #[test]
+  #[cfg(feature = "serde")]
+  fn test_serde() {
+    use crate::values::angle::Angle;
+    use crate::values::length::{Length, LengthPercentage};
+
+    fn round_trip<'i, T>(source: &'i str)
+    where
+      T: Parse<'i> + ToCss + serde::Serialize + serde::de::DeserializeOwned,
+    {
+      let value = T::parse_string(source).unwrap();
+      let json = serde_json::to_string(&value).unwrap();
+      let deserialized: T = serde_json::from_str(&json).unwrap();
+      assert_eq!(
+        deserialized.to_css_string(PrinterOptions::default()).unwrap(),
+        value.to_css_string(PrinterOptions::default()).unwrap()
+      );
+    }
 
-      @media (hover) {
-        .bar {
-          color: red;
-        }
-      }
-    "#},
+    round_trip::<Angle>("45deg");
+    round_trip::<Angle>("0.1turn");
+    round_trip::<Length>("12.34px");
+    round_trip::<Length>("calc(1px + 2em)");
+    round_trip::<Length>("calc(2 * (1.5px + 0.3em))");
+    round_trip::<LengthPercentage>("calc(100% - 0.1px)");
+    round_trip::<LengthPercentage>("max(10%, min(20px, 3em))");
+    round_trip::<CssColor>("#ff000080");
+    round_trip::<CssColor>("lab(40.123% 56.6 39)");
+    round_trip::<CssColor>("currentColor");
+  }
+
+  #[test]
+  #[cfg(feature = "debug_tree")]
+  fn test_debug_tree() {
+    let source = ".foo { color: red; width: 10px !important }\n@media print {\n  .bar { color: blue }\n}\n@keyframes fade {\n  from { opacity: 0 }\n}\n";
+    let stylesheet = StyleSheet::parse(
+      source,
       ParserOptions {
         filename: "test.css".into(),
-        error_recovery: true,
-        warnings: warnings.clone(),
         ..ParserOptions::default()
       },
-    );
-    let w = warnings.unwrap();
-    let warnings = w.read().unwrap();
-    assert_eq!(
-      *warnings,
-      vec![
-        Error {
-          kind: ParserError::SelectorError(SelectorError::EmptySelector),
-          loc: Some(ErrorLocation {
-            filename: "test.css".into(),
-            line: 1,
-            column: 7
-          })
-        },
-        Error {
-          kind: ParserError::UnexpectedToken(Token::Delim('*')),
-          loc: Some(ErrorLocation {
-            filename: "test.css".into(),
-            line: 10,
-            column: 9
-          })
-        },
-        Error {
-          kind: ParserError::SelectorError(SelectorError::EmptySelector),
-          loc: Some(ErrorLocation {
-            filename: "test.css".into(),
-            line: 15,
-            column: 9
-          })
-        },
-      ]
     )
+    .unwrap();
+    assert_eq!(
+      stylesheet.debug_tree().to_string(),
+      indoc! {r#"
+        StyleSheet
+          Style .foo @ test.css:0:1
+            color: red
+            width: 10px !important
+          Media print @ test.css:1:1
+            Style .bar @ test.css:2:3
+              color: #00f
+          Keyframes fade @ test.css:4:1
+            Keyframe from
+              opacity: 0
+      "#}
+    );
   }
 
   #[test]
-  fn test_container_queries() {
-    // with name
-    minify_test(
-      r#"
-      @container my-layout (inline-size > 45em) {
-        .foo {
-          color: red;
+  fn test_indent() {
+    use crate::printer::Indent;
+
+    fn indent_test(source: &str, expected: &str, indent: Indent) {
+      printer_test(
+        source,
+        expected,
+        PrinterOptions {
+          indent,
+          ..PrinterOptions::default()
+        },
+      );
+    }
+
+    let source = r#"
+      @media screen {
+        @supports (display: grid) {
+          .foo {
+            display: grid;
+            grid-template-areas: "a b"
+                                 "c d";
+          }
         }
       }
-    "#,
-      "@container my-layout (inline-size>45em){.foo{color:red}}",
+    "#;
+
+    indent_test(
+      source,
+      "@media screen {\n  @supports (display: grid) {\n    .foo {\n      display: grid;\n      grid-template-areas: \"a b\"\n                           \"c d\";\n    }\n  }\n}\n",
+      Indent::Spaces(2),
     );
 
-    minify_test(
-      r#"
-      @container my-layout ( not (width > 500px) ) {
-        .foo {
-          color: red;
-        }
-      }
-    "#,
-      "@container my-layout (not (width>500px)){.foo{color:red}}",
+    indent_test(
+      source,
+      "@media screen {\n    @supports (display: grid) {\n        .foo {\n            display: grid;\n            grid-template-areas: \"a b\"\n                                 \"c d\";\n        }\n    }\n}\n",
+      Indent::Spaces(4),
     );
 
-    minify_test(
-      r#"
-      @container my-layout not (width > 500px) {
-        .foo {
-          color: red;
-        }
-      }
-    "#,
-      "@container my-layout not (width>500px){.foo{color:red}}",
+    indent_test(
+      source,
+      "@media screen {\n\t@supports (display: grid) {\n\t\t.foo {\n\t\t\tdisplay: grid;\n\t\t\tgrid-template-areas: \"a b\"\n\t\t\t                     \"c d\";\n\t\t}\n\t}\n}\n",
+      Indent::Tab,
     );
+  }
 
-    minify_test(
-      r#"
-      @container not (width > 500px) {
-        .foo {
+  #[test]
+  fn test_line_width() {
+    fn line_width_test(source: &str, expected: &str, minify: bool) {
+      printer_test(
+        source,
+        expected,
+        PrinterOptions {
+          minify,
+          line_width: Some(80),
+          ..PrinterOptions::default()
+        },
+      );
+    }
+
+    line_width_test(
+      ".some-long-class-name, .another-long-class-name, .yet-another-long-class-name, .one-more { color: red }",
+      indoc! {r#"
+        .some-long-class-name,
+        .another-long-class-name,
+        .yet-another-long-class-name,
+        .one-more {
           color: red;
         }
-      }
-    "#,
-      "@container not (width>500px){.foo{color:red}}",
+      "#},
+      false,
     );
-
-    minify_test(
-      r#"
-      @container my-layout ((width: 100px) and (not (height: 100px))) {
-        .foo {
-          color: red;
+    line_width_test(
+      "@media print { .some-long-class-name, .another-long-class-name, .yet-another-long-class-name, .one-more { color: red } }",
+      indoc! {r#"
+        @media print {
+          .some-long-class-name,
+          .another-long-class-name,
+          .yet-another-long-class-name,
+          .one-more {
+            color: red;
+          }
         }
-      }
-    "#,
-      "@container my-layout ((width:100px) and (not (height:100px))){.foo{color:red}}",
+      "#},
+      false,
     );
-
-    minify_test(
-      r#"
-      @container my-layout (width = max(10px, 10em)) {
-        .foo {
+    line_width_test(
+      ".short, .list { color: red }",
+      indoc! {r#"
+        .short, .list {
           color: red;
         }
-      }
-    "#,
-      "@container my-layout (width=max(10px,10em)){.foo{color:red}}",
+      "#},
+      false,
     );
+    line_width_test(
+      ".some-long-class-name, .another-long-class-name, .yet-another-long-class-name, .one-more { color: red }",
+      ".some-long-class-name,.another-long-class-name,.yet-another-long-class-name,.one-more{color:red}",
+      true,
+    );
+  }
 
-    // without name
-    minify_test(
-      r#"
-      @container (inline-size > 45em) {
-        .foo {
+  #[test]
+  fn test_minify_selectors() {
+    fn minify_selectors_test(source: &str, expected: &str, minify: bool, minify_selectors: Option<bool>) {
+      printer_test(
+        source,
+        expected,
+        PrinterOptions {
+          minify,
+          minify_selectors,
+          ..PrinterOptions::default()
+        },
+      );
+    }
+
+    let source = r#".foo > [data-x="bar"] + *.baz, a ~ b { color: #ff0000 }"#;
+    minify_selectors_test(source, ".foo>[data-x=bar]+.baz,a~b{color:red}", true, None);
+    minify_selectors_test(source, ".foo>[data-x=bar]+.baz,a~b{color:red}", true, Some(true));
+    minify_selectors_test(
+      source,
+      r#".foo > [data-x="bar"] + *.baz, a ~ b{color:red}"#,
+      true,
+      Some(false),
+    );
+    minify_selectors_test(
+      source,
+      indoc! {r#"
+        .foo > [data-x="bar"] + *.baz, a ~ b {
           color: red;
         }
-      }
-    "#,
-      "@container (inline-size>45em){.foo{color:red}}",
+      "#},
+      false,
+      None,
     );
-
-    minify_test(
-      r#"
-      @container (inline-size > 45em) and (inline-size < 100em) {
-        .foo {
+    minify_selectors_test(
+      source,
+      indoc! {r#"
+        .foo>[data-x=bar]+.baz,a~b {
           color: red;
         }
-      }
-    "#,
-      "@container (inline-size>45em) and (inline-size<100em){.foo{color:red}}",
+      "#},
+      false,
+      Some(true),
+    );
+    minify_selectors_test(
+      "*.foo, *[href] { color: red }",
+      "*.foo, *[href]{color:red}",
+      true,
+      Some(false),
+    );
+    minify_selectors_test(
+      ".a:is(.b > .c, [d='e']) { color: red }",
+      r#".a:is(.b > .c, [d="e"]){color:red}"#,
+      true,
+      Some(false),
     );
+  }
 
-    // calc()
+  #[test]
+  fn test_comments() {
+    fn comments_test(source: &str, expected: &str, preserve_comments: bool, minify: bool) {
+      printer_test_with_options(
+        source,
+        expected,
+        ParserOptions {
+          preserve_comments,
+          ..ParserOptions::default()
+        },
+        PrinterOptions {
+          minify,
+          ..PrinterOptions::default()
+        },
+      );
+    }
+
+    minify_test("/*! license */ .foo { color: red }", "/*! license */.foo{color:red}");
+    minify_test("/* comment */ .foo { color: red }", ".foo{color:red}");
     minify_test(
-      r#"
-      @container (width > calc(100vw - 50px)) {
-        .foo {
-          color: red;
-        }
-      }
-    "#,
-      "@container (width>calc(100vw - 50px)){.foo{color:red}}",
+      ".foo { /*! inner */ color: red } /*! trailing */",
+      ".foo{/*! inner */color:red}/*! trailing */",
     );
-
     minify_test(
-      r#"
-      @container (calc(100vh - 50px) <= height ) {
-        .foo {
-          color: red;
-        }
-      }
-    "#,
-      "@container (height>=calc(100vh - 50px)){.foo{color:red}}",
+      ".foo { color: red; /*! license */ width: 10px; /* comment */ }",
+      ".foo{/*! license */color:red;width:10px}",
+    );
+    minify_test(
+      "@media print { /*! license */ .foo { color: red } /* comment */ }",
+      "@media print{/*! license */.foo{color:red}}",
+    );
+    minify_test(
+      "/*! license */ @import 'foo.css'; .foo { color: red }",
+      "/*! license */@import \"foo.css\";.foo{color:red}",
     );
-
-    // merge adjacent
     minify_test(
-      r#"
-      @container my-layout (inline-size > 45em) {
+      ".foo { color: red } /*! license */ .foo { width: 10px }",
+      ".foo{color:red;width:10px}/*! license */",
+    );
+    minify_test(
+      ".foo { color: red } /* comment */ .bar { height: 10px } .foo { width: 10px }",
+      ".foo{color:red;width:10px}.bar{height:10px}",
+    );
+    test(
+      "/*! license */\n.foo { color: red }",
+      indoc! {r#"
+        /*! license */
         .foo {
           color: red;
         }
-      }
+      "#},
+    );
 
-      @container my-layout (inline-size > 45em) {
+    let source = "/* one */ .foo { color: red } /* two */ .bar /* three */ { color: blue }";
+    comments_test(
+      source,
+      indoc! {r#"
+        /* one */
         .foo {
-          background: yellow;
+          color: red;
         }
 
+        /* two */
         .bar {
-          color: white;
+          color: #00f;
         }
-      }
-    "#,
-      "@container my-layout (inline-size>45em){.foo{color:red;background:#ff0}.bar{color:#fff}}",
-    );
-
-    minify_test(
-      r#"
-    .foo {
-      container-name: foo bar;
-      container-type: size inline-size;
-    }
-    "#,
-      ".foo{container:foo bar/size}",
-    );
-    minify_test(
-      r#"
-    .foo {
-      container-name: foo bar;
-      container-type: normal;
-    }
-    "#,
-      ".foo{container:foo bar}",
-    );
-    minify_test(
-      ".foo{ container-type: inline-size }",
-      ".foo{container-type:inline-size}",
-    );
-    minify_test(".foo{ container-name: none; }", ".foo{container-name:none}");
-    minify_test(".foo{ container-name: foo; }", ".foo{container-name:foo}");
-    minify_test(".foo{ container: foo / normal; }", ".foo{container:foo}");
-    minify_test(
-      ".foo{ container: foo / inline-size; }",
-      ".foo{container:foo/inline-size}",
+      "#},
+      true,
+      false,
     );
-    minify_test(".foo { width: calc(1cqw + 2cqw) }", ".foo{width:3cqw}");
-    minify_test(".foo { width: calc(1cqh + 2cqh) }", ".foo{width:3cqh}");
-    minify_test(".foo { width: calc(1cqi + 2cqi) }", ".foo{width:3cqi}");
-    minify_test(".foo { width: calc(1cqb + 2cqb) }", ".foo{width:3cqb}");
-    minify_test(".foo { width: calc(1cqmin + 2cqmin) }", ".foo{width:3cqmin}");
-    minify_test(".foo { width: calc(1cqmax + 2cqmax) }", ".foo{width:3cqmax}");
+    comments_test(source, ".foo{color:red}.bar{color:#00f}", true, true);
+    comments_test(
+      source,
+      indoc! {r#"
+        .foo {
+          color: red;
+        }
 
-    // Unlike in @media, there is no need to convert the range syntax in @container,
-    // because browsers all support this syntax.
-    prefix_test(
-      r#"
-      @container (width > 100px) {
-        .foo { padding: 5px; }
-      }
-      "#,
-      indoc! { r#"
-        @container (width > 100px) {
-          .foo {
-            padding: 5px;
-          }
+        .bar {
+          color: #00f;
         }
       "#},
-      Browsers {
-        chrome: Some(105 << 16),
-        ..Browsers::default()
-      },
+      false,
+      false,
     );
-    prefix_test(
-      r#"
-      @container (min-width: 100px) {
-        .foo { padding: 5px; }
-      }
-      "#,
-      indoc! { r#"
-        @container (min-width: 100px) {
+    comments_test(
+      "@media print { /* one */ .foo { /* two */ color: red } }",
+      indoc! {r#"
+        @media print {
+          /* one */
           .foo {
-            padding: 5px;
+            /* two */
+            color: red;
           }
         }
       "#},
-      Browsers {
-        chrome: Some(105 << 16),
-        ..Browsers::default()
-      },
+      true,
+      false,
     );
+  }
 
-    // Disallow 'none', 'not', 'and', 'or' as a `<container-name>`
-    // https://github.com/w3c/csswg-drafts/issues/7203#issuecomment-1144257312
-    // https://chromium-review.googlesource.com/c/chromium/src/+/3698402
-    error_test(
-      "@container none (width < 100vw) {}",
-      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("none".into())),
-    );
+  #[test]
+  fn test_parse_rules() {
+    use std::ops::ControlFlow;
 
-    error_test(
-      "@container and (width < 100vw) {}",
-      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("and".into())),
-    );
+    let source = r#"
+      @import "foo.css";
+      .foo { color: red }
+      @media print {
+        .bar { color: blue }
+        .baz { color: green }
+      }
+      .qux { color: yellow }
+    "#;
 
-    error_test(
-      "@container or (width < 100vw) {}",
-      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("or".into())),
-    );
+    let mut count = 0;
+    let mut nested = 0;
+    StyleSheet::parse_rules(source, &ParserOptions::default(), |rule| {
+      count += 1;
+      if let CssRule::Media(media) = rule {
+        nested += media.rules.0.len();
+      }
+      ControlFlow::Continue(())
+    })
+    .unwrap();
+    assert_eq!(count, 4);
+    assert_eq!(nested, 2);
+
+    let mut count = 0;
+    StyleSheet::parse_rules(source, &ParserOptions::default(), |rule| {
+      count += 1;
+      if let CssRule::Style(_) = rule {
+        ControlFlow::Break(())
+      } else {
+        ControlFlow::Continue(())
+      }
+    })
+    .unwrap();
+    assert_eq!(count, 2);
 
-    // Disallow CSS wide keywords as a `<container-name>`
-    error_test(
-      "@container revert-layer (width < 100vw) {}",
-      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("revert-layer".into())),
+    let res = StyleSheet::parse_rules(
+      ".foo { color: red } @import 'foo.css';",
+      &ParserOptions::default(),
+      |_| ControlFlow::Continue(()),
     );
+    assert_eq!(res.unwrap_err().kind, ParserError::UnexpectedImportRule);
+  }
 
-    error_test(
-      "@container initial (width < 100vw) {}",
-      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("initial".into())),
+  #[test]
+  fn test_angle_comparison() {
+    use crate::values::angle::Angle;
+    use std::cmp::Ordering;
+
+    assert_eq!(Angle::Deg(360.0), Angle::Turn(1.0));
+    assert_eq!(Angle::Grad(200.0), Angle::Deg(180.0));
+    assert_eq!(Angle::Turn(0.5), Angle::Grad(200.0));
+    assert_ne!(Angle::Deg(90.0), Angle::Turn(0.5));
+    assert!(Angle::Deg(90.0) < Angle::Turn(0.5));
+    assert!(Angle::Rad(1.0) > Angle::Deg(57.0));
+    assert!(Angle::Rad(1.0) < Angle::Deg(58.0));
+    assert_eq!(Angle::Grad(100.0).partial_cmp(&Angle::Deg(90.0)), Some(Ordering::Equal));
+    assert_eq!(Angle::Deg(f32::NAN).partial_cmp(&Angle::Deg(0.0)), None);
+
+    let mut angles = vec![Angle::Turn(0.5), Angle::Deg(45.0), Angle::Rad(1.0), Angle::Grad(300.0)];
+    angles.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_eq!(
+      angles,
+      vec![Angle::Deg(45.0), Angle::Rad(1.0), Angle::Turn(0.5), Angle::Grad(300.0)]
     );
+  }
 
-    // <ident> contains spaces
-    // https://github.com/web-platform-tests/wpt/blob/39f0da08fbbe33d0582a35749b6dbf8bd067a52d/css/css-contain/container-queries/at-container-parsing.html#L160-L178
-    error_test(
-      "@container foo bar (width < 100vw) {}",
-      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("bar".into())),
-    );
+  #[test]
+  fn test_angle_normalize() {
+    use crate::values::angle::Angle;
+
+    fn normalize_test(angle: Angle, expected: Angle) {
+      let normalized = angle.normalize();
+      assert_eq!(std::mem::discriminant(&normalized), std::mem::discriminant(&expected));
+      assert_eq!(normalized, expected);
+    }
+
+    normalize_test(Angle::Deg(45.0), Angle::Deg(45.0));
+    normalize_test(Angle::Deg(450.0), Angle::Deg(90.0));
+    normalize_test(Angle::Deg(-90.0), Angle::Deg(270.0));
+    normalize_test(Angle::Deg(360.0), Angle::Deg(0.0));
+    normalize_test(Angle::Deg(-720.0), Angle::Deg(0.0));
+    normalize_test(Angle::Turn(1.5), Angle::Turn(0.5));
+    normalize_test(Angle::Turn(-0.25), Angle::Turn(0.75));
+    normalize_test(Angle::Turn(1.0), Angle::Turn(0.0));
+    normalize_test(Angle::Grad(500.0), Angle::Grad(100.0));
+    normalize_test(Angle::Grad(-100.0), Angle::Grad(300.0));
+    normalize_test(Angle::Grad(400.0), Angle::Grad(0.0));
+    normalize_test(Angle::Rad(1.0), Angle::Rad(1.0));
+    normalize_test(Angle::Rad(-std::f32::consts::PI), Angle::Rad(std::f32::consts::PI));
+    normalize_test(Angle::Rad(2.0 * std::f32::consts::PI), Angle::Rad(0.0));
   }
 
   #[test]
-  fn test_unknown_at_rules() {
-    minify_test("@foo;", "@foo;");
-    minify_test("@foo bar;", "@foo bar;");
-    minify_test("@foo (bar: baz);", "@foo (bar: baz);");
-    test(
-      r#"@foo test {
-      div {
-        color: red;
-      }
-    }"#,
-      indoc! {r#"
-      @foo test {
-        div { color: red; }
-      }
-      "#},
+  fn test_precision() {
+    fn precision_test(source: &str, expected: &str, precision: Option<u8>) {
+      printer_test(
+        source,
+        expected,
+        PrinterOptions {
+          minify: true,
+          precision,
+          ..PrinterOptions::default()
+        },
+      );
+    }
+
+    let source =
+      ".foo { filter: hue-rotate(1.23456789rad); opacity: 0.123456789; width: 12.3456789px; height: 33.333333% }";
+    precision_test(
+      source,
+      ".foo{filter:hue-rotate(1.23457rad);opacity:.123457;width:12.3457px;height:33.3333%}",
+      None,
     );
-    minify_test(
-      r#"@foo test {
-      div {
-        color: red;
-      }
-    }"#,
-      "@foo test{div { color: red; }}",
+    precision_test(
+      source,
+      ".foo{filter:hue-rotate(71deg);opacity:.12;width:12px;height:33%}",
+      Some(2),
     );
+    precision_test(
+      source,
+      ".foo{filter:hue-rotate(1.234568rad);opacity:.1234568;width:12.34568px;height:33.33333%}",
+      Some(8),
+    );
+    precision_test(
+      ".foo { width: 100px; opacity: 0.5; margin-left: -0.25px }",
+      ".foo{width:100px;opacity:.5;margin-left:-.25px}",
+      Some(2),
+    );
+  }
+
+  #[test]
+  fn test_number_serialization() {
+    minify_test(".foo { opacity: 0.5 }", ".foo{opacity:.5}");
+    minify_test(".foo { opacity: .5 }", ".foo{opacity:.5}");
+    minify_test(".foo { line-height: 5.0 }", ".foo{line-height:5}");
+    minify_test(".foo { width: 5.0px }", ".foo{width:5px}");
+    minify_test(".foo { width: 0.50% }", ".foo{width:.5%}");
+    minify_test(".foo { margin-left: -0.25px }", ".foo{margin-left:-.25px}");
     minify_test(
-      r#"@foo test {
-        foo: bar;
-      }"#,
-      "@foo test{foo: bar;}",
+      ".foo { transform: rotate(-0.25deg) }",
+      ".foo{transform:rotate(-.25deg)}",
     );
+
     test(
-      r#"@foo {
-        foo: bar;
-      }"#,
+      ".foo { opacity: .5; line-height: 5.0; width: .5%; margin-left: -.25px; transform: rotate(-.25deg) }",
       indoc! {r#"
-      @foo {
-        foo: bar;
+      .foo {
+        opacity: 0.5;
+        line-height: 5;
+        width: 0.5%;
+        margin-left: -0.25px;
+        transform: rotate(-0.25deg);
       }
-      "#},
+    "#},
     );
-    minify_test(
-      r#"@foo {
-        foo: bar;
-      }"#,
-      "@foo{foo: bar;}",
+    test(
+      ".foo { width: 5.0px; transition-duration: 0.50s }",
+      indoc! {r#"
+      .foo {
+        width: 5px;
+        transition-duration: 0.5s;
+      }
+    "#},
     );
   }
 
   #[test]
-  fn test_resolution() {
-    prefix_test(
-      r#"
-      @media (resolution: 1dppx) {
-        body {
-          background: red;
-        }
-      }
-      "#,
-      indoc! { r#"
-      @media (resolution: 1dppx) {
-        body {
-          background: red;
-        }
-      }
-      "#},
-      Browsers {
-        chrome: Some(50 << 16),
-        ..Browsers::default()
-      },
-    );
+  fn test_url_rewriter() {
+    fn url_rewriter_test(source: &str, expected: &str, minify: bool) {
+      let rewrite = |url: &str| match url.strip_prefix("./") {
+        Some(path) => format!("/assets/{}", path),
+        None => url.replace("data:", "data:text/plain,"),
+      };
+      printer_test(
+        source,
+        expected,
+        PrinterOptions {
+          minify,
+          url_rewriter: Some(&rewrite),
+          ..PrinterOptions::default()
+        },
+      );
+    }
 
-    prefix_test(
-      r#"
-      @media (resolution: 1dppx) {
-        body {
-          background: red;
-        }
-      }
-      "#,
-      indoc! { r#"
-      @media (resolution: 1x) {
-        body {
-          background: red;
+    url_rewriter_test(
+      ".foo { background: url(./img.png) }",
+      ".foo{background:url(/assets/img.png)}",
+      true,
+    );
+    url_rewriter_test(
+      ".foo { background: url(./img.png) }",
+      indoc! {r#"
+        .foo {
+          background: url("/assets/img.png");
         }
-      }
       "#},
-      Browsers {
-        chrome: Some(95 << 16),
-        ..Browsers::default()
-      },
+      false,
+    );
+    url_rewriter_test(
+      "@import url(./foo.css) print; .foo { --bg: url('./b.png'); background-image: url(data:abc) }",
+      "@import \"/assets/foo.css\" print;.foo{--bg:url(/assets/b.png);background-image:url(data:text/plain,abc)}",
+      true,
+    );
+    url_rewriter_test(
+      ".foo { cursor: url(./a.cur) 4 4, url(./b.png), pointer }",
+      ".foo{cursor:url(/assets/a.cur) 4 4,url(/assets/b.png),pointer}",
+      true,
     );
   }
 }
@@ -6,7 +6,7 @@ use crate::macros::enum_property;
 use crate::printer::Printer;
 use crate::rules::custom_media::CustomMediaRule;
 use crate::rules::Location;
-use crate::traits::{Parse, ToCss};
+use crate::traits::{Map, Parse, ToCss};
 use crate::values::number::CSSNumber;
 use crate::values::string::CowArcStr;
 use crate::values::{length::Length, ratio::Ratio, resolution::Resolution};
@@ -714,8 +714,8 @@ where
   dest.delim(':', false)?;
 
   let adjusted = match operator {
-    MediaFeatureComparison::GreaterThan => Some(value.clone() + 0.001),
-    MediaFeatureComparison::LessThan => Some(value.clone() + -0.001),
+    MediaFeatureComparison::GreaterThan => Some(value.clone() + min_step(name, value)),
+    MediaFeatureComparison::LessThan => Some(value.clone() + -min_step(name, value)),
     _ => None,
   };
 
@@ -729,6 +729,22 @@ where
   Ok(())
 }
 
+/// Returns the smallest step between two distinct values of a media feature, which is
+/// used to convert a strict comparison (e.g. `<`) into an inclusive `min-` or `max-` feature.
+fn min_step(name: &str, value: &MediaFeatureValue) -> CSSNumber {
+  match value {
+    // These features only accept integers.
+    MediaFeatureValue::Number(..)
+      if name.eq_ignore_ascii_case("color")
+        || name.eq_ignore_ascii_case("color-index")
+        || name.eq_ignore_ascii_case("monochrome") =>
+    {
+      1.0
+    }
+    _ => 0.001,
+  }
+}
+
 /// [media feature value](https://drafts.csswg.org/mediaqueries/#typedef-mf-value) within a media query.
 ///
 /// See [MediaFeature](MediaFeature).
@@ -802,6 +818,10 @@ impl<'i> std::ops::Add<f32> for MediaFeatureValue<'i> {
 
   fn add(self, other: f32) -> Self {
     match self {
+      // Keep the original unit where possible, e.g. 20em + 0.001 = 20.001em.
+      MediaFeatureValue::Length(Length::Value(len)) => {
+        MediaFeatureValue::Length(Length::Value(len.map(|value| value + other)))
+      }
       MediaFeatureValue::Length(len) => MediaFeatureValue::Length(len + Length::px(other)),
       MediaFeatureValue::Number(num) => MediaFeatureValue::Number(num + other),
       MediaFeatureValue::Resolution(res) => MediaFeatureValue::Resolution(res + other),
@@ -52,6 +52,10 @@ pub struct ParserOptions<'o, 'i> {
   pub error_recovery: bool,
   /// A list that will be appended to when a warning occurs.
   pub warnings: Option<Arc<RwLock<Vec<Error<ParserError<'i>>>>>>,
+  /// Whether to preserve all comments between rules and declarations. Otherwise, only license
+  /// comments starting with `/*!` are preserved. Only license comments are printed when minifying.
+  /// Comments within selectors, preludes, and property values are always removed.
+  pub preserve_comments: bool,
 }
 
 impl<'o, 'i> ParserOptions<'o, 'i> {
@@ -201,7 +205,7 @@ impl<'a, 'o, 'i> AtRuleParser<'i> for TopLevelRuleParser<'a, 'o, 'i> {
         // @charset is removed by rust-cssparser if it’s the first rule in the stylesheet.
         // Anything left is technically invalid, however, users often concatenate CSS files
         // together, so we are more lenient and simply ignore @charset rules in the middle of a file.
-        input.expect_string()?;
+        parse_charset(input)?;
         return Ok(AtRulePrelude::Charset)
       },
       "custom-media" if self.options.custom_media => {
@@ -339,7 +343,19 @@ impl<'a, 'o, 'b, 'i> NestedRuleParser<'a, 'o, 'i> {
 
     let mut iter = RuleListParser::new_for_nested_rule(input, nested_parser);
     let mut rules = Vec::new();
-    while let Some(result) = iter.next() {
+    loop {
+      let start = iter.input.position();
+      let result = iter.next();
+
+      // Comments are attached to the following rule.
+      let comments = leading_comments(iter.input.slice_from(start), self.options.preserve_comments);
+      rules.extend(comments.into_iter().map(CssRule::Comment));
+
+      let result = match result {
+        Some(result) => result,
+        None => break,
+      };
+
       match result {
         Ok(CssRule::Ignored) => {}
         Ok(rule) => rules.push(rule),
@@ -551,11 +567,10 @@ impl<'a, 'o, 'b, 'i> AtRuleParser<'i> for NestedRuleParser<'a, 'o, 'i> {
           loc,
         }))
       }
-      AtRulePrelude::Page(selectors) => Ok(CssRule::Page(PageRule {
-        selectors,
-        declarations: DeclarationBlock::parse(input, self.options)?,
-        loc,
-      })),
+      AtRulePrelude::Page(selectors) => {
+        let rule = PageRule::parse(selectors, input, loc, self.options)?;
+        Ok(CssRule::Page(rule))
+      }
       AtRulePrelude::MozDocument => Ok(CssRule::MozDocument(MozDocumentRule {
         rules: self.parse_nested_rules(input)?,
         loc,
@@ -673,28 +688,49 @@ fn parse_declarations_and_nested_rules<'a, 'o, 'i, 't>(
     rules: &mut rules,
   };
 
+  let mut comments = Vec::new();
   let mut declaration_parser = DeclarationListParser::new(input, parser);
   let mut last = declaration_parser.input.state();
-  while let Some(decl) = declaration_parser.next() {
-    match decl {
-      Ok(_) => {}
-      _ => {
-        declaration_parser.input.reset(&last);
-        break;
-      }
+  loop {
+    let decl = declaration_parser.next();
+    if let Some(Err(_)) = decl {
+      declaration_parser.input.reset(&last);
+      break;
+    }
+
+    // Comments before a nested rule are parsed again below, so they are only collected here
+    // once the declaration after them was parsed successfully.
+    let source = declaration_parser.input.slice_from(last.position());
+    comments.extend(leading_comments(source, options.preserve_comments));
+    if decl.is_none() {
+      break;
     }
 
     last = declaration_parser.input.state();
   }
 
   let mut iter = RuleListParser::new_for_nested_rule(declaration_parser.input, declaration_parser.parser);
-  while let Some(result) = iter.next() {
-    if let Err((err, _)) = result {
-      if options.error_recovery {
-        options.warn(err);
-        continue;
+  loop {
+    let start = iter.input.position();
+    let index = iter.parser.rules.0.len();
+    let result = iter.next();
+
+    // Nested rules are added by the parser, so their comments are inserted before them.
+    let source = iter.input.slice_from(start);
+    for (i, comment) in leading_comments(source, options.preserve_comments).into_iter().enumerate() {
+      iter.parser.rules.0.insert(index + i, CssRule::Comment(comment));
+    }
+
+    match result {
+      Some(Err((err, _))) => {
+        if options.error_recovery {
+          options.warn(err);
+          continue;
+        }
+        return Err(err);
       }
-      return Err(err);
+      Some(Ok(_)) => {}
+      None => break,
     }
   }
 
@@ -702,11 +738,38 @@ fn parse_declarations_and_nested_rules<'a, 'o, 'i, 't>(
     DeclarationBlock {
       declarations,
       important_declarations,
+      comments,
     },
     rules,
   ))
 }
 
+/// Returns the comments at the start of the given source, before the first token that is not
+/// whitespace or a comment. Only license comments starting with `/*!` are included unless `all` is true.
+pub(crate) fn leading_comments<'i>(code: &'i str, all: bool) -> Vec<CowArcStr<'i>> {
+  let mut comments = Vec::new();
+  let trimmed = code.trim_start_matches(|c: char| c.is_ascii_whitespace() || c == ';');
+  if !trimmed.starts_with("/*") && !trimmed.starts_with("<!--") && !trimmed.starts_with("-->") {
+    return comments;
+  }
+
+  let mut input = ParserInput::new(code);
+  let mut parser = Parser::new(&mut input);
+  while let Ok(token) = parser.next_including_whitespace_and_comments() {
+    match token {
+      Token::Comment(comment) => {
+        if all || comment.starts_with('!') {
+          comments.push((*comment).into());
+        }
+      }
+      Token::WhiteSpace(..) | Token::Semicolon | Token::CDO | Token::CDC => {}
+      _ => break,
+    }
+  }
+
+  comments
+}
+
 pub struct StyleRuleParser<'a, 'o, 'i> {
   default_namespace: &'a Option<CowArcStr<'i>>,
   namespace_prefixes: &'a HashMap<CowArcStr<'i>, CowArcStr<'i>>,
@@ -920,6 +983,18 @@ impl<'a, 'o, 'b, 'i> QualifiedRuleParser<'i> for StyleRuleParser<'a, 'o, 'i> {
   }
 }
 
+/// Parses the prelude of an `@charset` rule. Style sheets are parsed from a `&str`, so
+/// UTF-8 is the only encoding that can be correct, and any other is an error.
+pub(crate) fn parse_charset<'i, 't>(input: &mut Parser<'i, 't>) -> Result<(), ParseError<'i, ParserError<'i>>> {
+  let location = input.current_source_location();
+  let charset = input.expect_string_cloned()?;
+  if charset.eq_ignore_ascii_case("utf-8") || charset.eq_ignore_ascii_case("utf8") {
+    Ok(())
+  } else {
+    Err(location.new_custom_error(ParserError::UnsupportedCharset(charset.into())))
+  }
+}
+
 #[inline]
 pub fn starts_with_ignore_ascii_case(string: &str, prefix: &str) -> bool {
   string.len() >= prefix.len() && string.as_bytes()[0..prefix.len()].eq_ignore_ascii_case(prefix.as_bytes())
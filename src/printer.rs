@@ -8,12 +8,17 @@ use crate::targets::Browsers;
 use crate::vendor_prefix::VendorPrefix;
 use cssparser::{serialize_identifier, serialize_name};
 use parcel_sourcemap::{OriginalLocation, SourceMap};
+use std::borrow::Cow;
 
 /// Options that control how CSS is serialized to a string.
 #[derive(Default)]
 pub struct PrinterOptions<'a> {
   /// Whether to minify the CSS, i.e. remove white space.
   pub minify: bool,
+  /// Whether to minify selectors, e.g. removing white space around combinators,
+  /// unquoting attribute values, and eliding universal selectors (`*.foo` -> `.foo`).
+  /// Defaults to the value of `minify` if not set.
+  pub minify_selectors: Option<bool>,
   /// An optional reference to a source map to write mappings into.
   pub source_map: Option<&'a mut SourceMap>,
   /// Browser targets to output the CSS for.
@@ -29,12 +34,41 @@ pub struct PrinterOptions<'a> {
   /// A mapping of pseudo classes to replace with class names that can be applied
   /// from JavaScript. Useful for polyfills, for example.
   pub pseudo_classes: Option<PseudoClasses<'a>>,
+  /// The indentation to use for each nesting level when not minifying.
+  pub indent: Indent,
+  /// A maximum line width. When not minifying, selector lists that would exceed this
+  /// width are written with each selector on its own line. Ignored when minifying.
+  pub line_width: Option<u32>,
+  /// The number of significant digits to round numbers, dimensions and percentages to.
+  /// Defaults to 6 if not set. At most 7 digits are used, since values are stored as `f32`.
+  pub precision: Option<u8>,
+  /// A function applied to every `url()` and `@import` specifier when printing, e.g. to rebase or
+  /// hash urls. The returned string is written as is, except for quoting. Ignored for specifiers
+  /// that are replaced with placeholders when `analyze_dependencies` is enabled.
+  pub url_rewriter: Option<&'a dyn Fn(&str) -> String>,
+}
+
+/// The indentation used for each nesting level in output that is not minified.
+///
+/// See [PrinterOptions](PrinterOptions).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Indent {
+  /// Indent with the given number of spaces.
+  Spaces(u8),
+  /// Indent with a single tab character.
+  Tab,
+}
+
+impl Default for Indent {
+  fn default() -> Indent {
+    Indent::Spaces(2)
+  }
 }
 
 /// A mapping of user action pseudo classes to replace with class names.
 ///
 /// See [PrinterOptions](PrinterOptions).
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone)]
 pub struct PseudoClasses<'a> {
   /// The class name to replace `:hover` with.
   pub hover: Option<&'a str>,
@@ -63,10 +97,13 @@ pub struct Printer<'a, 'b, 'c, W> {
   pub(crate) source_map: Option<&'a mut SourceMap>,
   pub(crate) source_maps: Vec<Option<SourceMap>>,
   pub(crate) loc: Location,
+  indent_style: Indent,
   indent: u8,
+  align: u8,
   line: u32,
   col: u32,
   pub(crate) minify: bool,
+  pub(crate) minify_selectors: bool,
   pub(crate) targets: Option<Browsers>,
   /// Vendor prefix override. When non-empty, it overrides
   /// the vendor prefix of whatever is being printed.
@@ -76,6 +113,9 @@ pub struct Printer<'a, 'b, 'c, W> {
   pub(crate) dependencies: Option<Vec<Dependency>>,
   pub(crate) remove_imports: bool,
   pub(crate) pseudo_classes: Option<PseudoClasses<'a>>,
+  pub(crate) line_width: Option<u32>,
+  pub(crate) precision: Option<u8>,
+  url_rewriter: Option<&'a dyn Fn(&str) -> String>,
 }
 
 impl<'a, 'b, 'c, W: std::fmt::Write + Sized> Printer<'a, 'b, 'c, W> {
@@ -91,10 +131,13 @@ impl<'a, 'b, 'c, W: std::fmt::Write + Sized> Printer<'a, 'b, 'c, W> {
         line: 0,
         column: 1,
       },
+      indent_style: options.indent,
       indent: 0,
+      align: 0,
       line: 0,
       col: 0,
       minify: options.minify,
+      minify_selectors: options.minify_selectors.unwrap_or(options.minify),
       targets: options.targets,
       vendor_prefix: VendorPrefix::empty(),
       in_calc: false,
@@ -106,6 +149,17 @@ impl<'a, 'b, 'c, W: std::fmt::Write + Sized> Printer<'a, 'b, 'c, W> {
       },
       remove_imports: matches!(&options.analyze_dependencies, Some(d) if d.remove_imports),
       pseudo_classes: options.pseudo_classes,
+      line_width: options.line_width,
+      precision: options.precision,
+      url_rewriter: options.url_rewriter,
+    }
+  }
+
+  /// Returns the url to print for the given specifier, applying the `url_rewriter` option if set.
+  pub(crate) fn rewrite_url<'u>(&self, url: &'u str) -> Cow<'u, str> {
+    match self.url_rewriter {
+      Some(rewrite) => Cow::Owned(rewrite(url)),
+      None => Cow::Borrowed(url),
     }
   }
 
@@ -122,6 +176,11 @@ impl<'a, 'b, 'c, W: std::fmt::Write + Sized> Printer<'a, 'b, 'c, W> {
     }
   }
 
+  /// Returns the current column position in the output, starting from 0.
+  pub fn col(&self) -> u32 {
+    self.col
+  }
+
   /// Writes a raw string to the underlying destination.
   ///
   /// NOTE: Is is assumed that the string does not contain any newline characters.
@@ -166,6 +225,18 @@ impl<'a, 'b, 'c, W: std::fmt::Write + Sized> Printer<'a, 'b, 'c, W> {
     self.whitespace()
   }
 
+  /// Calls the given function with the `minify` option replaced by the `minify_selectors` option.
+  pub(crate) fn with_selector_minification<F>(&mut self, f: F) -> Result<(), PrinterError>
+  where
+    F: FnOnce(&mut Self) -> Result<(), PrinterError>,
+  {
+    let minify = self.minify;
+    self.minify = self.minify_selectors;
+    let res = f(self);
+    self.minify = minify;
+    res
+  }
+
   /// Writes a newline character followed by indentation.
   /// If the `minify` option is enabled, then nothing is printed.
   pub fn newline(&mut self) -> Result<(), PrinterError> {
@@ -174,36 +245,51 @@ impl<'a, 'b, 'c, W: std::fmt::Write + Sized> Printer<'a, 'b, 'c, W> {
     }
 
     self.write_char('\n')?;
-    if self.indent > 0 {
-      self.write_str(&" ".repeat(self.indent as usize))?;
+    for _ in 0..self.indent {
+      match self.indent_style {
+        Indent::Spaces(n) => self.write_spaces(n as usize)?,
+        Indent::Tab => self.write_char('\t')?,
+      }
     }
 
+    self.write_spaces(self.align as usize)
+  }
+
+  /// Writes the given number of space characters.
+  fn write_spaces(&mut self, mut n: usize) -> Result<(), PrinterError> {
+    const SPACES: &str = "                                ";
+    while n > 0 {
+      let len = n.min(SPACES.len());
+      self.write_str(&SPACES[..len])?;
+      n -= len;
+    }
     Ok(())
   }
 
   /// Increases the current indent level.
   pub fn indent(&mut self) {
-    self.indent += 2;
+    self.indent += 1;
   }
 
   /// Decreases the current indent level.
   pub fn dedent(&mut self) {
-    self.indent -= 2;
+    self.indent -= 1;
   }
 
-  /// Increases the current indent level by the given number of characters.
+  /// Increases the current indent by the given number of space characters,
+  /// e.g. to align values across lines. This is independent of the indent style.
   pub fn indent_by(&mut self, amt: u8) {
-    self.indent += amt;
+    self.align += amt;
   }
 
-  /// Decreases the current indent level by the given number of characters.
+  /// Decreases the current indent by the given number of space characters.
   pub fn dedent_by(&mut self, amt: u8) {
-    self.indent -= amt;
+    self.align -= amt;
   }
 
   /// Returns whether the indent level is greater than one.
   pub fn is_nested(&self) -> bool {
-    self.indent > 2
+    self.indent > 1
   }
 
   /// Adds a mapping to the source map, if any.
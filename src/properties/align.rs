@@ -1027,6 +1027,25 @@ impl<'i> PropertyHandler<'i> for AlignHandler {
         self.column_gap = Some(val.column.clone());
         self.has_any = true;
       }
+      // The legacy grid-* aliases are only replaced when compiling for targets.
+      GridRowGap(val) if self.targets.is_some() => {
+        self.row_gap = Some(val.clone());
+        self.has_any = true;
+      }
+      GridColumnGap(val) if self.targets.is_some() => {
+        self.column_gap = Some(val.clone());
+        self.has_any = true;
+      }
+      GridGap(val) if self.targets.is_some() => {
+        self.row_gap = Some(val.row.clone());
+        self.column_gap = Some(val.column.clone());
+        self.has_any = true;
+      }
+      // Otherwise, flush the buffered gaps so they stay before the alias.
+      GridRowGap(..) | GridColumnGap(..) | GridGap(..) => {
+        self.flush(dest);
+        dest.push(property.clone())
+      }
       Unparsed(val) if is_align_property(&val.property_id) => {
         self.flush(dest);
         dest.push(property.clone()) // TODO: prefix?
@@ -1229,17 +1248,33 @@ impl AlignHandler {
     standard_property!(AlignItems, align_items);
     unprefixed_property!(JustifyItems, justify_items);
 
+    // Older browsers only support the grid-* aliases of the gap properties.
+    let legacy_gap = match self.targets {
+      Some(targets) => !compat::Feature::Gap.is_compatible(targets),
+      None => false,
+    };
+
     if row_gap.is_some() && column_gap.is_some() {
-      dest.push(Property::Gap(Gap {
+      let gap = Gap {
         row: row_gap.unwrap(),
         column: column_gap.unwrap(),
-      }))
+      };
+      if legacy_gap {
+        dest.push(Property::GridGap(gap.clone()))
+      }
+      dest.push(Property::Gap(gap))
     } else {
       if let Some(gap) = row_gap {
+        if legacy_gap {
+          dest.push(Property::GridRowGap(gap.clone()))
+        }
         dest.push(Property::RowGap(gap))
       }
 
       if let Some(gap) = column_gap {
+        if legacy_gap {
+          dest.push(Property::GridColumnGap(gap.clone()))
+        }
         dest.push(Property::ColumnGap(gap))
       }
     }
@@ -1266,7 +1301,10 @@ fn is_align_property(property_id: &PropertyId) -> bool {
     | PropertyId::PlaceItems
     | PropertyId::RowGap
     | PropertyId::ColumnGap
-    | PropertyId::Gap => true,
+    | PropertyId::Gap
+    | PropertyId::GridRowGap
+    | PropertyId::GridColumnGap
+    | PropertyId::GridGap => true,
     _ => false,
   }
 }
@@ -472,7 +472,9 @@ impl<'i> AnimationHandler<'i> {
       &mut delays,
       &mut fill_modes,
     ) {
-      // Only use shorthand syntax if the number of animations matches on all properties.
+      // The number of animations is determined by animation-name. Shorter lists for the other
+      // properties are repeated to match, but longer lists would lose values in the shorthand,
+      // so we only use shorthand syntax if no list is longer than the list of names.
       let len = names.len();
       let intersection = *names_vp
         & *durations_vp
@@ -483,23 +485,23 @@ impl<'i> AnimationHandler<'i> {
         & *delays_vp
         & *fill_modes_vp;
       if !intersection.is_empty()
-        && durations.len() == len
-        && timing_functions.len() == len
-        && iteration_counts.len() == len
-        && directions.len() == len
-        && play_states.len() == len
-        && delays.len() == len
-        && fill_modes.len() == len
+        && durations.len() <= len
+        && timing_functions.len() <= len
+        && iteration_counts.len() <= len
+        && directions.len() <= len
+        && play_states.len() <= len
+        && delays.len() <= len
+        && fill_modes.len() <= len
       {
         let animations = izip!(
           names.drain(..),
-          durations.drain(..),
-          timing_functions.drain(..),
-          iteration_counts.drain(..),
-          directions.drain(..),
-          play_states.drain(..),
-          delays.drain(..),
-          fill_modes.drain(..)
+          repeat_to_len(durations, len),
+          repeat_to_len(timing_functions, len),
+          repeat_to_len(iteration_counts, len),
+          repeat_to_len(directions, len),
+          repeat_to_len(play_states, len),
+          repeat_to_len(delays, len),
+          repeat_to_len(fill_modes, len)
         )
         .map(
           |(name, duration, timing_function, iteration_count, direction, play_state, delay, fill_mode)| {
@@ -561,6 +563,13 @@ impl<'i> AnimationHandler<'i> {
   }
 }
 
+/// Repeats the values in a list until it has the given length, as done when matching up
+/// the values of animation longhands with the list of animation names.
+#[inline]
+fn repeat_to_len<'a, T: Clone>(list: &'a SmallVec<[T; 1]>, len: usize) -> impl Iterator<Item = T> + 'a {
+  list.iter().cycle().take(len).cloned()
+}
+
 #[inline]
 fn is_animation_property(property_id: &PropertyId) -> bool {
   match property_id {
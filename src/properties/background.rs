@@ -332,7 +332,8 @@ impl<'i> Parse<'i> for Background<'i> {
     let mut clip: Option<BackgroundClip> = None;
 
     loop {
-      // TODO: only allowed on the last background.
+      // Only allowed on the last background. Layers are parsed independently,
+      // so this is checked by the BackgroundHandler.
       if color.is_none() {
         if let Ok(value) = input.try_parse(CssColor::parse) {
           color = Some(value);
@@ -820,6 +821,12 @@ impl<'i> PropertyHandler<'i> for BackgroundHandler<'i> {
           dest.push(property.clone())
         }
       }
+      Property::Background(val) if val.iter().rev().skip(1).any(|b| b.color != CssColor::default()) => {
+        // A background color in any layer but the last is invalid,
+        // so preserve the declaration as is rather than merging it.
+        self.flush(dest);
+        dest.push(property.clone())
+      }
       Property::Background(val) => {
         let images: SmallVec<[Image; 1]> = val.iter().map(|b| b.image.clone()).collect();
         background_image!(images);
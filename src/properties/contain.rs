@@ -18,6 +18,112 @@ use crate::{
   traits::{Parse, PropertyHandler, Shorthand, ToCss},
 };
 
+bitflags! {
+  /// A value for the [contain](https://drafts.csswg.org/css-contain-2/#contain-property) property.
+  ///
+  /// An empty set of flags represents the `none` keyword. The `strict` and `content` keywords
+  /// are represented by their equivalent combinations of flags.
+  #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+  pub struct Contain: u8 {
+    /// Size containment on both axes.
+    const Size = 0b00000001;
+    /// Size containment on the inline axis.
+    const InlineSize = 0b00000010;
+    /// Layout containment.
+    const Layout = 0b00000100;
+    /// Style containment.
+    const Style = 0b00001000;
+    /// Paint containment.
+    const Paint = 0b00010000;
+    /// The `strict` keyword, equivalent to `size layout paint style`.
+    const Strict = Self::Size.bits | Self::Layout.bits | Self::Paint.bits | Self::Style.bits;
+    /// The `content` keyword, equivalent to `layout paint style`.
+    const Content = Self::Layout.bits | Self::Paint.bits | Self::Style.bits;
+  }
+}
+
+impl Default for Contain {
+  fn default() -> Self {
+    Contain::empty()
+  }
+}
+
+impl<'i> Parse<'i> for Contain {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut flags = Contain::empty();
+    while let Ok(ident) = input.try_parse(|input| input.expect_ident_cloned()) {
+      let location = input.current_source_location();
+      let flag = match_ignore_ascii_case! { &ident,
+        // mutually exclusive
+        "none" if flags.is_empty() => return Ok(Contain::empty()),
+        "strict" if flags.is_empty() => return Ok(Contain::Strict),
+        "content" if flags.is_empty() => return Ok(Contain::Content),
+        "size" => Contain::Size,
+        "inline-size" => Contain::InlineSize,
+        "layout" => Contain::Layout,
+        "style" => Contain::Style,
+        "paint" => Contain::Paint,
+        _ => return Err(location.new_unexpected_token_error(
+          cssparser::Token::Ident(ident.clone())
+        ))
+      };
+      if flags.intersects(flag)
+        || (flag.intersects(Contain::Size | Contain::InlineSize)
+          && flags.intersects(Contain::Size | Contain::InlineSize))
+      {
+        return Err(location.new_unexpected_token_error(cssparser::Token::Ident(ident.clone())));
+      }
+      flags |= flag;
+    }
+
+    if flags.is_empty() {
+      return Err(input.new_error_for_next_token());
+    } else {
+      return Ok(flags);
+    }
+  }
+}
+
+impl ToCss for Contain {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.is_empty() {
+      return dest.write_str("none");
+    }
+
+    if *self == Contain::Strict {
+      return dest.write_str("strict");
+    }
+
+    if *self == Contain::Content {
+      return dest.write_str("content");
+    }
+
+    let mut first = true;
+    macro_rules! flag {
+      ($flag: ident, $name: literal) => {
+        if self.contains(Contain::$flag) {
+          if first {
+            first = false;
+          } else {
+            dest.write_char(' ')?;
+          }
+          dest.write_str($name)?;
+        }
+      };
+    }
+
+    flag!(Size, "size");
+    flag!(InlineSize, "inline-size");
+    flag!(Layout, "layout");
+    flag!(Style, "style");
+    flag!(Paint, "paint");
+    Ok(())
+  }
+}
+
 bitflags! {
   /// A value for the [container-type](https://drafts.csswg.org/css-contain-3/#container-type) property.
   /// Establishes the element as a query container for the purpose of container queries.
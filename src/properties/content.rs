@@ -0,0 +1,295 @@
+//! CSS properties related to generated content.
+
+use super::list::CounterStyle;
+use crate::error::{ParserError, PrinterError};
+use crate::macros::enum_property;
+use crate::printer::Printer;
+use crate::traits::{Parse, ToCss};
+use crate::values::string::CowArcStr;
+use crate::values::{ident::CustomIdent, image::Image};
+use cssparser::*;
+
+/// A value for the [content](https://www.w3.org/TR/css-content-3/#content-property) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+pub enum Content<'i> {
+  /// The `normal` keyword.
+  Normal,
+  /// The `none` keyword.
+  None,
+  /// A list of content items, with optional alternative text.
+  List {
+    /// The content items.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    items: Vec<ContentItem<'i>>,
+    /// The alternative text, or an empty list if none.
+    alt: Vec<ContentItem<'i>>,
+  },
+}
+
+impl Default for Content<'_> {
+  fn default() -> Self {
+    Content::Normal
+  }
+}
+
+impl<'i> Parse<'i> for Content<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(Content::Normal);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(Content::None);
+    }
+
+    let mut items = vec![ContentItem::parse(input)?];
+    while let Ok(item) = input.try_parse(ContentItem::parse) {
+      items.push(item);
+    }
+
+    let mut alt = Vec::new();
+    if input.try_parse(|input| input.expect_delim('/')).is_ok() {
+      loop {
+        let location = input.current_source_location();
+        match input.try_parse(ContentItem::parse) {
+          Ok(item) if item.is_valid_alt() => alt.push(item),
+          Ok(_) => return Err(location.new_custom_error(ParserError::InvalidValue)),
+          Err(e) if alt.is_empty() => return Err(e),
+          Err(_) => break,
+        }
+      }
+    }
+
+    Ok(Content::List { items, alt })
+  }
+}
+
+impl ToCss for Content<'_> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      Content::Normal => dest.write_str("normal"),
+      Content::None => dest.write_str("none"),
+      Content::List { items, alt } => {
+        write_items(items, dest)?;
+        if !alt.is_empty() {
+          dest.delim('/', true)?;
+          write_items(alt, dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+fn write_items<W>(items: &[ContentItem], dest: &mut Printer<W>) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+{
+  let mut first = true;
+  for item in items {
+    if first {
+      first = false;
+    } else {
+      dest.write_char(' ')?;
+    }
+    item.to_css(dest)?;
+  }
+  Ok(())
+}
+
+/// An item within the [content](https://www.w3.org/TR/css-content-3/#content-property) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+pub enum ContentItem<'i> {
+  /// A string.
+  String {
+    /// The string value.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    value: CowArcStr<'i>,
+  },
+  /// An image, e.g. `url()` or a gradient.
+  Image {
+    /// The image value.
+    value: Image<'i>,
+  },
+  /// A [`counter()`](https://www.w3.org/TR/css-lists-3/#funcdef-counter) function.
+  Counter {
+    /// The name of the counter.
+    name: CustomIdent<'i>,
+    /// The counter style, if specified.
+    style: Option<CounterStyle<'i>>,
+  },
+  /// A [`counters()`](https://www.w3.org/TR/css-lists-3/#funcdef-counters) function.
+  Counters {
+    /// The name of the counter.
+    name: CustomIdent<'i>,
+    /// The string used to join nested counter values.
+    separator: CowArcStr<'i>,
+    /// The counter style, if specified.
+    style: Option<CounterStyle<'i>>,
+  },
+  /// An [`attr()`](https://www.w3.org/TR/css-values-4/#attr-notation) function.
+  Attr {
+    /// The name of the attribute.
+    name: CowArcStr<'i>,
+  },
+  /// A [quote](https://www.w3.org/TR/css-content-3/#quote-values) keyword.
+  Quote {
+    /// The quote keyword.
+    value: Quote,
+  },
+}
+
+enum_property! {
+  /// A [quote](https://www.w3.org/TR/css-content-3/#quote-values) keyword within the `content` property.
+  pub enum Quote {
+    /// An opening quote from the `quotes` property.
+    "open-quote": OpenQuote,
+    /// A closing quote from the `quotes` property.
+    "close-quote": CloseQuote,
+    /// Increments the quote nesting level without inserting a quote.
+    "no-open-quote": NoOpenQuote,
+    /// Decrements the quote nesting level without inserting a quote.
+    "no-close-quote": NoCloseQuote,
+  }
+}
+
+impl<'i> ContentItem<'i> {
+  /// Returns whether the item is allowed within the alternative text.
+  fn is_valid_alt(&self) -> bool {
+    matches!(
+      self,
+      ContentItem::String { .. }
+        | ContentItem::Counter { .. }
+        | ContentItem::Counters { .. }
+        | ContentItem::Attr { .. }
+    )
+  }
+}
+
+impl<'i> Parse<'i> for ContentItem<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if let Ok(value) = input.try_parse(|input| input.expect_string_cloned()) {
+      return Ok(ContentItem::String { value: value.into() });
+    }
+
+    if let Ok(value) = input.try_parse(Quote::parse) {
+      return Ok(ContentItem::Quote { value });
+    }
+
+    let location = input.current_source_location();
+    if let Ok(value) = input.try_parse(Image::parse) {
+      if value == Image::None {
+        return Err(location.new_custom_error(ParserError::InvalidValue));
+      }
+      return Ok(ContentItem::Image { value });
+    }
+
+    let f = input.expect_function()?.clone();
+    input.parse_nested_block(|input| {
+      match_ignore_ascii_case! { &f,
+        "counter" => {
+          let name = CustomIdent::parse(input)?;
+          let style = parse_counter_style(input)?;
+          Ok(ContentItem::Counter { name, style })
+        },
+        "counters" => {
+          let name = CustomIdent::parse(input)?;
+          input.expect_comma()?;
+          let separator = input.expect_string_cloned()?.into();
+          let style = parse_counter_style(input)?;
+          Ok(ContentItem::Counters { name, separator, style })
+        },
+        "attr" => {
+          let name = input.expect_ident_cloned()?.into();
+          Ok(ContentItem::Attr { name })
+        },
+        _ => Err(location.new_unexpected_token_error(Token::Ident(f.clone())))
+      }
+    })
+  }
+}
+
+fn parse_counter_style<'i, 't>(
+  input: &mut Parser<'i, 't>,
+) -> Result<Option<CounterStyle<'i>>, ParseError<'i, ParserError<'i>>> {
+  if input.try_parse(|input| input.expect_comma()).is_ok() {
+    Ok(Some(CounterStyle::parse(input)?))
+  } else {
+    Ok(None)
+  }
+}
+
+impl ToCss for ContentItem<'_> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      ContentItem::String { value } => write_string(value, dest),
+      ContentItem::Image { value } => value.to_css(dest),
+      ContentItem::Counter { name, style } => {
+        dest.write_str("counter(")?;
+        name.to_css(dest)?;
+        write_counter_style(style, dest)?;
+        dest.write_char(')')
+      }
+      ContentItem::Counters { name, separator, style } => {
+        dest.write_str("counters(")?;
+        name.to_css(dest)?;
+        dest.delim(',', false)?;
+        write_string(separator, dest)?;
+        write_counter_style(style, dest)?;
+        dest.write_char(')')
+      }
+      ContentItem::Attr { name } => {
+        dest.write_str("attr(")?;
+        serialize_identifier(name, dest)?;
+        dest.write_char(')')
+      }
+      ContentItem::Quote { value } => value.to_css(dest),
+    }
+  }
+}
+
+fn write_counter_style<W>(style: &Option<CounterStyle>, dest: &mut Printer<W>) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+{
+  if let Some(style) = style {
+    dest.delim(',', false)?;
+    style.to_css(dest)?;
+  }
+  Ok(())
+}
+
+/// Writes a string, using single quotes when minifying if that avoids escaping double quotes.
+fn write_string<W>(value: &str, dest: &mut Printer<W>) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+{
+  use std::fmt::Write;
+
+  if dest.minify && value.contains('"') && !value.contains('\'') {
+    let mut escaped = String::new();
+    CssStringWriter::new(&mut escaped).write_str(value)?;
+    dest.write_char('\'')?;
+    dest.write_str(&escaped.replace("\\\"", "\""))?;
+    dest.write_char('\'')
+  } else {
+    serialize_string(value, dest)?;
+    Ok(())
+  }
+}
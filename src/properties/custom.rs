@@ -116,6 +116,8 @@ pub enum TokenOrValue<'i> {
   Url(Url<'i>),
   /// A CSS variable reference.
   Var(Variable<'i>),
+  /// A CSS environment variable reference.
+  Env(EnvironmentVariable<'i>),
 }
 
 impl<'i> From<Token<'i>> for TokenOrValue<'i> {
@@ -203,6 +205,10 @@ impl<'i> TokenList<'i> {
             tokens.push(var);
             last_is_delim = true;
             last_is_whitespace = false;
+          } else if let Some(env) = try_parse_env(&f, input, options, depth) {
+            tokens.push(TokenOrValue::Env(env));
+            last_is_delim = true;
+            last_is_whitespace = false;
           } else {
             tokens.push(Token::Function(f).into());
             input.parse_nested_block(|input| TokenList::parse_into(input, tokens, options, depth + 1))?;
@@ -286,6 +292,23 @@ fn try_parse_color_token<'i, 't>(
   None
 }
 
+#[inline]
+fn try_parse_env<'i, 't>(
+  f: &CowArcStr<'i>,
+  input: &mut Parser<'i, 't>,
+  options: &ParserOptions,
+  depth: usize,
+) -> Option<EnvironmentVariable<'i>> {
+  if f.eq_ignore_ascii_case("env") {
+    // Environment variables that we cannot parse, e.g. with indices, are preserved as raw tokens.
+    return input
+      .try_parse(|input| input.parse_nested_block(|input| EnvironmentVariable::parse(input, options, depth + 1)))
+      .ok();
+  }
+
+  None
+}
+
 impl<'i> TokenList<'i> {
   pub(crate) fn to_css<W>(&self, dest: &mut Printer<W>, is_custom_property: bool) -> Result<(), PrinterError>
   where
@@ -320,19 +343,11 @@ impl<'i> TokenList<'i> {
         }
         TokenOrValue::Var(var) => {
           var.to_css(dest, is_custom_property)?;
-          if !dest.minify
-            && i != self.0.len() - 1
-            && !matches!(
-              self.0[i + 1],
-              TokenOrValue::Token(Token::Comma) | TokenOrValue::Token(Token::CloseParenthesis)
-            )
-          {
-            // Whitespace is removed during parsing, so add it back if we aren't minifying.
-            dest.write_char(' ')?;
-            true
-          } else {
-            false
-          }
+          self.write_whitespace_if_needed(i, dest)?
+        }
+        TokenOrValue::Env(env) => {
+          env.to_css(dest, is_custom_property)?;
+          self.write_whitespace_if_needed(i, dest)?
         }
         TokenOrValue::Token(token) => {
           match token {
@@ -383,6 +398,25 @@ impl<'i> TokenList<'i> {
 
     Ok(())
   }
+
+  fn write_whitespace_if_needed<W>(&self, i: usize, dest: &mut Printer<W>) -> Result<bool, PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if !dest.minify
+      && i != self.0.len() - 1
+      && !matches!(
+        self.0[i + 1],
+        TokenOrValue::Token(Token::Comma) | TokenOrValue::Token(Token::CloseParenthesis)
+      )
+    {
+      // Whitespace is removed during parsing, so add it back if we aren't minifying.
+      dest.write_char(' ')?;
+      Ok(true)
+    } else {
+      Ok(false)
+    }
+  }
 }
 
 /// A raw CSS token.
@@ -793,6 +827,48 @@ impl<'i> Variable<'i> {
   }
 }
 
+/// A CSS [environment variable](https://drafts.csswg.org/css-env/) reference.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EnvironmentVariable<'i> {
+  /// The environment variable name, e.g. `safe-area-inset-top`.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub name: CowArcStr<'i>,
+  /// A fallback value in case the environment variable is not defined.
+  pub fallback: Option<TokenList<'i>>,
+}
+
+impl<'i> EnvironmentVariable<'i> {
+  fn parse<'t>(
+    input: &mut Parser<'i, 't>,
+    options: &ParserOptions,
+    depth: usize,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let name = input.expect_ident_cloned()?.into();
+
+    let fallback = if input.try_parse(|input| input.expect_comma()).is_ok() {
+      Some(TokenList::parse(input, options, depth)?)
+    } else {
+      None
+    };
+
+    Ok(EnvironmentVariable { name, fallback })
+  }
+
+  fn to_css<W>(&self, dest: &mut Printer<W>, is_custom_property: bool) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    dest.write_str("env(")?;
+    serialize_identifier(&self.name, dest)?;
+    if let Some(fallback) = &self.fallback {
+      dest.delim(',', false)?;
+      fallback.to_css(dest, is_custom_property)?;
+    }
+    dest.write_char(')')
+  }
+}
+
 /// A color value with an unresolved alpha value (e.g. a variable).
 /// These can be converted from the modern slash syntax to older comma syntax.
 /// This can only be done when the only unresolved component is the alpha
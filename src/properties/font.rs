@@ -2,6 +2,7 @@
 
 use std::collections::HashSet;
 
+use super::custom::{Token, TokenList, TokenOrValue};
 use super::{Property, PropertyId};
 use crate::compat::Feature;
 use crate::context::PropertyHandlerContext;
@@ -282,6 +283,30 @@ impl<'i> Parse<'i> for FontStretch {
   }
 }
 
+impl FontStretch {
+  /// Returns the keyword equivalent to this value, if any.
+  fn to_keyword(&self) -> Option<FontStretchKeyword> {
+    use FontStretchKeyword::*;
+    match self {
+      FontStretch::Keyword(keyword) => Some(*keyword),
+      FontStretch::Percentage(val) => [
+        UltraCondensed,
+        ExtraCondensed,
+        Condensed,
+        SemiCondensed,
+        Normal,
+        SemiExpanded,
+        Expanded,
+        ExtraExpanded,
+        UltraExpanded,
+      ]
+      .iter()
+      .find(|keyword| Into::<Percentage>::into(*keyword) == *val)
+      .copied(),
+    }
+  }
+}
+
 impl Into<Percentage> for &FontStretch {
   fn into(self) -> Percentage {
     match self {
@@ -754,9 +779,17 @@ impl<'i> ToCss for Font<'i> {
       dest.write_char(' ')?;
     }
 
-    if self.stretch != FontStretch::default() {
-      self.stretch.to_css(dest)?;
-      dest.write_char(' ')?;
+    // The `font` shorthand only accepts keywords for font-stretch.
+    match self.stretch.to_keyword() {
+      Some(FontStretchKeyword::Normal) => {}
+      Some(keyword) => {
+        keyword.to_css(dest)?;
+        dest.write_char(' ')?;
+      }
+      None => {
+        self.stretch.to_css(dest)?;
+        dest.write_char(' ')?;
+      }
     }
 
     self.size.to_css(dest)?;
@@ -827,6 +860,11 @@ impl<'i> PropertyHandler<'i> for FontHandler<'i> {
         self.has_any = true;
         // TODO: reset other properties
       }
+      Unparsed(val) if val.property_id == PropertyId::Font && is_system_font(&val.value) => {
+        // A system font keyword resets all of the longhands, so any previous values are overridden.
+        *self = FontHandler::default();
+        dest.push(property.clone());
+      }
       Unparsed(val) if is_font_property(&val.property_id) => {
         self.finalize(dest, context);
         dest.push(property.clone());
@@ -872,12 +910,17 @@ impl<'i> PropertyHandler<'i> for FontHandler<'i> {
       && variant_caps.is_some()
     {
       let caps = variant_caps.unwrap();
+      let stretch = stretch.unwrap();
+      let stretch_keyword = stretch.to_keyword();
       decls.push(Property::Font(Font {
         family: family.unwrap(),
         size: size.unwrap(),
         style: style.unwrap(),
         weight: weight.unwrap(),
-        stretch: stretch.unwrap(),
+        stretch: match stretch_keyword {
+          Some(keyword) => FontStretch::Keyword(keyword),
+          None => FontStretch::default(),
+        },
         line_height: line_height.unwrap(),
         variant_caps: if caps.is_css2() {
           caps
@@ -891,6 +934,11 @@ impl<'i> PropertyHandler<'i> for FontHandler<'i> {
       if !caps.is_css2() {
         decls.push(Property::FontVariantCaps(variant_caps.unwrap()))
       }
+
+      // Likewise, percentages that don't correspond to a keyword need a separate font-stretch property.
+      if stretch_keyword.is_none() {
+        decls.push(Property::FontStretch(stretch))
+      }
     } else {
       if let Some(val) = family {
         decls.push(Property::FontFamily(val))
@@ -975,3 +1023,18 @@ fn is_font_property(property_id: &PropertyId) -> bool {
     _ => false,
   }
 }
+
+/// Returns whether the value of a `font` declaration is one of the system font keywords.
+/// These cannot be expanded into longhands, so they are preserved as is.
+fn is_system_font(value: &TokenList) -> bool {
+  let mut tokens = value.0.iter().filter(|token| !token.is_whitespace());
+  match (tokens.next(), tokens.next()) {
+    (Some(TokenOrValue::Token(Token::Ident(ident))), None) => {
+      match_ignore_ascii_case! { ident.as_ref(),
+        "caption" | "icon" | "menu" | "message-box" | "small-caption" | "status-bar" => true,
+        _ => false
+      }
+    }
+    _ => false,
+  }
+}
@@ -8,6 +8,7 @@ use crate::error::{Error, ErrorLocation, ParserError, PrinterError, PrinterError
 use crate::macros::{define_shorthand, impl_shorthand};
 use crate::printer::Printer;
 use crate::properties::{Property, PropertyId};
+use crate::stylesheet::PrinterOptions;
 use crate::traits::{Parse, PropertyHandler, Shorthand, ToCss};
 use crate::values::ident::CustomIdent;
 use crate::values::length::serialize_dimension;
@@ -16,6 +17,7 @@ use crate::values::{ident::CustomIdentList, length::LengthPercentage};
 use bitflags::bitflags;
 use cssparser::*;
 use smallvec::SmallVec;
+use std::collections::HashSet;
 
 /// A [track sizing](https://drafts.csswg.org/css-grid-2/#track-sizing) value
 /// for the `grid-template-rows` and `grid-template-columns` properties.
@@ -125,12 +127,12 @@ pub enum TrackBreadth {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TrackRepeat<'i> {
   /// The repeat count.
-  count: RepeatCount,
+  pub count: RepeatCount,
   /// The line names to repeat.
   #[cfg_attr(feature = "serde", serde(borrow))]
-  line_names: Vec<CustomIdentList<'i>>,
+  pub line_names: Vec<CustomIdentList<'i>>,
   /// The track sizes to repeat.
-  track_sizes: Vec<TrackSize>,
+  pub track_sizes: Vec<TrackSize>,
 }
 
 /// A [`<repeat-count>`](https://drafts.csswg.org/css-grid-2/#typedef-track-repeat) value,
@@ -287,6 +289,58 @@ impl<'i> Parse<'i> for TrackRepeat<'i> {
 
 impl<'i> ToCss for TrackRepeat<'i> {
   fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    // A repeat() with a fixed count and no line names can be expanded into
+    // the repeated track sizes. When minifying, use whichever is shorter.
+    if let RepeatCount::Number(count) = self.count {
+      if dest.minify
+        && count > 0
+        && !self.track_sizes.is_empty()
+        && self.line_names.iter().all(|names| names.is_empty())
+      {
+        let options = || PrinterOptions {
+          minify: true,
+          ..PrinterOptions::default()
+        };
+
+        let mut repeat = String::new();
+        self.to_css_base(&mut Printer::new(&mut repeat, options()))?;
+
+        let mut sizes = String::new();
+        {
+          let mut printer = Printer::new(&mut sizes, options());
+          for (i, size) in self.track_sizes.iter().enumerate() {
+            if i > 0 {
+              printer.write_char(' ')?;
+            }
+            size.to_css(&mut printer)?;
+          }
+        }
+
+        let count = count as usize;
+        if count * (sizes.len() + 1) - 1 < repeat.len() {
+          for i in 0..count {
+            if i > 0 {
+              dest.write_char(' ')?;
+            }
+            dest.write_str(&sizes)?;
+          }
+        } else {
+          dest.write_str(&repeat)?;
+        }
+
+        return Ok(());
+      }
+    }
+
+    self.to_css_base(dest)
+  }
+}
+
+impl<'i> TrackRepeat<'i> {
+  fn to_css_base<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
   where
     W: std::fmt::Write,
   {
@@ -578,6 +632,10 @@ impl<'i> Parse<'i> for GridTemplateAreas {
       row += 1;
     }
 
+    if !Self::is_rectangular(columns, &tokens) {
+      return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+    }
+
     Ok(GridTemplateAreas::Areas { columns, areas: tokens })
   }
 }
@@ -616,6 +674,49 @@ impl GridTemplateAreas {
 
     Ok(column)
   }
+
+  /// Returns whether every named area forms a single filled-in rectangle.
+  fn is_rectangular(columns: u32, areas: &[Option<String>]) -> bool {
+    let columns = columns as usize;
+    let mut seen = HashSet::new();
+    for (index, area) in areas.iter().enumerate() {
+      let name = match area {
+        Some(name) => name,
+        None => continue,
+      };
+
+      // Cells are visited in row-major order, so the first one seen is the top left corner.
+      if !seen.insert(name) {
+        continue;
+      }
+
+      let (row, column) = (index / columns, index % columns);
+      let width = areas[index..index + (columns - column)]
+        .iter()
+        .take_while(|area| area.as_ref() == Some(name))
+        .count();
+      let height = areas[index..]
+        .iter()
+        .step_by(columns)
+        .take_while(|area| area.as_ref() == Some(name))
+        .count();
+
+      for r in row..row + height {
+        for c in column..column + width {
+          if areas[r * columns + c].as_ref() != Some(name) {
+            return false;
+          }
+        }
+      }
+
+      // The area must not have any cells outside of the rectangle.
+      if areas.iter().filter(|area| area.as_ref() == Some(name)).count() != width * height {
+        return false;
+      }
+    }
+
+    true
+  }
 }
 
 static HTML_SPACE_CHARACTERS: &'static [char] = &['\u{0020}', '\u{0009}', '\u{000a}', '\u{000c}', '\u{000d}'];
@@ -768,6 +869,10 @@ impl<'i> Parse<'i> for GridTemplate<'i> {
     }
 
     if !tokens.is_empty() {
+      if !GridTemplateAreas::is_rectangular(columns, &tokens) {
+        return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+      }
+
       if line_names.len() == items.len() {
         line_names.push(Default::default());
       }
@@ -8,9 +8,11 @@ use crate::macros::{define_shorthand, enum_property, shorthand_handler, shorthan
 use crate::printer::Printer;
 use crate::targets::Browsers;
 use crate::traits::{FallbackValues, Parse, PropertyHandler, Shorthand, ToCss};
+use crate::values::number::CSSInteger;
 use crate::values::string::CowArcStr;
 use crate::values::{ident::CustomIdent, image::Image};
 use cssparser::*;
+use smallvec::SmallVec;
 
 /// A value for the [list-style-type](https://www.w3.org/TR/2020/WD-css-lists-3-20201117/#text-markers) property.
 #[derive(Debug, Clone, PartialEq)]
@@ -297,6 +299,105 @@ enum_property! {
   }
 }
 
+/// A counter name and integer within the [counter-reset](https://www.w3.org/TR/css-lists-3/#counter-reset),
+/// [counter-set](https://www.w3.org/TR/css-lists-3/#propdef-counter-set), or
+/// [counter-increment](https://www.w3.org/TR/css-lists-3/#propdef-counter-increment) properties.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Counter<'i> {
+  /// The name of the counter.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub name: CustomIdent<'i>,
+  /// The integer value. If omitted in the source, this is the default for the property.
+  pub value: CSSInteger,
+}
+
+/// A list of counters, as used in the `counter-reset`, `counter-set`, and `counter-increment` properties.
+///
+/// `DEFAULT` is the integer used when a counter is specified without one. It is omitted when
+/// serializing counters that have this value.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum CounterList<'i, const DEFAULT: CSSInteger> {
+  /// The `none` keyword.
+  None,
+  /// A list of counters.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Counters(SmallVec<[Counter<'i>; 1]>),
+}
+
+/// A value for the [counter-reset](https://www.w3.org/TR/css-lists-3/#counter-reset) property.
+pub type CounterReset<'i> = CounterList<'i, 0>;
+
+/// A value for the [counter-set](https://www.w3.org/TR/css-lists-3/#propdef-counter-set) property.
+pub type CounterSet<'i> = CounterList<'i, 0>;
+
+/// A value for the [counter-increment](https://www.w3.org/TR/css-lists-3/#propdef-counter-increment) property.
+pub type CounterIncrement<'i> = CounterList<'i, 1>;
+
+impl<'i, const DEFAULT: CSSInteger> Default for CounterList<'i, DEFAULT> {
+  fn default() -> Self {
+    CounterList::None
+  }
+}
+
+impl<'i, const DEFAULT: CSSInteger> Parse<'i> for CounterList<'i, DEFAULT> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(CounterList::None);
+    }
+
+    let mut counters: SmallVec<[Counter<'i>; 1]> = SmallVec::new();
+    loop {
+      let location = input.current_source_location();
+      let name = match input.try_parse(CustomIdent::parse) {
+        Ok(name) => name,
+        Err(e) if counters.is_empty() => return Err(e),
+        Err(_) => break,
+      };
+      if name.0.eq_ignore_ascii_case("none") {
+        return Err(location.new_custom_error(ParserError::InvalidValue));
+      }
+
+      let value = input.try_parse(CSSInteger::parse).unwrap_or(DEFAULT);
+      counters.push(Counter { name, value });
+    }
+
+    Ok(CounterList::Counters(counters))
+  }
+}
+
+impl<'i, const DEFAULT: CSSInteger> ToCss for CounterList<'i, DEFAULT> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      CounterList::None => dest.write_str("none"),
+      CounterList::Counters(counters) => {
+        let mut first = true;
+        for counter in counters {
+          if first {
+            first = false;
+          } else {
+            dest.write_char(' ')?;
+          }
+          counter.name.to_css(dest)?;
+          if counter.value != DEFAULT {
+            dest.write_char(' ')?;
+            counter.value.to_css(dest)?;
+          }
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
 shorthand_property! {
   /// A value for the [list-style](https://www.w3.org/TR/2020/WD-css-lists-3-20201117/#list-style-property) shorthand property.
   pub struct ListStyle<'i> {
@@ -427,7 +427,9 @@ side_handler!(
   ScrollMargin,
   ScrollMarginBlock,
   ScrollMarginInline,
-  false
+  false,
+  LogicalScrollMargin,
+  LogicalScrollMarginShorthand
 );
 
 side_handler!(
@@ -443,7 +445,9 @@ side_handler!(
   ScrollPadding,
   ScrollPaddingBlock,
   ScrollPaddingInline,
-  false
+  false,
+  LogicalScrollPadding,
+  LogicalScrollPaddingShorthand
 );
 
 side_handler!(
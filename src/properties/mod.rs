@@ -98,6 +98,7 @@ pub mod border_image;
 pub mod border_radius;
 pub mod box_shadow;
 pub mod contain;
+pub mod content;
 pub mod css_modules;
 pub mod custom;
 pub mod display;
@@ -144,6 +145,7 @@ use border_image::*;
 use border_radius::*;
 use box_shadow::*;
 use contain::*;
+use content::*;
 use css_modules::*;
 use cssparser::*;
 use custom::*;
@@ -785,6 +787,7 @@ define_properties! {
   "max-block-size": MaxBlockSize(MaxSize) [logical_group: MaxSize, category: Logical],
   "max-inline-size": MaxInlineSize(MaxSize) [logical_group: MaxSize, category: Logical],
   "box-sizing": BoxSizing(BoxSizing, VendorPrefix) / WebKit / Moz,
+  "aspect-ratio": AspectRatio(AspectRatio),
 
   "overflow": Overflow(Overflow) shorthand: true,
   "overflow-x": OverflowX(OverflowKeyword),
@@ -903,6 +906,9 @@ define_properties! {
   "row-gap": RowGap(GapValue),
   "column-gap": ColumnGap(GapValue),
   "gap": Gap(Gap) shorthand: true,
+  "grid-row-gap": GridRowGap(GapValue),
+  "grid-column-gap": GridColumnGap(GapValue),
+  "grid-gap": GridGap(Gap) shorthand: true,
 
   // Old flex (2009): https://www.w3.org/TR/2009/WD-css3-flexbox-20090723/
   "box-orient": BoxOrient(BoxOrient, VendorPrefix) / WebKit / Moz unprefixed: false,
@@ -1086,12 +1092,21 @@ define_properties! {
   "accent-color": AccentColor(ColorOrAuto),
   "appearance": Appearance(Appearance<'i>, VendorPrefix) / WebKit / Moz / Ms,
 
+  // https://www.w3.org/TR/css-will-change-1/
+  "will-change": WillChange(WillChange<'i>),
+
   // https://www.w3.org/TR/2020/WD-css-lists-3-20201117
   "list-style-type": ListStyleType(ListStyleType<'i>),
   "list-style-image": ListStyleImage(Image<'i>),
   "list-style-position": ListStylePosition(ListStylePosition),
   "list-style": ListStyle(ListStyle<'i>) shorthand: true,
   "marker-side": MarkerSide(MarkerSide),
+  "counter-reset": CounterReset(CounterReset<'i>),
+  "counter-set": CounterSet(CounterSet<'i>),
+  "counter-increment": CounterIncrement(CounterIncrement<'i>),
+
+  // https://www.w3.org/TR/css-content-3
+  "content": Content(Content<'i>),
 
   // CSS modules
   "composes": Composes(Composes<'i>) if css_modules,
@@ -1159,6 +1174,9 @@ define_properties! {
   // https://drafts.csswg.org/css2/
   "z-index": ZIndex(position::ZIndex),
 
+  // https://drafts.csswg.org/css-contain-2/
+  "contain": Contain(Contain),
+
   // https://drafts.csswg.org/css-contain-3/
   "container-type": ContainerType(ContainerType),
   "container-name": ContainerName(ContainerNameList<'i>),
@@ -7,8 +7,11 @@ use crate::error::{ParserError, PrinterError};
 use crate::macros::enum_property;
 use crate::printer::Printer;
 use crate::properties::{Property, PropertyId};
-use crate::traits::{Parse, PropertyHandler, ToCss};
+use crate::targets::Browsers;
+use crate::traits::{FallbackValues, Parse, PropertyHandler, ToCss};
 use crate::values::length::LengthPercentage;
+use crate::values::percentage::DimensionPercentage;
+use crate::values::ratio::Ratio;
 use crate::vendor_prefix::VendorPrefix;
 use cssparser::*;
 
@@ -216,6 +219,32 @@ impl ToCss for MaxSize {
   }
 }
 
+impl FallbackValues for Size {
+  fn get_fallbacks(&mut self, targets: Browsers) -> Vec<Self> {
+    match self {
+      Size::LengthPercentage(DimensionPercentage::Dimension(length)) => length
+        .get_fallbacks(targets)
+        .into_iter()
+        .map(|length| Size::LengthPercentage(DimensionPercentage::Dimension(length)))
+        .collect(),
+      _ => Vec::new(),
+    }
+  }
+}
+
+impl FallbackValues for MaxSize {
+  fn get_fallbacks(&mut self, targets: Browsers) -> Vec<Self> {
+    match self {
+      MaxSize::LengthPercentage(DimensionPercentage::Dimension(length)) => length
+        .get_fallbacks(targets)
+        .into_iter()
+        .map(|length| MaxSize::LengthPercentage(DimensionPercentage::Dimension(length)))
+        .collect(),
+      _ => Vec::new(),
+    }
+  }
+}
+
 fn parse_fit_content<'i, 't>(
   input: &mut Parser<'i, 't>,
 ) -> Result<LengthPercentage, ParseError<'i, ParserError<'i>>> {
@@ -233,6 +262,77 @@ enum_property! {
   }
 }
 
+/// A value for the [aspect-ratio](https://drafts.csswg.org/css-sizing-4/#aspect-ratio) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AspectRatio {
+  /// The `auto` keyword.
+  pub auto: bool,
+  /// A preferred aspect ratio for the box, specified as width / height.
+  pub ratio: Option<Ratio>,
+}
+
+impl<'i> Parse<'i> for AspectRatio {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    let mut auto = input.try_parse(|i| i.expect_ident_matching("auto"));
+    let ratio = input.try_parse(Ratio::parse);
+    if auto.is_err() {
+      auto = input.try_parse(|i| i.expect_ident_matching("auto"));
+    }
+    if auto.is_err() && ratio.is_err() {
+      return Err(location.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(AspectRatio {
+      auto: auto.is_ok(),
+      ratio: ratio.ok(),
+    })
+  }
+}
+
+impl ToCss for AspectRatio {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.auto {
+      dest.write_str("auto")?;
+    }
+
+    if let Some(ratio) = &self.ratio {
+      if self.auto {
+        dest.write_char(' ')?;
+      }
+
+      if dest.minify {
+        reduce_ratio(ratio).to_css(dest)?;
+      } else {
+        ratio.to_css(dest)?;
+      }
+    }
+
+    Ok(())
+  }
+}
+
+/// Reduces a ratio of two integers by their greatest common divisor, e.g. `1920/1080` => `16/9`.
+fn reduce_ratio(ratio: &Ratio) -> Ratio {
+  let Ratio(a, b) = *ratio;
+  if a.fract() != 0.0 || b.fract() != 0.0 || a <= 0.0 || b <= 0.0 {
+    return ratio.clone();
+  }
+
+  let (mut x, mut y) = (a, b);
+  while y != 0.0 {
+    let t = x % y;
+    x = y;
+    y = t;
+  }
+
+  Ratio(a / x, b / x)
+}
+
 #[derive(Default)]
 pub(crate) struct SizeHandler;
 
@@ -268,6 +368,11 @@ impl<'i> PropertyHandler<'i> for SizeHandler {
           $size::FitContent(VendorPrefix::None) => prefix!($prop, $size, FitContent),
           _ => {}
         }
+        if let Some(targets) = context.targets {
+          for fallback in $val.clone().get_fallbacks(targets) {
+            dest.push(Property::$prop(fallback));
+          }
+        }
         dest.push(Property::$prop($val.clone()));
       }};
     }
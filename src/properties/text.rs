@@ -625,14 +625,21 @@ impl ToCss for TextDecoration {
   where
     W: std::fmt::Write,
   {
-    self.line.to_css(dest)?;
-    if self.line.is_empty() {
-      return Ok(());
+    // The line may be omitted if it is `none` and another value is written.
+    let mut needs_space = false;
+    if !self.line.is_empty()
+      || (self.thickness == TextDecorationThickness::default()
+        && self.style == TextDecorationStyle::default()
+        && self.color == CssColor::current_color())
+    {
+      self.line.to_css(dest)?;
+      needs_space = true;
     }
 
-    let mut needs_space = true;
     if self.thickness != TextDecorationThickness::default() {
-      dest.write_char(' ')?;
+      if needs_space {
+        dest.write_char(' ')?;
+      }
       self.thickness.to_css(dest)?;
       needs_space = true;
     }
@@ -1,6 +1,7 @@
 //! CSS properties related to 2D and 3D transforms.
 
 use super::{Property, PropertyId};
+use crate::compat;
 use crate::context::PropertyHandlerContext;
 use crate::declaration::DeclarationList;
 use crate::error::{ParserError, PrinterError};
@@ -54,11 +55,14 @@ impl ToCss for TransformList {
     }
 
     if dest.minify {
+      // Merge adjacent transforms first.
+      let simplified = self.simplify();
+
       // Combine transforms into a single matrix.
-      if let Some(matrix) = self.to_matrix() {
-        // Generate based on the original transforms.
+      if let Some(matrix) = simplified.to_matrix() {
+        // Generate based on the simplified transforms.
         let mut base = String::new();
-        self.to_css_base(&mut Printer::new(
+        simplified.to_css_base(&mut Printer::new(
           &mut base,
           PrinterOptions {
             minify: true,
@@ -110,6 +114,8 @@ impl ToCss for TransformList {
 
         return Ok(());
       }
+
+      return simplified.to_css_base(dest);
     }
 
     self.to_css_base(dest)
@@ -127,6 +133,34 @@ impl TransformList {
     Ok(())
   }
 
+  /// Returns an equivalent transform list with adjacent translateX() and translateY(),
+  /// or scaleX() and scaleY() functions merged into a single 2D function.
+  fn simplify(&self) -> TransformList {
+    use Transform::*;
+    let mut result: Vec<Transform> = Vec::with_capacity(self.0.len());
+    for transform in &self.0 {
+      // Only directly adjacent functions are merged, since a different function in between
+      // would change the coordinate system the second one is applied in. Identity functions
+      // are preserved: they still establish a stacking context and affect interpolation.
+      let merged = match (result.last(), transform) {
+        (Some(TranslateX(x)), TranslateY(y)) | (Some(TranslateY(y)), TranslateX(x)) => {
+          Some(Translate(x.clone(), y.clone()))
+        }
+        (Some(ScaleX(x)), ScaleY(y)) | (Some(ScaleY(y)), ScaleX(x)) => Some(Scale(x.clone(), y.clone())),
+        _ => None,
+      };
+
+      if let Some(merged) = merged {
+        result.pop();
+        result.push(merged);
+      } else {
+        result.push(transform.clone());
+      }
+    }
+
+    TransformList(result)
+  }
+
   /// Converts the transform list to a 3D matrix if possible.
   pub fn to_matrix(&self) -> Option<Matrix3d<f32>> {
     let mut matrix = Matrix3d::identity();
@@ -1279,6 +1313,16 @@ impl ToCss for Transform {
 }
 
 impl Transform {
+  /// Returns an equivalent transform, using `rotate()` for all rotations around the Z axis.
+  fn normalize(&self) -> Transform {
+    use Transform::*;
+    match self {
+      RotateZ(angle) => Rotate(angle.clone()),
+      Rotate3d(x, y, z, angle) if *x == 0.0 && *y == 0.0 && *z != 0.0 => Rotate(angle.clone() * z.signum()),
+      _ => self.clone(),
+    }
+  }
+
   /// Converts the transform to a 3D matrix.
   pub fn to_matrix(&self) -> Option<Matrix3d<f32>> {
     macro_rules! to_radians {
@@ -1508,7 +1552,8 @@ impl<'i> Parse<'i> for Rotate {
       });
     }
 
-    let angle = input.try_parse(Angle::parse);
+    // A leading `0` is the x component of the axis rather than an angle.
+    let angle = input.try_parse(Angle::parse_with_unit);
     let (x, y, z) = input
       .try_parse(|input| {
         let location = input.current_source_location();
@@ -1662,7 +1707,7 @@ impl<'i> PropertyHandler<'i> for TransformHandler {
 
     macro_rules! individual_property {
       ($prop: ident, $val: ident) => {
-        if let Some((transform, _)) = &mut self.transform {
+        if let (Some((transform, _)), false) = (&mut self.transform, self.converts_individual_transforms()) {
           transform.0.push($val.to_transform())
         } else {
           self.$prop = Some($val.clone());
@@ -1690,9 +1735,13 @@ impl<'i> PropertyHandler<'i> for TransformHandler {
           self.has_any = true;
         }
 
-        self.translate = None;
-        self.rotate = None;
-        self.scale = None;
+        // When the individual transform properties are converted, they are combined
+        // with the transform property rather than overridden by it.
+        if !self.converts_individual_transforms() {
+          self.translate = None;
+          self.rotate = None;
+          self.scale = None;
+        }
       }
       Translate(val) => individual_property!(translate, val),
       Rotate(val) => individual_property!(rotate, val),
@@ -1723,6 +1772,15 @@ impl<'i> PropertyHandler<'i> for TransformHandler {
 }
 
 impl TransformHandler {
+  /// Returns whether the translate, rotate, and scale properties are unsupported by the targets,
+  /// and must be converted to the transform property.
+  fn converts_individual_transforms(&self) -> bool {
+    match self.targets {
+      Some(targets) => !compat::Feature::IndividualTransforms.is_compatible(targets),
+      None => false,
+    }
+  }
+
   fn flush(&mut self, dest: &mut DeclarationList) {
     if !self.has_any {
       return;
@@ -1730,10 +1788,45 @@ impl TransformHandler {
 
     self.has_any = false;
 
-    let transform = std::mem::take(&mut self.transform);
-    let translate = std::mem::take(&mut self.translate);
-    let rotate = std::mem::take(&mut self.rotate);
-    let scale = std::mem::take(&mut self.scale);
+    let mut transform = std::mem::take(&mut self.transform);
+    let mut translate = std::mem::take(&mut self.translate);
+    let mut rotate = std::mem::take(&mut self.rotate);
+    let mut scale = std::mem::take(&mut self.scale);
+
+    // If the individual transform properties are not supported, convert them to transform functions.
+    // These are applied in the order translate, rotate, scale, followed by the transform property,
+    // so combine everything into a single transform declaration.
+    if (translate.is_some() || rotate.is_some() || scale.is_some()) && self.converts_individual_transforms() {
+      let mut transforms = Vec::new();
+      if let Some(translate) = translate.take() {
+        if translate.z.is_zero() {
+          transforms.push(Transform::Translate(translate.x, translate.y));
+        } else {
+          transforms.push(translate.to_transform());
+        }
+      }
+
+      if let Some(rotate) = rotate.take() {
+        transforms.push(rotate.to_transform().normalize());
+      }
+
+      if let Some(scale) = scale.take() {
+        if Into::<f32>::into(&scale.z) == 1.0 {
+          transforms.push(Transform::Scale(scale.x, scale.y));
+        } else {
+          transforms.push(scale.to_transform());
+        }
+      }
+
+      let prefix = match &mut transform {
+        Some((list, prefix)) => {
+          transforms.append(&mut list.0);
+          *prefix | VendorPrefix::None
+        }
+        None => VendorPrefix::None,
+      };
+      transform = Some((TransformList(transforms), prefix));
+    }
 
     if let Some((transform, prefix)) = transform {
       let mut prefix = prefix;
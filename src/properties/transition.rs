@@ -85,16 +85,29 @@ impl<'i> ToCss for Transition<'i> {
   where
     W: std::fmt::Write,
   {
-    self.property.to_css(dest)?;
-    if !self.duration.is_zero() || !self.delay.is_zero() {
-      dest.write_char(' ')?;
+    let has_duration = !self.duration.is_zero() || !self.delay.is_zero();
+    let has_timing_function = self.timing_function != EasingFunction::Ease
+      && self.timing_function != EasingFunction::CubicBezier(0.25, 0.1, 0.25, 1.0);
+
+    // `all` is the default property, so it can be omitted unless nothing else is printed.
+    let mut needs_space = false;
+    if self.property != PropertyId::All || (!has_duration && !has_timing_function) {
+      self.property.to_css(dest)?;
+      needs_space = true;
+    }
+
+    if has_duration {
+      if needs_space {
+        dest.write_char(' ')?;
+      }
       self.duration.to_css(dest)?;
+      needs_space = true;
     }
 
-    if self.timing_function != EasingFunction::Ease
-      && self.timing_function != EasingFunction::CubicBezier(0.25, 0.1, 0.25, 1.0)
-    {
-      dest.write_char(' ')?;
+    if has_timing_function {
+      if needs_space {
+        dest.write_char(' ')?;
+      }
       self.timing_function.to_css(dest)?;
     }
 
@@ -228,6 +241,13 @@ impl<'i> TransitionHandler<'i> {
     let mut delays = std::mem::take(&mut self.delays);
     let mut timing_functions = std::mem::take(&mut self.timing_functions);
 
+    // If another list has more values than transition-property, the extra values
+    // cannot be represented by the shorthand, so the longhands are output as is.
+    let property_count = properties.as_ref().map_or(0, |(properties, _)| properties.len());
+    let fits_shorthand = durations.as_ref().map_or(0, |(list, _)| list.len()) <= property_count
+      && delays.as_ref().map_or(0, |(list, _)| list.len()) <= property_count
+      && timing_functions.as_ref().map_or(0, |(list, _)| list.len()) <= property_count;
+
     let rtl_properties = if let Some((properties, _)) = &mut properties {
       expand_properties(properties, self.targets, context)
     } else {
@@ -245,7 +265,7 @@ impl<'i> TransitionHandler<'i> {
       // Remove that from the prefixes of each of the properties. The remaining
       // prefixes will be handled by outputing individual properties below.
       let intersection = *property_prefixes & *duration_prefixes & *delay_prefixes & *timing_prefixes;
-      if !intersection.is_empty() {
+      if !intersection.is_empty() && fits_shorthand {
         macro_rules! get_transitions {
           ($properties: ident) => {{
             // transition-property determines the number of transitions. The values of other
@@ -359,3 +359,121 @@ impl<'i> ToCss for Appearance<'i> {
     }
   }
 }
+
+/// A value for the [will-change](https://www.w3.org/TR/css-will-change-1/#will-change) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum WillChange<'i> {
+  /// The `auto` keyword.
+  Auto,
+  /// A list of features that are expected to change. Duplicates are removed.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Features(SmallVec<[WillChangeFeature<'i>; 1]>),
+}
+
+impl<'i> Default for WillChange<'i> {
+  fn default() -> Self {
+    WillChange::Auto
+  }
+}
+
+impl<'i> Parse<'i> for WillChange<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(WillChange::Auto);
+    }
+
+    let mut features: SmallVec<[WillChangeFeature<'i>; 1]> = SmallVec::new();
+    for feature in input.parse_comma_separated(WillChangeFeature::parse)? {
+      if !features.contains(&feature) {
+        features.push(feature);
+      }
+    }
+
+    Ok(WillChange::Features(features))
+  }
+}
+
+impl<'i> ToCss for WillChange<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      WillChange::Auto => dest.write_str("auto"),
+      WillChange::Features(features) => {
+        let mut first = true;
+        for feature in features {
+          if first {
+            first = false;
+          } else {
+            dest.delim(',', false)?;
+          }
+          feature.to_css(dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+/// An [animateable feature](https://www.w3.org/TR/css-will-change-1/#typedef-animateable-feature)
+/// within the `will-change` property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum WillChangeFeature<'i> {
+  /// The scroll position of the element is expected to change.
+  ScrollPosition,
+  /// The contents of the element are expected to change.
+  Contents,
+  /// The given property is expected to change. The name is lower cased.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Property(CowArcStr<'i>),
+}
+
+impl<'i> Parse<'i> for WillChangeFeature<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    let ident = input.expect_ident()?;
+    match_ignore_ascii_case! { &*ident,
+      "scroll-position" => Ok(WillChangeFeature::ScrollPosition),
+      "contents" => Ok(WillChangeFeature::Contents),
+      "will-change" | "none" | "all" | "auto" |
+      "initial" | "inherit" | "unset" | "default" | "revert" | "revert-layer" => {
+        Err(location.new_unexpected_token_error(Token::Ident(ident.clone())))
+      },
+      _ => {
+        let name = if ident.starts_with("--") || !ident.bytes().any(|b| b.is_ascii_uppercase()) {
+          ident.clone().into()
+        } else {
+          ident.to_ascii_lowercase().into()
+        };
+        Ok(WillChangeFeature::Property(name))
+      }
+    }
+  }
+}
+
+impl<'i> ToCss for WillChangeFeature<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      WillChangeFeature::ScrollPosition => dest.write_str("scroll-position"),
+      WillChangeFeature::Contents => dest.write_str("contents"),
+      WillChangeFeature::Property(name) => {
+        serialize_identifier(&name, dest)?;
+        Ok(())
+      }
+    }
+  }
+}
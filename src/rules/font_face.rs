@@ -1,11 +1,13 @@
 //! The `@font-face` rule.
 
-use super::Location;
+use super::{Location, MinifyContext};
+use crate::compat::Feature;
 use crate::error::{ParserError, PrinterError};
 use crate::macros::enum_property;
 use crate::printer::Printer;
 use crate::properties::custom::CustomProperty;
 use crate::properties::font::{FontFamily, FontStretch, FontStyle, FontWeight};
+use crate::targets::Browsers;
 use crate::traits::{Parse, ToCss};
 use crate::values::size::Size2D;
 use crate::values::string::CowArcStr;
@@ -24,6 +26,57 @@ pub struct FontFaceRule<'i> {
   pub loc: Location,
 }
 
+impl<'i> FontFaceRule<'i> {
+  pub(crate) fn minify(&mut self, context: &mut MinifyContext<'_, 'i>) {
+    for property in &mut self.properties {
+      match property {
+        FontFaceProperty::Source(sources) => {
+          if let Some(targets) = context.targets {
+            minify_sources(sources, *targets);
+          }
+        }
+        FontFaceProperty::UnicodeRange(ranges) => merge_unicode_ranges(ranges),
+        _ => {}
+      }
+    }
+  }
+}
+
+fn minify_sources<'i>(sources: &mut Vec<Source<'i>>, targets: Browsers) {
+  // Remove urls in formats that none of the targets support, as long as at least one url remains.
+  let is_supported = |source: &Source<'i>| match source {
+    Source::Url(UrlSource {
+      format: Some(format), ..
+    }) => format.is_partially_compatible(targets),
+    _ => true,
+  };
+  if sources
+    .iter()
+    .any(|source| matches!(source, Source::Url(_)) && is_supported(source))
+  {
+    sources.retain(is_supported);
+  }
+
+  // Browsers use the first source they support, so sort the urls by format preference.
+  // Local fonts stay where they are.
+  let indices: Vec<usize> = sources
+    .iter()
+    .enumerate()
+    .filter(|(_, source)| matches!(source, Source::Url(_)))
+    .map(|(i, _)| i)
+    .collect();
+  let mut urls: Vec<Source> = indices.iter().map(|i| sources[*i].clone()).collect();
+  urls.sort_by_key(|source| match source {
+    Source::Url(UrlSource {
+      format: Some(format), ..
+    }) => format.preference(),
+    _ => u8::MAX,
+  });
+  for (i, url) in indices.into_iter().zip(urls) {
+    sources[i] = url;
+  }
+}
+
 /// A property within an `@font-face` rule.
 ///
 /// See [FontFaceRule](FontFaceRule).
@@ -47,6 +100,8 @@ pub enum FontFaceProperty<'i> {
   FontStretch(Size2D<FontStretch>),
   /// The `unicode-range` property.
   UnicodeRange(Vec<UnicodeRange>),
+  /// The `font-display` property.
+  FontDisplay(FontDisplay),
   /// An unknown or unsupported property.
   Custom(CustomProperty<'i>),
 }
@@ -205,6 +260,36 @@ impl<'i> Parse<'i> for FontFormat<'i> {
   }
 }
 
+impl<'i> FontFormat<'i> {
+  /// Returns whether any of the given browsers support this format.
+  /// Unknown formats are assumed to be supported.
+  fn is_partially_compatible(&self, targets: Browsers) -> bool {
+    use FontFormat::*;
+    let feature = match self {
+      WOFF => Feature::Woff,
+      WOFF2 => Feature::Woff2,
+      TrueType | OpenType => Feature::Ttf,
+      EmbeddedOpenType => Feature::Eot,
+      Collection | SVG | String(_) => return true,
+    };
+
+    feature.is_partially_compatible(targets)
+  }
+
+  /// Returns the order in which this format should be listed. Lower is preferred.
+  fn preference(&self) -> u8 {
+    use FontFormat::*;
+    match self {
+      WOFF2 => 0,
+      WOFF => 1,
+      TrueType | OpenType | Collection => 2,
+      EmbeddedOpenType => 3,
+      SVG => 4,
+      String(_) => 5,
+    }
+  }
+}
+
 impl<'i> ToCss for FontFormat<'i> {
   fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
   where
@@ -272,6 +357,23 @@ enum_property! {
   }
 }
 
+enum_property! {
+  /// A value for the [font-display](https://drafts.csswg.org/css-fonts/#font-display-desc)
+  /// property in an `@font-face` rule.
+  pub enum FontDisplay {
+    /// The font display strategy is defined by the user agent.
+    "auto": Auto,
+    /// A short block period and an infinite swap period.
+    "block": Block,
+    /// An extremely small block period and an infinite swap period.
+    "swap": Swap,
+    /// An extremely small block period and a short swap period.
+    "fallback": Fallback,
+    /// An extremely small block period and no swap period.
+    "optional": Optional,
+  }
+}
+
 /// A contiguous range of Unicode code points.
 ///
 /// Cannot be empty. Can represent a single code point when start == end.
@@ -347,6 +449,38 @@ impl ToCss for UnicodeRange {
   }
 }
 
+/// Merges overlapping and adjacent ranges. Merged ranges take the position of the first range they include.
+fn merge_unicode_ranges(ranges: &mut Vec<UnicodeRange>) {
+  let mut merged: Vec<UnicodeRange> = Vec::with_capacity(ranges.len());
+  for mut range in ranges.drain(..) {
+    let mut index = None;
+    let mut i = 0;
+    while i < merged.len() {
+      let existing = &merged[i];
+      if range.start <= existing.end.saturating_add(1) && existing.start <= range.end.saturating_add(1) {
+        range.start = range.start.min(existing.start);
+        range.end = range.end.max(existing.end);
+        if index.is_none() {
+          index = Some(i);
+          i += 1;
+        } else {
+          // This range bridged the gap between two existing ranges.
+          merged.remove(i);
+        }
+      } else {
+        i += 1;
+      }
+    }
+
+    match index {
+      Some(i) => merged[i] = range,
+      None => merged.push(range),
+    }
+  }
+
+  *ranges = merged;
+}
+
 pub(crate) struct FontFaceDeclarationParser;
 
 /// Parse a declaration within {} block: `color: blue`
@@ -379,6 +513,7 @@ impl<'i> cssparser::DeclarationParser<'i> for FontFaceDeclarationParser {
       "font-style" => property!(FontStyle, FontStyle),
       "font-stretch" => property!(FontStretch, Size2D<FontStretch>),
       "unicode-range" => property!(UnicodeRange, Vec<UnicodeRange>),
+      "font-display" => property!(FontDisplay, FontDisplay),
       _ => {}
     }
 
@@ -455,6 +590,7 @@ impl<'i> ToCss for FontFaceProperty<'i> {
       FontWeight(value) => property!("font-weight", value),
       FontStretch(value) => property!("font-stretch", value),
       UnicodeRange(value) => property!("unicode-range", value),
+      FontDisplay(value) => property!("font-display", value),
       Custom(custom) => {
         dest.write_str(custom.name.as_ref())?;
         dest.delim(':', false)?;
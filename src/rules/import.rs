@@ -48,7 +48,7 @@ impl<'i> ToCss for ImportRule<'i> {
         dependencies.push(Dependency::Import(dep))
       }
     } else {
-      serialize_string(&self.url, dest)?;
+      serialize_string(&dest.rewrite_url(&self.url), dest)?;
     }
 
     if let Some(layer) = &self.layer {
@@ -105,6 +105,39 @@ impl<'i> KeyframesRule<'i> {
     }
 
     context.handler_context.context = DeclarationContext::None;
+
+    // Remove empty keyframes, and merge keyframes with identical declarations. A keyframe
+    // can only be moved earlier if no keyframe in between has the same offset, otherwise
+    // the cascade order between them would change.
+    let mut keyframes: Vec<Keyframe<'i>> = Vec::with_capacity(self.keyframes.len());
+    'outer: for keyframe in self.keyframes.drain(..) {
+      if keyframe.declarations.is_empty() {
+        continue;
+      }
+
+      for existing in keyframes.iter_mut().rev() {
+        if existing.declarations == keyframe.declarations {
+          for selector in keyframe.selectors {
+            if !existing.selectors.iter().any(|s| s.offset() == selector.offset()) {
+              existing.selectors.push(selector);
+            }
+          }
+          continue 'outer;
+        }
+
+        if existing
+          .selectors
+          .iter()
+          .any(|a| keyframe.selectors.iter().any(|b| a.offset() == b.offset()))
+        {
+          break;
+        }
+      }
+
+      keyframes.push(keyframe);
+    }
+
+    self.keyframes = keyframes;
   }
 
   pub(crate) fn get_fallbacks(&mut self, targets: Browsers) -> Vec<CssRule<'i>> {
@@ -175,6 +208,7 @@ impl<'i> KeyframesRule<'i> {
               _ => property.clone(),
             })
             .collect(),
+          comments: vec![],
         },
       })
       .collect();
@@ -278,6 +312,17 @@ impl<'i> Parse<'i> for KeyframeSelector {
   }
 }
 
+impl KeyframeSelector {
+  /// Returns the offset of the keyframe selector, between 0 and 1.
+  fn offset(&self) -> f32 {
+    match self {
+      KeyframeSelector::Percentage(p) => p.0,
+      KeyframeSelector::From => 0.0,
+      KeyframeSelector::To => 1.0,
+    }
+  }
+}
+
 impl ToCss for KeyframeSelector {
   fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
   where
@@ -59,15 +59,16 @@ use self::font_palette_values::FontPaletteValuesRule;
 use self::layer::{LayerBlockRule, LayerStatementRule};
 use self::property::PropertyRule;
 use crate::context::PropertyHandlerContext;
-use crate::declaration::DeclarationHandler;
+use crate::declaration::{DeclarationBlock, DeclarationHandler};
 use crate::dependencies::{Dependency, ImportDependency};
-use crate::error::{MinifyError, ParserError, PrinterError};
+use crate::error::{MinifyError, MinifyWarning, MinifyWarningKind, ParserError, PrinterError};
 use crate::parser::TopLevelRuleParser;
 use crate::prefixes::Feature;
 use crate::printer::Printer;
+use crate::properties::PropertyId;
 use crate::rules::keyframes::KeyframesName;
-use crate::selector::{downlevel_selectors, get_prefix, is_equivalent};
-use crate::stylesheet::ParserOptions;
+use crate::selector::{downlevel_selectors, get_prefix, is_equivalent, Selectors};
+use crate::stylesheet::{LogicalFallback, ParserOptions};
 use crate::targets::Browsers;
 use crate::traits::ToCss;
 use crate::values::string::CowArcStr;
@@ -84,6 +85,7 @@ use media::MediaRule;
 use namespace::NamespaceRule;
 use nesting::NestingRule;
 use page::PageRule;
+use parcel_selectors::SelectorList;
 use serde::Serialize;
 use std::collections::{HashMap, HashSet};
 use style::StyleRule;
@@ -102,7 +104,7 @@ pub(crate) trait ToCssWithContext<'a, 'i> {
 }
 
 pub(crate) struct StyleContext<'a, 'i> {
-  pub rule: &'a StyleRule<'i>,
+  pub selectors: &'a SelectorList<'i, Selectors>,
   pub parent: Option<&'a StyleContext<'a, 'i>>,
 }
 
@@ -164,6 +166,9 @@ pub enum CssRule<'i> {
   Property(PropertyRule<'i>),
   /// A `@container` rule.
   Container(ContainerRule<'i>),
+  /// A comment between rules, e.g. a `/*! license */` comment. Comments between declarations
+  /// are stored in the [DeclarationBlock](DeclarationBlock) instead.
+  Comment(CowArcStr<'i>),
   /// A placeholder for a rule that was removed.
   Ignored,
   /// An unknown at-rule.
@@ -186,7 +191,7 @@ impl<'a, 'i> ToCssWithContext<'a, 'i> for CssRule<'i> {
       CssRule::Keyframes(keyframes) => keyframes.to_css(dest),
       CssRule::FontFace(font_face) => font_face.to_css(dest),
       CssRule::FontPaletteValues(f) => f.to_css(dest),
-      CssRule::Page(font_face) => font_face.to_css(dest),
+      CssRule::Page(page) => page.to_css(dest),
       CssRule::Supports(supports) => supports.to_css_with_context(dest, context),
       CssRule::CounterStyle(counter_style) => counter_style.to_css(dest),
       CssRule::Namespace(namespace) => namespace.to_css(dest),
@@ -199,6 +204,11 @@ impl<'a, 'i> ToCssWithContext<'a, 'i> for CssRule<'i> {
       CssRule::Property(property) => property.to_css(dest),
       CssRule::Container(container) => container.to_css_with_context(dest, context),
       CssRule::Unknown(unknown) => unknown.to_css(dest),
+      CssRule::Comment(comment) => {
+        dest.write_str("/*")?;
+        dest.write_str(comment)?;
+        dest.write_str("*/")
+      }
       CssRule::Ignored => Ok(()),
     }
   }
@@ -247,6 +257,7 @@ pub(crate) struct MinifyContext<'a, 'i> {
   pub unused_symbols: &'a HashSet<String>,
   pub custom_media: Option<HashMap<CowArcStr<'i>, CustomMediaRule<'i>>>,
   pub css_modules: bool,
+  pub warnings: Option<Vec<MinifyWarning>>,
 }
 
 impl<'i> CssRuleList<'i> {
@@ -319,6 +330,33 @@ impl<'i> CssRuleList<'i> {
           }
         }
         CssRule::Supports(supports) => {
+          if let Some(targets) = context.targets {
+            match supports.condition.evaluate(*targets) {
+              Some(true) => {
+                let mut inner = CssRuleList(std::mem::take(&mut supports.rules.0));
+                inner.minify(context, parent_is_unused)?;
+                rules.extend(inner.0);
+                continue;
+              }
+              Some(false) => {
+                if let Some(warnings) = &mut context.warnings {
+                  if let Some((property, value)) = supports.condition.unsupported_declaration(*targets) {
+                    warnings.push(MinifyWarning {
+                      kind: MinifyWarningKind::UnsupportedValueRemoved {
+                        property: property.to_owned(),
+                        value: value.to_owned(),
+                        reason: "the @supports condition never matches the browser targets".into(),
+                      },
+                      loc: supports.loc,
+                    });
+                  }
+                }
+                continue;
+              }
+              None => {}
+            }
+          }
+
           if let Some(CssRule::Supports(last_rule)) = rules.last_mut() {
             if last_rule.condition == supports.condition {
               last_rule.rules.0.extend(supports.rules.0.drain(..));
@@ -346,18 +384,42 @@ impl<'i> CssRuleList<'i> {
           }
         }
         CssRule::LayerBlock(layer) => {
-          if let Some(CssRule::LayerBlock(last_rule)) = rules.last_mut() {
-            if last_rule.name == layer.name {
-              last_rule.rules.0.extend(layer.rules.0.drain(..));
-              last_rule.minify(context, parent_is_unused)?;
-              continue;
+          // Anonymous layers are always distinct, so only named layers can be merged.
+          if layer.name.is_some() {
+            if let Some(CssRule::LayerBlock(last_rule)) = rules.last_mut() {
+              if last_rule.name == layer.name {
+                last_rule.rules.0.extend(layer.rules.0.drain(..));
+                last_rule.minify(context, parent_is_unused)?;
+                continue;
+              }
             }
           }
           if layer.minify(context, parent_is_unused)? {
+            // An empty named layer still establishes the layer order, so keep it as a statement
+            // unless the layer has already been declared.
+            if let Some(name) = layer.name.take() {
+              let declared = rules.iter().any(|rule| match rule {
+                CssRule::LayerStatement(statement) => statement.names.contains(&name),
+                CssRule::LayerBlock(block) => block.name.as_ref() == Some(&name),
+                _ => false,
+              });
+              if !declared {
+                if let Some(CssRule::LayerStatement(last_rule)) = rules.last_mut() {
+                  last_rule.names.push(name);
+                } else {
+                  rules.push(CssRule::LayerStatement(LayerStatementRule {
+                    names: vec![name],
+                    loc: layer.loc,
+                  }));
+                }
+              }
+            }
             continue;
           }
         }
         CssRule::MozDocument(document) => document.minify(context)?,
+        CssRule::FontFace(font_face) => font_face.minify(context),
+        CssRule::Page(page) => page.minify(context),
         CssRule::Style(style) => {
           if parent_is_unused || style.minify(context, parent_is_unused)? {
             continue;
@@ -370,9 +432,11 @@ impl<'i> CssRuleList<'i> {
             }
           }
 
-          // Attempt to merge the new rule with the last rule we added.
+          // Attempt to merge the new rule with the last rule we added. Comments in between
+          // are skipped, and stay in place.
           let mut merged = false;
-          if let Some(CssRule::Style(last_style_rule)) = rules.last_mut() {
+          let last_rule = rules.iter_mut().rev().find(|rule| !matches!(rule, CssRule::Comment(..)));
+          if let Some(CssRule::Style(last_style_rule)) = last_rule {
             if merge_style_rules(style, last_style_rule, context) {
               // If that was successful, then the last rule has been updated to include the
               // selectors/declarations of the new rule. This might mean that we can merge it
@@ -396,6 +460,10 @@ impl<'i> CssRuleList<'i> {
 
           let supports = context.handler_context.get_supports_rules(&style);
           let logical = context.handler_context.get_logical_rules(&style);
+          if !merged && supports.is_empty() && logical.is_empty() {
+            merged = merge_with_earlier_rule(style, &mut rules, context);
+          }
+
           if !merged && !style.is_empty() {
             rules.push(rule);
           }
@@ -403,7 +471,19 @@ impl<'i> CssRuleList<'i> {
           if !logical.is_empty() {
             let mut logical = CssRuleList(logical);
             logical.minify(context, parent_is_unused)?;
-            rules.extend(logical.0)
+            for mut rule in logical.0 {
+              // Without :dir() selectors, the left-to-right rule can usually be merged with the original.
+              if context.handler_context.logical_fallback != LogicalFallback::Dir {
+                if let (CssRule::Style(style), Some(CssRule::Style(last_style_rule))) =
+                  (&mut rule, rules.last_mut())
+                {
+                  if merge_style_rules(style, last_style_rule, context) {
+                    continue;
+                  }
+                }
+              }
+              rules.push(rule);
+            }
           }
 
           rules.extend(supports);
@@ -470,6 +550,10 @@ fn merge_style_rules<'i>(
       .declarations
       .important_declarations
       .extend(style.declarations.important_declarations.drain(..));
+    last_style_rule
+      .declarations
+      .comments
+      .extend(style.declarations.comments.drain(..));
     last_style_rule
       .declarations
       .minify(context.handler, context.important_handler, context.handler_context);
@@ -504,6 +588,115 @@ fn merge_style_rules<'i>(
   false
 }
 
+/// Attempts to merge a style rule into an earlier, non-adjacent style rule. This moves the
+/// declarations of the new rule earlier in the cascade, so it is only done when every rule in
+/// between is a style rule that declares no property overlapping with those of the new rule.
+/// Other rules, such as `@media`, are never merged across. At most [MAX_EARLIER_RULES](MAX_EARLIER_RULES)
+/// earlier rules are checked, so that minification stays linear in the number of rules.
+fn merge_with_earlier_rule<'i>(
+  style: &mut StyleRule<'i>,
+  rules: &mut Vec<CssRule<'i>>,
+  context: &mut MinifyContext<'_, 'i>,
+) -> bool {
+  if !style.rules.0.is_empty() {
+    return false;
+  }
+
+  // The adjacent rule has already been tried, so it is only checked for conflicts.
+  let mut checked = 0;
+  for rule in rules.iter_mut().rev() {
+    match rule {
+      CssRule::Comment(..) => continue,
+      CssRule::Style(prev) => {
+        if checked > 0 && merge_style_rules(style, prev, context) {
+          return true;
+        }
+
+        if !prev.rules.0.is_empty() || declarations_overlap(&prev.declarations, &style.declarations) {
+          return false;
+        }
+      }
+      _ => return false,
+    }
+
+    checked += 1;
+    if checked == MAX_EARLIER_RULES {
+      break;
+    }
+  }
+
+  false
+}
+
+/// The maximum number of earlier style rules checked by [merge_with_earlier_rule](merge_with_earlier_rule).
+const MAX_EARLIER_RULES: usize = 16;
+
+fn declarations_overlap(a: &DeclarationBlock, b: &DeclarationBlock) -> bool {
+  b.declarations.iter().chain(b.important_declarations.iter()).any(|b| {
+    let b = b.property_id();
+    a.declarations
+      .iter()
+      .chain(a.important_declarations.iter())
+      .any(|a| properties_overlap(&a.property_id(), &b))
+  })
+}
+
+/// Returns whether two properties may affect the same computed value. This is conservative:
+/// properties with a common name prefix, and logical properties (which map onto physical ones),
+/// are always considered to overlap.
+fn properties_overlap(a: &PropertyId, b: &PropertyId) -> bool {
+  let (a_name, b_name) = (a.name(), b.name());
+  if a_name.starts_with("--") || b_name.starts_with("--") {
+    return a_name == b_name;
+  }
+
+  if a_name == "all" || b_name == "all" || is_logical_property(a_name) || is_logical_property(b_name) {
+    return true;
+  }
+
+  if a_name.split('-').next() == b_name.split('-').next() {
+    return true;
+  }
+
+  // Compare the properties and all of their longhands, treating aliases as the same property.
+  any_property(a, &mut |x| {
+    any_property(b, &mut |y| unaliased_name(x) == unaliased_name(y))
+  })
+}
+
+/// Returns whether the predicate matches the given property, or any of its longhands (recursively).
+fn any_property(property_id: &PropertyId, f: &mut dyn FnMut(&PropertyId) -> bool) -> bool {
+  if f(property_id) {
+    return true;
+  }
+
+  if let Some(longhands) = property_id.longhands() {
+    return longhands.iter().any(|longhand| any_property(longhand, f));
+  }
+
+  false
+}
+
+/// Returns the name of the property that a legacy alias maps to.
+fn unaliased_name<'a>(property_id: &'a PropertyId) -> &'a str {
+  match property_id {
+    PropertyId::WordWrap => "overflow-wrap",
+    PropertyId::GridRowGap => "row-gap",
+    PropertyId::GridColumnGap => "column-gap",
+    PropertyId::GridGap => "gap",
+    _ => property_id.name(),
+  }
+}
+
+fn is_logical_property(name: &str) -> bool {
+  name.contains("inline")
+    || name.contains("block")
+    || name.ends_with("-start")
+    || name.ends_with("-end")
+    || name.contains("-start-")
+    || name.contains("-end-")
+}
+
 impl<'i> ToCss for CssRuleList<'i> {
   fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
   where
@@ -524,12 +717,20 @@ impl<'a, 'i> ToCssWithContext<'a, 'i> for CssRuleList<'i> {
   {
     let mut first = true;
     let mut last_without_block = false;
+    let mut last_comment = false;
 
     for rule in &self.0 {
       if let CssRule::Ignored = &rule {
         continue;
       }
 
+      // Only license comments are preserved when minifying.
+      if let CssRule::Comment(comment) = &rule {
+        if dest.minify && !comment.starts_with('!') {
+          continue;
+        }
+      }
+
       // Skip @import rules if collecting dependencies.
       if let CssRule::Import(rule) = &rule {
         if dest.remove_imports {
@@ -550,6 +751,7 @@ impl<'a, 'i> ToCssWithContext<'a, 'i> for CssRuleList<'i> {
         first = false;
       } else {
         if !dest.minify
+          && !last_comment
           && !(last_without_block
             && matches!(
               rule,
@@ -565,6 +767,7 @@ impl<'a, 'i> ToCssWithContext<'a, 'i> for CssRuleList<'i> {
         rule,
         CssRule::Import(..) | CssRule::Namespace(..) | CssRule::LayerStatement(..)
       );
+      last_comment = matches!(rule, CssRule::Comment(..));
     }
 
     Ok(())
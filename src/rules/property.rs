@@ -51,8 +51,12 @@ impl<'i> PropertyRule<'i> {
 
     // `syntax` and `inherits` are always required.
     let parser = decl_parser.parser;
-    let syntax = parser.syntax.ok_or(input.new_custom_error(ParserError::AtRuleBodyInvalid))?;
-    let inherits = parser.inherits.ok_or(input.new_custom_error(ParserError::AtRuleBodyInvalid))?;
+    let syntax = parser
+      .syntax
+      .ok_or(input.new_custom_error(ParserError::MissingDescriptor("syntax")))?;
+    let inherits = parser
+      .inherits
+      .ok_or(input.new_custom_error(ParserError::MissingDescriptor("inherits")))?;
 
     // `initial-value` is required unless the syntax is a universal definition.
     let initial_value = match syntax {
@@ -67,7 +71,7 @@ impl<'i> PropertyRule<'i> {
       _ => {
         let val = parser
           .initial_value
-          .ok_or(input.new_custom_error(ParserError::AtRuleBodyInvalid))?;
+          .ok_or(input.new_custom_error(ParserError::MissingDescriptor("initial-value")))?;
         let mut input = ParserInput::new(val);
         let mut parser = Parser::new(&mut input);
         Some(syntax.parse_value(&mut parser)?)
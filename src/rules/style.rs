@@ -8,10 +8,10 @@ use crate::compat::Feature;
 use crate::context::DeclarationContext;
 use crate::declaration::DeclarationBlock;
 use crate::error::ParserError;
-use crate::error::{MinifyError, PrinterError, PrinterErrorKind};
-use crate::printer::Printer;
+use crate::error::{MinifyError, MinifyWarning, MinifyWarningKind, PrinterError, PrinterErrorKind};
+use crate::printer::{Printer, PrinterOptions};
 use crate::rules::{CssRuleList, StyleContext, ToCssWithContext};
-use crate::selector::{is_compatible, is_unused, Selectors};
+use crate::selector::{has_nth_child_of, has_relational, is_compatible, is_unused, Selectors};
 use crate::targets::Browsers;
 use crate::traits::ToCss;
 use crate::vendor_prefix::VendorPrefix;
@@ -65,6 +65,38 @@ impl<'i> StyleRule<'i> {
       }
     }
 
+    // :has() and :nth-child(An+B of S) cannot be polyfilled, so the rule is kept as is,
+    // but warn if the targets don't support them.
+    if let (Some(targets), Some(warnings)) = (context.targets, &mut context.warnings) {
+      if !Feature::CssHas.is_compatible(*targets) && has_relational(&mut self.selectors.0.iter()) {
+        let mut selector = String::new();
+        let mut printer = Printer::new(&mut selector, PrinterOptions::default());
+        if self.selectors.to_css_with_context(&mut printer, None).is_ok() {
+          warnings.push(MinifyWarning {
+            kind: MinifyWarningKind::UnsupportedSelector {
+              selector,
+              reason: "the :has() pseudo-class is not supported by the browser targets".into(),
+            },
+            loc: self.loc,
+          });
+        }
+      }
+
+      if !Feature::CssNthChildOf.is_compatible(*targets) && has_nth_child_of(&mut self.selectors.0.iter()) {
+        let mut selector = String::new();
+        let mut printer = Printer::new(&mut selector, PrinterOptions::default());
+        if self.selectors.to_css_with_context(&mut printer, None).is_ok() {
+          warnings.push(MinifyWarning {
+            kind: MinifyWarningKind::UnsupportedSelector {
+              selector,
+              reason: "the `of` clause of :nth-child() is not supported by the browser targets".into(),
+            },
+            loc: self.loc,
+          });
+        }
+      }
+    }
+
     context.handler_context.context = DeclarationContext::StyleRule;
     self
       .declarations
@@ -219,6 +251,7 @@ impl<'a, 'i> StyleRule<'i> {
       dest.whitespace()?;
       dest.write_char('{')?;
       dest.indent();
+      self.declarations.comments_to_css(dest)?;
 
       let mut i = 0;
       macro_rules! write {
@@ -286,7 +319,7 @@ impl<'a, 'i> StyleRule<'i> {
       self.rules.to_css_with_context(
         dest,
         Some(&StyleContext {
-          rule: self,
+          selectors: &self.selectors,
           parent: context,
         }),
       )?;
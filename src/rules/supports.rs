@@ -2,12 +2,18 @@
 
 use super::Location;
 use super::{CssRuleList, MinifyContext};
+use crate::compat::Feature;
 use crate::error::{MinifyError, ParserError, PrinterError};
 use crate::printer::Printer;
 use crate::rules::{StyleContext, ToCssWithContext};
+use crate::selector::{is_compatible, SelectorParser};
+use crate::stylesheet::ParserOptions;
+use crate::targets::Browsers;
 use crate::traits::{Parse, ToCss};
 use crate::values::string::CowArcStr;
 use cssparser::*;
+use parcel_selectors::{parser::NestingRequirement, SelectorList};
+use std::collections::HashMap;
 
 /// A [@supports](https://drafts.csswg.org/css-conditional-3/#at-supports) rule.
 #[derive(Debug, PartialEq, Clone)]
@@ -104,6 +110,146 @@ impl<'i> SupportsCondition<'i> {
       *self = SupportsCondition::Parens(Box::new(SupportsCondition::Or(vec![self.clone(), b.clone()])))
     }
   }
+
+  /// Evaluates the condition against the given browser targets.
+  ///
+  /// Returns `Some(true)` if the condition is known to be true in all targets, `Some(false)` if
+  /// it is known to be false in all targets, and `None` if it varies between targets or cannot
+  /// be determined from the compatibility data.
+  pub(crate) fn evaluate(&self, targets: Browsers) -> Option<bool> {
+    match self {
+      SupportsCondition::Not(condition) => condition.evaluate(targets).map(|v| !v),
+      SupportsCondition::And(conditions) => {
+        let mut result = Some(true);
+        for condition in conditions {
+          match condition.evaluate(targets) {
+            Some(false) => return Some(false),
+            None => result = None,
+            Some(true) => {}
+          }
+        }
+        result
+      }
+      SupportsCondition::Or(conditions) => {
+        let mut result = Some(false);
+        for condition in conditions {
+          match condition.evaluate(targets) {
+            Some(true) => return Some(true),
+            None => result = None,
+            Some(false) => {}
+          }
+        }
+        result
+      }
+      SupportsCondition::Parens(condition) => condition.evaluate(targets),
+      SupportsCondition::Declaration(decl) => {
+        let feature = declaration_feature(decl)?;
+        if feature.is_compatible(targets) {
+          Some(true)
+        } else if !feature.is_partially_compatible(targets) {
+          Some(false)
+        } else {
+          None
+        }
+      }
+      SupportsCondition::Selector(sel) => {
+        let selector_parser = SelectorParser {
+          default_namespace: &None,
+          namespace_prefixes: &HashMap::new(),
+          is_nesting_allowed: false,
+          options: &ParserOptions::default(),
+        };
+        let mut input = ParserInput::new(sel);
+        let mut parser = Parser::new(&mut input);
+        let selectors = SelectorList::parse(&selector_parser, &mut parser, NestingRequirement::None).ok()?;
+        // Unsupported selectors may be unknown to the compatibility data rather than
+        // unsupported, so only a positive result is trusted here.
+        if is_compatible(&selectors, Some(targets)) {
+          Some(true)
+        } else {
+          None
+        }
+      }
+      SupportsCondition::Unknown(_) => None,
+    }
+  }
+
+  /// Returns the property name and value of a declaration within the condition that none
+  /// of the targets support, if there is one.
+  pub(crate) fn unsupported_declaration(&self, targets: Browsers) -> Option<(&str, &str)> {
+    match self {
+      SupportsCondition::And(conditions) | SupportsCondition::Or(conditions) => conditions
+        .iter()
+        .find_map(|condition| condition.unsupported_declaration(targets)),
+      SupportsCondition::Parens(condition) => condition.unsupported_declaration(targets),
+      SupportsCondition::Declaration(decl) => {
+        let feature = declaration_feature(decl)?;
+        if feature.is_partially_compatible(targets) {
+          return None;
+        }
+        let (name, value) = decl.split_once(':')?;
+        Some((name.trim(), value.trim()))
+      }
+      _ => None,
+    }
+  }
+}
+
+/// Returns the compatibility feature that determines whether a `@supports` declaration
+/// condition is supported, for the declarations we have data for.
+fn declaration_feature(decl: &str) -> Option<Feature> {
+  let (name, value) = decl.split_once(':')?;
+  let name = name.trim();
+  let value = value.trim();
+  let feature = match_ignore_ascii_case! { name,
+    "place-content" => Feature::PlaceContent,
+    "place-items" => Feature::PlaceItems,
+    "place-self" => Feature::PlaceSelf,
+    "margin-block-start" | "margin-block-end" | "margin-inline-start" | "margin-inline-end" => {
+      Feature::LogicalMargin
+    },
+    "margin-block" | "margin-inline" => Feature::LogicalMarginShorthand,
+    "padding-block-start" | "padding-block-end" | "padding-inline-start" | "padding-inline-end" => {
+      Feature::LogicalPadding
+    },
+    "padding-block" | "padding-inline" => Feature::LogicalPaddingShorthand,
+    "inset-block-start" | "inset-block-end" | "inset-inline-start" | "inset-inline-end" |
+    "inset-block" | "inset-inline" | "inset" => Feature::LogicalInset,
+    "text-align" => {
+      match_ignore_ascii_case! { value,
+        "start" | "end" => Feature::LogicalTextAlign,
+        _ => return None
+      }
+    },
+    "display" => {
+      match_ignore_ascii_case! { value,
+        "grid" | "inline-grid" => Feature::DisplayGrid,
+        "flex" | "inline-flex" => Feature::DisplayFlex,
+        "contents" => Feature::DisplayContents,
+        _ => return None
+      }
+    },
+    "overflow" => {
+      if value.split_whitespace().count() == 2 {
+        Feature::OverflowShorthand
+      } else {
+        return None;
+      }
+    },
+    "color" | "background-color" => {
+      // Only the color function matters here, since these properties are supported everywhere.
+      let (function, _) = value.split_once('(')?;
+      match_ignore_ascii_case! { function,
+        "lab" | "lch" => Feature::LabColors,
+        "oklab" | "oklch" => Feature::OklabColors,
+        "color" => Feature::ColorFunction,
+        _ => return None
+      }
+    },
+    _ => return None
+  };
+
+  Some(feature)
 }
 
 impl<'i> Parse<'i> for SupportsCondition<'i> {
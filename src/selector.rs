@@ -769,14 +769,27 @@ impl<'i> ToCss for PseudoElement<'i> {
       }};
     }
 
+    // CSS2 pseudo elements support a single colon syntax in addition
+    // to the more correct double colon for other pseudo elements.
+    // The double colon is used when all targets are known to support it.
+    // Otherwise, we fall back to the single colon because it's supported
+    // everywhere. IE always gets the single colon form.
+    macro_rules! write_legacy {
+      ($feature: expr, $val: expr) => {{
+        if let Some(targets) = dest.targets {
+          if targets.ie.is_none() && $feature.is_compatible(targets) {
+            dest.write_char(':')?;
+          }
+        }
+        dest.write_str($val)
+      }};
+    }
+
     match &self {
-      // CSS2 pseudo elements support a single colon syntax in addition
-      // to the more correct double colon for other pseudo elements.
-      // We use that here because it's supported everywhere and is shorter.
-      After => dest.write_str(":after"),
-      Before => dest.write_str(":before"),
-      FirstLine => dest.write_str(":first-line"),
-      FirstLetter => dest.write_str(":first-letter"),
+      After => write_legacy!(Feature::CssGencontent, ":after"),
+      Before => write_legacy!(Feature::CssGencontent, ":before"),
+      FirstLine => write_legacy!(Feature::CssFirstLine, ":first-line"),
+      FirstLetter => write_legacy!(Feature::CssFirstLetter, ":first-letter"),
       Marker => dest.write_str("::marker"),
       Selection(prefix) => write_prefixed!(prefix, "selection"),
       Cue => dest.write_str("::cue"),
@@ -908,10 +921,158 @@ impl<'a, 'i> ToCssWithContext<'a, 'i> for SelectorList<'i, Selectors> {
   where
     W: fmt::Write,
   {
-    serialize_selector_list(self.0.iter(), dest, context, false)
+    if let Some(ctx) = context {
+      if should_expand_nesting(self, ctx, dest) {
+        return dest.with_selector_minification(|dest| serialize_expanded_nesting(self, dest, ctx));
+      }
+    }
+
+    if !should_wrap_selector_list(self, dest, context)? {
+      return dest.with_selector_minification(|dest| serialize_selector_list(self.0.iter(), dest, context, false));
+    }
+
+    // Write each selector on its own line, aligned with the first.
+    let mut first = true;
+    for selector in self.0.iter() {
+      if !first {
+        dest.write_char(',')?;
+        dest.newline()?;
+      }
+      first = false;
+      dest.with_selector_minification(|dest| serialize_selector(selector, dest, context, false))?;
+    }
+    Ok(())
+  }
+}
+
+/// Returns whether nested selectors should be expanded into a list with each combination of
+/// parent selectors, because the targets do not support the `:is()` selector.
+///
+/// Selectors that reference their parent more than once, e.g. `& + &`, are not expanded,
+/// since each `&` would need to be replaced independently. They fall back to `:is()`.
+fn should_expand_nesting<W>(
+  selectors: &SelectorList<Selectors>,
+  context: &StyleContext,
+  dest: &Printer<W>,
+) -> bool {
+  match dest.targets {
+    Some(targets) if !Feature::CssMatchesPseudo.is_compatible(targets) => {}
+    _ => return false,
+  }
+
+  if selectors.0.iter().any(|selector| nesting_count(selector) > 1) {
+    return false;
+  }
+
+  let mut has_multiple_parents = false;
+  let mut context = Some(context);
+  while let Some(ctx) = context {
+    if ctx.selectors.0.len() > 1 {
+      has_multiple_parents = true;
+    }
+    if ctx.parent.is_some() && ctx.selectors.0.iter().any(|selector| nesting_count(selector) > 1) {
+      return false;
+    }
+    context = ctx.parent;
+  }
+
+  has_multiple_parents
+}
+
+/// Returns the number of `&` nesting selectors in the selector, including within pseudo-classes.
+fn nesting_count(selector: &Selector<Selectors>) -> usize {
+  selector
+    .iter_raw_match_order()
+    .map(|component| match component {
+      Component::Nesting => 1,
+      Component::Is(list)
+      | Component::Where(list)
+      | Component::Negation(list)
+      | Component::Any(_, list)
+      | Component::Has(list)
+      | Component::NthChildOf(_, _, list)
+      | Component::NthLastChildOf(_, _, list) => list.iter().map(nesting_count).sum(),
+      _ => 0,
+    })
+    .sum()
+}
+
+/// Writes a nested selector list, substituting each combination of parent selectors for `&`.
+/// For example, `.a, .b { & .c {} }` produces `.a .c, .b .c`.
+fn serialize_expanded_nesting<'a, 'i, W>(
+  selectors: &SelectorList<'i, Selectors>,
+  dest: &mut Printer<W>,
+  context: &StyleContext<'a, 'i>,
+) -> Result<(), PrinterError>
+where
+  W: fmt::Write,
+{
+  let mut first = true;
+  for_each_nesting_context(context, &mut |ctx| {
+    for selector in selectors.0.iter() {
+      if !first {
+        dest.delim(',', false)?;
+      }
+      first = false;
+      serialize_selector(selector, dest, Some(ctx), false)?;
+    }
+    Ok(())
+  })
+}
+
+/// Calls the given function with each combination of single parent selectors in the context chain.
+fn for_each_nesting_context<'a, 'i>(
+  context: &StyleContext<'a, 'i>,
+  f: &mut dyn FnMut(&StyleContext<'_, 'i>) -> Result<(), PrinterError>,
+) -> Result<(), PrinterError> {
+  let mut each_selector = |parent: Option<&StyleContext<'_, 'i>>| -> Result<(), PrinterError> {
+    for selector in context.selectors.0.iter() {
+      let selectors = SelectorList(smallvec::smallvec![selector.clone()]);
+      f(&StyleContext {
+        selectors: &selectors,
+        parent,
+      })?;
+    }
+    Ok(())
+  };
+
+  match context.parent {
+    Some(parent) => for_each_nesting_context(parent, &mut |parent| each_selector(Some(parent))),
+    None => each_selector(None),
   }
 }
 
+/// Returns whether the selector list would exceed the `line_width` printer option if written on one line.
+fn should_wrap_selector_list<'a, 'i, W>(
+  selectors: &SelectorList<'i, Selectors>,
+  dest: &Printer<W>,
+  context: Option<&StyleContext<'a, 'i>>,
+) -> Result<bool, PrinterError>
+where
+  W: fmt::Write,
+{
+  let line_width = match dest.line_width {
+    Some(line_width) if !dest.minify && selectors.0.len() > 1 => line_width,
+    _ => return Ok(false),
+  };
+
+  // Measure the selector list by writing it to a temporary string.
+  // Note that CSS module class names are measured before hashing.
+  let mut s = String::new();
+  let mut printer = Printer::new(
+    &mut s,
+    PrinterOptions {
+      targets: dest.targets,
+      minify_selectors: Some(dest.minify_selectors),
+      pseudo_classes: dest.pseudo_classes.clone(),
+      ..PrinterOptions::default()
+    },
+  );
+  printer.vendor_prefix = dest.vendor_prefix;
+  serialize_selector_list(selectors.0.iter(), &mut printer, context, false)?;
+  Ok(dest.col() + s.len() as u32 > line_width)
+}
+
 impl ToCss for Combinator {
   fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
   where
@@ -1016,8 +1177,9 @@ where
         // selector contains the pseudo element selector as well
         // -- Combinator::PseudoElement, just like
         // Combinator::SlotAssignment, don't exist in the
-        // spec.
-        (Some(Combinator::PseudoElement), _) | (Some(Combinator::SlotAssignment), _) => (),
+        // spec. A universal selector followed by a pseudo element
+        // is still serialized as is below (e.g. `*::before`).
+        (Some(Combinator::SlotAssignment), _) => (),
         (_, &Component::ExplicitUniversalType) => {
           // Iterate over everything so we serialize the namespace
           // too.
@@ -1075,12 +1237,13 @@ where
       }
 
       for simple in iter {
+        // The universal selector is only elided when minifying selectors, e.g. `*.foo` -> `.foo`.
         if let Component::ExplicitUniversalType = *simple {
           // Can't have a namespace followed by a pseudo-element
           // selector followed by a universal selector in the same
           // compound selector, so we don't have to worry about the
           // real namespace being in a different `compound`.
-          if can_elide_namespace {
+          if can_elide_namespace && dest.minify {
             continue;
           }
         }
@@ -1133,18 +1296,16 @@ impl<'a, 'i> ToCssWithContext<'a, 'i> for Component<'i, Selectors> {
         cssparser::ToCss::to_css(operator, dest)?;
 
         if dest.minify {
-          // Serialize as both an identifier and a string and choose the shorter one.
+          // Omit the quotes if the value is a valid identifier that doesn't need escaping.
+          // Otherwise (e.g. empty, leading digit, or whitespace), keep it as a string.
           let mut id = String::new();
           value.write_identifier(&mut id)?;
 
-          let mut s = String::new();
-          value.to_css(&mut s)?;
-
-          if id.len() > 0 && id.len() < s.len() + 2 {
+          if !id.is_empty() && id == *value.0 {
             dest.write_str(&id)?;
           } else {
             dest.write_char('"')?;
-            dest.write_str(&s)?;
+            value.to_css(dest)?;
             dest.write_char('"')?;
           }
         } else {
@@ -1190,6 +1351,17 @@ impl<'a, 'i> ToCssWithContext<'a, 'i> for Component<'i, Selectors> {
         serialize_selector_list(list.iter(), dest, context, true)?;
         dest.write_str(")")
       }
+      NthChildOf(a, b, ref list) | NthLastChildOf(a, b, ref list) => {
+        match *self {
+          NthChildOf(..) => dest.write_str(":nth-child(")?,
+          NthLastChildOf(..) => dest.write_str(":nth-last-child(")?,
+          _ => unreachable!(),
+        }
+        parcel_selectors::parser::write_affine(dest, *a, *b)?;
+        dest.write_str(" of ")?;
+        serialize_selector_list(list.iter(), dest, context, false)?;
+        dest.write_char(')')
+      }
       NonTSPseudoClass(pseudo) => pseudo.to_css_with_context(dest, context),
       PseudoElement(pseudo) => pseudo.to_css(dest),
       Nesting => serialize_nesting(dest, context, false),
@@ -1222,13 +1394,13 @@ where
     // Otherwise, use an :is() pseudo class.
     // Type selectors are only allowed at the start of a compound selector,
     // so use :is() if that is not the case.
-    if ctx.rule.selectors.0.len() == 1
-      && (first || (!has_type_selector(&ctx.rule.selectors.0[0]) && is_simple(&ctx.rule.selectors.0[0])))
+    if ctx.selectors.0.len() == 1
+      && (first || (!has_type_selector(&ctx.selectors.0[0]) && is_simple(&ctx.selectors.0[0])))
     {
-      ctx.rule.selectors.0.first().unwrap().to_css_with_context(dest, ctx.parent)
+      ctx.selectors.0.first().unwrap().to_css_with_context(dest, ctx.parent)
     } else {
       dest.write_str(":is(")?;
-      serialize_selector_list(ctx.rule.selectors.0.iter(), dest, ctx.parent, false)?;
+      serialize_selector_list(ctx.selectors.0.iter(), dest, ctx.parent, false)?;
       dest.write_char(')')
     }
   } else {
@@ -1395,6 +1567,8 @@ pub fn is_compatible(selectors: &SelectorList<Selectors>, targets: Option<Browse
         | Component::OnlyOfType
         | Component::Root => Feature::CssSel3,
 
+        Component::NthChildOf(..) | Component::NthLastChildOf(..) => Feature::CssNthChildOf,
+
         Component::Is(_) | Component::Nesting => Feature::CssMatchesPseudo,
         Component::Any(..) => Feature::AnyPseudo,
         Component::Has(_) => Feature::CssHas,
@@ -1547,6 +1721,17 @@ pub fn get_prefix(selectors: &SelectorList<Selectors>) -> VendorPrefix {
     }
   }
 
+  // :is() and :where() in any compound may need to be expanded by downlevel_selectors.
+  if prefix.is_empty()
+    && selectors.0.iter().any(|selector| {
+      selector
+        .iter_raw_match_order()
+        .any(|component| matches!(component, Component::Is(..) | Component::Where(..)))
+    })
+  {
+    return VendorPrefix::None;
+  }
+
   prefix
 }
 
@@ -1558,6 +1743,18 @@ const RTL_LANGS: &[&str] = &[
 /// Downlevels the given selectors to be compatible with the given browser targets.
 /// Returns the necessary vendor prefixes.
 pub fn downlevel_selectors(selectors: &mut SelectorList<Selectors>, targets: Browsers) -> VendorPrefix {
+  let mut necessary_prefixes = downlevel_selector_components(selectors, targets);
+  if !Feature::CssMatchesPseudo.is_compatible(targets) {
+    if let Some(expanded) = expand_is_selectors(&selectors.0, targets) {
+      selectors.0 = expanded.into();
+      necessary_prefixes = downlevel_selector_components(selectors, targets);
+    }
+  }
+
+  necessary_prefixes
+}
+
+fn downlevel_selector_components(selectors: &mut SelectorList<Selectors>, targets: Browsers) -> VendorPrefix {
   let mut necessary_prefixes = VendorPrefix::empty();
   for selector in &mut selectors.0 {
     for component in selector.iter_mut_raw_match_order() {
@@ -1609,6 +1806,201 @@ fn downlevel_component<'i>(component: &mut Component<'i, Selectors>, targets: Br
   }
 }
 
+/// Expands :is() and :where() into a list of selectors, e.g. `:is(h1, h2) .title` becomes
+/// `h1 .title, h2 .title`. This is only done when the arguments cannot be wrapped in :-webkit-any
+/// or :-moz-any for the given targets, and when it does not change the specificity of the selector.
+/// Returns `None` if nothing was expanded.
+fn expand_is_selectors<'i>(
+  selectors: &[Selector<'i, Selectors>],
+  targets: Browsers,
+) -> Option<Vec<Selector<'i, Selectors>>> {
+  let mut expanded_any = false;
+  let mut result = Vec::new();
+  for selector in selectors {
+    let components = parse_order_components(selector);
+    match expand_is_components(&components, targets) {
+      Some(expanded) => {
+        expanded_any = true;
+        result.extend(expanded.into_iter().map(|components| selector.with_components(components)));
+      }
+      None => result.push(selector.clone()),
+    }
+  }
+
+  if expanded_any {
+    Some(result)
+  } else {
+    None
+  }
+}
+
+/// Returns the components of a selector in parse order, including combinators.
+fn parse_order_components<'i>(selector: &Selector<'i, Selectors>) -> Vec<Component<'i, Selectors>> {
+  // Compounds are stored right-to-left, but the components within each compound are left-to-right.
+  let raw = selector.iter_raw_match_order().as_slice();
+  let compounds: Vec<_> = raw.split(|component| component.is_combinator()).collect();
+  let combinators: Vec<_> = raw.iter().filter(|component| component.is_combinator()).collect();
+
+  let mut components = Vec::with_capacity(raw.len());
+  for (i, compound) in compounds.iter().enumerate().rev() {
+    components.extend(compound.iter().cloned());
+    if i > 0 {
+      components.push(combinators[i - 1].clone());
+    }
+  }
+
+  components
+}
+
+fn expand_is_components<'i>(
+  components: &[Component<'i, Selectors>],
+  targets: Browsers,
+) -> Option<Vec<Vec<Component<'i, Selectors>>>> {
+  for (index, component) in components.iter().enumerate() {
+    let selectors = match component {
+      Component::Is(selectors) | Component::Where(selectors) => selectors,
+      _ => continue,
+    };
+
+    if !can_expand_is(component, selectors, targets) {
+      continue;
+    }
+
+    // If this :is() cannot be expanded, it is kept as is, and the following ones are tried.
+    if let Some(result) = expand_is_component(components, index, selectors, targets) {
+      return Some(result);
+    }
+  }
+
+  None
+}
+
+/// Expands the :is() or :where() at the given index, and any remaining or nested ones.
+/// Returns `None` if one of its arguments cannot replace it in the surrounding compound selector.
+fn expand_is_component<'i>(
+  components: &[Component<'i, Selectors>],
+  index: usize,
+  selectors: &[Selector<'i, Selectors>],
+  targets: Browsers,
+) -> Option<Vec<Vec<Component<'i, Selectors>>>> {
+  // Find the bounds of the compound selector containing the :is().
+  let start = components[..index].iter().rposition(|c| c.is_combinator()).map_or(0, |i| i + 1);
+  let end = components[index..]
+    .iter()
+    .position(|c| c.is_combinator())
+    .map_or(components.len(), |i| index + i);
+  let compound = &components[start..end];
+
+  let mut result = Vec::new();
+  for selector in selectors.iter() {
+    let arg = parse_order_components(selector);
+    let mut expanded = Vec::with_capacity(components.len() + arg.len());
+    expanded.extend(components[..start].iter().cloned());
+    if selector.has_combinator() {
+      // A complex selector can only replace an entire compound selector.
+      if compound.len() > 1 {
+        return None;
+      }
+      expanded.extend(arg);
+    } else {
+      // Type selectors must come first in a compound selector, and there can only be one.
+      let type_len = arg.iter().take_while(|c| is_type_or_namespace(c)).count();
+      if type_len > 0
+        && compound
+          .iter()
+          .any(|c| is_type_or_namespace(c) || matches!(c, Component::Nesting))
+      {
+        return None;
+      }
+      expanded.extend(arg[..type_len].iter().cloned());
+      expanded.extend(components[start..index].iter().cloned());
+      expanded.extend(arg[type_len..].iter().cloned());
+      expanded.extend(components[index + 1..end].iter().cloned());
+    }
+    expanded.extend(components[end..].iter().cloned());
+
+    // Expand any remaining or nested :is() selectors.
+    match expand_is_components(&expanded, targets) {
+      Some(nested) => result.extend(nested),
+      None => result.push(expanded),
+    }
+  }
+
+  Some(result)
+}
+
+fn can_expand_is<'i>(
+  component: &Component<'i, Selectors>,
+  selectors: &[Selector<'i, Selectors>],
+  targets: Browsers,
+) -> bool {
+  let first = match selectors.first() {
+    Some(first) => first,
+    None => return false,
+  };
+
+  // Nesting selectors and pseudo elements cannot be moved out of :is().
+  if selectors.iter().any(|selector| {
+    selector.has_pseudo_element() || selector.iter_raw_match_order().any(|c| matches!(c, Component::Nesting))
+  }) {
+    return false;
+  }
+
+  match component {
+    // :is() takes the specificity of its most specific argument, so all arguments must have the same
+    // specificity. If all arguments are simple, prefer :-webkit-any or :-moz-any where supported.
+    Component::Is(..) => {
+      selectors.iter().all(|selector| selector.specificity() == first.specificity())
+        && (selectors.iter().any(|selector| selector.has_combinator())
+          || crate::prefixes::Feature::AnyPseudo.prefixes_for(targets) == VendorPrefix::None)
+    }
+    // :where() always has zero specificity.
+    Component::Where(..) => selectors.iter().all(|selector| selector.specificity() == 0),
+    _ => false,
+  }
+}
+
+fn is_type_or_namespace(component: &Component<Selectors>) -> bool {
+  matches!(
+    component,
+    Component::LocalName(..)
+      | Component::ExplicitUniversalType
+      | Component::ExplicitAnyNamespace
+      | Component::ExplicitNoNamespace
+      | Component::DefaultNamespace(..)
+      | Component::Namespace(..)
+  )
+}
+
+/// Returns a copy of the selector list that only matches elements with `dir=rtl`, or their descendants.
+/// The attribute selector is wrapped in `:where()` so that the specificity is unchanged.
+pub(crate) fn scope_to_rtl<'i>(selectors: &SelectorList<'i, Selectors>) -> SelectorList<'i, Selectors> {
+  let dir = || {
+    Component::Where(
+      vec![Selector::from_vec2(vec![Component::AttributeInNoNamespace {
+        local_name: SelectorIdent("dir".into()),
+        operator: AttrSelectorOperator::Equal,
+        value: SelectorString("rtl".into()),
+        case_sensitivity: ParsedCaseSensitivity::AsciiCaseInsensitiveIfInHtmlElementInHtmlDocument,
+        never_matches: false,
+      }])]
+      .into_boxed_slice(),
+    )
+  };
+
+  let mut result = SelectorList(smallvec::SmallVec::with_capacity(selectors.0.len() * 2));
+  for selector in &selectors.0 {
+    let mut components = vec![dir(), Component::Combinator(Combinator::Descendant)];
+    components.extend(parse_order_components(selector));
+    result.0.push(selector.with_components(components));
+
+    let mut selector = selector.clone();
+    selector.append(dir());
+    result.0.push(selector);
+  }
+  result
+}
+
 fn lang_list_to_selectors<'i>(langs: &Vec<CowArcStr<'i>>) -> Box<[Selector<'i, Selectors>]> {
   langs
     .iter()
@@ -1674,6 +2066,37 @@ pub fn is_unused(
   })
 }
 
+/// Determines whether any selector in the list contains a `:has()` relational pseudo-class.
+pub(crate) fn has_relational(selectors: &mut std::slice::Iter<Selector<Selectors>>) -> bool {
+  selectors.any(|selector| {
+    selector.iter_raw_match_order().any(|component| match component {
+      Component::Has(_) => true,
+      Component::Is(list)
+      | Component::Where(list)
+      | Component::Negation(list)
+      | Component::Any(_, list)
+      | Component::NthChildOf(_, _, list)
+      | Component::NthLastChildOf(_, _, list) => has_relational(&mut list.iter()),
+      _ => false,
+    })
+  })
+}
+
+/// Returns whether any of the selectors contain `:nth-child()` or `:nth-last-child()` with an `of` clause.
+pub(crate) fn has_nth_child_of(selectors: &mut std::slice::Iter<Selector<Selectors>>) -> bool {
+  selectors.any(|selector| {
+    selector.iter_raw_match_order().any(|component| match component {
+      Component::NthChildOf(..) | Component::NthLastChildOf(..) => true,
+      Component::Is(list)
+      | Component::Where(list)
+      | Component::Negation(list)
+      | Component::Any(_, list)
+      | Component::Has(list) => has_nth_child_of(&mut list.iter()),
+      _ => false,
+    })
+  })
+}
+
 #[cfg(feature = "serde")]
 pub fn serialize_selectors<S>(selectors: &SelectorList<Selectors>, s: S) -> Result<S::Ok, S::Error>
 where
@@ -8,15 +8,25 @@ use crate::context::{DeclarationContext, PropertyHandlerContext};
 use crate::css_modules::{CssModule, CssModuleExports, CssModuleReferences};
 use crate::declaration::{DeclarationBlock, DeclarationHandler};
 use crate::dependencies::Dependency;
-use crate::error::{Error, ErrorLocation, MinifyErrorKind, ParserError, PrinterError, PrinterErrorKind};
-use crate::parser::TopLevelRuleParser;
+use crate::error::{
+  Error, ErrorLocation, MinifyErrorKind, MinifyWarningKind, ParserError, PrinterError, PrinterErrorKind,
+};
+use crate::parser::{leading_comments, parse_charset, TopLevelRuleParser};
 use crate::printer::Printer;
+use crate::properties::animation::AnimationName;
+use crate::properties::font::FontFamily;
+use crate::properties::{Property, PropertyId};
+use crate::rules::font_face::FontFaceProperty;
+use crate::rules::keyframes::KeyframesName;
 use crate::rules::{CssRule, CssRuleList, MinifyContext};
 use crate::targets::Browsers;
 use crate::traits::ToCss;
-use cssparser::{Parser, ParserInput, RuleListParser};
+use crate::visitor::{Visit, Visitor};
+use cssparser::{Parser, ParserInput, RuleListParser, Token};
 use parcel_sourcemap::SourceMap;
 use std::collections::{HashMap, HashSet};
+use std::ops::ControlFlow;
+use std::sync::{Arc, RwLock};
 
 pub use crate::parser::ParserOptions;
 pub use crate::printer::PrinterOptions;
@@ -53,8 +63,8 @@ pub use crate::printer::PseudoClasses;
 /// stylesheet.minify(MinifyOptions::default()).unwrap();
 ///
 /// // Serialize it to a string.
-/// let res = stylesheet.to_css(PrinterOptions::default()).unwrap();
-/// assert_eq!(res.code, ".foo, .bar {\n  color: red;\n}\n");
+/// let (code, _) = stylesheet.to_css_string(PrinterOptions::default()).unwrap();
+/// assert_eq!(code, ".foo, .bar {\n  color: red;\n}\n");
 /// ```
 #[derive(Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -81,6 +91,45 @@ pub struct MinifyOptions {
   /// A list of known unused symbols, including CSS class names,
   /// ids, and `@keyframe` names. The declarations of these will be removed.
   pub unused_symbols: HashSet<String>,
+  /// How logical properties are converted to physical properties for
+  /// browser targets that do not support them.
+  pub logical_fallback: LogicalFallback,
+  /// If provided, a warning is recorded here for each value that is removed because
+  /// none of the targets support it. The output is not affected.
+  pub warnings: Option<Arc<RwLock<Vec<Error<MinifyWarningKind>>>>>,
+  /// Whether to remove `@keyframes` rules that are not referenced by an `animation` or
+  /// `animation-name` declaration anywhere in the style sheet. Nothing is removed if one
+  /// of these declarations contains `var()`, since it could refer to any name.
+  pub remove_unused_keyframes: bool,
+  /// Whether to remove `@font-face` rules whose family is not referenced by a `font` or
+  /// `font-family` declaration anywhere in the style sheet. Nothing is removed if one
+  /// of these declarations contains `var()`, since it could refer to any family.
+  pub remove_unused_font_faces: bool,
+}
+
+/// How logical properties and values (e.g. `margin-inline-start`) are converted to
+/// physical properties for browser targets that do not support them.
+///
+/// In all cases, a `horizontal-tb` writing mode is assumed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LogicalFallback {
+  /// Emit a rule for each direction using the `:dir()` pseudo class,
+  /// which is converted to `:lang()` for browsers that do not support it.
+  Dir,
+  /// Assume a left-to-right direction, and only emit the left-to-right properties.
+  Ltr,
+  /// Assume a left-to-right direction, and also emit the mirrored right-to-left
+  /// properties in a rule that applies to `[dir=rtl]` elements and their descendants.
+  /// Left-to-right properties that are not mirrored are reset to `initial` in that rule.
+  /// The attribute selector is wrapped in `:where()`, so the right-to-left rule has the same
+  /// specificity as the original rule. This requires browsers that support `:where()`.
+  LtrWithRtlAttribute,
+}
+
+impl Default for LogicalFallback {
+  fn default() -> LogicalFallback {
+    LogicalFallback::Dir
+  }
 }
 
 /// A result returned from `to_css`, including the serialize CSS
@@ -113,36 +162,35 @@ impl<'i, 'o> StyleSheet<'i, 'o> {
 
   /// Parse a style sheet from a string.
   pub fn parse(code: &'i str, options: ParserOptions<'o, 'i>) -> Result<Self, Error<ParserError<'i>>> {
-    let mut input = ParserInput::new(&code);
-    let mut parser = Parser::new(&mut input);
-    let rule_list_parser = RuleListParser::new_for_stylesheet(&mut parser, TopLevelRuleParser::new(&options));
-
     let mut rules = vec![];
-    for rule in rule_list_parser {
-      let rule = match rule {
-        Ok((_, CssRule::Ignored)) => continue,
-        Ok((_, rule)) => rule,
-        Err((e, _)) => {
-          if options.error_recovery {
-            options.warn(e);
-            continue;
-          }
-
-          return Err(Error::from(e, options.filename.clone()));
-        }
-      };
-
-      rules.push(rule)
-    }
+    let source_map_url = parse_rule_list(code, &options, |rule| {
+      rules.push(rule);
+      ControlFlow::Continue(())
+    })?;
 
     Ok(StyleSheet {
       sources: vec![options.filename.clone()],
-      source_map_urls: vec![parser.current_source_map_url().map(|s| s.to_owned())],
+      source_map_urls: vec![source_map_url],
       rules: CssRuleList(rules),
       options,
     })
   }
 
+  /// Parses the top-level rules of a style sheet one at a time, without building the whole
+  /// rule list in memory. The callback is called with each rule as it is parsed, and may return
+  /// `ControlFlow::Break` to stop parsing early. Rules nested within at-rules are fully parsed.
+  pub fn parse_rules<F>(
+    code: &'i str,
+    options: &ParserOptions<'o, 'i>,
+    callback: F,
+  ) -> Result<(), Error<ParserError<'i>>>
+  where
+    F: FnMut(CssRule<'i>) -> ControlFlow<()>,
+  {
+    parse_rule_list(code, options, callback)?;
+    Ok(())
+  }
+
   /// Returns the source map URL for the source at the given index.
   pub fn source_map_url(&self, source_index: usize) -> Option<&String> {
     self.source_map_urls.get(source_index)?.as_ref()
@@ -156,6 +204,7 @@ impl<'i, 'o> StyleSheet<'i, 'o> {
   /// Minify and transform the style sheet for the provided browser targets.
   pub fn minify(&mut self, options: MinifyOptions) -> Result<(), Error<MinifyErrorKind>> {
     let mut context = PropertyHandlerContext::new(options.targets, &options.unused_symbols);
+    context.logical_fallback = options.logical_fallback;
     let mut handler = DeclarationHandler::new(options.targets);
     let mut important_handler = DeclarationHandler::new(options.targets);
 
@@ -176,6 +225,14 @@ impl<'i, 'o> StyleSheet<'i, 'o> {
       None
     };
 
+    if options.remove_unused_keyframes || options.remove_unused_font_faces {
+      let mut references = References::default();
+      references.collect(&self.rules);
+      let remove_keyframes = options.remove_unused_keyframes && !references.dynamic_animation_names;
+      let remove_font_faces = options.remove_unused_font_faces && !references.dynamic_font_families;
+      references.remove_unused(&mut self.rules, remove_keyframes, remove_font_faces);
+    }
+
     let mut ctx = MinifyContext {
       targets: &options.targets,
       handler: &mut handler,
@@ -184,6 +241,7 @@ impl<'i, 'o> StyleSheet<'i, 'o> {
       unused_symbols: &options.unused_symbols,
       custom_media,
       css_modules: self.options.css_modules.is_some(),
+      warnings: options.warnings.as_ref().map(|_| Vec::new()),
     };
 
     self.rules.minify(&mut ctx, false).map_err(|e| Error {
@@ -194,9 +252,37 @@ impl<'i, 'o> StyleSheet<'i, 'o> {
       )),
     })?;
 
+    if let (Some(warnings), Some(collected)) = (&options.warnings, ctx.warnings) {
+      if let Ok(mut warnings) = warnings.write() {
+        for warning in collected {
+          warnings.push(Error {
+            kind: warning.kind,
+            loc: Some(ErrorLocation::new(
+              warning.loc,
+              self.sources[warning.loc.source_index as usize].clone(),
+            )),
+          });
+        }
+      }
+    }
+
     Ok(())
   }
 
+  /// Visits the values within the style sheet with the given visitor, which may mutate them.
+  ///
+  /// See the [visitor](crate::visitor) module for details about the traversal order.
+  pub fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    self.rules.visit(visitor)
+  }
+
+  /// Returns a tree view of the rules and declarations in the style sheet, along with their
+  /// source locations. See the [debug](crate::debug) module for details.
+  #[cfg(feature = "debug_tree")]
+  pub fn debug_tree(&self) -> crate::debug::DebugTree<'_, 'i> {
+    crate::debug::DebugTree::new(&self.rules, &self.sources)
+  }
+
   /// Serialize the style sheet to a CSS string.
   pub fn to_css(&self, options: PrinterOptions) -> Result<ToCssResult, Error<PrinterErrorKind>> {
     // Make sure we always have capacity > 0: https://github.com/napi-rs/napi-rs/issues/1124.
@@ -235,6 +321,239 @@ impl<'i, 'o> StyleSheet<'i, 'o> {
       })
     }
   }
+
+  /// Serialize the style sheet to a CSS string, along with a source map if requested.
+  ///
+  /// When a source map is provided in the printer options, mappings are added to it
+  /// as with [to_css](StyleSheet::to_css), and a copy of it is returned along with the
+  /// code. Use [to_css](StyleSheet::to_css) to access CSS module exports or dependencies.
+  pub fn to_css_string(&self, mut options: PrinterOptions) -> Result<(String, Option<SourceMap>), PrinterError> {
+    if let Some(source_map) = options.source_map.take() {
+      let res = self.to_css(PrinterOptions {
+        source_map: Some(&mut *source_map),
+        ..options
+      })?;
+      return Ok((res.code, Some(source_map.clone())));
+    }
+
+    let res = self.to_css(options)?;
+    Ok((res.code, None))
+  }
+}
+
+/// rust-cssparser skips a leading `@charset` rule without passing it to the rule parser,
+/// so its encoding is checked here instead. The parser is left at the start of the input.
+fn check_leading_charset<'i, 't>(
+  input: &mut Parser<'i, 't>,
+) -> Result<(), cssparser::ParseError<'i, ParserError<'i>>> {
+  let state = input.state();
+  let is_charset = matches!(input.next(), Ok(Token::AtKeyword(name)) if name.eq_ignore_ascii_case("charset"));
+  let result = if is_charset { parse_charset(input) } else { Ok(()) };
+  input.reset(&state);
+  result
+}
+
+/// Parses the top-level rules of a style sheet, calling the callback with each one, and
+/// returns the source map URL found in the style sheet, if any.
+fn parse_rule_list<'i, 'o, F>(
+  code: &'i str,
+  options: &ParserOptions<'o, 'i>,
+  mut callback: F,
+) -> Result<Option<String>, Error<ParserError<'i>>>
+where
+  F: FnMut(CssRule<'i>) -> ControlFlow<()>,
+{
+  // A leading UTF-8 byte order mark is not part of the style sheet.
+  let code = code.strip_prefix('\u{feff}').unwrap_or(code);
+  let mut input = ParserInput::new(&code);
+  let mut parser = Parser::new(&mut input);
+  if let Err(e) = check_leading_charset(&mut parser) {
+    if options.error_recovery {
+      options.warn(e);
+    } else {
+      return Err(Error::from(e, options.filename.clone()));
+    }
+  }
+
+  let mut rule_list_parser = RuleListParser::new_for_stylesheet(&mut parser, TopLevelRuleParser::new(options));
+
+  // Only whitespace and comments can appear between the end of one rule and the start of the next,
+  // so comments are collected from that range rather than by tokenizing the source again.
+  let mut last_end = 0;
+  while let Some(rule) = rule_list_parser.next() {
+    let end = rule_list_parser.input.position().byte_index();
+    let (position, rule) = match rule {
+      Ok((_, CssRule::Ignored)) => {
+        last_end = end;
+        continue;
+      }
+      Ok(rule) => rule,
+      Err((e, _)) => {
+        last_end = end;
+        if options.error_recovery {
+          options.warn(e);
+          continue;
+        }
+
+        return Err(Error::from(e, options.filename.clone()));
+      }
+    };
+
+    // Comments are attached to the following rule.
+    for comment in leading_comments(&code[last_end..position.byte_index()], options.preserve_comments) {
+      if callback(CssRule::Comment(comment)).is_break() {
+        return Ok(None);
+      }
+    }
+
+    last_end = end;
+    if callback(rule).is_break() {
+      return Ok(None);
+    }
+  }
+
+  for comment in leading_comments(&code[last_end..], options.preserve_comments) {
+    if callback(CssRule::Comment(comment)).is_break() {
+      return Ok(None);
+    }
+  }
+
+  Ok(parser.current_source_map_url().map(|s| s.to_owned()))
+}
+
+/// The `@keyframes` names and font families referenced by the declarations in a style sheet.
+#[derive(Default)]
+struct References {
+  animation_names: HashSet<String>,
+  /// Font families, lower cased since they are matched case insensitively.
+  font_families: HashSet<String>,
+  /// Set when an animation or font declaration could not be parsed, e.g. because it contains `var()`.
+  /// Any name may be referenced in this case.
+  dynamic_animation_names: bool,
+  dynamic_font_families: bool,
+}
+
+impl References {
+  fn collect(&mut self, rules: &CssRuleList) {
+    for rule in &rules.0 {
+      match rule {
+        CssRule::Style(style) => {
+          self.collect_declarations(&style.declarations);
+          self.collect(&style.rules);
+        }
+        CssRule::Nesting(nesting) => {
+          self.collect_declarations(&nesting.style.declarations);
+          self.collect(&nesting.style.rules);
+        }
+        CssRule::Page(page) => {
+          self.collect_declarations(&page.declarations);
+          for margin_rule in &page.rules {
+            self.collect_declarations(&margin_rule.declarations);
+          }
+        }
+        CssRule::Media(media) => self.collect(&media.rules),
+        CssRule::Supports(supports) => self.collect(&supports.rules),
+        CssRule::LayerBlock(layer) => self.collect(&layer.rules),
+        CssRule::Container(container) => self.collect(&container.rules),
+        CssRule::MozDocument(document) => self.collect(&document.rules),
+        _ => {}
+      }
+    }
+  }
+
+  fn collect_declarations(&mut self, declarations: &DeclarationBlock) {
+    for property in declarations
+      .declarations
+      .iter()
+      .chain(declarations.important_declarations.iter())
+    {
+      match property {
+        Property::AnimationName(names, _) => {
+          for name in names {
+            self.add_animation_name(name);
+          }
+        }
+        Property::Animation(animations, _) => {
+          for animation in animations {
+            self.add_animation_name(&animation.name);
+          }
+        }
+        Property::FontFamily(families) => self.add_font_families(families),
+        Property::Font(font) => self.add_font_families(&font.family),
+        Property::Unparsed(unparsed) => match unparsed.property_id {
+          PropertyId::Animation(_) | PropertyId::AnimationName(_) => self.dynamic_animation_names = true,
+          PropertyId::Font | PropertyId::FontFamily => self.dynamic_font_families = true,
+          _ => {}
+        },
+        _ => {}
+      }
+    }
+  }
+
+  fn add_animation_name(&mut self, name: &AnimationName) {
+    match name {
+      AnimationName::Ident(ident) => self.animation_names.insert(ident.0.to_string()),
+      AnimationName::String(string) => self.animation_names.insert(string.to_string()),
+      AnimationName::None => false,
+    };
+  }
+
+  fn add_font_families(&mut self, families: &[FontFamily]) {
+    for family in families {
+      if let FontFamily::FamilyName(name) = family {
+        self.font_families.insert(name.to_lowercase());
+      }
+    }
+  }
+
+  fn remove_unused(&self, rules: &mut CssRuleList, remove_keyframes: bool, remove_font_faces: bool) {
+    rules.0.retain_mut(|rule| match rule {
+      CssRule::Keyframes(keyframes) if remove_keyframes => {
+        let name = match &keyframes.name {
+          KeyframesName::Ident(ident) => ident.0.as_ref(),
+          KeyframesName::Custom(string) => string.as_ref(),
+        };
+        self.animation_names.contains(name)
+      }
+      CssRule::FontFace(font_face) if remove_font_faces => {
+        font_face.properties.iter().all(|property| match property {
+          FontFaceProperty::FontFamily(FontFamily::FamilyName(name)) => {
+            self.font_families.contains(&name.to_lowercase())
+          }
+          _ => true,
+        })
+      }
+      CssRule::Style(style) => {
+        self.remove_unused(&mut style.rules, remove_keyframes, remove_font_faces);
+        true
+      }
+      CssRule::Nesting(nesting) => {
+        self.remove_unused(&mut nesting.style.rules, remove_keyframes, remove_font_faces);
+        true
+      }
+      CssRule::Media(media) => {
+        self.remove_unused(&mut media.rules, remove_keyframes, remove_font_faces);
+        true
+      }
+      CssRule::Supports(supports) => {
+        self.remove_unused(&mut supports.rules, remove_keyframes, remove_font_faces);
+        true
+      }
+      CssRule::LayerBlock(layer) => {
+        self.remove_unused(&mut layer.rules, remove_keyframes, remove_font_faces);
+        true
+      }
+      CssRule::Container(container) => {
+        self.remove_unused(&mut container.rules, remove_keyframes, remove_font_faces);
+        true
+      }
+      CssRule::MozDocument(document) => {
+        self.remove_unused(&mut document.rules, remove_keyframes, remove_font_faces);
+        true
+      }
+      _ => true,
+    });
+  }
 }
 
 /// An inline style attribute, as in HTML or SVG.
@@ -291,6 +610,11 @@ impl<'i> StyleAttribute<'i> {
     self.declarations.minify(&mut handler, &mut important_handler, &mut context);
   }
 
+  /// Visits the values within the style attribute with the given visitor, which may mutate them.
+  pub fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    self.declarations.visit(visitor)
+  }
+
   /// Serializes the style attribute to a CSS string.
   pub fn to_css(&self, options: PrinterOptions) -> Result<ToCssResult, PrinterError> {
     assert!(
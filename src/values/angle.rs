@@ -2,10 +2,10 @@
 
 use super::calc::Calc;
 use super::length::serialize_dimension;
-use super::number::CSSNumber;
+use super::number::{CSSNumber, MAX_PRECISION};
 use super::percentage::DimensionPercentage;
 use crate::error::{ParserError, PrinterError};
-use crate::printer::Printer;
+use crate::printer::{Printer, PrinterOptions};
 use crate::traits::{
   impl_op,
   private::{AddInternal, TryAdd},
@@ -17,7 +17,7 @@ use std::f32::consts::PI;
 /// A CSS [`<angle>`](https://www.w3.org/TR/css-values-4/#angles) value.
 ///
 /// Angles may be explicit or computed by `calc()`, but are always stored and serialized
-/// as their computed value.
+/// as their computed value. A unitless `0` is parsed as `0deg`.
 #[derive(Debug, Clone)]
 #[cfg_attr(
   feature = "serde",
@@ -37,18 +37,26 @@ pub enum Angle {
 
 impl<'i> Parse<'i> for Angle {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
-    Self::parse_internal(input, false)
+    Self::parse_internal(input, true)
   }
 }
 
 impl Angle {
-  /// Parses an angle, allowing unitless zero values.
+  /// Parses an angle, allowing unitless zero values. This is the same as [Angle::parse](Parse::parse).
   pub fn parse_with_unitless_zero<'i, 't>(
     input: &mut Parser<'i, 't>,
   ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     Self::parse_internal(input, true)
   }
 
+  /// Parses an angle with an explicit unit, rejecting unitless zero values.
+  /// This is used where a bare `0` must be parsed as a number instead.
+  pub(crate) fn parse_with_unit<'i, 't>(
+    input: &mut Parser<'i, 't>,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    Self::parse_internal(input, false)
+  }
+
   fn parse_internal<'i, 't>(
     input: &mut Parser<'i, 't>,
     allow_unitless_zero: bool,
@@ -83,26 +91,101 @@ impl ToCss for Angle {
   where
     W: std::fmt::Write,
   {
-    let (value, unit) = match self {
+    let (value, unit) = if dest.minify {
+      self.minify_with_precision(dest.precision).value_and_unit(dest.precision)
+    } else {
+      self.value_and_unit(dest.precision)
+    };
+
+    serialize_dimension(value, unit, dest)
+  }
+}
+
+impl Angle {
+  fn value_and_unit(&self, precision: Option<u8>) -> (CSSNumber, &'static str) {
+    match self {
       Angle::Deg(val) => (*val, "deg"),
       Angle::Grad(val) => (*val, "grad"),
       Angle::Rad(val) => {
-        let deg = self.to_degrees();
-        // We print 5 digits of precision by default.
-        // Switch to degrees if there are an even number of them.
-        if (deg * 100000.0).round().fract() == 0.0 {
+        // Switch to degrees when they are printed shorter. This gives whole
+        // numbers for common fractions of π, e.g. `60deg` rather than `1.0472rad`.
+        let deg = round_to_print_precision(self.to_degrees(), precision);
+        if serialized_len(deg, "deg", precision) < serialized_len(*val, "rad", precision) {
           (deg, "deg")
         } else {
           (*val, "rad")
         }
       }
       Angle::Turn(val) => (*val, "turn"),
-    };
+    }
+  }
 
-    serialize_dimension(value, unit, dest)
+  /// Returns an equivalent angle in the unit with the shortest exact serialization.
+  ///
+  /// Gradians are converted to degrees when that is shorter and exact, e.g. `50grad` to `45deg`,
+  /// and radians are printed as degrees when that is shorter. Degrees and turns are kept as
+  /// authored, e.g. `90deg` is not converted to `100grad`, and `0.25turn` is kept.
+  pub fn minify(&self) -> Angle {
+    self.minify_with_precision(None)
+  }
+
+  fn minify_with_precision(&self, precision: Option<u8>) -> Angle {
+    match self {
+      Angle::Grad(grad) => {
+        let deg = self.to_degrees();
+        if round_to_print_precision(deg, precision) == deg
+          && serialized_len(deg, "deg", precision) < serialized_len(*grad, "grad", precision)
+        {
+          Angle::Deg(deg)
+        } else {
+          self.clone()
+        }
+      }
+      Angle::Rad(_) => match self.value_and_unit(precision) {
+        (deg, "deg") => Angle::Deg(deg),
+        _ => self.clone(),
+      },
+      Angle::Deg(_) | Angle::Turn(_) => self.clone(),
+    }
   }
 }
 
+/// Returns the length of a minified dimension printed with the given precision, without allocating.
+fn serialized_len(value: CSSNumber, unit: &str, precision: Option<u8>) -> usize {
+  struct Counter(usize);
+  impl std::fmt::Write for Counter {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+      self.0 += s.len();
+      Ok(())
+    }
+  }
+
+  let mut counter = Counter(0);
+  let mut printer = Printer::new(
+    &mut counter,
+    PrinterOptions {
+      minify: true,
+      precision,
+      ..PrinterOptions::default()
+    },
+  );
+  match serialize_dimension(value, unit, &mut printer) {
+    Ok(()) => counter.0,
+    Err(_) => usize::MAX,
+  }
+}
+
+/// Rounds a number to the significant digits it is printed with, which is 6 by default.
+fn round_to_print_precision(value: f32, precision: Option<u8>) -> f32 {
+  if value == 0.0 || !value.is_finite() {
+    return value;
+  }
+
+  let digits = precision.unwrap_or(6).clamp(1, MAX_PRECISION) as i32;
+  let scale = 10f64.powi(digits - 1 - value.abs().log10().floor() as i32);
+  ((value as f64 * scale).round() / scale) as f32
+}
+
 impl Angle {
   /// Prints the angle, allowing unitless zero values.
   pub fn to_css_with_unitless_zero<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
@@ -139,6 +222,42 @@ impl Angle {
       Angle::Turn(turn) => turn * 360.0,
     }
   }
+
+  /// Returns the angle in gradians.
+  pub fn to_grads(&self) -> CSSNumber {
+    match self {
+      Angle::Grad(grad) => *grad,
+      _ => self.to_degrees() * 200.0 / 180.0,
+    }
+  }
+
+  /// Returns the angle in turns.
+  pub fn to_turns(&self) -> CSSNumber {
+    match self {
+      Angle::Turn(turn) => *turn,
+      _ => self.to_degrees() / 360.0,
+    }
+  }
+
+  /// Returns an equivalent angle in the range `[0deg, 360deg)`, in the same unit.
+  pub fn normalize(&self) -> Angle {
+    fn wrap(value: CSSNumber, full: CSSNumber) -> CSSNumber {
+      let value = value.rem_euclid(full);
+      // rem_euclid may round up to the full circle for tiny negative values.
+      if value == full {
+        0.0
+      } else {
+        value
+      }
+    }
+
+    match self {
+      Angle::Deg(deg) => Angle::Deg(wrap(*deg, 360.0)),
+      Angle::Rad(rad) => Angle::Rad(wrap(*rad, 2.0 * PI)),
+      Angle::Grad(grad) => Angle::Grad(wrap(*grad, 400.0)),
+      Angle::Turn(turn) => Angle::Turn(wrap(*turn, 1.0)),
+    }
+  }
 }
 
 impl Zero for Angle {
@@ -249,6 +368,15 @@ impl Sign for Angle {
 
 impl_op!(Angle, std::ops::Rem, rem);
 impl_op!(Angle, std::ops::Add, add);
+impl_op!(Angle, std::ops::Sub, sub);
+
+impl std::ops::Neg for Angle {
+  type Output = Self;
+
+  fn neg(self) -> Angle {
+    self.map(|v| -v)
+  }
+}
 
 /// A CSS [`<angle-percentage>`](https://www.w3.org/TR/css-values-4/#typedef-angle-percentage) value.
 /// May be specified as either an angle or a percentage that resolves to an angle.
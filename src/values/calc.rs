@@ -647,7 +647,18 @@ impl<
     input.expect_comma()?;
     let b: Calc<V> = Calc::parse_sum(input, parse_ident)?;
 
-    Ok(Self::apply_op(&a, &b, op).unwrap_or_else(|| Calc::Function(Box::new(fallback(a, b)))))
+    // Leave the function unsimplified if the result is not finite, e.g. a step of zero.
+    let mut finite = true;
+    let res = Self::apply_op(&a, &b, |a, b| {
+      let v = op(a, b);
+      finite = v.is_finite();
+      v
+    });
+
+    match res {
+      Some(res) if finite => Ok(res),
+      _ => Ok(Calc::Function(Box::new(fallback(a, b)))),
+    }
   }
 
   fn apply_op<'t, O: FnOnce(f32, f32) -> f32>(a: &Calc<V>, b: &Calc<V>, op: O) -> Option<Self> {
@@ -845,6 +856,75 @@ impl<V: std::ops::Mul<f32, Output = V>> std::ops::Mul<f32> for Calc<V> {
   }
 }
 
+impl<
+    V: AddInternal
+      + std::ops::Mul<f32, Output = V>
+      + std::convert::Into<Calc<V>>
+      + std::convert::From<Calc<V>>
+      + std::fmt::Debug,
+  > Calc<V>
+{
+  /// Simplifies the expression, folding sums and products of compatible values together.
+  ///
+  /// Expressions produced by the parser are already simplified, so this is mainly useful
+  /// for calc trees that were constructed or modified manually. The value type must support
+  /// addition, multiplication by a number, and conversion to and from `Calc<V>`, which all of
+  /// the built-in value types such as [Length](super::length::Length) do. The arguments of math
+  /// functions such as `min()` are simplified, but the functions themselves are preserved.
+  ///
+  /// ```
+  /// use lightningcss::{traits::Parse, values::{calc::Calc, length::Length}};
+  ///
+  /// let calc = Calc::<Length>::parse_string("calc(1px + 1px)").unwrap().simplify();
+  /// assert_eq!(calc, Calc::Value(Box::new(Length::px(2.0))));
+  ///
+  /// let sum = Calc::Sum(
+  ///   Box::new(Calc::Value(Box::new(Length::px(1.0)))),
+  ///   Box::new(Calc::Value(Box::new(Length::px(1.0)))),
+  /// );
+  /// assert_eq!(sum.simplify(), Calc::Value(Box::new(Length::px(2.0))));
+  /// ```
+  pub fn simplify(self) -> Calc<V> {
+    match self {
+      Calc::Sum(a, b) => a.simplify().add(b.simplify()),
+      Calc::Product(num, calc) => calc.simplify() * num,
+      Calc::Function(f) => match *f {
+        MathFunction::Calc(c) => match c.simplify() {
+          c @ (Calc::Value(_) | Calc::Number(_)) => c,
+          c => Calc::Function(Box::new(MathFunction::Calc(c))),
+        },
+        f => Calc::Function(Box::new(f.simplify_args())),
+      },
+      v => v,
+    }
+  }
+}
+
+impl<
+    V: AddInternal
+      + std::ops::Mul<f32, Output = V>
+      + std::convert::Into<Calc<V>>
+      + std::convert::From<Calc<V>>
+      + std::fmt::Debug,
+  > MathFunction<V>
+{
+  fn simplify_args(self) -> MathFunction<V> {
+    let simplify_all = |args: Vec<Calc<V>>| args.into_iter().map(Calc::simplify).collect();
+    match self {
+      MathFunction::Calc(c) => MathFunction::Calc(c.simplify()),
+      MathFunction::Min(args) => MathFunction::Min(simplify_all(args)),
+      MathFunction::Max(args) => MathFunction::Max(simplify_all(args)),
+      MathFunction::Clamp(a, b, c) => MathFunction::Clamp(a.simplify(), b.simplify(), c.simplify()),
+      MathFunction::Round(strategy, a, b) => MathFunction::Round(strategy, a.simplify(), b.simplify()),
+      MathFunction::Rem(a, b) => MathFunction::Rem(a.simplify(), b.simplify()),
+      MathFunction::Mod(a, b) => MathFunction::Mod(a.simplify(), b.simplify()),
+      MathFunction::Abs(v) => MathFunction::Abs(v.simplify()),
+      MathFunction::Sign(v) => MathFunction::Sign(v.simplify()),
+      MathFunction::Hypot(args) => MathFunction::Hypot(simplify_all(args)),
+    }
+  }
+}
+
 impl<V: AddInternal + std::convert::Into<Calc<V>> + std::convert::From<Calc<V>> + std::fmt::Debug> AddInternal
   for Calc<V>
 {
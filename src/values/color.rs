@@ -45,6 +45,8 @@ pub enum CssColor {
   Predefined(Box<PredefinedColor>),
   /// A floating point representation of an RGB, HSL, or HWB color when it contains `none` components.
   Float(Box<FloatColor>),
+  /// A [system color](https://www.w3.org/TR/css-color-4/#css-system-colors) keyword.
+  System(SystemColor),
 }
 
 /// A color in a LAB color space, including the `lab()`, `lch()`, `oklab()`, and `oklch()` functions.
@@ -131,6 +133,103 @@ bitflags! {
   }
 }
 
+enum_property! {
+  /// A [system color](https://www.w3.org/TR/css-color-4/#css-system-colors) keyword.
+  ///
+  /// System colors are resolved by the user agent, e.g. according to the user's forced colors
+  /// theme, so they are always preserved as is and never converted to a concrete color.
+  pub enum SystemColor {
+    /// Background of accented user interface controls.
+    "AccentColor": AccentColor,
+    /// Text of accented user interface controls.
+    "AccentColorText": AccentColorText,
+    /// Text in active links.
+    "ActiveText": ActiveText,
+    /// The base border color for controls.
+    "ButtonBorder": ButtonBorder,
+    /// The face background color for controls.
+    "ButtonFace": ButtonFace,
+    /// Text in controls.
+    "ButtonText": ButtonText,
+    /// Background of application content or documents.
+    "Canvas": Canvas,
+    /// Text in application content or documents.
+    "CanvasText": CanvasText,
+    /// Background of input fields.
+    "Field": Field,
+    /// Text in input fields.
+    "FieldText": FieldText,
+    /// Disabled text.
+    "GrayText": GrayText,
+    /// Background of selected text.
+    "Highlight": Highlight,
+    /// Text of selected text.
+    "HighlightText": HighlightText,
+    /// Text in non-active, non-visited links.
+    "LinkText": LinkText,
+    /// Background of text that has been specially marked.
+    "Mark": Mark,
+    /// Text that has been specially marked.
+    "MarkText": MarkText,
+    /// Background of selected items.
+    "SelectedItem": SelectedItem,
+    /// Text of selected items.
+    "SelectedItemText": SelectedItemText,
+    /// Text in visited links.
+    "VisitedText": VisitedText,
+
+    // Deprecated system colors.
+    // https://www.w3.org/TR/css-color-4/#deprecated-system-colors
+
+    /// Active window border. Same as `ButtonBorder`.
+    "ActiveBorder": ActiveBorder,
+    /// Active window caption. Same as `Canvas`.
+    "ActiveCaption": ActiveCaption,
+    /// Background color of multiple document interface. Same as `Canvas`.
+    "AppWorkspace": AppWorkspace,
+    /// Desktop background. Same as `Canvas`.
+    "Background": Background,
+    /// Border of 3-D elements facing the light source. Same as `ButtonFace`.
+    "ButtonHighlight": ButtonHighlight,
+    /// Border of 3-D elements away from the light source. Same as `ButtonFace`.
+    "ButtonShadow": ButtonShadow,
+    /// Text in caption, size box, and scrollbar arrow box. Same as `CanvasText`.
+    "CaptionText": CaptionText,
+    /// Inactive window border. Same as `ButtonBorder`.
+    "InactiveBorder": InactiveBorder,
+    /// Inactive window caption. Same as `Canvas`.
+    "InactiveCaption": InactiveCaption,
+    /// Color of text in an inactive caption. Same as `GrayText`.
+    "InactiveCaptionText": InactiveCaptionText,
+    /// Background color for tooltip controls. Same as `Canvas`.
+    "InfoBackground": InfoBackground,
+    /// Text color for tooltip controls. Same as `CanvasText`.
+    "InfoText": InfoText,
+    /// Menu background. Same as `Canvas`.
+    "Menu": Menu,
+    /// Text in menus. Same as `CanvasText`.
+    "MenuText": MenuText,
+    /// Scroll bar gray area. Same as `Canvas`.
+    "Scrollbar": Scrollbar,
+    /// Outer border of 3-D elements away from the light source. Same as `ButtonBorder`.
+    "ThreeDDarkShadow": ThreeDDarkShadow,
+    /// Face background of 3-D elements. Same as `ButtonFace`.
+    "ThreeDFace": ThreeDFace,
+    /// Outer border of 3-D elements facing the light source. Same as `ButtonBorder`.
+    "ThreeDHighlight": ThreeDHighlight,
+    /// Inner border of 3-D elements facing the light source. Same as `ButtonBorder`.
+    "ThreeDLightShadow": ThreeDLightShadow,
+    /// Inner border of 3-D elements away from the light source. Same as `ButtonBorder`.
+    "ThreeDShadow": ThreeDShadow,
+    /// Window background. Same as `Canvas`.
+    "Window": Window,
+    /// Window frame. Same as `ButtonBorder`.
+    "WindowFrame": WindowFrame,
+    /// Text in windows. Same as `CanvasText`.
+    "WindowText": WindowText,
+  }
+}
+
 enum_property! {
   /// A [color space](https://www.w3.org/TR/css-color-4/#interpolation-space) keyword
   /// used in interpolation functions such as `color-mix()`.
@@ -213,18 +312,44 @@ impl CssColor {
   }
 
   /// Converts the color to RGBA.
+  ///
+  /// `currentColor` and system colors are returned unchanged.
   pub fn to_rgb(&self) -> CssColor {
-    RGBA::from(self).into()
+    match self {
+      CssColor::CurrentColor | CssColor::System(..) => self.clone(),
+      _ => RGBA::from(self).into(),
+    }
+  }
+
+  /// Resolves the color to 8-bit sRGB components.
+  ///
+  /// Each component is rounded to the nearest integer, and out of gamut values are clamped.
+  /// Returns `None` for `currentColor` and system colors, which cannot be resolved until computed value time.
+  pub fn to_rgba(&self) -> Option<RGBA> {
+    match self {
+      CssColor::CurrentColor | CssColor::System(..) => None,
+      _ => Some(RGBA::from(self)),
+    }
   }
 
   /// Converts the color to the LAB color space.
+  ///
+  /// `currentColor` and system colors are returned unchanged.
   pub fn to_lab(&self) -> CssColor {
-    LAB::from(self).into()
+    match self {
+      CssColor::CurrentColor | CssColor::System(..) => self.clone(),
+      _ => LAB::from(self).into(),
+    }
   }
 
   /// Converts the color to the P3 color space.
+  ///
+  /// `currentColor` and system colors are returned unchanged.
   pub fn to_p3(&self) -> CssColor {
-    P3::from(self).into()
+    match self {
+      CssColor::CurrentColor | CssColor::System(..) => self.clone(),
+      _ => P3::from(self).into(),
+    }
   }
 
   pub(crate) fn get_possible_fallbacks(&self, targets: Browsers) -> ColorFallbackKind {
@@ -232,7 +357,9 @@ impl CssColor {
     // below and including the authored color space, and remove the ones that aren't
     // compatible with our browser targets.
     let mut fallbacks = match self {
-      CssColor::CurrentColor | CssColor::RGBA(_) | CssColor::Float(..) => return ColorFallbackKind::empty(),
+      CssColor::CurrentColor | CssColor::RGBA(_) | CssColor::Float(..) | CssColor::System(..) => {
+        return ColorFallbackKind::empty()
+      }
       CssColor::LAB(lab) => match &**lab {
         LABColor::LAB(..) | LABColor::LCH(..) => ColorFallbackKind::LAB.and_below(),
         LABColor::OKLAB(..) | LABColor::OKLCH(..) => ColorFallbackKind::OKLAB.and_below(),
@@ -289,7 +416,7 @@ impl CssColor {
 
   /// Returns a fallback color for the given fallback type.
   pub fn get_fallback(&self, kind: ColorFallbackKind) -> CssColor {
-    if matches!(self, CssColor::RGBA(_)) {
+    if matches!(self, CssColor::RGBA(_) | CssColor::CurrentColor | CssColor::System(..)) {
       return self.clone();
     }
 
@@ -345,6 +472,10 @@ impl<'i> Parse<'i> for CssColor {
       return Ok(color.into());
     }
 
+    if let Ok(system) = input.try_parse(SystemColor::parse) {
+      return Ok(CssColor::System(system));
+    }
+
     parse_color_function(input)
   }
 }
@@ -356,6 +487,7 @@ impl ToCss for CssColor {
   {
     match self {
       CssColor::CurrentColor => dest.write_str("currentColor"),
+      CssColor::System(system) => system.to_css(dest),
       CssColor::RGBA(color) => {
         if color.alpha == 255 {
           let hex: u32 = ((color.red as u32) << 16) | ((color.green as u32) << 8) | (color.blue as u32);
@@ -653,8 +785,8 @@ impl ComponentParser {
     input: &mut Parser<'i, 't>,
   ) -> Result<(), ParseError<'i, ParserError<'i>>> {
     if input.try_parse(|input| input.expect_ident_matching("from")).is_ok() {
-      let from = T::from(CssColor::parse(input)?).resolve();
-      self.from = Some(RelativeComponentParser::new(&from));
+      let from = parse_relative_origin(input)?;
+      self.from = Some(RelativeComponentParser::new(&T::from(from).resolve()));
     }
 
     Ok(())
@@ -848,6 +980,17 @@ fn parse_lch<'i, 't, T: From<CssColor> + ColorSpace>(
   Ok(res)
 }
 
+/// Parses the origin color of a relative color function, which must be resolvable at parse time.
+fn parse_relative_origin<'i, 't>(input: &mut Parser<'i, 't>) -> Result<CssColor, ParseError<'i, ParserError<'i>>> {
+  let location = input.current_source_location();
+  let color = CssColor::parse(input)?;
+  if !color.is_resolvable() {
+    return Err(location.new_custom_error(ParserError::InvalidValue));
+  }
+
+  Ok(color)
+}
+
 #[inline]
 fn parse_predefined<'i, 't>(
   input: &mut Parser<'i, 't>,
@@ -856,7 +999,7 @@ fn parse_predefined<'i, 't>(
   // https://www.w3.org/TR/css-color-4/#color-function
   let res = input.parse_nested_block(|input| {
     let from = if input.try_parse(|input| input.expect_ident_matching("from")).is_ok() {
-      Some(CssColor::parse(input)?)
+      Some(parse_relative_origin(input)?)
     } else {
       None
     };
@@ -2451,7 +2594,7 @@ macro_rules! color_space {
           CssColor::LAB(lab) => (**lab).into(),
           CssColor::Predefined(predefined) => (**predefined).into(),
           CssColor::Float(float) => (**float).into(),
-          CssColor::CurrentColor => unreachable!(),
+          CssColor::CurrentColor | CssColor::System(..) => unreachable!(),
         }
       }
     }
@@ -2463,7 +2606,7 @@ macro_rules! color_space {
           CssColor::LAB(lab) => (*lab).into(),
           CssColor::Predefined(predefined) => (*predefined).into(),
           CssColor::Float(float) => (*float).into(),
-          CssColor::CurrentColor => unreachable!(),
+          CssColor::CurrentColor | CssColor::System(..) => unreachable!(),
         }
       }
     }
@@ -2735,6 +2878,11 @@ fn parse_color_mix<'i, 't>(input: &mut Parser<'i, 't>) -> Result<CssColor, Parse
     .or_else(|_| input.try_parse(|input| input.expect_percentage()))
     .ok();
 
+  // currentColor and system colors cannot be resolved until computed value time, so leave the function as is.
+  if !first_color.is_resolvable() || !second_color.is_resolvable() {
+    return Err(input.new_custom_error(ParserError::InvalidValue));
+  }
+
   // https://drafts.csswg.org/css-color-5/#color-mix-percent-norm
   let (p1, p2) = if first_percent.is_none() && second_percent.is_none() {
     (0.5, 0.5)
@@ -2765,6 +2913,11 @@ fn parse_color_mix<'i, 't>(input: &mut Parser<'i, 't>) -> Result<CssColor, Parse
 }
 
 impl CssColor {
+  /// Returns whether the color can be resolved to concrete components at parse time.
+  fn is_resolvable(&self) -> bool {
+    !matches!(self, CssColor::CurrentColor | CssColor::System(..))
+  }
+
   fn get_type_id(&self) -> TypeId {
     match self {
       CssColor::RGBA(..) => TypeId::of::<SRGB>(),
@@ -387,6 +387,20 @@ impl LineDirection {
   fn parse<'i, 't>(
     input: &mut Parser<'i, 't>,
     is_prefixed: bool,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let direction = LineDirection::parse_direction(input, is_prefixed)?;
+
+    // Legacy prefixed gradients use different semantics, so convert to the standard direction.
+    if is_prefixed {
+      return Ok(direction.legacy());
+    }
+
+    Ok(direction)
+  }
+
+  fn parse_direction<'i, 't>(
+    input: &mut Parser<'i, 't>,
+    is_prefixed: bool,
   ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     // Spec allows unitless zero angles for gradients.
     // https://w3c.github.io/csswg-drafts/css-images-3/#linear-gradient-syntax
@@ -412,10 +426,50 @@ impl LineDirection {
     Ok(LineDirection::Vertical(y))
   }
 
+  /// Converts between standard and legacy prefixed directions. Prefixed gradients specify
+  /// the starting point rather than the ending point, and their angles are measured
+  /// counter-clockwise from the right rather than clockwise from the top. The
+  /// conversion is its own inverse.
+  fn legacy(&self) -> LineDirection {
+    fn flip_x(x: &HorizontalPositionKeyword) -> HorizontalPositionKeyword {
+      match x {
+        HorizontalPositionKeyword::Left => HorizontalPositionKeyword::Right,
+        HorizontalPositionKeyword::Right => HorizontalPositionKeyword::Left,
+      }
+    }
+
+    fn flip_y(y: &VerticalPositionKeyword) -> VerticalPositionKeyword {
+      match y {
+        VerticalPositionKeyword::Top => VerticalPositionKeyword::Bottom,
+        VerticalPositionKeyword::Bottom => VerticalPositionKeyword::Top,
+      }
+    }
+
+    match self {
+      LineDirection::Angle(angle) => LineDirection::Angle(Angle::Deg(90.0 - angle.to_degrees())),
+      LineDirection::Horizontal(x) => LineDirection::Horizontal(flip_x(x)),
+      LineDirection::Vertical(y) => LineDirection::Vertical(flip_y(y)),
+      LineDirection::Corner(x, y) => LineDirection::Corner(flip_x(x), flip_y(y)),
+    }
+  }
+
   fn to_css<W>(&self, dest: &mut Printer<W>, is_prefixed: bool) -> Result<(), PrinterError>
   where
     W: std::fmt::Write,
   {
+    if is_prefixed {
+      return match self.legacy() {
+        LineDirection::Angle(angle) => angle.to_css(dest),
+        LineDirection::Horizontal(k) => k.to_css(dest),
+        LineDirection::Vertical(k) => k.to_css(dest),
+        LineDirection::Corner(x, y) => {
+          y.to_css(dest)?;
+          dest.write_char(' ')?;
+          x.to_css(dest)
+        }
+      };
+    }
+
     match self {
       LineDirection::Angle(angle) => angle.to_css(dest),
       LineDirection::Horizontal(k) => {
@@ -425,9 +479,7 @@ impl LineDirection {
             HorizontalPositionKeyword::Right => dest.write_str("90deg"),
           }
         } else {
-          if !is_prefixed {
-            dest.write_str("to ")?;
-          }
+          dest.write_str("to ")?;
           k.to_css(dest)
         }
       }
@@ -438,16 +490,12 @@ impl LineDirection {
             VerticalPositionKeyword::Bottom => dest.write_str("180deg"),
           }
         } else {
-          if !is_prefixed {
-            dest.write_str("to ")?;
-          }
+          dest.write_str("to ")?;
           k.to_css(dest)
         }
       }
       LineDirection::Corner(x, y) => {
-        if !is_prefixed {
-          dest.write_str("to ")?;
-        }
+        dest.write_str("to ")?;
         y.to_css(dest)?;
         dest.write_char(' ')?;
         x.to_css(dest)
@@ -863,7 +911,7 @@ fn parse_items<'i, 't, D: Parse<'i>>(
 }
 
 fn serialize_items<
-  D: ToCss + std::cmp::PartialEq<D> + std::ops::Mul<f32, Output = D> + TrySign + Clone + std::fmt::Debug,
+  D: ToCss + std::cmp::PartialEq<D> + std::ops::Mul<f32, Output = D> + TrySign + Zero + Clone + std::fmt::Debug,
   W,
 >(
   items: &Vec<GradientItem<DimensionPercentage<D>>>,
@@ -872,6 +920,14 @@ fn serialize_items<
 where
   W: std::fmt::Write,
 {
+  let minified;
+  let items = if dest.minify {
+    minified = minify_items(items);
+    &minified
+  } else {
+    items
+  };
+
   let mut first = true;
   let mut last: Option<&GradientItem<DimensionPercentage<D>>> = None;
   for item in items {
@@ -915,6 +971,62 @@ where
   Ok(())
 }
 
+/// Removes duplicate color stops, and stop positions that are implied by default.
+/// e.g. `red 0%, red 0%, blue 100%` => `red, blue`
+fn minify_items<D: std::cmp::PartialEq<D> + Zero + Clone>(
+  items: &Vec<GradientItem<DimensionPercentage<D>>>,
+) -> Vec<GradientItem<DimensionPercentage<D>>> {
+  let mut res: Vec<GradientItem<DimensionPercentage<D>>> = Vec::with_capacity(items.len());
+  for item in items {
+    // A stop with the same color and position as the previous stop has no effect.
+    if matches!(item, GradientItem::ColorStop(ColorStop { position: Some(_), .. })) && res.last() == Some(item) {
+      continue;
+    }
+
+    res.push(item.clone());
+  }
+
+  // The first stop defaults to 0%, and the last stop defaults to 100%. Hard transitions at
+  // the ends are unaffected, since stop positions are clamped to the previous position.
+  if res.len() >= 2 {
+    if is_default_position(&res[0], &res[1], |p| p.is_zero()) {
+      clear_position(&mut res[0]);
+    }
+
+    let len = res.len();
+    if is_default_position(&res[len - 1], &res[len - 2], |p| {
+      *p == DimensionPercentage::Percentage(Percentage(1.0))
+    }) {
+      clear_position(&mut res[len - 1]);
+    }
+  }
+
+  res
+}
+
+fn is_default_position<D: std::cmp::PartialEq<D>, F: FnOnce(&D) -> bool>(
+  item: &GradientItem<D>,
+  neighbor: &GradientItem<D>,
+  is_default: F,
+) -> bool {
+  match item {
+    GradientItem::ColorStop(ColorStop {
+      position: Some(position),
+      color,
+    }) if is_default(position) => {
+      // Keep the position if it can be combined with the neighbor into a double position stop.
+      !matches!(neighbor, GradientItem::ColorStop(ColorStop { position: Some(_), color: c }) if c == color)
+    }
+    _ => false,
+  }
+}
+
+fn clear_position<D>(item: &mut GradientItem<D>) {
+  if let GradientItem::ColorStop(stop) = item {
+    stop.position = None;
+  }
+}
+
 /// A legacy `-webkit-gradient()`.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(
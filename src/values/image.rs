@@ -487,7 +487,12 @@ impl<'i> ImageSetOption<'i> {
     // In other places, x was added as an alias later.
     // Temporarily ignore the targets while printing here.
     let targets = std::mem::take(&mut dest.targets);
-    self.resolution.to_css(dest)?;
+    if is_prefixed {
+      // The prefixed syntax only supported the x unit, so convert dpi and dpcm.
+      Resolution::Dppx(self.resolution.to_dppx()).to_css(dest)?;
+    } else {
+      self.resolution.to_css(dest)?;
+    }
     dest.targets = targets;
 
     if let Some(file_type) = &self.file_type {
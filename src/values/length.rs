@@ -2,14 +2,16 @@
 
 use super::angle::impl_try_from_angle;
 use super::calc::{Calc, MathFunction};
-use super::number::CSSNumber;
+use super::number::{serialize_numeric, serialize_with_precision, CSSNumber};
 use super::percentage::DimensionPercentage;
+use crate::compat::Feature;
 use crate::error::{ParserError, PrinterError};
 use crate::printer::Printer;
+use crate::targets::Browsers;
 use crate::traits::TrySign;
 use crate::traits::{
   private::{AddInternal, TryAdd},
-  Map, Parse, Sign, ToCss, TryMap, TryOp, Zero,
+  FallbackValues, Map, Parse, Sign, ToCss, TryMap, TryOp, Zero,
 };
 use const_str;
 use cssparser::*;
@@ -412,7 +414,11 @@ pub(crate) fn serialize_dimension<W>(value: f32, unit: &str, dest: &mut Printer<
 where
   W: std::fmt::Write,
 {
-  use cssparser::ToCss;
+  if let Some(precision) = dest.precision {
+    serialize_with_precision(value as f64, precision, dest)?;
+    return dest.write_str(unit);
+  }
+
   let int_value = if value.fract() == 0.0 { Some(value as i32) } else { None };
   let token = Token::Dimension {
     has_sign: value < 0.0,
@@ -420,19 +426,7 @@ where
     int_value,
     unit: CowRcStr::from(unit),
   };
-  if value != 0.0 && value.abs() < 1.0 {
-    let mut s = String::new();
-    token.to_css(&mut s)?;
-    if value < 0.0 {
-      dest.write_char('-')?;
-      dest.write_str(s.trim_start_matches("-0"))
-    } else {
-      dest.write_str(s.trim_start_matches('0'))
-    }
-  } else {
-    token.to_css(dest)?;
-    Ok(())
-  }
+  serialize_numeric(value, &token, dest)
 }
 
 impl LengthValue {
@@ -453,6 +447,41 @@ impl LengthValue {
   }
 }
 
+impl FallbackValues for LengthValue {
+  fn get_fallbacks(&mut self, targets: Browsers) -> Vec<Self> {
+    use LengthValue::*;
+    // Small, large, and dynamic viewport units fall back to the default viewport units
+    // in browsers that don't support them.
+    let (fallback, feature) = match *self {
+      Svw(v) => (Vw(v), Feature::ViewportPercentageUnitsSmall),
+      Svh(v) => (Vh(v), Feature::ViewportPercentageUnitsSmall),
+      Svi(v) => (Vi(v), Feature::ViewportPercentageUnitsSmall),
+      Svb(v) => (Vb(v), Feature::ViewportPercentageUnitsSmall),
+      Svmin(v) => (Vmin(v), Feature::ViewportPercentageUnitsSmall),
+      Svmax(v) => (Vmax(v), Feature::ViewportPercentageUnitsSmall),
+      Lvw(v) => (Vw(v), Feature::ViewportPercentageUnitsLarge),
+      Lvh(v) => (Vh(v), Feature::ViewportPercentageUnitsLarge),
+      Lvi(v) => (Vi(v), Feature::ViewportPercentageUnitsLarge),
+      Lvb(v) => (Vb(v), Feature::ViewportPercentageUnitsLarge),
+      Lvmin(v) => (Vmin(v), Feature::ViewportPercentageUnitsLarge),
+      Lvmax(v) => (Vmax(v), Feature::ViewportPercentageUnitsLarge),
+      Dvw(v) => (Vw(v), Feature::ViewportPercentageUnitsDynamic),
+      Dvh(v) => (Vh(v), Feature::ViewportPercentageUnitsDynamic),
+      Dvi(v) => (Vi(v), Feature::ViewportPercentageUnitsDynamic),
+      Dvb(v) => (Vb(v), Feature::ViewportPercentageUnitsDynamic),
+      Dvmin(v) => (Vmin(v), Feature::ViewportPercentageUnitsDynamic),
+      Dvmax(v) => (Vmax(v), Feature::ViewportPercentageUnitsDynamic),
+      _ => return Vec::new(),
+    };
+
+    if feature.is_compatible(targets) {
+      Vec::new()
+    } else {
+      vec![fallback]
+    }
+  }
+}
+
 /// A CSS [`<length>`](https://www.w3.org/TR/css-values-4/#lengths) value, with support for `calc()`.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(
@@ -34,20 +34,90 @@ impl ToCss for CSSNumber {
   where
     W: std::fmt::Write,
   {
-    let number = *self;
-    if number != 0.0 && number.abs() < 1.0 {
-      let mut s = String::new();
-      cssparser::ToCss::to_css(self, &mut s)?;
-      if number < 0.0 {
-        dest.write_char('-')?;
-        dest.write_str(s.trim_start_matches("-0"))
-      } else {
-        dest.write_str(s.trim_start_matches('0'))
-      }
-    } else {
-      cssparser::ToCss::to_css(self, dest)?;
-      Ok(())
+    if let Some(precision) = dest.precision {
+      return serialize_with_precision(*self as f64, precision, dest);
     }
+
+    serialize_numeric(*self, self, dest)
+  }
+}
+
+/// Writes a numeric value, e.g. a number, or a dimension or percentage token, where `value`
+/// is the number that is printed.
+///
+/// Trailing zeros and decimal points are never written, e.g. `5.0` is serialized as `5`.
+/// When minifying, the leading zero before the decimal point is omitted as well (e.g. `.5`).
+/// Otherwise, the canonical form is used (e.g. `0.5`).
+pub(crate) fn serialize_numeric<T, W>(value: f32, token: &T, dest: &mut Printer<W>) -> Result<(), PrinterError>
+where
+  T: cssparser::ToCss,
+  W: std::fmt::Write,
+{
+  // Only values between -1 and 1 can be written with a leading zero.
+  if !dest.minify || value == 0.0 || value.abs() >= 1.0 {
+    token.to_css(dest)?;
+    return Ok(());
+  }
+
+  let mut s = String::new();
+  token.to_css(&mut s)?;
+  write_without_leading_zero(&s, dest)
+}
+
+/// Writes a serialized number, omitting the leading zero before the decimal point.
+fn write_without_leading_zero<W>(s: &str, dest: &mut Printer<W>) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+{
+  if let Some(rest) = s.strip_prefix("-0.") {
+    dest.write_str("-.")?;
+    dest.write_str(rest)
+  } else if let Some(rest) = s.strip_prefix("0.") {
+    dest.write_char('.')?;
+    dest.write_str(rest)
+  } else {
+    dest.write_str(s)
+  }
+}
+
+/// The number of significant digits that an `f32` can represent exactly.
+pub(crate) const MAX_PRECISION: u8 = 7;
+
+/// Writes a number rounded to the given number of significant digits, omitting
+/// trailing zeros, and the leading zero before the decimal point if minifying.
+/// The precision is limited to 7 digits, since further digits are only float noise.
+pub(crate) fn serialize_with_precision<W>(
+  value: f64,
+  precision: u8,
+  dest: &mut Printer<W>,
+) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+{
+  if value == 0.0 || !value.is_finite() {
+    cssparser::ToCss::to_css(&(value as f32), dest)?;
+    return Ok(());
+  }
+
+  let digits = precision.clamp(1, MAX_PRECISION) as i32 - 1 - value.abs().log10().floor() as i32;
+  let rounded = if digits >= 0 {
+    let scale = 10f64.powi(digits);
+    (value * scale).round() / scale
+  } else {
+    let scale = 10f64.powi(-digits);
+    (value / scale).round() * scale
+  };
+  let mut s = format!("{:.*}", digits.max(0) as usize, rounded);
+  if s.contains('.') {
+    s.truncate(s.trim_end_matches('0').trim_end_matches('.').len());
+  }
+
+  if s == "-0" {
+    dest.write_char('0')
+  } else if dest.minify {
+    write_without_leading_zero(&s, dest)
+  } else {
+    dest.write_str(&s)
   }
 }
 
@@ -2,7 +2,7 @@
 
 use super::angle::{impl_try_from_angle, Angle};
 use super::calc::{Calc, MathFunction};
-use super::number::CSSNumber;
+use super::number::{serialize_numeric, serialize_with_precision, CSSNumber};
 use crate::error::{ParserError, PrinterError};
 use crate::printer::Printer;
 use crate::traits::private::AddInternal;
@@ -36,7 +36,12 @@ impl ToCss for Percentage {
   where
     W: std::fmt::Write,
   {
-    use cssparser::ToCss;
+    if let Some(precision) = dest.precision {
+      // Scale in f64 to avoid adding float noise, e.g. 33.333336% for 1/3.
+      serialize_with_precision(self.0 as f64 * 100.0, precision, dest)?;
+      return dest.write_char('%');
+    }
+
     let int_value = if (self.0 * 100.0).fract() == 0.0 {
       Some(self.0 as i32)
     } else {
@@ -47,19 +52,7 @@ impl ToCss for Percentage {
       unit_value: self.0,
       int_value,
     };
-    if self.0 != 0.0 && self.0.abs() < 0.01 {
-      let mut s = String::new();
-      percent.to_css(&mut s)?;
-      if self.0 < 0.0 {
-        dest.write_char('-')?;
-        dest.write_str(s.trim_start_matches("-0"))
-      } else {
-        dest.write_str(s.trim_start_matches('0'))
-      }
-    } else {
-      percent.to_css(dest)?;
-      Ok(())
-    }
+    serialize_numeric(self.0 * 100.0, &percent, dest)
   }
 }
 
@@ -350,6 +343,10 @@ impl<D: TryAdd<D> + Clone + Zero + TrySign + std::fmt::Debug> DimensionPercentag
     let mut b = other;
 
     if a.is_zero() {
+      // If both are zero, prefer the dimension so e.g. calc(0px + 0%) serializes as 0.
+      if b.is_zero() && matches!(a, DimensionPercentage::Dimension(_)) {
+        return a;
+      }
       return b;
     }
 
@@ -94,9 +94,9 @@ pub struct Polygon {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Point {
   /// The x position of the point.
-  x: LengthPercentage,
+  pub x: LengthPercentage,
   /// the y position of the point.
-  y: LengthPercentage,
+  pub y: LengthPercentage,
 }
 
 enum_property! {
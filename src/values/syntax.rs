@@ -202,7 +202,9 @@ impl<'i> SyntaxString {
                 SyntaxComponentKind::Image => ParsedComponent::Image(values::image::Image::parse(input)?),
                 SyntaxComponentKind::Url => ParsedComponent::Url(values::url::Url::parse(input)?),
                 SyntaxComponentKind::Integer => ParsedComponent::Integer(CSSInteger::parse(input)?),
-                SyntaxComponentKind::Angle => ParsedComponent::Angle(values::angle::Angle::parse(input)?),
+                SyntaxComponentKind::Angle => {
+                  ParsedComponent::Angle(values::angle::Angle::parse_with_unit(input)?)
+                }
                 SyntaxComponentKind::Time => ParsedComponent::Time(values::time::Time::parse(input)?),
                 SyntaxComponentKind::Resolution => {
                   ParsedComponent::Resolution(values::resolution::Resolution::parse(input)?)
@@ -58,15 +58,16 @@ impl<'i> ToCss for Url<'i> {
     }
 
     use cssparser::ToCss;
+    let url = dest.rewrite_url(&self.url);
     if dest.minify {
       let mut buf = String::new();
-      Token::UnquotedUrl(CowRcStr::from(self.url.as_ref())).to_css(&mut buf)?;
+      Token::UnquotedUrl(CowRcStr::from(url.as_ref())).to_css(&mut buf)?;
 
       // If the unquoted url is longer than it would be quoted (e.g. `url("...")`)
       // then serialize as a string and choose the shorter version.
-      if buf.len() > self.url.len() + 7 {
+      if buf.len() > url.len() + 7 {
         let mut buf2 = String::new();
-        serialize_string(&self.url, &mut buf2)?;
+        serialize_string(&url, &mut buf2)?;
         if buf2.len() + 5 < buf.len() {
           dest.write_str("url(")?;
           dest.write_str(&buf2)?;
@@ -77,7 +78,7 @@ impl<'i> ToCss for Url<'i> {
       dest.write_str(&buf)?;
     } else {
       dest.write_str("url(")?;
-      serialize_string(&self.url, dest)?;
+      serialize_string(&url, dest)?;
       dest.write_char(')')?;
     }
 
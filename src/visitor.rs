@@ -0,0 +1,1140 @@
+//! Visitors for traversing and mutating the values within a style sheet.
+//!
+//! A [Visitor](Visitor) receives mutable references to values such as urls, colors, and
+//! lengths as a style sheet is traversed, and may modify them in place. All methods have
+//! default no-op implementations, so only the values of interest need to be handled.
+//!
+//! Rules are visited depth first in source order. Within a declaration block, the normal
+//! declarations are visited before the `!important` declarations. Custom properties are
+//! passed to [visit_custom_property](Visitor::visit_custom_property) before the values within them
+//! are visited.
+//!
+//! Values are visited within custom properties, properties with unparsed values (e.g. containing
+//! `var()` references), `@import` and `@font-face` urls, and all properties containing colors,
+//! images, urls, and lengths, including shorthands.
+//!
+//! # Example
+//!
+//! This example shows how you could rewrite all urls in a style sheet to point to a CDN.
+//!
+//! ```
+//! use lightningcss::{
+//!   stylesheet::{StyleSheet, ParserOptions, PrinterOptions},
+//!   values::url::Url,
+//!   visitor::Visitor,
+//! };
+//!
+//! struct CdnVisitor;
+//!
+//! impl<'i> Visitor<'i> for CdnVisitor {
+//!   fn visit_url(&mut self, url: &mut Url<'i>) {
+//!     url.url = format!("https://cdn.example.com/{}", url.url).into();
+//!   }
+//! }
+//!
+//! let mut stylesheet = StyleSheet::parse(
+//!   ".foo { background: url(bg.png) }",
+//!   ParserOptions::default()
+//! ).unwrap();
+//!
+//! stylesheet.visit(&mut CdnVisitor);
+//!
+//! let res = stylesheet.to_css(PrinterOptions { minify: true, ..PrinterOptions::default() }).unwrap();
+//! assert_eq!(res.code, ".foo{background:url(https://cdn.example.com/bg.png)}");
+//! ```
+
+use crate::declaration::DeclarationBlock;
+use crate::dependencies::Location;
+use crate::properties::align::{Gap, GapValue};
+use crate::properties::background::{Background, BackgroundPosition, BackgroundSize};
+use crate::properties::border::{
+  BorderBlockColor, BorderBlockWidth, BorderColor, BorderInlineColor, BorderInlineWidth, BorderSideWidth,
+  BorderWidth, GenericBorder,
+};
+use crate::properties::border_image::{BorderImage, BorderImageSideWidth};
+use crate::properties::border_radius::BorderRadius;
+use crate::properties::box_shadow::BoxShadow;
+use crate::properties::content::{Content, ContentItem};
+use crate::properties::custom::{CustomProperty, TokenList, TokenOrValue, UnresolvedColor};
+use crate::properties::effects::{DropShadow, Filter, FilterList};
+use crate::properties::font::{FontSize, LineHeight, VerticalAlign};
+#[cfg(feature = "grid")]
+use crate::properties::grid::{TrackBreadth, TrackListItem, TrackSize, TrackSizeList, TrackSizing};
+use crate::properties::list::{CounterStyle, ListStyleType, Symbol};
+use crate::properties::margin_padding::{
+  Inset, InsetBlock, InsetInline, Margin, MarginBlock, MarginInline, Padding, PaddingBlock, PaddingInline,
+  ScrollMargin, ScrollMarginBlock, ScrollMarginInline, ScrollPadding, ScrollPaddingBlock, ScrollPaddingInline,
+};
+use crate::properties::masking::{ClipPath, Mask, MaskBorder};
+use crate::properties::size::{MaxSize, Size};
+use crate::properties::svg::{Marker, SVGPaint, SVGPaintFallback, StrokeDasharray};
+use crate::properties::text::{Spacing, TextDecorationThickness, TextShadow};
+use crate::properties::transform::{Perspective, Transform, TransformList, Translate};
+use crate::properties::ui::ColorOrAuto;
+use crate::properties::Property;
+use crate::rules::font_face::{FontFaceProperty, Source};
+use crate::rules::style::StyleRule;
+use crate::rules::{CssRule, CssRuleList};
+use crate::values::angle::Angle;
+use crate::values::calc::{Calc, MathFunction};
+use crate::values::color::CssColor;
+use crate::values::gradient::{Gradient, GradientItem, WebKitGradient};
+use crate::values::image::Image;
+use crate::values::length::{Length, LengthOrNumber, LengthPercentageOrAuto, LengthValue};
+use crate::values::percentage::DimensionPercentage;
+use crate::values::position::{Position, PositionComponent};
+use crate::values::rect::Rect;
+use crate::values::shape::{BasicShape, ShapeRadius};
+use crate::values::size::Size2D;
+use crate::values::url::Url;
+
+/// A visitor that can inspect and mutate values during the traversal of a style sheet.
+///
+/// See the [module documentation](self) for details about the traversal order.
+pub trait Visitor<'i> {
+  /// Visits a `url()`.
+  fn visit_url(&mut self, _url: &mut Url<'i>) {}
+
+  /// Visits a color.
+  fn visit_color(&mut self, _color: &mut CssColor) {}
+
+  /// Visits a length value. Lengths within `calc()` expressions are visited individually.
+  fn visit_length(&mut self, _length: &mut LengthValue) {}
+
+  /// Visits a custom property, before the values within it are visited.
+  fn visit_custom_property(&mut self, _property: &mut CustomProperty<'i>) {}
+}
+
+/// A value that can be traversed by a [Visitor](Visitor).
+pub trait Visit<'i> {
+  /// Visits the value and all of its children with the given visitor.
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V);
+}
+
+impl<'i> Visit<'i> for CssRuleList<'i> {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    for rule in self.0.iter_mut() {
+      rule.visit(visitor);
+    }
+  }
+}
+
+impl<'i> Visit<'i> for CssRule<'i> {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    match self {
+      CssRule::Import(import) => {
+        let mut url = Url {
+          url: std::mem::replace(&mut import.url, "".into()),
+          loc: Location {
+            line: import.loc.line + 1,
+            column: import.loc.column,
+          },
+        };
+        url.visit(visitor);
+        import.url = url.url;
+      }
+      CssRule::Style(style) => style.visit(visitor),
+      CssRule::Nesting(nesting) => nesting.style.visit(visitor),
+      CssRule::Media(media) => media.rules.visit(visitor),
+      CssRule::Supports(supports) => supports.rules.visit(visitor),
+      CssRule::LayerBlock(layer) => layer.rules.visit(visitor),
+      CssRule::Container(container) => container.rules.visit(visitor),
+      CssRule::MozDocument(document) => document.rules.visit(visitor),
+      CssRule::Page(page) => {
+        page.declarations.visit(visitor);
+        for rule in page.rules.iter_mut() {
+          rule.declarations.visit(visitor);
+        }
+      }
+      CssRule::Viewport(viewport) => viewport.declarations.visit(visitor),
+      CssRule::Keyframes(keyframes) => {
+        for keyframe in keyframes.keyframes.iter_mut() {
+          keyframe.declarations.visit(visitor);
+        }
+      }
+      CssRule::FontFace(font_face) => {
+        for property in font_face.properties.iter_mut() {
+          if let FontFaceProperty::Source(sources) = property {
+            for source in sources.iter_mut() {
+              if let Source::Url(source) = source {
+                source.url.visit(visitor);
+              }
+            }
+          }
+        }
+      }
+      _ => {}
+    }
+  }
+}
+
+impl<'i> Visit<'i> for StyleRule<'i> {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    self.declarations.visit(visitor);
+    self.rules.visit(visitor);
+  }
+}
+
+impl<'i> Visit<'i> for DeclarationBlock<'i> {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    for property in self.declarations.iter_mut() {
+      property.visit(visitor);
+    }
+
+    for property in self.important_declarations.iter_mut() {
+      property.visit(visitor);
+    }
+  }
+}
+
+impl<'i> Visit<'i> for Property<'i> {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    match self {
+      Property::Custom(custom) => {
+        visitor.visit_custom_property(custom);
+        custom.value.visit(visitor);
+      }
+      Property::Unparsed(unparsed) => unparsed.value.visit(visitor),
+      Property::BackgroundColor(color)
+      | Property::Color(color)
+      | Property::BorderTopColor(color)
+      | Property::BorderBottomColor(color)
+      | Property::BorderLeftColor(color)
+      | Property::BorderRightColor(color)
+      | Property::BorderBlockStartColor(color)
+      | Property::BorderBlockEndColor(color)
+      | Property::BorderInlineStartColor(color)
+      | Property::BorderInlineEndColor(color)
+      | Property::OutlineColor(color)
+      | Property::TextDecorationColor(color, _)
+      | Property::TextEmphasisColor(color, _) => color.visit(visitor),
+      Property::CaretColor(color) | Property::AccentColor(color) => color.visit(visitor),
+      Property::Caret(caret) => caret.color.visit(visitor),
+      Property::BackgroundImage(images) | Property::MaskImage(images, _) => {
+        for image in images.iter_mut() {
+          image.visit(visitor);
+        }
+      }
+      Property::BackgroundPositionX(positions) | Property::MaskPositionX(positions) => {
+        for position in positions.iter_mut() {
+          position.visit(visitor);
+        }
+      }
+      Property::BackgroundPositionY(positions) | Property::MaskPositionY(positions) => {
+        for position in positions.iter_mut() {
+          position.visit(visitor);
+        }
+      }
+      Property::BackgroundPosition(positions) => {
+        for position in positions.iter_mut() {
+          position.visit(visitor);
+        }
+      }
+      Property::MaskPosition(positions, _) => {
+        for position in positions.iter_mut() {
+          position.visit(visitor);
+        }
+      }
+      Property::BackgroundSize(sizes) | Property::MaskSize(sizes, _) => {
+        for size in sizes.iter_mut() {
+          size.visit(visitor);
+        }
+      }
+      Property::Background(backgrounds) => {
+        for background in backgrounds.iter_mut() {
+          background.visit(visitor);
+        }
+      }
+      Property::BoxShadow(shadows, _) => {
+        for shadow in shadows.iter_mut() {
+          shadow.visit(visitor);
+        }
+      }
+      Property::TextShadow(shadows) => {
+        for shadow in shadows.iter_mut() {
+          shadow.visit(visitor);
+        }
+      }
+      Property::BorderImageSource(image)
+      | Property::ListStyleImage(image)
+      | Property::MaskBorderSource(image)
+      | Property::WebKitMaskBoxImageSource(image, _) => image.visit(visitor),
+      Property::BorderImageOutset(outset)
+      | Property::MaskBorderOutset(outset)
+      | Property::WebKitMaskBoxImageOutset(outset, _) => outset.visit(visitor),
+      Property::BorderImageWidth(width)
+      | Property::MaskBorderWidth(width)
+      | Property::WebKitMaskBoxImageWidth(width, _) => width.visit(visitor),
+      Property::BorderImage(border_image, _) | Property::WebKitMaskBoxImage(border_image, _) => {
+        border_image.visit(visitor)
+      }
+      Property::MaskBorder(mask_border) => mask_border.visit(visitor),
+      Property::ListStyleType(list_style_type) => list_style_type.visit(visitor),
+      Property::ListStyle(list_style) => {
+        list_style.list_style_type.visit(visitor);
+        list_style.image.visit(visitor);
+      }
+      Property::Mask(masks, _) => {
+        for mask in masks.iter_mut() {
+          mask.visit(visitor);
+        }
+      }
+      Property::ClipPath(clip_path, _) => clip_path.visit(visitor),
+      Property::Cursor(cursor) => {
+        for image in cursor.images.iter_mut() {
+          image.url.visit(visitor);
+        }
+      }
+      Property::Filter(filters, _) | Property::BackdropFilter(filters, _) => filters.visit(visitor),
+      Property::Content(Content::List { items, .. }) => {
+        for item in items.iter_mut() {
+          if let ContentItem::Image { value } = item {
+            value.visit(visitor);
+          }
+        }
+      }
+      Property::Content(..) => {}
+      Property::Fill(paint) | Property::Stroke(paint) => paint.visit(visitor),
+      Property::MarkerStart(marker)
+      | Property::MarkerMid(marker)
+      | Property::MarkerEnd(marker)
+      | Property::Marker(marker) => marker.visit(visitor),
+      Property::Width(size)
+      | Property::Height(size)
+      | Property::MinWidth(size)
+      | Property::MinHeight(size)
+      | Property::BlockSize(size)
+      | Property::InlineSize(size)
+      | Property::MinBlockSize(size)
+      | Property::MinInlineSize(size) => size.visit(visitor),
+      Property::MaxWidth(size)
+      | Property::MaxHeight(size)
+      | Property::MaxBlockSize(size)
+      | Property::MaxInlineSize(size) => size.visit(visitor),
+      Property::Top(value)
+      | Property::Bottom(value)
+      | Property::Left(value)
+      | Property::Right(value)
+      | Property::InsetBlockStart(value)
+      | Property::InsetBlockEnd(value)
+      | Property::InsetInlineStart(value)
+      | Property::InsetInlineEnd(value)
+      | Property::MarginTop(value)
+      | Property::MarginBottom(value)
+      | Property::MarginLeft(value)
+      | Property::MarginRight(value)
+      | Property::MarginBlockStart(value)
+      | Property::MarginBlockEnd(value)
+      | Property::MarginInlineStart(value)
+      | Property::MarginInlineEnd(value)
+      | Property::PaddingTop(value)
+      | Property::PaddingBottom(value)
+      | Property::PaddingLeft(value)
+      | Property::PaddingRight(value)
+      | Property::PaddingBlockStart(value)
+      | Property::PaddingBlockEnd(value)
+      | Property::PaddingInlineStart(value)
+      | Property::PaddingInlineEnd(value)
+      | Property::ScrollMarginTop(value)
+      | Property::ScrollMarginBottom(value)
+      | Property::ScrollMarginLeft(value)
+      | Property::ScrollMarginRight(value)
+      | Property::ScrollMarginBlockStart(value)
+      | Property::ScrollMarginBlockEnd(value)
+      | Property::ScrollMarginInlineStart(value)
+      | Property::ScrollMarginInlineEnd(value)
+      | Property::ScrollPaddingTop(value)
+      | Property::ScrollPaddingBottom(value)
+      | Property::ScrollPaddingLeft(value)
+      | Property::ScrollPaddingRight(value)
+      | Property::ScrollPaddingBlockStart(value)
+      | Property::ScrollPaddingBlockEnd(value)
+      | Property::ScrollPaddingInlineStart(value)
+      | Property::ScrollPaddingInlineEnd(value)
+      | Property::FlexBasis(value, _)
+      | Property::FlexPreferredSize(value, _) => value.visit(visitor),
+      Property::Inset(value) => value.visit(visitor),
+      Property::InsetBlock(value) => value.visit(visitor),
+      Property::InsetInline(value) => value.visit(visitor),
+      Property::Margin(value) => value.visit(visitor),
+      Property::MarginBlock(value) => value.visit(visitor),
+      Property::MarginInline(value) => value.visit(visitor),
+      Property::Padding(value) => value.visit(visitor),
+      Property::PaddingBlock(value) => value.visit(visitor),
+      Property::PaddingInline(value) => value.visit(visitor),
+      Property::ScrollMargin(value) => value.visit(visitor),
+      Property::ScrollMarginBlock(value) => value.visit(visitor),
+      Property::ScrollMarginInline(value) => value.visit(visitor),
+      Property::ScrollPadding(value) => value.visit(visitor),
+      Property::ScrollPaddingBlock(value) => value.visit(visitor),
+      Property::ScrollPaddingInline(value) => value.visit(visitor),
+      Property::Flex(flex, _) => flex.basis.visit(visitor),
+      Property::StrokeWidth(value) | Property::StrokeDashoffset(value) => value.visit(visitor),
+      Property::StrokeDasharray(dasharray) => dasharray.visit(visitor),
+      Property::BorderSpacing(spacing) => spacing.visit(visitor),
+      Property::BorderTopWidth(width)
+      | Property::BorderBottomWidth(width)
+      | Property::BorderLeftWidth(width)
+      | Property::BorderRightWidth(width)
+      | Property::BorderBlockStartWidth(width)
+      | Property::BorderBlockEndWidth(width)
+      | Property::BorderInlineStartWidth(width)
+      | Property::BorderInlineEndWidth(width)
+      | Property::OutlineWidth(width) => width.visit(visitor),
+      Property::BorderTopLeftRadius(radius, _)
+      | Property::BorderTopRightRadius(radius, _)
+      | Property::BorderBottomLeftRadius(radius, _)
+      | Property::BorderBottomRightRadius(radius, _)
+      | Property::BorderStartStartRadius(radius)
+      | Property::BorderStartEndRadius(radius)
+      | Property::BorderEndStartRadius(radius)
+      | Property::BorderEndEndRadius(radius) => radius.visit(visitor),
+      Property::BorderRadius(radius, _) => radius.visit(visitor),
+      Property::BorderColor(value) => value.visit(visitor),
+      Property::BorderWidth(value) => value.visit(visitor),
+      Property::BorderBlockColor(value) => value.visit(visitor),
+      Property::BorderBlockWidth(value) => value.visit(visitor),
+      Property::BorderInlineColor(value) => value.visit(visitor),
+      Property::BorderInlineWidth(value) => value.visit(visitor),
+      Property::Border(border)
+      | Property::BorderTop(border)
+      | Property::BorderBottom(border)
+      | Property::BorderLeft(border)
+      | Property::BorderRight(border)
+      | Property::BorderBlock(border)
+      | Property::BorderBlockStart(border)
+      | Property::BorderBlockEnd(border)
+      | Property::BorderInline(border)
+      | Property::BorderInlineStart(border)
+      | Property::BorderInlineEnd(border) => border.visit(visitor),
+      Property::Outline(outline) => outline.visit(visitor),
+      Property::RowGap(gap)
+      | Property::ColumnGap(gap)
+      | Property::GridRowGap(gap)
+      | Property::GridColumnGap(gap) => gap.visit(visitor),
+      Property::Gap(gap) | Property::GridGap(gap) => gap.visit(visitor),
+      #[cfg(feature = "grid")]
+      Property::GridTemplateColumns(tracks) | Property::GridTemplateRows(tracks) => tracks.visit(visitor),
+      #[cfg(feature = "grid")]
+      Property::GridAutoColumns(tracks) | Property::GridAutoRows(tracks) => tracks.visit(visitor),
+      #[cfg(feature = "grid")]
+      Property::GridTemplate(template) => {
+        template.rows.visit(visitor);
+        template.columns.visit(visitor);
+      }
+      #[cfg(feature = "grid")]
+      Property::Grid(grid) => {
+        grid.rows.visit(visitor);
+        grid.columns.visit(visitor);
+        grid.auto_rows.visit(visitor);
+        grid.auto_columns.visit(visitor);
+      }
+      Property::FontSize(size) => size.visit(visitor),
+      Property::LineHeight(line_height) => line_height.visit(visitor),
+      Property::Font(font) => {
+        font.size.visit(visitor);
+        font.line_height.visit(visitor);
+      }
+      Property::VerticalAlign(vertical_align) => vertical_align.visit(visitor),
+      Property::Transform(transforms, _) => transforms.visit(visitor),
+      Property::TransformOrigin(position, _) | Property::PerspectiveOrigin(position, _) => position.visit(visitor),
+      Property::Perspective(perspective, _) => perspective.visit(visitor),
+      Property::Translate(translate) => translate.visit(visitor),
+      Property::TabSize(value, _) => value.visit(visitor),
+      Property::WordSpacing(spacing) | Property::LetterSpacing(spacing) => spacing.visit(visitor),
+      Property::TextIndent(text_indent) => text_indent.value.visit(visitor),
+      Property::TextDecorationThickness(thickness) => thickness.visit(visitor),
+      Property::TextDecoration(text_decoration, _) => {
+        text_decoration.thickness.visit(visitor);
+        text_decoration.color.visit(visitor);
+      }
+      Property::TextEmphasis(text_emphasis, _) => text_emphasis.color.visit(visitor),
+      // The remaining properties do not contain urls, colors, or lengths.
+      Property::BackgroundRepeat(..)
+      | Property::BackgroundAttachment(..)
+      | Property::BackgroundClip(..)
+      | Property::BackgroundOrigin(..)
+      | Property::Opacity(..)
+      | Property::Display(..)
+      | Property::Visibility(..)
+      | Property::BoxSizing(..)
+      | Property::AspectRatio(..)
+      | Property::Overflow(..)
+      | Property::OverflowX(..)
+      | Property::OverflowY(..)
+      | Property::TextOverflow(..)
+      | Property::Position(..)
+      | Property::BorderTopStyle(..)
+      | Property::BorderBottomStyle(..)
+      | Property::BorderLeftStyle(..)
+      | Property::BorderRightStyle(..)
+      | Property::BorderBlockStartStyle(..)
+      | Property::BorderBlockEndStyle(..)
+      | Property::BorderInlineStartStyle(..)
+      | Property::BorderInlineEndStyle(..)
+      | Property::BorderImageRepeat(..)
+      | Property::BorderImageSlice(..)
+      | Property::BorderStyle(..)
+      | Property::BorderBlockStyle(..)
+      | Property::BorderInlineStyle(..)
+      | Property::OutlineStyle(..)
+      | Property::FlexDirection(..)
+      | Property::FlexWrap(..)
+      | Property::FlexFlow(..)
+      | Property::FlexGrow(..)
+      | Property::FlexShrink(..)
+      | Property::Order(..)
+      | Property::AlignContent(..)
+      | Property::JustifyContent(..)
+      | Property::PlaceContent(..)
+      | Property::AlignSelf(..)
+      | Property::JustifySelf(..)
+      | Property::PlaceSelf(..)
+      | Property::AlignItems(..)
+      | Property::JustifyItems(..)
+      | Property::PlaceItems(..)
+      | Property::BoxOrient(..)
+      | Property::BoxDirection(..)
+      | Property::BoxOrdinalGroup(..)
+      | Property::BoxAlign(..)
+      | Property::BoxFlex(..)
+      | Property::BoxFlexGroup(..)
+      | Property::BoxPack(..)
+      | Property::BoxLines(..)
+      | Property::FlexPack(..)
+      | Property::FlexOrder(..)
+      | Property::FlexAlign(..)
+      | Property::FlexItemAlign(..)
+      | Property::FlexLinePack(..)
+      | Property::FlexPositive(..)
+      | Property::FlexNegative(..)
+      | Property::FontWeight(..)
+      | Property::FontStretch(..)
+      | Property::FontFamily(..)
+      | Property::FontStyle(..)
+      | Property::FontVariantCaps(..)
+      | Property::FontPalette(..)
+      | Property::TransitionProperty(..)
+      | Property::TransitionDuration(..)
+      | Property::TransitionDelay(..)
+      | Property::TransitionTimingFunction(..)
+      | Property::Transition(..)
+      | Property::AnimationName(..)
+      | Property::AnimationDuration(..)
+      | Property::AnimationTimingFunction(..)
+      | Property::AnimationIterationCount(..)
+      | Property::AnimationDirection(..)
+      | Property::AnimationPlayState(..)
+      | Property::AnimationDelay(..)
+      | Property::AnimationFillMode(..)
+      | Property::Animation(..)
+      | Property::TransformStyle(..)
+      | Property::TransformBox(..)
+      | Property::BackfaceVisibility(..)
+      | Property::Rotate(..)
+      | Property::Scale(..)
+      | Property::TextTransform(..)
+      | Property::WhiteSpace(..)
+      | Property::WordBreak(..)
+      | Property::LineBreak(..)
+      | Property::Hyphens(..)
+      | Property::OverflowWrap(..)
+      | Property::WordWrap(..)
+      | Property::TextAlign(..)
+      | Property::TextAlignLast(..)
+      | Property::TextJustify(..)
+      | Property::TextDecorationLine(..)
+      | Property::TextDecorationStyle(..)
+      | Property::TextDecorationSkipInk(..)
+      | Property::TextEmphasisStyle(..)
+      | Property::TextEmphasisPosition(..)
+      | Property::BoxDecorationBreak(..)
+      | Property::Resize(..)
+      | Property::CaretShape(..)
+      | Property::UserSelect(..)
+      | Property::Appearance(..)
+      | Property::WillChange(..)
+      | Property::ListStylePosition(..)
+      | Property::MarkerSide(..)
+      | Property::CounterReset(..)
+      | Property::CounterSet(..)
+      | Property::CounterIncrement(..)
+      | Property::Composes(..)
+      | Property::FillRule(..)
+      | Property::FillOpacity(..)
+      | Property::StrokeOpacity(..)
+      | Property::StrokeLinecap(..)
+      | Property::StrokeLinejoin(..)
+      | Property::StrokeMiterlimit(..)
+      | Property::ColorInterpolation(..)
+      | Property::ColorInterpolationFilters(..)
+      | Property::ColorRendering(..)
+      | Property::ShapeRendering(..)
+      | Property::TextRendering(..)
+      | Property::ImageRendering(..)
+      | Property::ClipRule(..)
+      | Property::MaskMode(..)
+      | Property::MaskRepeat(..)
+      | Property::MaskClip(..)
+      | Property::MaskOrigin(..)
+      | Property::MaskComposite(..)
+      | Property::MaskType(..)
+      | Property::MaskBorderMode(..)
+      | Property::MaskBorderSlice(..)
+      | Property::MaskBorderRepeat(..)
+      | Property::WebKitMaskComposite(..)
+      | Property::WebKitMaskSourceType(..)
+      | Property::WebKitMaskBoxImageSlice(..)
+      | Property::WebKitMaskBoxImageRepeat(..)
+      | Property::ZIndex(..)
+      | Property::Contain(..)
+      | Property::ContainerType(..)
+      | Property::ContainerName(..)
+      | Property::Container(..) => {}
+      #[cfg(feature = "grid")]
+      Property::GridAutoFlow(..)
+      | Property::GridTemplateAreas(..)
+      | Property::GridRowStart(..)
+      | Property::GridRowEnd(..)
+      | Property::GridColumnStart(..)
+      | Property::GridColumnEnd(..)
+      | Property::GridRow(..)
+      | Property::GridColumn(..)
+      | Property::GridArea(..) => {}
+    }
+  }
+}
+
+impl<'i> Visit<'i> for TokenList<'i> {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    for token in self.0.iter_mut() {
+      match token {
+        TokenOrValue::Url(url) => url.visit(visitor),
+        TokenOrValue::Color(color) => color.visit(visitor),
+        TokenOrValue::UnresolvedColor(UnresolvedColor::RGB { alpha, .. })
+        | TokenOrValue::UnresolvedColor(UnresolvedColor::HSL { alpha, .. }) => alpha.visit(visitor),
+        TokenOrValue::Var(var) => {
+          if let Some(fallback) = &mut var.fallback {
+            fallback.visit(visitor);
+          }
+        }
+        TokenOrValue::Env(env) => {
+          if let Some(fallback) = &mut env.fallback {
+            fallback.visit(visitor);
+          }
+        }
+        TokenOrValue::Token(..) => {}
+      }
+    }
+  }
+}
+
+impl<'i> Visit<'i> for Url<'i> {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    visitor.visit_url(self)
+  }
+}
+
+impl<'i> Visit<'i> for CssColor {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    visitor.visit_color(self)
+  }
+}
+
+impl<'i> Visit<'i> for LengthValue {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    visitor.visit_length(self)
+  }
+}
+
+impl<'i> Visit<'i> for Length {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    match self {
+      Length::Value(value) => value.visit(visitor),
+      Length::Calc(calc) => calc.visit(visitor),
+    }
+  }
+}
+
+impl<'i> Visit<'i> for Angle {
+  // Angles are not currently visited, but may appear within gradients.
+  fn visit<V: Visitor<'i>>(&mut self, _visitor: &mut V) {}
+}
+
+impl<'i, D: Visit<'i>> Visit<'i> for DimensionPercentage<D> {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    match self {
+      DimensionPercentage::Dimension(value) => value.visit(visitor),
+      DimensionPercentage::Percentage(_) => {}
+      DimensionPercentage::Calc(calc) => calc.visit(visitor),
+    }
+  }
+}
+
+impl<'i, T: Visit<'i>> Visit<'i> for Calc<T> {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    match self {
+      Calc::Value(value) => value.visit(visitor),
+      Calc::Number(_) => {}
+      Calc::Sum(a, b) => {
+        a.visit(visitor);
+        b.visit(visitor);
+      }
+      Calc::Product(_, calc) => calc.visit(visitor),
+      Calc::Function(f) => match &mut **f {
+        MathFunction::Calc(a) | MathFunction::Abs(a) | MathFunction::Sign(a) => a.visit(visitor),
+        MathFunction::Min(args) | MathFunction::Max(args) | MathFunction::Hypot(args) => {
+          for arg in args.iter_mut() {
+            arg.visit(visitor);
+          }
+        }
+        MathFunction::Clamp(a, b, c) => {
+          a.visit(visitor);
+          b.visit(visitor);
+          c.visit(visitor);
+        }
+        MathFunction::Round(_, a, b) | MathFunction::Rem(a, b) | MathFunction::Mod(a, b) => {
+          a.visit(visitor);
+          b.visit(visitor);
+        }
+      },
+    }
+  }
+}
+
+impl<'i> Visit<'i> for LengthPercentageOrAuto {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    if let LengthPercentageOrAuto::LengthPercentage(value) = self {
+      value.visit(visitor);
+    }
+  }
+}
+
+impl<'i> Visit<'i> for Size {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    match self {
+      Size::LengthPercentage(value) | Size::FitContentFunction(value) => value.visit(visitor),
+      _ => {}
+    }
+  }
+}
+
+impl<'i> Visit<'i> for MaxSize {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    match self {
+      MaxSize::LengthPercentage(value) | MaxSize::FitContentFunction(value) => value.visit(visitor),
+      _ => {}
+    }
+  }
+}
+
+impl<'i> Visit<'i> for Image<'i> {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    match self {
+      Image::None => {}
+      Image::Url(url) => url.visit(visitor),
+      Image::Gradient(gradient) => gradient.visit(visitor),
+      Image::ImageSet(image_set) => {
+        for option in image_set.options.iter_mut() {
+          option.image.visit(visitor);
+        }
+      }
+    }
+  }
+}
+
+impl<'i> Visit<'i> for Gradient {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    match self {
+      Gradient::Linear(linear, _) | Gradient::RepeatingLinear(linear, _) => linear.items.visit(visitor),
+      Gradient::Radial(radial, _) | Gradient::RepeatingRadial(radial, _) => radial.items.visit(visitor),
+      Gradient::Conic(conic) | Gradient::RepeatingConic(conic) => conic.items.visit(visitor),
+      Gradient::WebKitGradient(WebKitGradient::Linear { stops, .. })
+      | Gradient::WebKitGradient(WebKitGradient::Radial { stops, .. }) => {
+        for stop in stops.iter_mut() {
+          stop.color.visit(visitor);
+        }
+      }
+    }
+  }
+}
+
+impl<'i, D: Visit<'i>> Visit<'i> for Vec<GradientItem<D>> {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    for item in self.iter_mut() {
+      match item {
+        GradientItem::ColorStop(stop) => {
+          stop.color.visit(visitor);
+          if let Some(position) = &mut stop.position {
+            position.visit(visitor);
+          }
+        }
+        GradientItem::Hint(hint) => hint.visit(visitor),
+      }
+    }
+  }
+}
+
+impl<'i> Visit<'i> for SVGPaint<'i> {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    match self {
+      SVGPaint::Url(url, fallback) => {
+        url.visit(visitor);
+        if let Some(SVGPaintFallback::Color(color)) = fallback {
+          color.visit(visitor);
+        }
+      }
+      SVGPaint::Color(color) => color.visit(visitor),
+      _ => {}
+    }
+  }
+}
+
+/// Implements [Visit](Visit) for a struct by visiting each of the given fields in order.
+macro_rules! impl_visit_fields {
+  ($($t: ty { $($field: ident),+ })+) => {
+    $(
+      impl<'i> Visit<'i> for $t {
+        fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+          $(self.$field.visit(visitor);)+
+        }
+      }
+    )+
+  };
+}
+
+impl_visit_fields! {
+  Margin { top, right, bottom, left }
+  Padding { top, right, bottom, left }
+  ScrollMargin { top, right, bottom, left }
+  ScrollPadding { top, right, bottom, left }
+  Inset { top, right, bottom, left }
+  MarginBlock { block_start, block_end }
+  MarginInline { inline_start, inline_end }
+  PaddingBlock { block_start, block_end }
+  PaddingInline { inline_start, inline_end }
+  ScrollMarginBlock { block_start, block_end }
+  ScrollMarginInline { inline_start, inline_end }
+  ScrollPaddingBlock { block_start, block_end }
+  ScrollPaddingInline { inline_start, inline_end }
+  InsetBlock { block_start, block_end }
+  InsetInline { inline_start, inline_end }
+  BorderColor { top, right, bottom, left }
+  BorderWidth { top, right, bottom, left }
+  BorderBlockColor { start, end }
+  BorderBlockWidth { start, end }
+  BorderInlineColor { start, end }
+  BorderInlineWidth { start, end }
+  BorderRadius { top_left, top_right, bottom_right, bottom_left }
+  BorderImage<'i> { source, width, outset }
+  MaskBorder<'i> { source, width, outset }
+  Background<'i> { image, color, position, size }
+  Mask<'i> { image, position, size }
+  BackgroundPosition { x, y }
+  Position { x, y }
+  BoxShadow { color, x_offset, y_offset, blur, spread }
+  TextShadow { color, x_offset, y_offset, blur, spread }
+  DropShadow { color, x_offset, y_offset, blur }
+  Gap { row, column }
+  Translate { x, y, z }
+}
+
+impl<'i, T: Visit<'i>> Visit<'i> for Rect<T> {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    self.0.visit(visitor);
+    self.1.visit(visitor);
+    self.2.visit(visitor);
+    self.3.visit(visitor);
+  }
+}
+
+impl<'i, T: Visit<'i>> Visit<'i> for Size2D<T> {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    self.0.visit(visitor);
+    self.1.visit(visitor);
+  }
+}
+
+impl<'i> Visit<'i> for LengthOrNumber {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    if let LengthOrNumber::Length(length) = self {
+      length.visit(visitor);
+    }
+  }
+}
+
+impl<'i, S> Visit<'i> for PositionComponent<S> {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    match self {
+      PositionComponent::Center => {}
+      PositionComponent::Length(length) => length.visit(visitor),
+      PositionComponent::Side(_, offset) => {
+        if let Some(offset) = offset {
+          offset.visit(visitor);
+        }
+      }
+    }
+  }
+}
+
+impl<'i> Visit<'i> for BackgroundSize {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    if let BackgroundSize::Explicit { width, height } = self {
+      width.visit(visitor);
+      height.visit(visitor);
+    }
+  }
+}
+
+impl<'i> Visit<'i> for BorderSideWidth {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    if let BorderSideWidth::Length(length) = self {
+      length.visit(visitor);
+    }
+  }
+}
+
+impl<'i, S, const P: u8> Visit<'i> for GenericBorder<S, P> {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    self.width.visit(visitor);
+    self.color.visit(visitor);
+  }
+}
+
+impl<'i> Visit<'i> for BorderImageSideWidth {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    if let BorderImageSideWidth::LengthPercentage(value) = self {
+      value.visit(visitor);
+    }
+  }
+}
+
+impl<'i> Visit<'i> for ColorOrAuto {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    if let ColorOrAuto::Color(color) = self {
+      color.visit(visitor);
+    }
+  }
+}
+
+impl<'i> Visit<'i> for GapValue {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    if let GapValue::LengthPercentage(value) = self {
+      value.visit(visitor);
+    }
+  }
+}
+
+impl<'i> Visit<'i> for FontSize {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    if let FontSize::Length(value) = self {
+      value.visit(visitor);
+    }
+  }
+}
+
+impl<'i> Visit<'i> for LineHeight {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    if let LineHeight::Length(value) = self {
+      value.visit(visitor);
+    }
+  }
+}
+
+impl<'i> Visit<'i> for VerticalAlign {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    if let VerticalAlign::Length(value) = self {
+      value.visit(visitor);
+    }
+  }
+}
+
+impl<'i> Visit<'i> for Spacing {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    if let Spacing::Length(length) = self {
+      length.visit(visitor);
+    }
+  }
+}
+
+impl<'i> Visit<'i> for TextDecorationThickness {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    if let TextDecorationThickness::LengthPercentage(value) = self {
+      value.visit(visitor);
+    }
+  }
+}
+
+impl<'i> Visit<'i> for TransformList {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    for transform in self.0.iter_mut() {
+      transform.visit(visitor);
+    }
+  }
+}
+
+impl<'i> Visit<'i> for Transform {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    match self {
+      Transform::Translate(x, y) => {
+        x.visit(visitor);
+        y.visit(visitor);
+      }
+      Transform::TranslateX(value) | Transform::TranslateY(value) => value.visit(visitor),
+      Transform::TranslateZ(length) | Transform::Perspective(length) => length.visit(visitor),
+      Transform::Translate3d(x, y, z) => {
+        x.visit(visitor);
+        y.visit(visitor);
+        z.visit(visitor);
+      }
+      _ => {}
+    }
+  }
+}
+
+impl<'i> Visit<'i> for Perspective {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    if let Perspective::Length(length) = self {
+      length.visit(visitor);
+    }
+  }
+}
+
+impl<'i> Visit<'i> for FilterList<'i> {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    if let FilterList::Filters(filters) = self {
+      for filter in filters.iter_mut() {
+        match filter {
+          Filter::Blur(length) => length.visit(visitor),
+          Filter::DropShadow(drop_shadow) => drop_shadow.visit(visitor),
+          Filter::Url(url) => url.visit(visitor),
+          _ => {}
+        }
+      }
+    }
+  }
+}
+
+impl<'i> Visit<'i> for ListStyleType<'i> {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    if let ListStyleType::CounterStyle(CounterStyle::Symbols(_, symbols)) = self {
+      for symbol in symbols.iter_mut() {
+        if let Symbol::Image(image) = symbol {
+          image.visit(visitor);
+        }
+      }
+    }
+  }
+}
+
+impl<'i> Visit<'i> for StrokeDasharray {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    if let StrokeDasharray::Values(values) = self {
+      for value in values.iter_mut() {
+        value.visit(visitor);
+      }
+    }
+  }
+}
+
+impl<'i> Visit<'i> for Marker<'i> {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    if let Marker::Url(url) = self {
+      url.visit(visitor);
+    }
+  }
+}
+
+impl<'i> Visit<'i> for ClipPath<'i> {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    match self {
+      ClipPath::Url(url) => url.visit(visitor),
+      ClipPath::Shape(shape, _) => shape.visit(visitor),
+      _ => {}
+    }
+  }
+}
+
+impl<'i> Visit<'i> for BasicShape {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    match self {
+      BasicShape::Inset(inset) => {
+        inset.rect.visit(visitor);
+        inset.radius.visit(visitor);
+      }
+      BasicShape::Circle(circle) => {
+        circle.radius.visit(visitor);
+        circle.position.visit(visitor);
+      }
+      BasicShape::Ellipse(ellipse) => {
+        ellipse.radius_x.visit(visitor);
+        ellipse.radius_y.visit(visitor);
+        ellipse.position.visit(visitor);
+      }
+      BasicShape::Polygon(polygon) => {
+        for point in polygon.points.iter_mut() {
+          point.x.visit(visitor);
+          point.y.visit(visitor);
+        }
+      }
+    }
+  }
+}
+
+impl<'i> Visit<'i> for ShapeRadius {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    if let ShapeRadius::LengthPercentage(value) = self {
+      value.visit(visitor);
+    }
+  }
+}
+
+#[cfg(feature = "grid")]
+impl<'i> Visit<'i> for TrackSizing<'i> {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    if let TrackSizing::TrackList(list) = self {
+      for item in list.items.iter_mut() {
+        match item {
+          TrackListItem::TrackSize(size) => size.visit(visitor),
+          TrackListItem::TrackRepeat(repeat) => {
+            for size in repeat.track_sizes.iter_mut() {
+              size.visit(visitor);
+            }
+          }
+        }
+      }
+    }
+  }
+}
+
+#[cfg(feature = "grid")]
+impl<'i> Visit<'i> for TrackSizeList {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    for size in self.0.iter_mut() {
+      size.visit(visitor);
+    }
+  }
+}
+
+#[cfg(feature = "grid")]
+impl<'i> Visit<'i> for TrackSize {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    match self {
+      TrackSize::TrackBreadth(breadth) => breadth.visit(visitor),
+      TrackSize::MinMax(min, max) => {
+        min.visit(visitor);
+        max.visit(visitor);
+      }
+      TrackSize::FitContent(value) => value.visit(visitor),
+    }
+  }
+}
+
+#[cfg(feature = "grid")]
+impl<'i> Visit<'i> for TrackBreadth {
+  fn visit<V: Visitor<'i>>(&mut self, visitor: &mut V) {
+    if let TrackBreadth::Length(value) = self {
+      value.visit(visitor);
+    }
+  }
+}